@@ -0,0 +1,170 @@
+// The references for the accuracy report are the Wronskians relating the functions of orders nu and
+// nu + 1, which hold for all orders and complex arguments. The residual of a Wronskian, relative to
+// the larger of its value and the magnitude of its products, is a lower bound on the relative errors
+// of the functions entering it.
+use amos_rs::zairy::{aip, bip, zairy_ierr, zbiry_ierr};
+use amos_rs::zbesh::zbesh_ierr;
+use amos_rs::zbesi::zbesi_vec_ierr;
+use amos_rs::zbesj::zbesj_vec_ierr;
+use amos_rs::zbesk::zbesk_vec_ierr;
+use amos_rs::zbesy::zbesy_vec_ierr;
+use num::complex::Complex;
+use std::f64::consts::PI;
+
+// NZ and IERR of an AMOS routine
+#[derive(Clone, Copy)]
+pub struct Codes {
+    pub nz: i32,
+    pub ierr: i32,
+}
+
+impl Codes {
+    fn new(nz: i32, ierr: i32) -> Self {
+        Codes { nz, ierr }
+    }
+}
+
+pub struct Point {
+    // Relative residual of the Wronskian, which is NaN if any of the products is not finite
+    pub err: f64,
+    // Codes of the two routines in Check::routines, for the first two orders of the sequences
+    pub codes: [Codes; 2],
+}
+
+#[derive(Clone, Copy)]
+pub enum Check {
+    // I_nu(z) K_{nu + 1}(z) + I_{nu + 1}(z) K_nu(z) = 1 / z, see DLMF 10.28.2
+    BesselIK,
+    // J_{nu + 1}(z) Y_nu(z) - J_nu(z) Y_{nu + 1}(z) = 2 / (pi z), see DLMF 10.5.4
+    BesselJY,
+    // H^(1)_{nu + 1}(z) H^(2)_nu(z) - H^(1)_nu(z) H^(2)_{nu + 1}(z) = -4i / (pi z), see DLMF 10.5.5
+    Hankel,
+    // Ai(z) Bi'(z) - Ai'(z) Bi(z) = 1 / pi, see DLMF 9.2.7
+    Airy,
+}
+
+impl Check {
+    pub const ALL: [Check; 4] = [Check::BesselIK, Check::BesselJY, Check::Hankel, Check::Airy];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Check::BesselIK => "ik",
+            Check::BesselJY => "jy",
+            Check::Hankel => "hankel",
+            Check::Airy => "airy",
+        }
+    }
+
+    pub fn routines(&self) -> [&'static str; 2] {
+        match self {
+            Check::BesselIK => ["zbesi", "zbesk"],
+            Check::BesselJY => ["zbesj", "zbesy"],
+            Check::Hankel => ["zbesh1", "zbesh2"],
+            Check::Airy => ["zairy", "zbiry"],
+        }
+    }
+
+    // The Airy functions have no order
+    pub fn has_order(&self) -> bool {
+        !matches!(self, Check::Airy)
+    }
+
+    pub fn evaluate(&self, nu: f64, z: Complex<f64>) -> Point {
+        // For negative orders the sequences run from nu to nu - 1, and the Wronskian of order nu - 1
+        // is used instead
+        let (lo, hi) = match nu.is_sign_negative() {
+            true => (1, 0),
+            false => (0, 1),
+        };
+
+        // The Wronskian is the sum of two products, and the codes are those of the sequences of
+        // the first two orders, which include the reflection for negative orders
+        let (terms, w_exact, codes) = match self {
+            Check::BesselIK => {
+                let (i, i_nz, i_ierr) = zbesi_vec_ierr(nu, z, 1, 2);
+                let (k, k_nz, k_ierr) = zbesk_vec_ierr(nu, z, 1, 2);
+
+                (
+                    [i[lo] * k[hi], i[hi] * k[lo]],
+                    1.0 / z,
+                    [Codes::new(i_nz, i_ierr), Codes::new(k_nz, k_ierr)],
+                )
+            }
+            Check::BesselJY => {
+                let (j, j_nz, j_ierr) = zbesj_vec_ierr(nu, z, 1, 2);
+                let (y, y_nz, y_ierr) = zbesy_vec_ierr(nu, z, 1, 2);
+
+                (
+                    [j[hi] * y[lo], -j[lo] * y[hi]],
+                    2.0 / (PI * z),
+                    [Codes::new(j_nz, j_ierr), Codes::new(y_nz, y_ierr)],
+                )
+            }
+            Check::Hankel => {
+                let (h1, h1_nz, h1_ierr) = zbesh_ierr(nu, z, 1, 1, 2);
+                let (h2, h2_nz, h2_ierr) = zbesh_ierr(nu, z, 1, 2, 2);
+
+                (
+                    [h1[hi] * h2[lo], -h1[lo] * h2[hi]],
+                    Complex::new(0.0, -4.0) / (PI * z),
+                    [Codes::new(h1_nz, h1_ierr), Codes::new(h2_nz, h2_ierr)],
+                )
+            }
+            // The codes are those of Ai and Bi, where ZBIRY has no NZ, as Bi does not underflow
+            Check::Airy => {
+                let (ai, ai_nz, ai_ierr) = zairy_ierr(z, 0, 1);
+                let (bi, bi_ierr) = zbiry_ierr(z, 0, 1);
+
+                (
+                    [ai * bip(z), -aip(z) * bi],
+                    Complex::new(1.0 / PI, 0.0),
+                    [Codes::new(ai_nz, ai_ierr), Codes::new(0, bi_ierr)],
+                )
+            }
+        };
+
+        let w = terms[0] + terms[1];
+
+        // Where the functions grow exponentially, e.g., I and K in the left half plane, the
+        // products cancel, and relative errors of the functions of eps give a residual of eps
+        // times the products
+        let scale = w_exact.norm().max(terms[0].norm() + terms[1].norm());
+
+        let err = match w.is_finite() {
+            true => (w - w_exact).norm() / scale,
+            false => f64::NAN,
+        };
+
+        Point { err, codes }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Away from the losses of significance for large |z|, the Wronskians hold to near machine
+    // precision
+    #[test]
+    fn test_wronskians() {
+        for check in Check::ALL {
+            for nu in [0.0, 0.3, 1.0, 7.5, -2.6] {
+                for r in [0.1, 1.0, 10.0] {
+                    for k in -3..=4 {
+                        let z = Complex::from_polar(r, k as f64 * PI / 4.0);
+                        let point = check.evaluate(nu, z);
+
+                        assert!(
+                            point.err < 1e-12,
+                            "{}: {} at nu = {nu}, z = {z}",
+                            check.name(),
+                            point.err
+                        );
+                        assert_eq!(point.codes[0].ierr, 0);
+                        assert_eq!(point.codes[1].ierr, 0);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,70 @@
+// Heatmaps of log10 of the relative error as binary PPM (P6) images, which need no image library
+// and are read by most image viewers and converters.
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+// The color scale runs from blue at machine precision to red where all digits are lost
+pub const MIN_LOG10_ERR: f64 = -16.0;
+pub const MAX_LOG10_ERR: f64 = 0.0;
+
+// NaN or infinite results, typically with IERR = 2, 4 or 5
+const NOT_FINITE: [u8; 3] = [0, 0, 0];
+
+// Piecewise linear through blue, cyan, green, yellow and red
+fn color(log10_err: f64) -> [u8; 3] {
+    if log10_err.is_nan() {
+        return NOT_FINITE;
+    }
+
+    const STOPS: [[f64; 3]; 5] = [
+        [0.0, 0.0, 255.0],
+        [0.0, 255.0, 255.0],
+        [0.0, 255.0, 0.0],
+        [255.0, 255.0, 0.0],
+        [255.0, 0.0, 0.0],
+    ];
+
+    let t = ((log10_err - MIN_LOG10_ERR) / (MAX_LOG10_ERR - MIN_LOG10_ERR)).clamp(0.0, 1.0);
+    let x = t * (STOPS.len() - 1) as f64;
+    let i = (x.floor() as usize).min(STOPS.len() - 2);
+    let s = x - i as f64;
+
+    let mut rgb = [0; 3];
+
+    for c in 0..3 {
+        rgb[c] = (STOPS[i][c] + s * (STOPS[i + 1][c] - STOPS[i][c])).round() as u8;
+    }
+
+    rgb
+}
+
+// Writes the row-major grid of log10 errors, where the first row is the top of the image
+pub fn write_ppm(path: &Path, width: usize, height: usize, log10_err: &[f64]) -> io::Result<()> {
+    assert_eq!(log10_err.len(), width * height);
+
+    let mut file = BufWriter::new(File::create(path)?);
+
+    write!(file, "P6\n{width} {height}\n255\n")?;
+
+    for &err in log10_err {
+        file.write_all(&color(err))?;
+    }
+
+    file.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_color() {
+        assert_eq!(color(MIN_LOG10_ERR), [0, 0, 255]);
+        assert_eq!(color(-20.0), [0, 0, 255]);
+        assert_eq!(color(-8.0), [0, 255, 0]);
+        assert_eq!(color(MAX_LOG10_ERR), [255, 0, 0]);
+        assert_eq!(color(3.0), [255, 0, 0]);
+        assert_eq!(color(f64::NAN), NOT_FINITE);
+    }
+}
@@ -0,0 +1,242 @@
+// Maps the accuracy of amos-rs over the complex plane. Each Wronskian in checks.rs is evaluated on a
+// grid of log10 |z| (rows, largest at the top) and arg z (columns, from -pi to pi), for each order,
+// and written to the output directory as <check>_nu<nu>.csv along with a heatmap
+// <check>_nu<nu>.ppm of log10 of the relative error, see heatmap.rs for the colors. The CSV files
+// also hold the NZ and IERR of the AMOS routines, and a summary is printed per order:
+//
+//     cargo run --release -p amos-accuracy -- --out accuracy-report --nu 0,0.5,10,-3.3
+mod checks;
+mod heatmap;
+
+use checks::Check;
+use num::complex::Complex;
+use std::env;
+use std::f64::consts::PI;
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::PathBuf;
+use std::process;
+
+const USAGE: &str = "Usage: accuracy [--out DIR] [--size N] [--nu NU,...] [--log10-abs-z MIN,MAX]";
+
+struct Options {
+    out: PathBuf,
+    // The grid is size x size
+    size: usize,
+    orders: Vec<f64>,
+    log10_abs_z: (f64, f64),
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            out: PathBuf::from("accuracy-report"),
+            size: 200,
+            orders: vec![0.0, 0.5, 1.0, 10.0, 100.0, -3.3],
+            // The losses of significance start at |z| = 1.3e8, see amos/zbesi.f lines 92-105
+            log10_abs_z: (-3.0, 10.0),
+        }
+    }
+}
+
+fn parse_list(value: &str) -> Option<Vec<f64>> {
+    value.split(',').map(|v| v.trim().parse().ok()).collect()
+}
+
+fn parse_args(args: &[String]) -> Option<Options> {
+    let mut options = Options::default();
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        let value = args.next()?;
+
+        match arg.as_str() {
+            "--out" => options.out = PathBuf::from(value),
+            "--size" => options.size = value.parse().ok().filter(|&size| size > 0)?,
+            "--nu" => options.orders = parse_list(value)?,
+            "--log10-abs-z" => match parse_list(value)?.as_slice() {
+                &[min, max] if min < max => options.log10_abs_z = (min, max),
+                _ => return None,
+            },
+            _ => return None,
+        }
+    }
+
+    Some(options)
+}
+
+// The grid points are the midpoints of the pixels, such that arg z = -pi, where the Bessel
+// functions are not defined, is avoided
+fn grid(options: &Options) -> Vec<Complex<f64>> {
+    let n = options.size;
+    let (min, max) = options.log10_abs_z;
+
+    let mut z = Vec::with_capacity(n * n);
+
+    for i in 0..n {
+        let log10_r = max - (i as f64 + 0.5) / n as f64 * (max - min);
+
+        for j in 0..n {
+            let theta = -PI + (j as f64 + 0.5) / n as f64 * 2.0 * PI;
+
+            z.push(Complex::from_polar(10.0_f64.powf(log10_r), theta));
+        }
+    }
+
+    z
+}
+
+fn write_report(check: Check, nu: f64, options: &Options, stem: &str) -> io::Result<()> {
+    let z = grid(options);
+    let points: Vec<checks::Point> = z.iter().map(|&z| check.evaluate(nu, z)).collect();
+    let log10_err: Vec<f64> = points.iter().map(|point| point.err.log10()).collect();
+
+    let [first, second] = check.routines();
+    let mut csv = BufWriter::new(File::create(options.out.join(format!("{stem}.csv")))?);
+
+    writeln!(
+        csv,
+        "abs_z,arg_z,nu,log10_err,nz_{first},ierr_{first},nz_{second},ierr_{second}"
+    )?;
+
+    for ((z, point), err) in z.iter().zip(&points).zip(&log10_err) {
+        let [c1, c2] = point.codes;
+
+        writeln!(
+            csv,
+            "{:e},{},{nu},{err},{},{},{},{}",
+            z.norm(),
+            z.arg(),
+            c1.nz,
+            c1.ierr,
+            c2.nz,
+            c2.ierr
+        )?;
+    }
+
+    csv.flush()?;
+
+    heatmap::write_ppm(
+        &options.out.join(format!("{stem}.ppm")),
+        options.size,
+        options.size,
+        &log10_err,
+    )?;
+
+    print_summary(stem, &z, &points, &log10_err);
+
+    Ok(())
+}
+
+// The maximum error is reported separately for the points where AMOS reports neither underflow nor
+// an error, as the others are expected to be inaccurate
+fn print_summary(stem: &str, z: &[Complex<f64>], points: &[checks::Point], log10_err: &[f64]) {
+    let clean = |point: &checks::Point| {
+        point
+            .codes
+            .iter()
+            .all(|codes| codes.nz == 0 && codes.ierr == 0)
+    };
+
+    let worst = log10_err
+        .iter()
+        .zip(points)
+        .enumerate()
+        .filter(|(_, (err, point))| !err.is_nan() && clean(point))
+        .max_by(|(_, (a, _)), (_, (b, _))| a.total_cmp(b));
+
+    let not_finite = log10_err.iter().filter(|err| err.is_nan()).count();
+
+    let mut ierr = [0; 6];
+    let mut underflow = 0;
+
+    for point in points {
+        let worst_ierr = point.codes.iter().map(|codes| codes.ierr).max().unwrap();
+
+        ierr[worst_ierr.clamp(0, 5) as usize] += 1;
+
+        if point.codes.iter().any(|codes| codes.nz > 0) {
+            underflow += 1;
+        }
+    }
+
+    match worst {
+        Some((i, (err, _))) => print!("{stem:<16} max log10 err {err:6.2} at z = {:.3e}", z[i]),
+        None => print!("{stem:<16} no clean results"),
+    }
+
+    println!(
+        ", not finite: {not_finite}, NZ > 0: {underflow}, IERR 2-5: {:?}",
+        &ierr[2..]
+    );
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let options = parse_args(&args).unwrap_or_else(|| {
+        eprintln!("{USAGE}");
+        process::exit(2);
+    });
+
+    fs::create_dir_all(&options.out).unwrap_or_else(|err| {
+        eprintln!("Failed to create {}: {err}", options.out.display());
+        process::exit(1);
+    });
+
+    for check in Check::ALL {
+        let orders = match check.has_order() {
+            true => options.orders.clone(),
+            false => vec![0.0],
+        };
+
+        for nu in orders {
+            let stem = match check.has_order() {
+                true => format!("{}_nu{nu}", check.name()),
+                false => check.name().to_string(),
+            };
+
+            write_report(check, nu, &options, &stem).unwrap_or_else(|err| {
+                eprintln!("Failed to write {stem} to {}: {err}", options.out.display());
+                process::exit(1);
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_args() {
+        let args: Vec<String> = ["--size", "10", "--nu", "0,1.5", "--log10-abs-z", "-1,2"]
+            .iter()
+            .map(|arg| arg.to_string())
+            .collect();
+        let options = parse_args(&args).unwrap();
+
+        assert_eq!(options.size, 10);
+        assert_eq!(options.orders, vec![0.0, 1.5]);
+        assert_eq!(options.log10_abs_z, (-1.0, 2.0));
+
+        assert!(parse_args(&["--size".to_string()]).is_none());
+        assert!(parse_args(&["--log10-abs-z".to_string(), "2,1".to_string()]).is_none());
+    }
+
+    #[test]
+    fn test_grid() {
+        let options = Options {
+            size: 4,
+            log10_abs_z: (0.0, 4.0),
+            ..Options::default()
+        };
+        let z = grid(&options);
+
+        assert_eq!(z.len(), 16);
+        // Top left is the largest |z| closest to arg z = -pi
+        assert!((z[0].norm().log10() - 3.5).abs() < 1e-12);
+        assert!((z[0].arg() + 0.75 * PI).abs() < 1e-12);
+        assert!((z[15].norm().log10() - 0.5).abs() < 1e-12);
+    }
+}
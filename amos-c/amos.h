@@ -0,0 +1,133 @@
+/* Generated by scripts/f77-to-c.py from the Fortran sources in amos/, do not edit. */
+
+#ifndef AMOS_H
+#define AMOS_H
+
+#include <math.h>
+#include <stdlib.h>
+
+static inline int imax(int a, int b) { return a > b ? a : b; }
+static inline int imin(int a, int b) { return a < b ? a : b; }
+static inline double dmax(double a, double b) { return a > b ? a : b; }
+static inline double dmin(double a, double b) { return a < b ? a : b; }
+static inline double d_sign(double a, double b) { return b >= 0 ? fabs(a) : -fabs(a); }
+
+static inline int pow_ii(int a, int n)
+{
+    int p = 1;
+
+    if (n < 0)
+        return a == 1 ? 1 : (a == -1 ? (n % 2 ? -1 : 1) : 0);
+
+    for (; n > 0; --n)
+        p *= a;
+
+    return p;
+}
+
+static inline double pow_di(double a, int n)
+{
+    double p = 1;
+
+    if (n < 0) {
+        n = -n;
+        a = 1 / a;
+    }
+
+    for (; n > 0; n >>= 1, a *= a)
+        if (n & 1)
+            p *= a;
+
+    return p;
+}
+
+
+#ifdef AMOS_PREFIX
+#define AMOS_PASTE(a, b) a##b
+#define AMOS_NAME(a, b) AMOS_PASTE(a, b)
+#define d1mach_ AMOS_NAME(AMOS_PREFIX, d1mach_)
+#define i1mach_ AMOS_NAME(AMOS_PREFIX, i1mach_)
+#define r1mach_ AMOS_NAME(AMOS_PREFIX, r1mach_)
+#define dgamln_ AMOS_NAME(AMOS_PREFIX, dgamln_)
+#define dsclmr_ AMOS_NAME(AMOS_PREFIX, dsclmr_)
+#define fdump_ AMOS_NAME(AMOS_PREFIX, fdump_)
+#define azabs_ AMOS_NAME(AMOS_PREFIX, azabs_)
+#define zacai_ AMOS_NAME(AMOS_PREFIX, zacai_)
+#define zacon_ AMOS_NAME(AMOS_PREFIX, zacon_)
+#define zairy_ AMOS_NAME(AMOS_PREFIX, zairy_)
+#define zasyi_ AMOS_NAME(AMOS_PREFIX, zasyi_)
+#define zbesh_ AMOS_NAME(AMOS_PREFIX, zbesh_)
+#define zbesi_ AMOS_NAME(AMOS_PREFIX, zbesi_)
+#define zbesj_ AMOS_NAME(AMOS_PREFIX, zbesj_)
+#define zbesk_ AMOS_NAME(AMOS_PREFIX, zbesk_)
+#define zbesy_ AMOS_NAME(AMOS_PREFIX, zbesy_)
+#define zbinu_ AMOS_NAME(AMOS_PREFIX, zbinu_)
+#define zbiry_ AMOS_NAME(AMOS_PREFIX, zbiry_)
+#define zbknu_ AMOS_NAME(AMOS_PREFIX, zbknu_)
+#define zbuni_ AMOS_NAME(AMOS_PREFIX, zbuni_)
+#define zbunk_ AMOS_NAME(AMOS_PREFIX, zbunk_)
+#define zdiv_ AMOS_NAME(AMOS_PREFIX, zdiv_)
+#define azexp_ AMOS_NAME(AMOS_PREFIX, azexp_)
+#define zkscl_ AMOS_NAME(AMOS_PREFIX, zkscl_)
+#define azlog_ AMOS_NAME(AMOS_PREFIX, azlog_)
+#define zmlri_ AMOS_NAME(AMOS_PREFIX, zmlri_)
+#define zmlt_ AMOS_NAME(AMOS_PREFIX, zmlt_)
+#define zrati_ AMOS_NAME(AMOS_PREFIX, zrati_)
+#define zs1s2_ AMOS_NAME(AMOS_PREFIX, zs1s2_)
+#define zseri_ AMOS_NAME(AMOS_PREFIX, zseri_)
+#define zshch_ AMOS_NAME(AMOS_PREFIX, zshch_)
+#define azsqrt_ AMOS_NAME(AMOS_PREFIX, azsqrt_)
+#define zuchk_ AMOS_NAME(AMOS_PREFIX, zuchk_)
+#define zunhj_ AMOS_NAME(AMOS_PREFIX, zunhj_)
+#define zuni1_ AMOS_NAME(AMOS_PREFIX, zuni1_)
+#define zuni2_ AMOS_NAME(AMOS_PREFIX, zuni2_)
+#define zunik_ AMOS_NAME(AMOS_PREFIX, zunik_)
+#define zunk1_ AMOS_NAME(AMOS_PREFIX, zunk1_)
+#define zunk2_ AMOS_NAME(AMOS_PREFIX, zunk2_)
+#define zuoik_ AMOS_NAME(AMOS_PREFIX, zuoik_)
+#define zwrsk_ AMOS_NAME(AMOS_PREFIX, zwrsk_)
+#endif
+
+double d1mach_(int *i);
+int i1mach_(int *i);
+float r1mach_(int *i);
+double dgamln_(double *z, int *ierr);
+void dsclmr_(void);
+void fdump_(void);
+double azabs_(double *zr, double *zi);
+void zacai_(double *zr, double *zi, double *fnu, int *kode, int *mr, int *n, double *yr, double *yi, int *nz, double *rl, double *tol, double *elim, double *alim);
+void zacon_(double *zr, double *zi, double *fnu, int *kode, int *mr, int *n, double *yr, double *yi, int *nz, double *rl, double *fnul, double *tol, double *elim, double *alim);
+void zairy_(double *zr, double *zi, int *id, int *kode, double *air, double *aii, int *nz, int *ierr);
+void zasyi_(double *zr, double *zi, double *fnu, int *kode, int *n, double *yr, double *yi, int *nz, double *rl, double *tol, double *elim, double *alim);
+void zbesh_(double *zr, double *zi, double *fnu, int *kode, int *m, int *n, double *cyr, double *cyi, int *nz, int *ierr);
+void zbesi_(double *zr, double *zi, double *fnu, int *kode, int *n, double *cyr, double *cyi, int *nz, int *ierr);
+void zbesj_(double *zr, double *zi, double *fnu, int *kode, int *n, double *cyr, double *cyi, int *nz, int *ierr);
+void zbesk_(double *zr, double *zi, double *fnu, int *kode, int *n, double *cyr, double *cyi, int *nz, int *ierr);
+void zbesy_(double *zr, double *zi, double *fnu, int *kode, int *n, double *cyr, double *cyi, int *nz, double *cwrkr, double *cwrki, int *ierr);
+void zbinu_(double *zr, double *zi, double *fnu, int *kode, int *n, double *cyr, double *cyi, int *nz, double *rl, double *fnul, double *tol, double *elim, double *alim);
+void zbiry_(double *zr, double *zi, int *id, int *kode, double *bir, double *bii, int *ierr);
+void zbknu_(double *zr, double *zi, double *fnu, int *kode, int *n, double *yr, double *yi, int *nz, double *tol, double *elim, double *alim);
+void zbuni_(double *zr, double *zi, double *fnu, int *kode, int *n, double *yr, double *yi, int *nz, int *nui, int *nlast, double *fnul, double *tol, double *elim, double *alim);
+void zbunk_(double *zr, double *zi, double *fnu, int *kode, int *mr, int *n, double *yr, double *yi, int *nz, double *tol, double *elim, double *alim);
+void zdiv_(double *ar, double *ai, double *br, double *bi, double *cr, double *ci);
+void azexp_(double *ar, double *ai, double *br, double *bi);
+void zkscl_(double *zrr, double *zri, double *fnu, int *n, double *yr, double *yi, int *nz, double *rzr, double *rzi, double *ascle, double *tol, double *elim);
+void azlog_(double *ar, double *ai, double *br, double *bi, int *ierr);
+void zmlri_(double *zr, double *zi, double *fnu, int *kode, int *n, double *yr, double *yi, int *nz, double *tol);
+void zmlt_(double *ar, double *ai, double *br, double *bi, double *cr, double *ci);
+void zrati_(double *zr, double *zi, double *fnu, int *n, double *cyr, double *cyi, double *tol);
+void zs1s2_(double *zrr, double *zri, double *s1r, double *s1i, double *s2r, double *s2i, int *nz, double *ascle, double *alim, int *iuf);
+void zseri_(double *zr, double *zi, double *fnu, int *kode, int *n, double *yr, double *yi, int *nz, double *tol, double *elim, double *alim);
+void zshch_(double *zr, double *zi, double *cshr, double *cshi, double *cchr, double *cchi);
+void azsqrt_(double *ar, double *ai, double *br, double *bi);
+void zuchk_(double *yr, double *yi, int *nz, double *ascle, double *tol);
+void zunhj_(double *zr, double *zi, double *fnu, int *ipmtr, double *tol, double *phir, double *phii, double *argr, double *argi, double *zeta1r, double *zeta1i, double *zeta2r, double *zeta2i, double *asumr, double *asumi, double *bsumr, double *bsumi);
+void zuni1_(double *zr, double *zi, double *fnu, int *kode, int *n, double *yr, double *yi, int *nz, int *nlast, double *fnul, double *tol, double *elim, double *alim);
+void zuni2_(double *zr, double *zi, double *fnu, int *kode, int *n, double *yr, double *yi, int *nz, int *nlast, double *fnul, double *tol, double *elim, double *alim);
+void zunik_(double *zrr, double *zri, double *fnu, int *ikflg, int *ipmtr, double *tol, int *init, double *phir, double *phii, double *zeta1r, double *zeta1i, double *zeta2r, double *zeta2i, double *sumr, double *sumi, double *cwrkr, double *cwrki);
+void zunk1_(double *zr, double *zi, double *fnu, int *kode, int *mr, int *n, double *yr, double *yi, int *nz, double *tol, double *elim, double *alim);
+void zunk2_(double *zr, double *zi, double *fnu, int *kode, int *mr, int *n, double *yr, double *yi, int *nz, double *tol, double *elim, double *alim);
+void zuoik_(double *zr, double *zi, double *fnu, int *kode, int *ikflg, int *n, double *yr, double *yi, int *nuf, double *tol, double *elim, double *alim);
+void zwrsk_(double *zrr, double *zri, double *fnu, int *kode, int *n, double *yr, double *yi, int *nz, double *cwr, double *cwi, double *tol, double *elim, double *alim);
+
+#endif
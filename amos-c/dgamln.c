@@ -0,0 +1,135 @@
+/* Generated by scripts/f77-to-c.py from amos/dgamln.f, do not edit. */
+
+#include "amos.h"
+
+double dgamln_(double *z, int *ierr)
+{
+    static double cf[22] = {
+        8.33333333333333333e-02, -2.77777777777777778e-03, 7.93650793650793651e-04,
+        -5.95238095238095238e-04, 8.41750841750841751e-04, -1.91752691752691753e-03,
+        6.41025641025641026e-03, -2.95506535947712418e-02, 1.79644372368830573e-01,
+        -1.39243221690590112e+00, 1.34028640441683920e+01, -1.56848284626002017e+02,
+        2.19310333333333333e+03, -3.61087712537249894e+04, 6.91472268851313067e+05,
+        -1.52382215394074162e+07, 3.82900751391414141e+08, -1.08822660357843911e+10,
+        3.47320283765002252e+11, -1.23696021422692745e+13, 4.88788064793079335e+14,
+        -2.13203339609193739e+16,
+    };
+    static double con = 1.83787706640934548e+00;
+    double fln = 0;
+    double fz = 0;
+    static double gln[100] = {
+        0.00000000000000000e+00, 0.00000000000000000e+00, 6.93147180559945309e-01,
+        1.79175946922805500e+00, 3.17805383034794562e+00, 4.78749174278204599e+00,
+        6.57925121201010100e+00, 8.52516136106541430e+00, 1.06046029027452502e+01,
+        1.28018274800814696e+01, 1.51044125730755153e+01, 1.75023078458738858e+01,
+        1.99872144956618861e+01, 2.25521638531234229e+01, 2.51912211827386815e+01,
+        2.78992713838408916e+01, 3.06718601060806728e+01, 3.35050734501368889e+01,
+        3.63954452080330536e+01, 3.93398841871994940e+01, 4.23356164607534850e+01,
+        4.53801388984769080e+01, 4.84711813518352239e+01, 5.16066755677643736e+01,
+        5.47847293981123192e+01, 5.80036052229805199e+01, 6.12617017610020020e+01,
+        6.45575386270063311e+01, 6.78897431371815350e+01, 7.12570389671680090e+01,
+        7.46582363488301644e+01, 7.80922235533153106e+01, 8.15579594561150372e+01,
+        8.50544670175815174e+01, 8.85808275421976788e+01, 9.21361756036870925e+01,
+        9.57196945421432025e+01, 9.93306124547874269e+01, 1.02968198614513813e+02,
+        1.06631760260643459e+02, 1.10320639714757395e+02, 1.14034211781461703e+02,
+        1.17771881399745072e+02, 1.21533081515438634e+02, 1.25317271149356895e+02,
+        1.29123933639127215e+02, 1.32952575035616310e+02, 1.36802722637326368e+02,
+        1.40673923648234259e+02, 1.44565743946344886e+02, 1.48477766951773032e+02,
+        1.52409592584497358e+02, 1.56360836303078785e+02, 1.60331128216630907e+02,
+        1.64320112263195181e+02, 1.68327445448427652e+02, 1.72352797139162802e+02,
+        1.76395848406997352e+02, 1.80456291417543771e+02, 1.84533828861449491e+02,
+        1.88628173423671591e+02, 1.92739047287844902e+02, 1.96866181672889994e+02,
+        2.01009316399281527e+02, 2.05168199482641199e+02, 2.09342586752536836e+02,
+        2.13532241494563261e+02, 2.17736934113954227e+02, 2.21956441819130334e+02,
+        2.26190548323727593e+02, 2.30439043565776952e+02, 2.34701723442818268e+02,
+        2.38978389561834323e+02, 2.43268849002982714e+02, 2.47572914096186884e+02,
+        2.51890402209723194e+02, 2.56221135550009525e+02, 2.60564940971863209e+02,
+        2.64921649798552801e+02, 2.69291097651019823e+02, 2.73673124285693704e+02,
+        2.78067573440366143e+02, 2.82474292687630396e+02, 2.86893133295426994e+02,
+        2.91323950094270308e+02, 2.95766601350760624e+02, 3.00220948647014132e+02,
+        3.04686856765668715e+02, 3.09164193580146922e+02, 3.13652829949879062e+02,
+        3.18152639620209327e+02, 3.22663499126726177e+02, 3.27185287703775217e+02,
+        3.31717887196928473e+02, 3.36261181979198477e+02, 3.40815058870799018e+02,
+        3.45379407062266854e+02, 3.49954118040770237e+02, 3.54539085519440809e+02,
+        3.59134205369575399e+02,
+    };
+    int i = 0;
+    int i1m = 0;
+    int k = 0;
+    int mz = 0;
+    int nz = 0;
+    double rln = 0;
+    double s = 0;
+    double t1 = 0;
+    double tlg = 0;
+    double trm = 0;
+    double tst = 0;
+    double wdtol = 0;
+    double zdmy = 0;
+    double zinc = 0;
+    double zm = 0;
+    double zmin = 0;
+    double zp = 0;
+    double zsq = 0;
+    double ret_val = 0;
+    int i1_;
+    int i2_;
+
+    *ierr = 0;
+    if (*z <= 0.0e0) goto L70;
+    if (*z > 101.0e0) goto L10;
+    nz = (int)((float)(*z));
+    fz = *z - (float)(nz);
+    if (fz > 0.0e0) goto L10;
+    if (nz > 100) goto L10;
+    ret_val = gln[(nz - 1)];
+    return ret_val;
+L10:;
+    wdtol = d1mach_(&(int){4});
+    wdtol = dmax(wdtol, 0.5e-18);
+    i1m = i1mach_(&(int){14});
+    rln = d1mach_(&(int){5}) * (float)(i1m);
+    fln = dmin(rln, 20.0e0);
+    fln = dmax(fln, 3.0e0);
+    fln = fln - 3.0e0;
+    zm = 1.8000e0 + 0.3875e0 * fln;
+    mz = (int)((float)(zm)) + 1;
+    zmin = (float)(mz);
+    zdmy = *z;
+    zinc = 0.0e0;
+    if (*z >= zmin) goto L20;
+    zinc = zmin - (float)(nz);
+    zdmy = *z + zinc;
+L20:;
+    zp = 1.0e0 / zdmy;
+    t1 = cf[(1 - 1)] * zp;
+    s = t1;
+    if (zp < wdtol) goto L40;
+    zsq = zp * zp;
+    tst = t1 * wdtol;
+    k = 2;
+    for (i1_ = 22 - k + 1; i1_ > 0; --i1_, k += 1) {
+        zp = zp * zsq;
+        trm = cf[(k - 1)] * zp;
+        if (fabs(trm) < tst) goto L40;
+        s = s + trm;
+    }
+L40:;
+    if (zinc != 0.0e0) goto L50;
+    tlg = log(*z);
+    ret_val = *z * (tlg - 1.0e0) + 0.5e0 * (con - tlg) + s;
+    return ret_val;
+L50:;
+    zp = 1.0e0;
+    nz = (int)((float)(zinc));
+    i = 1;
+    for (i2_ = nz - i + 1; i2_ > 0; --i2_, i += 1) {
+        zp = zp * (*z + (float)(i - 1));
+    }
+    tlg = log(zdmy);
+    ret_val = zdmy * (tlg - 1.0e0) - log(zp) + 0.5e0 * (con - tlg) + s;
+    return ret_val;
+L70:;
+    *ierr = 1;
+    return ret_val;
+}
@@ -0,0 +1,7 @@
+/* Generated by scripts/f77-to-c.py from amos/dsclmr.f, do not edit. */
+
+#include "amos.h"
+
+void dsclmr_(void)
+{
+}
@@ -0,0 +1,8 @@
+/* Generated by scripts/f77-to-c.py from amos/fdump.f, do not edit. */
+
+#include "amos.h"
+
+void fdump_(void)
+{
+    return;
+}
@@ -0,0 +1,90 @@
+/* Machine constants of amos/d1mach.f90, amos/i1mach.f90 and amos/r1mach.f90, which are Fortran 90
+ * and not translated by scripts/f77-to-c.py. The values follow the Fortran intrinsics used there,
+ * e.g., MINEXPONENT(1.0D0) = DBL_MIN_EXP and DIGITS(1.0D0) = DBL_MANT_DIG. */
+
+#include <float.h>
+#include <limits.h>
+#include <math.h>
+#include <stdio.h>
+#include <stdlib.h>
+
+#include "amos.h"
+
+double d1mach_(int *i)
+{
+    switch (*i) {
+    case 1:
+        return DBL_MIN;
+    case 2:
+        return DBL_MAX;
+    case 3:
+        return DBL_EPSILON / FLT_RADIX;
+    case 4:
+        return DBL_EPSILON;
+    case 5:
+        return log10((double)FLT_RADIX);
+    }
+
+    fprintf(stderr, "ERROR    1 IN D1MACH - I OUT OF BOUNDS\n");
+    exit(1);
+}
+
+float r1mach_(int *i)
+{
+    switch (*i) {
+    case 1:
+        return FLT_MIN;
+    case 2:
+        return FLT_MAX;
+    case 3:
+        return FLT_EPSILON / FLT_RADIX;
+    case 4:
+        return FLT_EPSILON;
+    case 5:
+        return log10f((float)FLT_RADIX);
+    }
+
+    fprintf(stderr, "ERROR    1 IN R1MACH - I OUT OF BOUNDS\n");
+    exit(1);
+}
+
+int i1mach_(int *i)
+{
+    switch (*i) {
+    case 1:
+        return 5;
+    case 2:
+        return 6;
+    case 3:
+        return 0;
+    case 4:
+        return 0;
+    case 5:
+        return (int)(sizeof(int) * CHAR_BIT);
+    case 6:
+        return (int)sizeof(int);
+    case 7:
+        return 2;
+    case 8:
+        return (int)(sizeof(int) * CHAR_BIT) - 1;
+    case 9:
+        return INT_MAX;
+    case 10:
+        return FLT_RADIX;
+    case 11:
+        return FLT_MANT_DIG;
+    case 12:
+        return FLT_MIN_EXP;
+    case 13:
+        return FLT_MAX_EXP;
+    case 14:
+        return DBL_MANT_DIG;
+    case 15:
+        return DBL_MIN_EXP;
+    case 16:
+        return DBL_MAX_EXP;
+    }
+
+    fprintf(stderr, "ERROR    1 IN I1MACH - I OUT OF BOUNDS\n");
+    exit(1);
+}
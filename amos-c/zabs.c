@@ -0,0 +1,29 @@
+/* Generated by scripts/f77-to-c.py from amos/zabs.f, do not edit. */
+
+#include "amos.h"
+
+double azabs_(double *zr, double *zi)
+{
+    double q = 0;
+    double s = 0;
+    double u = 0;
+    double v = 0;
+    double ret_val = 0;
+
+    u = fabs(*zr);
+    v = fabs(*zi);
+    s = u + v;
+    s = s * 1.0e+0;
+    if (s == 0.0e+0) goto L20;
+    if (u > v) goto L10;
+    q = u / v;
+    ret_val = v * sqrt(1.e+0 + q * q);
+    return ret_val;
+L10:;
+    q = v / u;
+    ret_val = u * sqrt(1.e+0 + q * q);
+    return ret_val;
+L20:;
+    ret_val = 0.0e+0;
+    return ret_val;
+}
@@ -0,0 +1,88 @@
+/* Generated by scripts/f77-to-c.py from amos/zacai.f, do not edit. */
+
+#include "amos.h"
+
+void zacai_(double *zr, double *zi, double *fnu, int *kode, int *mr, int *n, double *yr, double *yi, int *nz, double *rl, double *tol, double *elim, double *alim)
+{
+    double arg = 0;
+    double ascle = 0;
+    double az = 0;
+    double c1i = 0;
+    double c1r = 0;
+    double c2i = 0;
+    double c2r = 0;
+    double csgni = 0;
+    double csgnr = 0;
+    double cspni = 0;
+    double cspnr = 0;
+    double cyi[2];
+    double cyr[2];
+    double dfnu = 0;
+    double fmr = 0;
+    int inu = 0;
+    int iuf = 0;
+    int nn = 0;
+    int nw = 0;
+    static double pi = 3.14159265358979324e0;
+    double sgn = 0;
+    double yy = 0;
+    double zni = 0;
+    double znr = 0;
+
+    *nz = 0;
+    znr = -*zr;
+    zni = -*zi;
+    az = azabs_(zr, zi);
+    nn = *n;
+    dfnu = *fnu + (double)((float)(*n - 1));
+    if (az <= 2.0e0) goto L10;
+    if (az * az * 0.25e0 > dfnu + 1.0e0) goto L20;
+L10:;
+    zseri_(&znr, &zni, fnu, kode, &nn, yr, yi, &nw, tol, elim, alim);
+    goto L40;
+L20:;
+    if (az < *rl) goto L30;
+    zasyi_(&znr, &zni, fnu, kode, &nn, yr, yi, &nw, rl, tol, elim, alim);
+    if (nw < 0) goto L80;
+    goto L40;
+L30:;
+    zmlri_(&znr, &zni, fnu, kode, &nn, yr, yi, &nw, tol);
+    if (nw < 0) goto L80;
+L40:;
+    zbknu_(&znr, &zni, fnu, kode, &(int){1}, cyr, cyi, &nw, tol, elim, alim);
+    if (nw != 0) goto L80;
+    fmr = (double)((float)(*mr));
+    sgn = -d_sign(pi, fmr);
+    csgnr = 0.0e0;
+    csgni = sgn;
+    if (*kode == 1) goto L50;
+    yy = -zni;
+    csgnr = -csgni * sin(yy);
+    csgni = csgni * cos(yy);
+L50:;
+    inu = (int)((float)(*fnu));
+    arg = (*fnu - (double)((float)(inu))) * sgn;
+    cspnr = cos(arg);
+    cspni = sin(arg);
+    if ((inu) % (2) == 0) goto L60;
+    cspnr = -cspnr;
+    cspni = -cspni;
+L60:;
+    c1r = cyr[(1 - 1)];
+    c1i = cyi[(1 - 1)];
+    c2r = yr[(1 - 1)];
+    c2i = yi[(1 - 1)];
+    if (*kode == 1) goto L70;
+    iuf = 0;
+    ascle = 1.0e+3 * d1mach_(&(int){1}) / *tol;
+    zs1s2_(&znr, &zni, &c1r, &c1i, &c2r, &c2i, &nw, &ascle, alim, &iuf);
+    *nz = *nz + nw;
+L70:;
+    yr[(1 - 1)] = cspnr * c1r - cspni * c1i + csgnr * c2r - csgni * c2i;
+    yi[(1 - 1)] = cspnr * c1i + cspni * c1r + csgnr * c2i + csgni * c2r;
+    return;
+L80:;
+    *nz = -1;
+    if (nw == (-2)) *nz = -2;
+    return;
+}
@@ -0,0 +1,231 @@
+/* Generated by scripts/f77-to-c.py from amos/zacon.f, do not edit. */
+
+#include "amos.h"
+
+void zacon_(double *zr, double *zi, double *fnu, int *kode, int *mr, int *n, double *yr, double *yi, int *nz, double *rl, double *fnul, double *tol, double *elim, double *alim)
+{
+    double arg = 0;
+    double as2 = 0;
+    double ascle = 0;
+    double azn = 0;
+    double bry[3];
+    double bscle = 0;
+    double c1i = 0;
+    double c1m = 0;
+    double c1r = 0;
+    double c2i = 0;
+    double c2r = 0;
+    double cki = 0;
+    double ckr = 0;
+    static double coner = 1.0e0;
+    double cpn = 0;
+    double cscl = 0;
+    double cscr = 0;
+    double csgni = 0;
+    double csgnr = 0;
+    double cspni = 0;
+    double cspnr = 0;
+    double csr = 0;
+    double csrr[3];
+    double cssr[3];
+    double cyi[2];
+    double cyr[2];
+    double fmr = 0;
+    double fn = 0;
+    int i = 0;
+    int inu = 0;
+    int iuf = 0;
+    int kflag = 0;
+    int nn = 0;
+    int nw = 0;
+    static double pi = 3.14159265358979324e0;
+    double pti = 0;
+    double ptr = 0;
+    double razn = 0;
+    double rzi = 0;
+    double rzr = 0;
+    double s1i = 0;
+    double s1r = 0;
+    double s2i = 0;
+    double s2r = 0;
+    double sc1i = 0;
+    double sc1r = 0;
+    double sc2i = 0;
+    double sc2r = 0;
+    double sgn = 0;
+    double spn = 0;
+    double sti = 0;
+    double str = 0;
+    double yy = 0;
+    static double zeror = 0.0e0;
+    double zni = 0;
+    double znr = 0;
+    int i1_;
+
+    *nz = 0;
+    znr = -*zr;
+    zni = -*zi;
+    nn = *n;
+    zbinu_(&znr, &zni, fnu, kode, &nn, yr, yi, &nw, rl, fnul, tol, elim, alim);
+    if (nw < 0) goto L90;
+    nn = imin(2, *n);
+    zbknu_(&znr, &zni, fnu, kode, &nn, cyr, cyi, &nw, tol, elim, alim);
+    if (nw != 0) goto L90;
+    s1r = cyr[(1 - 1)];
+    s1i = cyi[(1 - 1)];
+    fmr = (double)((float)(*mr));
+    sgn = -d_sign(pi, fmr);
+    csgnr = zeror;
+    csgni = sgn;
+    if (*kode == 1) goto L10;
+    yy = -zni;
+    cpn = cos(yy);
+    spn = sin(yy);
+    zmlt_(&csgnr, &csgni, &cpn, &spn, &csgnr, &csgni);
+L10:;
+    inu = (int)((float)(*fnu));
+    arg = (*fnu - (double)((float)(inu))) * sgn;
+    cpn = cos(arg);
+    spn = sin(arg);
+    cspnr = cpn;
+    cspni = spn;
+    if ((inu) % (2) == 0) goto L20;
+    cspnr = -cspnr;
+    cspni = -cspni;
+L20:;
+    iuf = 0;
+    c1r = s1r;
+    c1i = s1i;
+    c2r = yr[(1 - 1)];
+    c2i = yi[(1 - 1)];
+    ascle = 1.0e+3 * d1mach_(&(int){1}) / *tol;
+    if (*kode == 1) goto L30;
+    zs1s2_(&znr, &zni, &c1r, &c1i, &c2r, &c2i, &nw, &ascle, alim, &iuf);
+    *nz = *nz + nw;
+    sc1r = c1r;
+    sc1i = c1i;
+L30:;
+    zmlt_(&cspnr, &cspni, &c1r, &c1i, &str, &sti);
+    zmlt_(&csgnr, &csgni, &c2r, &c2i, &ptr, &pti);
+    yr[(1 - 1)] = str + ptr;
+    yi[(1 - 1)] = sti + pti;
+    if (*n == 1) return;
+    cspnr = -cspnr;
+    cspni = -cspni;
+    s2r = cyr[(2 - 1)];
+    s2i = cyi[(2 - 1)];
+    c1r = s2r;
+    c1i = s2i;
+    c2r = yr[(2 - 1)];
+    c2i = yi[(2 - 1)];
+    if (*kode == 1) goto L40;
+    zs1s2_(&znr, &zni, &c1r, &c1i, &c2r, &c2i, &nw, &ascle, alim, &iuf);
+    *nz = *nz + nw;
+    sc2r = c1r;
+    sc2i = c1i;
+L40:;
+    zmlt_(&cspnr, &cspni, &c1r, &c1i, &str, &sti);
+    zmlt_(&csgnr, &csgni, &c2r, &c2i, &ptr, &pti);
+    yr[(2 - 1)] = str + ptr;
+    yi[(2 - 1)] = sti + pti;
+    if (*n == 2) return;
+    cspnr = -cspnr;
+    cspni = -cspni;
+    azn = azabs_(&znr, &zni);
+    razn = 1.0e0 / azn;
+    str = znr * razn;
+    sti = -zni * razn;
+    rzr = (str + str) * razn;
+    rzi = (sti + sti) * razn;
+    fn = *fnu + 1.0e0;
+    ckr = fn * rzr;
+    cki = fn * rzi;
+    cscl = 1.0e0 / *tol;
+    cscr = *tol;
+    cssr[(1 - 1)] = cscl;
+    cssr[(2 - 1)] = coner;
+    cssr[(3 - 1)] = cscr;
+    csrr[(1 - 1)] = cscr;
+    csrr[(2 - 1)] = coner;
+    csrr[(3 - 1)] = cscl;
+    bry[(1 - 1)] = ascle;
+    bry[(2 - 1)] = 1.0e0 / ascle;
+    bry[(3 - 1)] = d1mach_(&(int){2});
+    as2 = azabs_(&s2r, &s2i);
+    kflag = 2;
+    if (as2 > bry[(1 - 1)]) goto L50;
+    kflag = 1;
+    goto L60;
+L50:;
+    if (as2 < bry[(2 - 1)]) goto L60;
+    kflag = 3;
+L60:;
+    bscle = bry[(kflag - 1)];
+    s1r = s1r * cssr[(kflag - 1)];
+    s1i = s1i * cssr[(kflag - 1)];
+    s2r = s2r * cssr[(kflag - 1)];
+    s2i = s2i * cssr[(kflag - 1)];
+    csr = csrr[(kflag - 1)];
+    i = 3;
+    for (i1_ = *n - i + 1; i1_ > 0; --i1_, i += 1) {
+        str = s2r;
+        sti = s2i;
+        s2r = ckr * str - cki * sti + s1r;
+        s2i = ckr * sti + cki * str + s1i;
+        s1r = str;
+        s1i = sti;
+        c1r = s2r * csr;
+        c1i = s2i * csr;
+        str = c1r;
+        sti = c1i;
+        c2r = yr[(i - 1)];
+        c2i = yi[(i - 1)];
+        if (*kode == 1) goto L70;
+        if (iuf < 0) goto L70;
+        zs1s2_(&znr, &zni, &c1r, &c1i, &c2r, &c2i, &nw, &ascle, alim, &iuf);
+        *nz = *nz + nw;
+        sc1r = sc2r;
+        sc1i = sc2i;
+        sc2r = c1r;
+        sc2i = c1i;
+        if (iuf != 3) goto L70;
+        iuf = -4;
+        s1r = sc1r * cssr[(kflag - 1)];
+        s1i = sc1i * cssr[(kflag - 1)];
+        s2r = sc2r * cssr[(kflag - 1)];
+        s2i = sc2i * cssr[(kflag - 1)];
+        str = sc2r;
+        sti = sc2i;
+    L70:;
+        ptr = cspnr * c1r - cspni * c1i;
+        pti = cspnr * c1i + cspni * c1r;
+        yr[(i - 1)] = ptr + csgnr * c2r - csgni * c2i;
+        yi[(i - 1)] = pti + csgnr * c2i + csgni * c2r;
+        ckr = ckr + rzr;
+        cki = cki + rzi;
+        cspnr = -cspnr;
+        cspni = -cspni;
+        if (kflag >= 3) goto L80;
+        ptr = fabs(c1r);
+        pti = fabs(c1i);
+        c1m = dmax(ptr, pti);
+        if (c1m <= bscle) goto L80;
+        kflag = kflag + 1;
+        bscle = bry[(kflag - 1)];
+        s1r = s1r * csr;
+        s1i = s1i * csr;
+        s2r = str;
+        s2i = sti;
+        s1r = s1r * cssr[(kflag - 1)];
+        s1i = s1i * cssr[(kflag - 1)];
+        s2r = s2r * cssr[(kflag - 1)];
+        s2i = s2i * cssr[(kflag - 1)];
+        csr = csrr[(kflag - 1)];
+    L80:;
+    }
+    return;
+L90:;
+    *nz = -1;
+    if (nw == (-2)) *nz = -2;
+    return;
+}
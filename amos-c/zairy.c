@@ -0,0 +1,284 @@
+/* Generated by scripts/f77-to-c.py from amos/zairy.f, do not edit. */
+
+#include "amos.h"
+
+void zairy_(double *zr, double *zi, int *id, int *kode, double *air, double *aii, int *nz, int *ierr)
+{
+    double aa = 0;
+    double ad = 0;
+    double ak = 0;
+    double alaz = 0;
+    double alim = 0;
+    double atrm = 0;
+    double az = 0;
+    double az3 = 0;
+    double bb = 0;
+    double bk = 0;
+    static double c1 = 3.55028053887817240e-01;
+    static double c2 = 2.58819403792806799e-01;
+    double cc = 0;
+    double ck = 0;
+    static double coef = 1.83776298473930683e-01;
+    static double conei = 0.0e0;
+    static double coner = 1.0e0;
+    double csqi = 0;
+    double csqr = 0;
+    double cyi[1];
+    double cyr[1];
+    double d1 = 0;
+    double d2 = 0;
+    double dig = 0;
+    double dk = 0;
+    double elim = 0;
+    double fid = 0;
+    double fnu = 0;
+    int iflag = 0;
+    int k = 0;
+    int k1 = 0;
+    int k2 = 0;
+    int mr = 0;
+    int nn = 0;
+    double ptr = 0;
+    double r1m5 = 0;
+    double rl = 0;
+    double s1i = 0;
+    double s1r = 0;
+    double s2i = 0;
+    double s2r = 0;
+    double sfac = 0;
+    double sti = 0;
+    double str = 0;
+    double tol = 0;
+    double trm1i = 0;
+    double trm1r = 0;
+    double trm2i = 0;
+    double trm2r = 0;
+    static double tth = 6.66666666666666667e-01;
+    double z3i = 0;
+    double z3r = 0;
+    static double zeroi = 0.0e0;
+    static double zeror = 0.0e0;
+    double ztai = 0;
+    double ztar = 0;
+    int i1_;
+
+    *ierr = 0;
+    *nz = 0;
+    if (*id < 0 || *id > 1) *ierr = 1;
+    if (*kode < 1 || *kode > 2) *ierr = 1;
+    if (*ierr != 0) return;
+    az = azabs_(zr, zi);
+    tol = dmax(d1mach_(&(int){4}), 1.0e-18);
+    fid = (double)((float)(*id));
+    if (az > 1.0e0) goto L70;
+    s1r = coner;
+    s1i = conei;
+    s2r = coner;
+    s2i = conei;
+    if (az < tol) goto L170;
+    aa = az * az;
+    if (aa < tol / az) goto L40;
+    trm1r = coner;
+    trm1i = conei;
+    trm2r = coner;
+    trm2i = conei;
+    atrm = 1.0e0;
+    str = *zr * *zr - *zi * *zi;
+    sti = *zr * *zi + *zi * *zr;
+    z3r = str * *zr - sti * *zi;
+    z3i = str * *zi + sti * *zr;
+    az3 = az * aa;
+    ak = 2.0e0 + fid;
+    bk = 3.0e0 - fid - fid;
+    ck = 4.0e0 - fid;
+    dk = 3.0e0 + fid + fid;
+    d1 = ak * dk;
+    d2 = bk * ck;
+    ad = dmin(d1, d2);
+    ak = 24.0e0 + 9.0e0 * fid;
+    bk = 30.0e0 - 9.0e0 * fid;
+    k = 1;
+    for (i1_ = 25 - k + 1; i1_ > 0; --i1_, k += 1) {
+        str = (trm1r * z3r - trm1i * z3i) / d1;
+        trm1i = (trm1r * z3i + trm1i * z3r) / d1;
+        trm1r = str;
+        s1r = s1r + trm1r;
+        s1i = s1i + trm1i;
+        str = (trm2r * z3r - trm2i * z3i) / d2;
+        trm2i = (trm2r * z3i + trm2i * z3r) / d2;
+        trm2r = str;
+        s2r = s2r + trm2r;
+        s2i = s2i + trm2i;
+        atrm = atrm * az3 / ad;
+        d1 = d1 + ak;
+        d2 = d2 + bk;
+        ad = dmin(d1, d2);
+        if (atrm < tol * ad) goto L40;
+        ak = ak + 18.0e0;
+        bk = bk + 18.0e0;
+    }
+L40:;
+    if (*id == 1) goto L50;
+    *air = s1r * c1 - c2 * (*zr * s2r - *zi * s2i);
+    *aii = s1i * c1 - c2 * (*zr * s2i + *zi * s2r);
+    if (*kode == 1) return;
+    azsqrt_(zr, zi, &str, &sti);
+    ztar = tth * (*zr * str - *zi * sti);
+    ztai = tth * (*zr * sti + *zi * str);
+    azexp_(&ztar, &ztai, &str, &sti);
+    ptr = *air * str - *aii * sti;
+    *aii = *air * sti + *aii * str;
+    *air = ptr;
+    return;
+L50:;
+    *air = -s2r * c2;
+    *aii = -s2i * c2;
+    if (az <= tol) goto L60;
+    str = *zr * s1r - *zi * s1i;
+    sti = *zr * s1i + *zi * s1r;
+    cc = c1 / (1.0e0 + fid);
+    *air = *air + cc * (str * *zr - sti * *zi);
+    *aii = *aii + cc * (str * *zi + sti * *zr);
+L60:;
+    if (*kode == 1) return;
+    azsqrt_(zr, zi, &str, &sti);
+    ztar = tth * (*zr * str - *zi * sti);
+    ztai = tth * (*zr * sti + *zi * str);
+    azexp_(&ztar, &ztai, &str, &sti);
+    ptr = str * *air - sti * *aii;
+    *aii = str * *aii + sti * *air;
+    *air = ptr;
+    return;
+L70:;
+    fnu = (1.0e0 + fid) / 3.0e0;
+    k1 = i1mach_(&(int){15});
+    k2 = i1mach_(&(int){16});
+    r1m5 = d1mach_(&(int){5});
+    k = imin(abs(k1), abs(k2));
+    elim = 2.303e0 * ((double)((float)(k)) * r1m5 - 3.0e0);
+    k1 = i1mach_(&(int){14}) - 1;
+    aa = r1m5 * (double)((float)(k1));
+    dig = dmin(aa, 18.0e0);
+    aa = aa * 2.303e0;
+    alim = elim + dmax(-aa, -41.45e0);
+    rl = 1.2e0 * dig + 3.0e0;
+    alaz = log(az);
+    aa = 0.5e0 / tol;
+    bb = (double)((float)(i1mach_(&(int){9}))) * 0.5e0;
+    aa = dmin(aa, bb);
+    aa = pow(aa, tth);
+    if (az > aa) goto L260;
+    aa = sqrt(aa);
+    if (az > aa) *ierr = 3;
+    azsqrt_(zr, zi, &csqr, &csqi);
+    ztar = tth * (*zr * csqr - *zi * csqi);
+    ztai = tth * (*zr * csqi + *zi * csqr);
+    iflag = 0;
+    sfac = 1.0e0;
+    ak = ztai;
+    if (*zr >= 0.0e0) goto L80;
+    bk = ztar;
+    ck = -fabs(bk);
+    ztar = ck;
+    ztai = ak;
+L80:;
+    if (*zi != 0.0e0) goto L90;
+    if (*zr > 0.0e0) goto L90;
+    ztar = 0.0e0;
+    ztai = ak;
+L90:;
+    aa = ztar;
+    if (aa >= 0.0e0 && *zr > 0.0e0) goto L110;
+    if (*kode == 2) goto L100;
+    if (aa > (-alim)) goto L100;
+    aa = -aa + 0.25e0 * alaz;
+    iflag = 1;
+    sfac = tol;
+    if (aa > elim) goto L270;
+L100:;
+    mr = 1;
+    if (*zi < 0.0e0) mr = -1;
+    zacai_(&ztar, &ztai, &fnu, kode, &mr, &(int){1}, cyr, cyi, &nn, &rl, &tol, &elim, &alim);
+    if (nn < 0) goto L280;
+    *nz = *nz + nn;
+    goto L130;
+L110:;
+    if (*kode == 2) goto L120;
+    if (aa < alim) goto L120;
+    aa = -aa - 0.25e0 * alaz;
+    iflag = 2;
+    sfac = 1.0e0 / tol;
+    if (aa < (-elim)) goto L210;
+L120:;
+    zbknu_(&ztar, &ztai, &fnu, kode, &(int){1}, cyr, cyi, nz, &tol, &elim, &alim);
+L130:;
+    s1r = cyr[(1 - 1)] * coef;
+    s1i = cyi[(1 - 1)] * coef;
+    if (iflag != 0) goto L150;
+    if (*id == 1) goto L140;
+    *air = csqr * s1r - csqi * s1i;
+    *aii = csqr * s1i + csqi * s1r;
+    return;
+L140:;
+    *air = -(*zr * s1r - *zi * s1i);
+    *aii = -(*zr * s1i + *zi * s1r);
+    return;
+L150:;
+    s1r = s1r * sfac;
+    s1i = s1i * sfac;
+    if (*id == 1) goto L160;
+    str = s1r * csqr - s1i * csqi;
+    s1i = s1r * csqi + s1i * csqr;
+    s1r = str;
+    *air = s1r / sfac;
+    *aii = s1i / sfac;
+    return;
+L160:;
+    str = -(s1r * *zr - s1i * *zi);
+    s1i = -(s1r * *zi + s1i * *zr);
+    s1r = str;
+    *air = s1r / sfac;
+    *aii = s1i / sfac;
+    return;
+L170:;
+    aa = 1.0e+3 * d1mach_(&(int){1});
+    s1r = zeror;
+    s1i = zeroi;
+    if (*id == 1) goto L190;
+    if (az <= aa) goto L180;
+    s1r = c2 * *zr;
+    s1i = c2 * *zi;
+L180:;
+    *air = c1 - s1r;
+    *aii = -s1i;
+    return;
+L190:;
+    *air = -c2;
+    *aii = 0.0e0;
+    aa = sqrt(aa);
+    if (az <= aa) goto L200;
+    s1r = 0.5e0 * (*zr * *zr - *zi * *zi);
+    s1i = *zr * *zi;
+L200:;
+    *air = *air + c1 * s1r;
+    *aii = *aii + c1 * s1i;
+    return;
+L210:;
+    *nz = 1;
+    *air = zeror;
+    *aii = zeroi;
+    return;
+L270:;
+    *nz = 0;
+    *ierr = 2;
+    return;
+L280:;
+    if (nn == (-1)) goto L270;
+    *nz = 0;
+    *ierr = 5;
+    return;
+L260:;
+    *ierr = 4;
+    *nz = 0;
+    return;
+}
@@ -0,0 +1,202 @@
+/* Generated by scripts/f77-to-c.py from amos/zasyi.f, do not edit. */
+
+#include "amos.h"
+
+void zasyi_(double *zr, double *zi, double *fnu, int *kode, int *n, double *yr, double *yi, int *nz, double *rl, double *tol, double *elim, double *alim)
+{
+    double aa = 0;
+    double aez = 0;
+    double ak = 0;
+    double ak1i = 0;
+    double ak1r = 0;
+    double arg = 0;
+    double arm = 0;
+    double atol = 0;
+    double az = 0;
+    double bb = 0;
+    double bk = 0;
+    double cki = 0;
+    double ckr = 0;
+    static double conei = 0.0e0;
+    static double coner = 1.0e0;
+    double cs1i = 0;
+    double cs1r = 0;
+    double cs2i = 0;
+    double cs2r = 0;
+    double czi = 0;
+    double czr = 0;
+    double dfnu = 0;
+    double dki = 0;
+    double dkr = 0;
+    double dnu2 = 0;
+    double ezi = 0;
+    double ezr = 0;
+    double fdn = 0;
+    int i = 0;
+    int ib = 0;
+    int il = 0;
+    int inu = 0;
+    int j = 0;
+    int jl = 0;
+    int k = 0;
+    int koded = 0;
+    int m = 0;
+    int nn = 0;
+    double p1i = 0;
+    double p1r = 0;
+    static double pi = 3.14159265358979324e0;
+    double raz = 0;
+    static double rtpi = 0.159154943091895336e0;
+    double rtr1 = 0;
+    double rzi = 0;
+    double rzr = 0;
+    double s = 0;
+    double s2i = 0;
+    double s2r = 0;
+    double sgn = 0;
+    double sqk = 0;
+    double sti = 0;
+    double str = 0;
+    double tzi = 0;
+    double tzr = 0;
+    static double zeroi = 0.0e0;
+    static double zeror = 0.0e0;
+    int i1_;
+    int i2_;
+    int i3_;
+    int i4_;
+
+    *nz = 0;
+    az = azabs_(zr, zi);
+    arm = 1.0e+3 * d1mach_(&(int){1});
+    rtr1 = sqrt(arm);
+    il = imin(2, *n);
+    dfnu = *fnu + (double)((float)(*n - il));
+    raz = 1.0e0 / az;
+    str = *zr * raz;
+    sti = -*zi * raz;
+    ak1r = rtpi * str * raz;
+    ak1i = rtpi * sti * raz;
+    azsqrt_(&ak1r, &ak1i, &ak1r, &ak1i);
+    czr = *zr;
+    czi = *zi;
+    if (*kode != 2) goto L10;
+    czr = zeror;
+    czi = *zi;
+L10:;
+    if (fabs(czr) > *elim) goto L100;
+    dnu2 = dfnu + dfnu;
+    koded = 1;
+    if ((fabs(czr) > *alim) && (*n > 2)) goto L20;
+    koded = 0;
+    azexp_(&czr, &czi, &str, &sti);
+    zmlt_(&ak1r, &ak1i, &str, &sti, &ak1r, &ak1i);
+L20:;
+    fdn = 0.0e0;
+    if (dnu2 > rtr1) fdn = dnu2 * dnu2;
+    ezr = *zr * 8.0e0;
+    ezi = *zi * 8.0e0;
+    aez = 8.0e0 * az;
+    s = *tol / aez;
+    jl = (int)((float)(*rl + *rl)) + 2;
+    p1r = zeror;
+    p1i = zeroi;
+    if (*zi == 0.0e0) goto L30;
+    inu = (int)((float)(*fnu));
+    arg = (*fnu - (double)((float)(inu))) * pi;
+    inu = inu + *n - il;
+    ak = -sin(arg);
+    bk = cos(arg);
+    if (*zi < 0.0e0) bk = -bk;
+    p1r = ak;
+    p1i = bk;
+    if ((inu) % (2) == 0) goto L30;
+    p1r = -p1r;
+    p1i = -p1i;
+L30:;
+    k = 1;
+    for (i1_ = il - k + 1; i1_ > 0; --i1_, k += 1) {
+        sqk = fdn - 1.0e0;
+        atol = s * fabs(sqk);
+        sgn = 1.0e0;
+        cs1r = coner;
+        cs1i = conei;
+        cs2r = coner;
+        cs2i = conei;
+        ckr = coner;
+        cki = conei;
+        ak = 0.0e0;
+        aa = 1.0e0;
+        bb = aez;
+        dkr = ezr;
+        dki = ezi;
+        j = 1;
+        for (i2_ = jl - j + 1; i2_ > 0; --i2_, j += 1) {
+            zdiv_(&ckr, &cki, &dkr, &dki, &str, &sti);
+            ckr = str * sqk;
+            cki = sti * sqk;
+            cs2r = cs2r + ckr;
+            cs2i = cs2i + cki;
+            sgn = -sgn;
+            cs1r = cs1r + ckr * sgn;
+            cs1i = cs1i + cki * sgn;
+            dkr = dkr + ezr;
+            dki = dki + ezi;
+            aa = aa * fabs(sqk) / bb;
+            bb = bb + aez;
+            ak = ak + 8.0e0;
+            sqk = sqk - ak;
+            if (aa <= atol) goto L50;
+        }
+        goto L110;
+    L50:;
+        s2r = cs1r;
+        s2i = cs1i;
+        if (*zr + *zr >= *elim) goto L60;
+        tzr = *zr + *zr;
+        tzi = *zi + *zi;
+        azexp_(&(double){-tzr}, &(double){-tzi}, &str, &sti);
+        zmlt_(&str, &sti, &p1r, &p1i, &str, &sti);
+        zmlt_(&str, &sti, &cs2r, &cs2i, &str, &sti);
+        s2r = s2r + str;
+        s2i = s2i + sti;
+    L60:;
+        fdn = fdn + 8.0e0 * dfnu + 4.0e0;
+        p1r = -p1r;
+        p1i = -p1i;
+        m = *n - il + k;
+        yr[(m - 1)] = s2r * ak1r - s2i * ak1i;
+        yi[(m - 1)] = s2r * ak1i + s2i * ak1r;
+    }
+    if (*n <= 2) return;
+    nn = *n;
+    k = nn - 2;
+    ak = (double)((float)(k));
+    str = *zr * raz;
+    sti = -*zi * raz;
+    rzr = (str + str) * raz;
+    rzi = (sti + sti) * raz;
+    ib = 3;
+    i = ib;
+    for (i3_ = nn - i + 1; i3_ > 0; --i3_, i += 1) {
+        yr[(k - 1)] = (ak + *fnu) * (rzr * yr[(k + 1 - 1)] - rzi * yi[(k + 1 - 1)]) + yr[(k + 2 - 1)];
+        yi[(k - 1)] = (ak + *fnu) * (rzr * yi[(k + 1 - 1)] + rzi * yr[(k + 1 - 1)]) + yi[(k + 2 - 1)];
+        ak = ak - 1.0e0;
+        k = k - 1;
+    }
+    if (koded == 0) return;
+    azexp_(&czr, &czi, &ckr, &cki);
+    i = 1;
+    for (i4_ = nn - i + 1; i4_ > 0; --i4_, i += 1) {
+        str = yr[(i - 1)] * ckr - yi[(i - 1)] * cki;
+        yi[(i - 1)] = yr[(i - 1)] * cki + yi[(i - 1)] * ckr;
+        yr[(i - 1)] = str;
+    }
+    return;
+L100:;
+    *nz = -1;
+    return;
+L110:;
+    *nz = -2;
+    return;
+}
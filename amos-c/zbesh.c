@@ -0,0 +1,173 @@
+/* Generated by scripts/f77-to-c.py from amos/zbesh.f, do not edit. */
+
+#include "amos.h"
+
+void zbesh_(double *zr, double *zi, double *fnu, int *kode, int *m, int *n, double *cyr, double *cyi, int *nz, int *ierr)
+{
+    double aa = 0;
+    double alim = 0;
+    double aln = 0;
+    double arg = 0;
+    double ascle = 0;
+    double atol = 0;
+    double az = 0;
+    double bb = 0;
+    double csgni = 0;
+    double csgnr = 0;
+    double dig = 0;
+    double elim = 0;
+    double fmm = 0;
+    double fn = 0;
+    double fnul = 0;
+    static double hpi = 1.57079632679489662e0;
+    int i = 0;
+    int inu = 0;
+    int inuh = 0;
+    int ir = 0;
+    int k = 0;
+    int k1 = 0;
+    int k2 = 0;
+    int mm = 0;
+    int mr = 0;
+    int nn = 0;
+    int nuf = 0;
+    int nw = 0;
+    double r1m5 = 0;
+    double rhpi = 0;
+    double rl = 0;
+    double rtol = 0;
+    double sgn = 0;
+    double sti = 0;
+    double str = 0;
+    double tol = 0;
+    double ufl = 0;
+    double zni = 0;
+    double znr = 0;
+    double zti = 0;
+    int i1_;
+
+    *ierr = 0;
+    *nz = 0;
+    if (*zr == 0.0e0 && *zi == 0.0e0) *ierr = 1;
+    if (*fnu < 0.0e0) *ierr = 1;
+    if (*m < 1 || *m > 2) *ierr = 1;
+    if (*kode < 1 || *kode > 2) *ierr = 1;
+    if (*n < 1) *ierr = 1;
+    if (*ierr != 0) return;
+    nn = *n;
+    tol = dmax(d1mach_(&(int){4}), 1.0e-18);
+    k1 = i1mach_(&(int){15});
+    k2 = i1mach_(&(int){16});
+    r1m5 = d1mach_(&(int){5});
+    k = imin(abs(k1), abs(k2));
+    elim = 2.303e0 * ((double)((float)(k)) * r1m5 - 3.0e0);
+    k1 = i1mach_(&(int){14}) - 1;
+    aa = r1m5 * (double)((float)(k1));
+    dig = dmin(aa, 18.0e0);
+    aa = aa * 2.303e0;
+    alim = elim + dmax(-aa, -41.45e0);
+    fnul = 10.0e0 + 6.0e0 * (dig - 3.0e0);
+    rl = 1.2e0 * dig + 3.0e0;
+    fn = *fnu + (double)((float)(nn - 1));
+    mm = 3 - *m - *m;
+    fmm = (double)((float)(mm));
+    znr = fmm * *zi;
+    zni = -fmm * *zr;
+    az = azabs_(zr, zi);
+    aa = 0.5e0 / tol;
+    bb = (double)((float)(i1mach_(&(int){9}))) * 0.5e0;
+    aa = dmin(aa, bb);
+    if (az > aa) goto L260;
+    if (fn > aa) goto L260;
+    aa = sqrt(aa);
+    if (az > aa) *ierr = 3;
+    if (fn > aa) *ierr = 3;
+    ufl = d1mach_(&(int){1}) * 1.0e+3;
+    if (az < ufl) goto L230;
+    if (*fnu > fnul) goto L90;
+    if (fn <= 1.0e0) goto L70;
+    if (fn > 2.0e0) goto L60;
+    if (az > tol) goto L70;
+    arg = 0.5e0 * az;
+    aln = -fn * log(arg);
+    if (aln > elim) goto L230;
+    goto L70;
+L60:;
+    zuoik_(&znr, &zni, fnu, kode, &(int){2}, &nn, cyr, cyi, &nuf, &tol, &elim, &alim);
+    if (nuf < 0) goto L230;
+    *nz = *nz + nuf;
+    nn = nn - nuf;
+    if (nn == 0) goto L140;
+L70:;
+    if ((znr < 0.0e0) || (znr == 0.0e0 && zni < 0.0e0 && *m == 2)) goto L80;
+    zbknu_(&znr, &zni, fnu, kode, &nn, cyr, cyi, nz, &tol, &elim, &alim);
+    goto L110;
+L80:;
+    mr = -mm;
+    zacon_(&znr, &zni, fnu, kode, &mr, &nn, cyr, cyi, &nw, &rl, &fnul, &tol, &elim, &alim);
+    if (nw < 0) goto L240;
+    *nz = nw;
+    goto L110;
+L90:;
+    mr = 0;
+    if ((znr >= 0.0e0) && (znr != 0.0e0 || zni >= 0.0e0 || *m != 2)) goto L100;
+    mr = -mm;
+    if (znr != 0.0e0 || zni >= 0.0e0) goto L100;
+    znr = -znr;
+    zni = -zni;
+L100:;
+    zbunk_(&znr, &zni, fnu, kode, &mr, &nn, cyr, cyi, &nw, &tol, &elim, &alim);
+    if (nw < 0) goto L240;
+    *nz = *nz + nw;
+L110:;
+    sgn = d_sign(hpi, -fmm);
+    inu = (int)((float)(*fnu));
+    inuh = inu / 2;
+    ir = inu - 2 * inuh;
+    arg = (*fnu - (double)((float)(inu - ir))) * sgn;
+    rhpi = 1.0e0 / sgn;
+    csgni = rhpi * cos(arg);
+    csgnr = -rhpi * sin(arg);
+    if ((inuh) % (2) == 0) goto L120;
+    csgnr = -csgnr;
+    csgni = -csgni;
+L120:;
+    zti = -fmm;
+    rtol = 1.0e0 / tol;
+    ascle = ufl * rtol;
+    i = 1;
+    for (i1_ = nn - i + 1; i1_ > 0; --i1_, i += 1) {
+        aa = cyr[(i - 1)];
+        bb = cyi[(i - 1)];
+        atol = 1.0e0;
+        if (dmax(fabs(aa), fabs(bb)) > ascle) goto L135;
+        aa = aa * rtol;
+        bb = bb * rtol;
+        atol = tol;
+    L135:;
+        str = aa * csgnr - bb * csgni;
+        sti = aa * csgni + bb * csgnr;
+        cyr[(i - 1)] = str * atol;
+        cyi[(i - 1)] = sti * atol;
+        str = -csgni * zti;
+        csgni = csgnr * zti;
+        csgnr = str;
+    }
+    return;
+L140:;
+    if (znr < 0.0e0) goto L230;
+    return;
+L230:;
+    *nz = 0;
+    *ierr = 2;
+    return;
+L240:;
+    if (nw == (-1)) goto L230;
+    *nz = 0;
+    *ierr = 5;
+    return;
+L260:;
+    *nz = 0;
+    *ierr = 4;
+    return;
+}
@@ -0,0 +1,126 @@
+/* Generated by scripts/f77-to-c.py from amos/zbesj.f, do not edit. */
+
+#include "amos.h"
+
+void zbesj_(double *zr, double *zi, double *fnu, int *kode, int *n, double *cyr, double *cyi, int *nz, int *ierr)
+{
+    double aa = 0;
+    double alim = 0;
+    double arg = 0;
+    double ascle = 0;
+    double atol = 0;
+    double az = 0;
+    double bb = 0;
+    double cii = 0;
+    double csgni = 0;
+    double csgnr = 0;
+    double dig = 0;
+    double elim = 0;
+    double fn = 0;
+    double fnul = 0;
+    static double hpi = 1.57079632679489662e0;
+    int i = 0;
+    int inu = 0;
+    int inuh = 0;
+    int ir = 0;
+    int k = 0;
+    int k1 = 0;
+    int k2 = 0;
+    int nl = 0;
+    double r1m5 = 0;
+    double rl = 0;
+    double rtol = 0;
+    double sti = 0;
+    double str = 0;
+    double tol = 0;
+    double zni = 0;
+    double znr = 0;
+    int i1_;
+
+    *ierr = 0;
+    *nz = 0;
+    if (*fnu < 0.0e0) *ierr = 1;
+    if (*kode < 1 || *kode > 2) *ierr = 1;
+    if (*n < 1) *ierr = 1;
+    if (*ierr != 0) return;
+    tol = dmax(d1mach_(&(int){4}), 1.0e-18);
+    k1 = i1mach_(&(int){15});
+    k2 = i1mach_(&(int){16});
+    r1m5 = d1mach_(&(int){5});
+    k = imin(abs(k1), abs(k2));
+    elim = 2.303e0 * ((double)((float)(k)) * r1m5 - 3.0e0);
+    k1 = i1mach_(&(int){14}) - 1;
+    aa = r1m5 * (double)((float)(k1));
+    dig = dmin(aa, 18.0e0);
+    aa = aa * 2.303e0;
+    alim = elim + dmax(-aa, -41.45e0);
+    rl = 1.2e0 * dig + 3.0e0;
+    fnul = 10.0e0 + 6.0e0 * (dig - 3.0e0);
+    az = azabs_(zr, zi);
+    fn = *fnu + (double)((float)(*n - 1));
+    aa = 0.5e0 / tol;
+    bb = (double)((float)(i1mach_(&(int){9}))) * 0.5e0;
+    aa = dmin(aa, bb);
+    if (az > aa) goto L260;
+    if (fn > aa) goto L260;
+    aa = sqrt(aa);
+    if (az > aa) *ierr = 3;
+    if (fn > aa) *ierr = 3;
+    cii = 1.0e0;
+    inu = (int)((float)(*fnu));
+    inuh = inu / 2;
+    ir = inu - 2 * inuh;
+    arg = (*fnu - (double)((float)(inu - ir))) * hpi;
+    csgnr = cos(arg);
+    csgni = sin(arg);
+    if ((inuh) % (2) == 0) goto L40;
+    csgnr = -csgnr;
+    csgni = -csgni;
+L40:;
+    znr = *zi;
+    zni = -*zr;
+    if (*zi >= 0.0e0) goto L50;
+    znr = -znr;
+    zni = -zni;
+    csgni = -csgni;
+    cii = -cii;
+L50:;
+    zbinu_(&znr, &zni, fnu, kode, n, cyr, cyi, nz, &rl, &fnul, &tol, &elim, &alim);
+    if (*nz < 0) goto L130;
+    nl = *n - *nz;
+    if (nl == 0) return;
+    rtol = 1.0e0 / tol;
+    ascle = d1mach_(&(int){1}) * rtol * 1.0e+3;
+    i = 1;
+    for (i1_ = nl - i + 1; i1_ > 0; --i1_, i += 1) {
+        aa = cyr[(i - 1)];
+        bb = cyi[(i - 1)];
+        atol = 1.0e0;
+        if (dmax(fabs(aa), fabs(bb)) > ascle) goto L55;
+        aa = aa * rtol;
+        bb = bb * rtol;
+        atol = tol;
+    L55:;
+        str = aa * csgnr - bb * csgni;
+        sti = aa * csgni + bb * csgnr;
+        cyr[(i - 1)] = str * atol;
+        cyi[(i - 1)] = sti * atol;
+        str = -csgni * cii;
+        csgni = csgnr * cii;
+        csgnr = str;
+    }
+    return;
+L130:;
+    if (*nz == (-2)) goto L140;
+    *nz = 0;
+    *ierr = 2;
+    return;
+L140:;
+    *nz = 0;
+    *ierr = 5;
+    return;
+L260:;
+    *nz = 0;
+    *ierr = 4;
+    return;
+}
@@ -0,0 +1,116 @@
+/* Generated by scripts/f77-to-c.py from amos/zbesk.f, do not edit. */
+
+#include "amos.h"
+
+void zbesk_(double *zr, double *zi, double *fnu, int *kode, int *n, double *cyr, double *cyi, int *nz, int *ierr)
+{
+    double aa = 0;
+    double alim = 0;
+    double aln = 0;
+    double arg = 0;
+    double az = 0;
+    double bb = 0;
+    double dig = 0;
+    double elim = 0;
+    double fn = 0;
+    double fnul = 0;
+    int k = 0;
+    int k1 = 0;
+    int k2 = 0;
+    int mr = 0;
+    int nn = 0;
+    int nuf = 0;
+    int nw = 0;
+    double r1m5 = 0;
+    double rl = 0;
+    double tol = 0;
+    double ufl = 0;
+
+    *ierr = 0;
+    *nz = 0;
+    if (*zi == 0.0e0f && *zr == 0.0e0f) *ierr = 1;
+    if (*fnu < 0.0e0) *ierr = 1;
+    if (*kode < 1 || *kode > 2) *ierr = 1;
+    if (*n < 1) *ierr = 1;
+    if (*ierr != 0) return;
+    nn = *n;
+    tol = dmax(d1mach_(&(int){4}), 1.0e-18);
+    k1 = i1mach_(&(int){15});
+    k2 = i1mach_(&(int){16});
+    r1m5 = d1mach_(&(int){5});
+    k = imin(abs(k1), abs(k2));
+    elim = 2.303e0 * ((double)((float)(k)) * r1m5 - 3.0e0);
+    k1 = i1mach_(&(int){14}) - 1;
+    aa = r1m5 * (double)((float)(k1));
+    dig = dmin(aa, 18.0e0);
+    aa = aa * 2.303e0;
+    alim = elim + dmax(-aa, -41.45e0);
+    fnul = 10.0e0 + 6.0e0 * (dig - 3.0e0);
+    rl = 1.2e0 * dig + 3.0e0;
+    az = azabs_(zr, zi);
+    fn = *fnu + (double)((float)(nn - 1));
+    aa = 0.5e0 / tol;
+    bb = (double)((float)(i1mach_(&(int){9}))) * 0.5e0;
+    aa = dmin(aa, bb);
+    if (az > aa) goto L260;
+    if (fn > aa) goto L260;
+    aa = sqrt(aa);
+    if (az > aa) *ierr = 3;
+    if (fn > aa) *ierr = 3;
+    ufl = d1mach_(&(int){1}) * 1.0e+3;
+    if (az < ufl) goto L180;
+    if (*fnu > fnul) goto L80;
+    if (fn <= 1.0e0) goto L60;
+    if (fn > 2.0e0) goto L50;
+    if (az > tol) goto L60;
+    arg = 0.5e0 * az;
+    aln = -fn * log(arg);
+    if (aln > elim) goto L180;
+    goto L60;
+L50:;
+    zuoik_(zr, zi, fnu, kode, &(int){2}, &nn, cyr, cyi, &nuf, &tol, &elim, &alim);
+    if (nuf < 0) goto L180;
+    *nz = *nz + nuf;
+    nn = nn - nuf;
+    if (nn == 0) goto L100;
+L60:;
+    if (*zr < 0.0e0) goto L70;
+    zbknu_(zr, zi, fnu, kode, &nn, cyr, cyi, &nw, &tol, &elim, &alim);
+    if (nw < 0) goto L200;
+    *nz = nw;
+    return;
+L70:;
+    if (*nz != 0) goto L180;
+    mr = 1;
+    if (*zi < 0.0e0) mr = -1;
+    zacon_(zr, zi, fnu, kode, &mr, &nn, cyr, cyi, &nw, &rl, &fnul, &tol, &elim, &alim);
+    if (nw < 0) goto L200;
+    *nz = nw;
+    return;
+L80:;
+    mr = 0;
+    if (*zr >= 0.0e0) goto L90;
+    mr = 1;
+    if (*zi < 0.0e0) mr = -1;
+L90:;
+    zbunk_(zr, zi, fnu, kode, &mr, &nn, cyr, cyi, &nw, &tol, &elim, &alim);
+    if (nw < 0) goto L200;
+    *nz = *nz + nw;
+    return;
+L100:;
+    if (*zr < 0.0e0) goto L180;
+    return;
+L180:;
+    *nz = 0;
+    *ierr = 2;
+    return;
+L200:;
+    if (nw == (-1)) goto L180;
+    *nz = 0;
+    *ierr = 5;
+    return;
+L260:;
+    *nz = 0;
+    *ierr = 4;
+    return;
+}
@@ -0,0 +1,114 @@
+/* Generated by scripts/f77-to-c.py from amos/zbesy.f, do not edit. */
+
+#include "amos.h"
+
+void zbesy_(double *zr, double *zi, double *fnu, int *kode, int *n, double *cyr, double *cyi, int *nz, double *cwrkr, double *cwrki, int *ierr)
+{
+    double aa = 0;
+    double ascle = 0;
+    double atol = 0;
+    double bb = 0;
+    double c1i = 0;
+    double c1r = 0;
+    double c2i = 0;
+    double c2r = 0;
+    double elim = 0;
+    double exi = 0;
+    double exr = 0;
+    double ey = 0;
+    double hcii = 0;
+    int i = 0;
+    int k = 0;
+    int k1 = 0;
+    int k2 = 0;
+    int nz1 = 0;
+    int nz2 = 0;
+    double r1m5 = 0;
+    double rtol = 0;
+    double sti = 0;
+    double str = 0;
+    double tay = 0;
+    double tol = 0;
+    int i1_;
+    int i2_;
+
+    *ierr = 0;
+    *nz = 0;
+    if (*zr == 0.0e0 && *zi == 0.0e0) *ierr = 1;
+    if (*fnu < 0.0e0) *ierr = 1;
+    if (*kode < 1 || *kode > 2) *ierr = 1;
+    if (*n < 1) *ierr = 1;
+    if (*ierr != 0) return;
+    hcii = 0.5e0;
+    zbesh_(zr, zi, fnu, kode, &(int){1}, n, cyr, cyi, &nz1, ierr);
+    if (*ierr != 0 && *ierr != 3) goto L170;
+    zbesh_(zr, zi, fnu, kode, &(int){2}, n, cwrkr, cwrki, &nz2, ierr);
+    if (*ierr != 0 && *ierr != 3) goto L170;
+    *nz = imin(nz1, nz2);
+    if (*kode == 2) goto L60;
+    i = 1;
+    for (i1_ = *n - i + 1; i1_ > 0; --i1_, i += 1) {
+        str = cwrkr[(i - 1)] - cyr[(i - 1)];
+        sti = cwrki[(i - 1)] - cyi[(i - 1)];
+        cyr[(i - 1)] = -sti * hcii;
+        cyi[(i - 1)] = str * hcii;
+    }
+    return;
+L60:;
+    tol = dmax(d1mach_(&(int){4}), 1.0e-18);
+    k1 = i1mach_(&(int){15});
+    k2 = i1mach_(&(int){16});
+    k = imin(abs(k1), abs(k2));
+    r1m5 = d1mach_(&(int){5});
+    elim = 2.303e0 * ((double)((float)(k)) * r1m5 - 3.0e0);
+    exr = cos(*zr);
+    exi = sin(*zr);
+    ey = 0.0e0;
+    tay = fabs(*zi + *zi);
+    if (tay < elim) ey = exp(-tay);
+    if (*zi < 0.0e0) goto L90;
+    c1r = exr * ey;
+    c1i = exi * ey;
+    c2r = exr;
+    c2i = -exi;
+L70:;
+    *nz = 0;
+    rtol = 1.0e0 / tol;
+    ascle = d1mach_(&(int){1}) * rtol * 1.0e+3;
+    i = 1;
+    for (i2_ = *n - i + 1; i2_ > 0; --i2_, i += 1) {
+        aa = cwrkr[(i - 1)];
+        bb = cwrki[(i - 1)];
+        atol = 1.0e0;
+        if (dmax(fabs(aa), fabs(bb)) > ascle) goto L75;
+        aa = aa * rtol;
+        bb = bb * rtol;
+        atol = tol;
+    L75:;
+        str = (aa * c2r - bb * c2i) * atol;
+        sti = (aa * c2i + bb * c2r) * atol;
+        aa = cyr[(i - 1)];
+        bb = cyi[(i - 1)];
+        atol = 1.0e0;
+        if (dmax(fabs(aa), fabs(bb)) > ascle) goto L85;
+        aa = aa * rtol;
+        bb = bb * rtol;
+        atol = tol;
+    L85:;
+        str = str - (aa * c1r - bb * c1i) * atol;
+        sti = sti - (aa * c1i + bb * c1r) * atol;
+        cyr[(i - 1)] = -sti * hcii;
+        cyi[(i - 1)] = str * hcii;
+        if (str == 0.0e0 && sti == 0.0e0 && ey == 0.0e0) *nz = *nz + 1;
+    }
+    return;
+L90:;
+    c1r = exr;
+    c1i = exi;
+    c2r = exr * ey;
+    c2i = -exi * ey;
+    goto L70;
+L170:;
+    *nz = 0;
+    return;
+}
@@ -0,0 +1,90 @@
+/* Generated by scripts/f77-to-c.py from amos/zbinu.f, do not edit. */
+
+#include "amos.h"
+
+void zbinu_(double *zr, double *zi, double *fnu, int *kode, int *n, double *cyr, double *cyi, int *nz, double *rl, double *fnul, double *tol, double *elim, double *alim)
+{
+    double az = 0;
+    double cwi[2];
+    double cwr[2];
+    double dfnu = 0;
+    int i = 0;
+    int inw = 0;
+    int nlast = 0;
+    int nn = 0;
+    int nui = 0;
+    int nw = 0;
+    static double zeroi = 0.0e0;
+    static double zeror = 0.0e0;
+    int i1_;
+
+    *nz = 0;
+    az = azabs_(zr, zi);
+    nn = *n;
+    dfnu = *fnu + (double)((float)(*n - 1));
+    if (az <= 2.0e0) goto L10;
+    if (az * az * 0.25e0 > dfnu + 1.0e0) goto L20;
+L10:;
+    zseri_(zr, zi, fnu, kode, &nn, cyr, cyi, &nw, tol, elim, alim);
+    inw = abs(nw);
+    *nz = *nz + inw;
+    nn = nn - inw;
+    if (nn == 0) return;
+    if (nw >= 0) goto L120;
+    dfnu = *fnu + (double)((float)(nn - 1));
+L20:;
+    if (az < *rl) goto L40;
+    if (dfnu <= 1.0e0) goto L30;
+    if (az + az < dfnu * dfnu) goto L50;
+L30:;
+    zasyi_(zr, zi, fnu, kode, &nn, cyr, cyi, &nw, rl, tol, elim, alim);
+    if (nw < 0) goto L130;
+    goto L120;
+L40:;
+    if (dfnu <= 1.0e0) goto L70;
+L50:;
+    zuoik_(zr, zi, fnu, kode, &(int){1}, &nn, cyr, cyi, &nw, tol, elim, alim);
+    if (nw < 0) goto L130;
+    *nz = *nz + nw;
+    nn = nn - nw;
+    if (nn == 0) return;
+    dfnu = *fnu + (double)((float)(nn - 1));
+    if (dfnu > *fnul) goto L110;
+    if (az > *fnul) goto L110;
+L60:;
+    if (az > *rl) goto L80;
+L70:;
+    zmlri_(zr, zi, fnu, kode, &nn, cyr, cyi, &nw, tol);
+    if (nw < 0) goto L130;
+    goto L120;
+L80:;
+    zuoik_(zr, zi, fnu, kode, &(int){2}, &(int){2}, cwr, cwi, &nw, tol, elim, alim);
+    if (nw >= 0) goto L100;
+    *nz = nn;
+    i = 1;
+    for (i1_ = nn - i + 1; i1_ > 0; --i1_, i += 1) {
+        cyr[(i - 1)] = zeror;
+        cyi[(i - 1)] = zeroi;
+    }
+    return;
+L100:;
+    if (nw > 0) goto L130;
+    zwrsk_(zr, zi, fnu, kode, &nn, cyr, cyi, &nw, cwr, cwi, tol, elim, alim);
+    if (nw < 0) goto L130;
+    goto L120;
+L110:;
+    nui = (int)((float)(*fnul - dfnu)) + 1;
+    nui = imax(nui, 0);
+    zbuni_(zr, zi, fnu, kode, &nn, cyr, cyi, &nw, &nui, &nlast, fnul, tol, elim, alim);
+    if (nw < 0) goto L130;
+    *nz = *nz + nw;
+    if (nlast == 0) goto L120;
+    nn = nlast;
+    goto L60;
+L120:;
+    return;
+L130:;
+    *nz = -1;
+    if (nw == (-2)) *nz = -2;
+    return;
+}
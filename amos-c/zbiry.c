@@ -0,0 +1,257 @@
+/* Generated by scripts/f77-to-c.py from amos/zbiry.f, do not edit. */
+
+#include "amos.h"
+
+void zbiry_(double *zr, double *zi, int *id, int *kode, double *bir, double *bii, int *ierr)
+{
+    double aa = 0;
+    double ad = 0;
+    double ak = 0;
+    double alim = 0;
+    double atrm = 0;
+    double az = 0;
+    double az3 = 0;
+    double bb = 0;
+    double bk = 0;
+    static double c1 = 6.14926627446000736e-01;
+    static double c2 = 4.48288357353826359e-01;
+    double cc = 0;
+    double ck = 0;
+    static double coef = 5.77350269189625765e-01;
+    static double conei = 0.0e0;
+    static double coner = 1.0e0;
+    double csqi = 0;
+    double csqr = 0;
+    double cyi[2];
+    double cyr[2];
+    double d1 = 0;
+    double d2 = 0;
+    double dig = 0;
+    double dk = 0;
+    double eaa = 0;
+    double elim = 0;
+    double fid = 0;
+    double fmr = 0;
+    double fnu = 0;
+    double fnul = 0;
+    int k = 0;
+    int k1 = 0;
+    int k2 = 0;
+    int nz = 0;
+    static double pi = 3.14159265358979324e+00;
+    double r1m5 = 0;
+    double rl = 0;
+    double s1i = 0;
+    double s1r = 0;
+    double s2i = 0;
+    double s2r = 0;
+    double sfac = 0;
+    double sti = 0;
+    double str = 0;
+    double tol = 0;
+    double trm1i = 0;
+    double trm1r = 0;
+    double trm2i = 0;
+    double trm2r = 0;
+    static double tth = 6.66666666666666667e-01;
+    double z3i = 0;
+    double z3r = 0;
+    double ztai = 0;
+    double ztar = 0;
+    int i1_;
+
+    *ierr = 0;
+    nz = 0;
+    if (*id < 0 || *id > 1) *ierr = 1;
+    if (*kode < 1 || *kode > 2) *ierr = 1;
+    if (*ierr != 0) return;
+    az = azabs_(zr, zi);
+    tol = dmax(d1mach_(&(int){4}), 1.0e-18);
+    fid = (double)((float)(*id));
+    if (az > 1.0e0f) goto L70;
+    s1r = coner;
+    s1i = conei;
+    s2r = coner;
+    s2i = conei;
+    if (az < tol) goto L130;
+    aa = az * az;
+    if (aa < tol / az) goto L40;
+    trm1r = coner;
+    trm1i = conei;
+    trm2r = coner;
+    trm2i = conei;
+    atrm = 1.0e0;
+    str = *zr * *zr - *zi * *zi;
+    sti = *zr * *zi + *zi * *zr;
+    z3r = str * *zr - sti * *zi;
+    z3i = str * *zi + sti * *zr;
+    az3 = az * aa;
+    ak = 2.0e0 + fid;
+    bk = 3.0e0 - fid - fid;
+    ck = 4.0e0 - fid;
+    dk = 3.0e0 + fid + fid;
+    d1 = ak * dk;
+    d2 = bk * ck;
+    ad = dmin(d1, d2);
+    ak = 24.0e0 + 9.0e0 * fid;
+    bk = 30.0e0 - 9.0e0 * fid;
+    k = 1;
+    for (i1_ = 25 - k + 1; i1_ > 0; --i1_, k += 1) {
+        str = (trm1r * z3r - trm1i * z3i) / d1;
+        trm1i = (trm1r * z3i + trm1i * z3r) / d1;
+        trm1r = str;
+        s1r = s1r + trm1r;
+        s1i = s1i + trm1i;
+        str = (trm2r * z3r - trm2i * z3i) / d2;
+        trm2i = (trm2r * z3i + trm2i * z3r) / d2;
+        trm2r = str;
+        s2r = s2r + trm2r;
+        s2i = s2i + trm2i;
+        atrm = atrm * az3 / ad;
+        d1 = d1 + ak;
+        d2 = d2 + bk;
+        ad = dmin(d1, d2);
+        if (atrm < tol * ad) goto L40;
+        ak = ak + 18.0e0;
+        bk = bk + 18.0e0;
+    }
+L40:;
+    if (*id == 1) goto L50;
+    *bir = c1 * s1r + c2 * (*zr * s2r - *zi * s2i);
+    *bii = c1 * s1i + c2 * (*zr * s2i + *zi * s2r);
+    if (*kode == 1) return;
+    azsqrt_(zr, zi, &str, &sti);
+    ztar = tth * (*zr * str - *zi * sti);
+    ztai = tth * (*zr * sti + *zi * str);
+    aa = ztar;
+    aa = -fabs(aa);
+    eaa = exp(aa);
+    *bir = *bir * eaa;
+    *bii = *bii * eaa;
+    return;
+L50:;
+    *bir = s2r * c2;
+    *bii = s2i * c2;
+    if (az <= tol) goto L60;
+    cc = c1 / (1.0e0 + fid);
+    str = s1r * *zr - s1i * *zi;
+    sti = s1r * *zi + s1i * *zr;
+    *bir = *bir + cc * (str * *zr - sti * *zi);
+    *bii = *bii + cc * (str * *zi + sti * *zr);
+L60:;
+    if (*kode == 1) return;
+    azsqrt_(zr, zi, &str, &sti);
+    ztar = tth * (*zr * str - *zi * sti);
+    ztai = tth * (*zr * sti + *zi * str);
+    aa = ztar;
+    aa = -fabs(aa);
+    eaa = exp(aa);
+    *bir = *bir * eaa;
+    *bii = *bii * eaa;
+    return;
+L70:;
+    fnu = (1.0e0 + fid) / 3.0e0;
+    k1 = i1mach_(&(int){15});
+    k2 = i1mach_(&(int){16});
+    r1m5 = d1mach_(&(int){5});
+    k = imin(abs(k1), abs(k2));
+    elim = 2.303e0 * ((double)((float)(k)) * r1m5 - 3.0e0);
+    k1 = i1mach_(&(int){14}) - 1;
+    aa = r1m5 * (double)((float)(k1));
+    dig = dmin(aa, 18.0e0);
+    aa = aa * 2.303e0;
+    alim = elim + dmax(-aa, -41.45e0);
+    rl = 1.2e0 * dig + 3.0e0;
+    fnul = 10.0e0 + 6.0e0 * (dig - 3.0e0);
+    aa = 0.5e0 / tol;
+    bb = (double)((float)(i1mach_(&(int){9}))) * 0.5e0;
+    aa = dmin(aa, bb);
+    aa = pow(aa, tth);
+    if (az > aa) goto L260;
+    aa = sqrt(aa);
+    if (az > aa) *ierr = 3;
+    azsqrt_(zr, zi, &csqr, &csqi);
+    ztar = tth * (*zr * csqr - *zi * csqi);
+    ztai = tth * (*zr * csqi + *zi * csqr);
+    sfac = 1.0e0;
+    ak = ztai;
+    if (*zr >= 0.0e0) goto L80;
+    bk = ztar;
+    ck = -fabs(bk);
+    ztar = ck;
+    ztai = ak;
+L80:;
+    if (*zi != 0.0e0 || *zr > 0.0e0) goto L90;
+    ztar = 0.0e0;
+    ztai = ak;
+L90:;
+    aa = ztar;
+    if (*kode == 2) goto L100;
+    bb = fabs(aa);
+    if (bb < alim) goto L100;
+    bb = bb + 0.25e0 * log(az);
+    sfac = tol;
+    if (bb > elim) goto L190;
+L100:;
+    fmr = 0.0e0;
+    if (aa >= 0.0e0 && *zr > 0.0e0) goto L110;
+    fmr = pi;
+    if (*zi < 0.0e0) fmr = -pi;
+    ztar = -ztar;
+    ztai = -ztai;
+L110:;
+    zbinu_(&ztar, &ztai, &fnu, kode, &(int){1}, cyr, cyi, &nz, &rl, &fnul, &tol, &elim, &alim);
+    if (nz < 0) goto L200;
+    aa = fmr * fnu;
+    z3r = sfac;
+    str = cos(aa);
+    sti = sin(aa);
+    s1r = (str * cyr[(1 - 1)] - sti * cyi[(1 - 1)]) * z3r;
+    s1i = (str * cyi[(1 - 1)] + sti * cyr[(1 - 1)]) * z3r;
+    fnu = (2.0e0 - fid) / 3.0e0;
+    zbinu_(&ztar, &ztai, &fnu, kode, &(int){2}, cyr, cyi, &nz, &rl, &fnul, &tol, &elim, &alim);
+    cyr[(1 - 1)] = cyr[(1 - 1)] * z3r;
+    cyi[(1 - 1)] = cyi[(1 - 1)] * z3r;
+    cyr[(2 - 1)] = cyr[(2 - 1)] * z3r;
+    cyi[(2 - 1)] = cyi[(2 - 1)] * z3r;
+    zdiv_(&cyr[(1 - 1)], &cyi[(1 - 1)], &ztar, &ztai, &str, &sti);
+    s2r = (fnu + fnu) * str + cyr[(2 - 1)];
+    s2i = (fnu + fnu) * sti + cyi[(2 - 1)];
+    aa = fmr * (fnu - 1.0e0);
+    str = cos(aa);
+    sti = sin(aa);
+    s1r = coef * (s1r + s2r * str - s2i * sti);
+    s1i = coef * (s1i + s2r * sti + s2i * str);
+    if (*id == 1) goto L120;
+    str = csqr * s1r - csqi * s1i;
+    s1i = csqr * s1i + csqi * s1r;
+    s1r = str;
+    *bir = s1r / sfac;
+    *bii = s1i / sfac;
+    return;
+L120:;
+    str = *zr * s1r - *zi * s1i;
+    s1i = *zr * s1i + *zi * s1r;
+    s1r = str;
+    *bir = s1r / sfac;
+    *bii = s1i / sfac;
+    return;
+L130:;
+    aa = c1 * (1.0e0 - fid) + fid * c2;
+    *bir = aa;
+    *bii = 0.0e0;
+    return;
+L190:;
+    *ierr = 2;
+    nz = 0;
+    return;
+L200:;
+    if (nz == (-1)) goto L190;
+    nz = 0;
+    *ierr = 5;
+    return;
+L260:;
+    *ierr = 4;
+    nz = 0;
+    return;
+}
@@ -0,0 +1,595 @@
+/* Generated by scripts/f77-to-c.py from amos/zbknu.f, do not edit. */
+
+#include "amos.h"
+
+void zbknu_(double *zr, double *zi, double *fnu, int *kode, int *n, double *yr, double *yi, int *nz, double *tol, double *elim, double *alim)
+{
+    double a1 = 0;
+    double a2 = 0;
+    double aa = 0;
+    double ak = 0;
+    double alas = 0;
+    double as = 0;
+    double ascle = 0;
+    double bb = 0;
+    double bk = 0;
+    double bry[3];
+    double caz = 0;
+    double cbi = 0;
+    double cbr = 0;
+    static double cc[8] = {
+        5.77215664901532861e-01, -4.20026350340952355e-02, -4.21977345555443367e-02,
+        7.21894324666309954e-03, -2.15241674114950973e-04, -2.01348547807882387e-05,
+        1.13302723198169588e-06, 6.11609510448141582e-09,
+    };
+    double cchi = 0;
+    double cchr = 0;
+    double celmr = 0;
+    double cki = 0;
+    double ckr = 0;
+    double coefi = 0;
+    double coefr = 0;
+    static double conei = 0.0e0;
+    static double coner = 1.0e0;
+    double crscr = 0;
+    double csclr = 0;
+    double cshi = 0;
+    double cshr = 0;
+    double csi = 0;
+    double csr = 0;
+    double csrr[3];
+    double cssr[3];
+    static double ctwor = 2.0e0;
+    double cyi[2];
+    double cyr[2];
+    static double czeroi = 0.0e0;
+    static double czeror = 0.0e0;
+    double czi = 0;
+    double czr = 0;
+    double dnu = 0;
+    double dnu2 = 0;
+    static double dpi = 3.14159265358979324e0;
+    double elm = 0;
+    double etest = 0;
+    double fc = 0;
+    double fhs = 0;
+    double fi = 0;
+    double fk = 0;
+    double fks = 0;
+    double fmui = 0;
+    double fmur = 0;
+    static double fpi = 1.89769999331517738e0;
+    double fr = 0;
+    double g1 = 0;
+    double g2 = 0;
+    double helim = 0;
+    static double hpi = 1.57079632679489662e0;
+    int i = 0;
+    int ic = 0;
+    int idum = 0;
+    int iflag = 0;
+    int inu = 0;
+    int inub = 0;
+    int j = 0;
+    int k = 0;
+    int kflag = 0;
+    int kk = 0;
+    static int kmax = 30;
+    int koded = 0;
+    int nw = 0;
+    double p1i = 0;
+    double p1r = 0;
+    double p2i = 0;
+    double p2m = 0;
+    double p2r = 0;
+    double pi = 0;
+    double pr = 0;
+    double pti = 0;
+    double ptr = 0;
+    double qi = 0;
+    double qr = 0;
+    static double r1 = 2.0e0;
+    double rak = 0;
+    double rcaz = 0;
+    static double rthpi = 1.25331413731550025e0;
+    double rzi = 0;
+    double rzr = 0;
+    double s = 0;
+    double s1i = 0;
+    double s1r = 0;
+    double s2i = 0;
+    double s2r = 0;
+    double smui = 0;
+    double smur = 0;
+    static double spi = 1.90985931710274403e0;
+    double sti = 0;
+    double str = 0;
+    double t1 = 0;
+    double t2 = 0;
+    double tm = 0;
+    static double tth = 6.66666666666666666e-01;
+    double zdi = 0;
+    double zdr = 0;
+    int i1_;
+    int i2_;
+    int i3_;
+    int i4_;
+    int i5_;
+    int i6_;
+
+    caz = azabs_(zr, zi);
+    csclr = 1.0e0 / *tol;
+    crscr = *tol;
+    cssr[(1 - 1)] = csclr;
+    cssr[(2 - 1)] = 1.0e0;
+    cssr[(3 - 1)] = crscr;
+    csrr[(1 - 1)] = crscr;
+    csrr[(2 - 1)] = 1.0e0;
+    csrr[(3 - 1)] = csclr;
+    bry[(1 - 1)] = 1.0e+3 * d1mach_(&(int){1}) / *tol;
+    bry[(2 - 1)] = 1.0e0 / bry[(1 - 1)];
+    bry[(3 - 1)] = d1mach_(&(int){2});
+    *nz = 0;
+    iflag = 0;
+    koded = *kode;
+    rcaz = 1.0e0 / caz;
+    str = *zr * rcaz;
+    sti = -*zi * rcaz;
+    rzr = (str + str) * rcaz;
+    rzi = (sti + sti) * rcaz;
+    inu = (int)((float)(*fnu + 0.5e0));
+    dnu = *fnu - (double)((float)(inu));
+    if (fabs(dnu) == 0.5e0) goto L110;
+    dnu2 = 0.0e0;
+    if (fabs(dnu) > *tol) dnu2 = dnu * dnu;
+    if (caz > r1) goto L110;
+    fc = 1.0e0;
+    azlog_(&rzr, &rzi, &smur, &smui, &idum);
+    fmur = smur * dnu;
+    fmui = smui * dnu;
+    zshch_(&fmur, &fmui, &cshr, &cshi, &cchr, &cchi);
+    if (dnu == 0.0e0) goto L10;
+    fc = dnu * dpi;
+    fc = fc / sin(fc);
+    smur = cshr / dnu;
+    smui = cshi / dnu;
+L10:;
+    a2 = 1.0e0 + dnu;
+    t2 = exp(-dgamln_(&a2, &idum));
+    t1 = 1.0e0 / (t2 * fc);
+    if (fabs(dnu) > 0.1e0) goto L40;
+    ak = 1.0e0;
+    s = cc[(1 - 1)];
+    k = 2;
+    for (i1_ = 8 - k + 1; i1_ > 0; --i1_, k += 1) {
+        ak = ak * dnu2;
+        tm = cc[(k - 1)] * ak;
+        s = s + tm;
+        if (fabs(tm) < *tol) goto L30;
+    }
+L30:;
+    g1 = -s;
+    goto L50;
+L40:;
+    g1 = (t1 - t2) / (dnu + dnu);
+L50:;
+    g2 = (t1 + t2) * 0.5e0;
+    fr = fc * (cchr * g1 + smur * g2);
+    fi = fc * (cchi * g1 + smui * g2);
+    azexp_(&fmur, &fmui, &str, &sti);
+    pr = 0.5e0 * str / t2;
+    pi = 0.5e0 * sti / t2;
+    zdiv_(&(double){0.5e0}, &(double){0.0e0}, &str, &sti, &ptr, &pti);
+    qr = ptr / t1;
+    qi = pti / t1;
+    s1r = fr;
+    s1i = fi;
+    s2r = pr;
+    s2i = pi;
+    ak = 1.0e0;
+    a1 = 1.0e0;
+    ckr = coner;
+    cki = conei;
+    bk = 1.0e0 - dnu2;
+    if (inu > 0 || *n > 1) goto L80;
+    if (caz < *tol) goto L70;
+    zmlt_(zr, zi, zr, zi, &czr, &czi);
+    czr = 0.25e0 * czr;
+    czi = 0.25e0 * czi;
+    t1 = 0.25e0 * caz * caz;
+L60:;
+    fr = (fr * ak + pr + qr) / bk;
+    fi = (fi * ak + pi + qi) / bk;
+    str = 1.0e0 / (ak - dnu);
+    pr = pr * str;
+    pi = pi * str;
+    str = 1.0e0 / (ak + dnu);
+    qr = qr * str;
+    qi = qi * str;
+    str = ckr * czr - cki * czi;
+    rak = 1.0e0 / ak;
+    cki = (ckr * czi + cki * czr) * rak;
+    ckr = str * rak;
+    s1r = ckr * fr - cki * fi + s1r;
+    s1i = ckr * fi + cki * fr + s1i;
+    a1 = a1 * t1 * rak;
+    bk = bk + ak + ak + 1.0e0;
+    ak = ak + 1.0e0;
+    if (a1 > *tol) goto L60;
+L70:;
+    yr[(1 - 1)] = s1r;
+    yi[(1 - 1)] = s1i;
+    if (koded == 1) return;
+    azexp_(zr, zi, &str, &sti);
+    zmlt_(&s1r, &s1i, &str, &sti, &yr[(1 - 1)], &yi[(1 - 1)]);
+    return;
+L80:;
+    if (caz < *tol) goto L100;
+    zmlt_(zr, zi, zr, zi, &czr, &czi);
+    czr = 0.25e0 * czr;
+    czi = 0.25e0 * czi;
+    t1 = 0.25e0 * caz * caz;
+L90:;
+    fr = (fr * ak + pr + qr) / bk;
+    fi = (fi * ak + pi + qi) / bk;
+    str = 1.0e0 / (ak - dnu);
+    pr = pr * str;
+    pi = pi * str;
+    str = 1.0e0 / (ak + dnu);
+    qr = qr * str;
+    qi = qi * str;
+    str = ckr * czr - cki * czi;
+    rak = 1.0e0 / ak;
+    cki = (ckr * czi + cki * czr) * rak;
+    ckr = str * rak;
+    s1r = ckr * fr - cki * fi + s1r;
+    s1i = ckr * fi + cki * fr + s1i;
+    str = pr - fr * ak;
+    sti = pi - fi * ak;
+    s2r = ckr * str - cki * sti + s2r;
+    s2i = ckr * sti + cki * str + s2i;
+    a1 = a1 * t1 * rak;
+    bk = bk + ak + ak + 1.0e0;
+    ak = ak + 1.0e0;
+    if (a1 > *tol) goto L90;
+L100:;
+    kflag = 2;
+    a1 = *fnu + 1.0e0;
+    ak = a1 * fabs(smur);
+    if (ak > *alim) kflag = 3;
+    str = cssr[(kflag - 1)];
+    p2r = s2r * str;
+    p2i = s2i * str;
+    zmlt_(&p2r, &p2i, &rzr, &rzi, &s2r, &s2i);
+    s1r = s1r * str;
+    s1i = s1i * str;
+    if (koded == 1) goto L210;
+    azexp_(zr, zi, &fr, &fi);
+    zmlt_(&s1r, &s1i, &fr, &fi, &s1r, &s1i);
+    zmlt_(&s2r, &s2i, &fr, &fi, &s2r, &s2i);
+    goto L210;
+L110:;
+    azsqrt_(zr, zi, &str, &sti);
+    zdiv_(&rthpi, &czeroi, &str, &sti, &coefr, &coefi);
+    kflag = 2;
+    if (koded == 2) goto L120;
+    if (*zr > *alim) goto L290;
+    str = exp(-*zr) * cssr[(kflag - 1)];
+    sti = -str * sin(*zi);
+    str = str * cos(*zi);
+    zmlt_(&coefr, &coefi, &str, &sti, &coefr, &coefi);
+L120:;
+    if (fabs(dnu) == 0.5e0) goto L300;
+    ak = cos(dpi * dnu);
+    ak = fabs(ak);
+    if (ak == czeror) goto L300;
+    fhs = fabs(0.25e0 - dnu2);
+    if (fhs == czeror) goto L300;
+    t1 = (double)((float)(i1mach_(&(int){14}) - 1));
+    t1 = t1 * d1mach_(&(int){5}) * 3.321928094e0;
+    t1 = dmax(t1, 12.0e0);
+    t1 = dmin(t1, 60.0e0);
+    t2 = tth * t1 - 6.0e0;
+    if (*zr != 0.0e0) goto L130;
+    t1 = hpi;
+    goto L140;
+L130:;
+    t1 = atan(*zi / *zr);
+    t1 = fabs(t1);
+L140:;
+    if (t2 > caz) goto L170;
+    etest = ak / (dpi * caz * *tol);
+    fk = coner;
+    if (etest < coner) goto L180;
+    fks = ctwor;
+    ckr = caz + caz + ctwor;
+    p1r = czeror;
+    p2r = coner;
+    i = 1;
+    for (i2_ = kmax - i + 1; i2_ > 0; --i2_, i += 1) {
+        ak = fhs / fks;
+        cbr = ckr / (fk + coner);
+        ptr = p2r;
+        p2r = cbr * p2r - p1r * ak;
+        p1r = ptr;
+        ckr = ckr + ctwor;
+        fks = fks + fk + fk + ctwor;
+        fhs = fhs + fk + fk;
+        fk = fk + coner;
+        str = fabs(p2r) * fk;
+        if (etest < str) goto L160;
+    }
+    goto L310;
+L160:;
+    fk = fk + spi * t1 * sqrt(t2 / caz);
+    fhs = fabs(0.25e0 - dnu2);
+    goto L180;
+L170:;
+    a2 = sqrt(caz);
+    ak = fpi * ak / (*tol * sqrt(a2));
+    aa = 3.0e0 * t1 / (1.0e0 + caz);
+    bb = 14.7e0 * t1 / (28.0e0 + caz);
+    ak = (log(ak) + caz * cos(aa) / (1.0e0 + 0.008e0 * caz)) / cos(bb);
+    fk = 0.12125e0 * ak * ak / caz + 1.5e0;
+L180:;
+    k = (int)((float)(fk));
+    fk = (double)((float)(k));
+    fks = fk * fk;
+    p1r = czeror;
+    p1i = czeroi;
+    p2r = *tol;
+    p2i = czeroi;
+    csr = p2r;
+    csi = p2i;
+    i = 1;
+    for (i3_ = k - i + 1; i3_ > 0; --i3_, i += 1) {
+        a1 = fks - fk;
+        ak = (fks + fk) / (a1 + fhs);
+        rak = 2.0e0 / (fk + coner);
+        cbr = (fk + *zr) * rak;
+        cbi = *zi * rak;
+        ptr = p2r;
+        pti = p2i;
+        p2r = (ptr * cbr - pti * cbi - p1r) * ak;
+        p2i = (pti * cbr + ptr * cbi - p1i) * ak;
+        p1r = ptr;
+        p1i = pti;
+        csr = csr + p2r;
+        csi = csi + p2i;
+        fks = a1 - fk + coner;
+        fk = fk - coner;
+    }
+    tm = azabs_(&csr, &csi);
+    ptr = 1.0e0 / tm;
+    s1r = p2r * ptr;
+    s1i = p2i * ptr;
+    csr = csr * ptr;
+    csi = -csi * ptr;
+    zmlt_(&coefr, &coefi, &s1r, &s1i, &str, &sti);
+    zmlt_(&str, &sti, &csr, &csi, &s1r, &s1i);
+    if (inu > 0 || *n > 1) goto L200;
+    zdr = *zr;
+    zdi = *zi;
+    if (iflag == 1) goto L270;
+    goto L240;
+L200:;
+    tm = azabs_(&p2r, &p2i);
+    ptr = 1.0e0 / tm;
+    p1r = p1r * ptr;
+    p1i = p1i * ptr;
+    p2r = p2r * ptr;
+    p2i = -p2i * ptr;
+    zmlt_(&p1r, &p1i, &p2r, &p2i, &ptr, &pti);
+    str = dnu + 0.5e0 - ptr;
+    sti = -pti;
+    zdiv_(&str, &sti, zr, zi, &str, &sti);
+    str = str + 1.0e0;
+    zmlt_(&str, &sti, &s1r, &s1i, &s2r, &s2i);
+L210:;
+    str = dnu + 1.0e0;
+    ckr = str * rzr;
+    cki = str * rzi;
+    if (*n == 1) inu = inu - 1;
+    if (inu > 0) goto L220;
+    if (*n > 1) goto L215;
+    s1r = s2r;
+    s1i = s2i;
+L215:;
+    zdr = *zr;
+    zdi = *zi;
+    if (iflag == 1) goto L270;
+    goto L240;
+L220:;
+    inub = 1;
+    if (iflag == 1) goto L261;
+L225:;
+    p1r = csrr[(kflag - 1)];
+    ascle = bry[(kflag - 1)];
+    i = inub;
+    for (i4_ = inu - i + 1; i4_ > 0; --i4_, i += 1) {
+        str = s2r;
+        sti = s2i;
+        s2r = ckr * str - cki * sti + s1r;
+        s2i = ckr * sti + cki * str + s1i;
+        s1r = str;
+        s1i = sti;
+        ckr = ckr + rzr;
+        cki = cki + rzi;
+        if (kflag >= 3) goto L230;
+        p2r = s2r * p1r;
+        p2i = s2i * p1r;
+        str = fabs(p2r);
+        sti = fabs(p2i);
+        p2m = dmax(str, sti);
+        if (p2m <= ascle) goto L230;
+        kflag = kflag + 1;
+        ascle = bry[(kflag - 1)];
+        s1r = s1r * p1r;
+        s1i = s1i * p1r;
+        s2r = p2r;
+        s2i = p2i;
+        str = cssr[(kflag - 1)];
+        s1r = s1r * str;
+        s1i = s1i * str;
+        s2r = s2r * str;
+        s2i = s2i * str;
+        p1r = csrr[(kflag - 1)];
+    L230:;
+    }
+    if (*n != 1) goto L240;
+    s1r = s2r;
+    s1i = s2i;
+L240:;
+    str = csrr[(kflag - 1)];
+    yr[(1 - 1)] = s1r * str;
+    yi[(1 - 1)] = s1i * str;
+    if (*n == 1) return;
+    yr[(2 - 1)] = s2r * str;
+    yi[(2 - 1)] = s2i * str;
+    if (*n == 2) return;
+    kk = 2;
+L250:;
+    kk = kk + 1;
+    if (kk > *n) return;
+    p1r = csrr[(kflag - 1)];
+    ascle = bry[(kflag - 1)];
+    i = kk;
+    for (i5_ = *n - i + 1; i5_ > 0; --i5_, i += 1) {
+        p2r = s2r;
+        p2i = s2i;
+        s2r = ckr * p2r - cki * p2i + s1r;
+        s2i = cki * p2r + ckr * p2i + s1i;
+        s1r = p2r;
+        s1i = p2i;
+        ckr = ckr + rzr;
+        cki = cki + rzi;
+        p2r = s2r * p1r;
+        p2i = s2i * p1r;
+        yr[(i - 1)] = p2r;
+        yi[(i - 1)] = p2i;
+        if (kflag >= 3) goto L260;
+        str = fabs(p2r);
+        sti = fabs(p2i);
+        p2m = dmax(str, sti);
+        if (p2m <= ascle) goto L260;
+        kflag = kflag + 1;
+        ascle = bry[(kflag - 1)];
+        s1r = s1r * p1r;
+        s1i = s1i * p1r;
+        s2r = p2r;
+        s2i = p2i;
+        str = cssr[(kflag - 1)];
+        s1r = s1r * str;
+        s1i = s1i * str;
+        s2r = s2r * str;
+        s2i = s2i * str;
+        p1r = csrr[(kflag - 1)];
+    L260:;
+    }
+    return;
+L261:;
+    helim = 0.5e0 * *elim;
+    elm = exp(-*elim);
+    celmr = elm;
+    ascle = bry[(1 - 1)];
+    zdr = *zr;
+    zdi = *zi;
+    ic = -1;
+    j = 2;
+    i = 1;
+    for (i6_ = inu - i + 1; i6_ > 0; --i6_, i += 1) {
+        str = s2r;
+        sti = s2i;
+        s2r = str * ckr - sti * cki + s1r;
+        s2i = sti * ckr + str * cki + s1i;
+        s1r = str;
+        s1i = sti;
+        ckr = ckr + rzr;
+        cki = cki + rzi;
+        as = azabs_(&s2r, &s2i);
+        alas = log(as);
+        p2r = -zdr + alas;
+        if (p2r < (-*elim)) goto L263;
+        azlog_(&s2r, &s2i, &str, &sti, &idum);
+        p2r = -zdr + str;
+        p2i = -zdi + sti;
+        p2m = exp(p2r) / *tol;
+        p1r = p2m * cos(p2i);
+        p1i = p2m * sin(p2i);
+        zuchk_(&p1r, &p1i, &nw, &ascle, tol);
+        if (nw != 0) goto L263;
+        j = 3 - j;
+        cyr[(j - 1)] = p1r;
+        cyi[(j - 1)] = p1i;
+        if (ic == (i - 1)) goto L264;
+        ic = i;
+        goto L262;
+    L263:;
+        if (alas < helim) goto L262;
+        zdr = zdr - *elim;
+        s1r = s1r * celmr;
+        s1i = s1i * celmr;
+        s2r = s2r * celmr;
+        s2i = s2i * celmr;
+    L262:;
+    }
+    if (*n != 1) goto L270;
+    s1r = s2r;
+    s1i = s2i;
+    goto L270;
+L264:;
+    kflag = 1;
+    inub = i + 1;
+    s2r = cyr[(j - 1)];
+    s2i = cyi[(j - 1)];
+    j = 3 - j;
+    s1r = cyr[(j - 1)];
+    s1i = cyi[(j - 1)];
+    if (inub <= inu) goto L225;
+    if (*n != 1) goto L240;
+    s1r = s2r;
+    s1i = s2i;
+    goto L240;
+L270:;
+    yr[(1 - 1)] = s1r;
+    yi[(1 - 1)] = s1i;
+    if (*n == 1) goto L280;
+    yr[(2 - 1)] = s2r;
+    yi[(2 - 1)] = s2i;
+L280:;
+    ascle = bry[(1 - 1)];
+    zkscl_(&zdr, &zdi, fnu, n, yr, yi, nz, &rzr, &rzi, &ascle, tol, elim);
+    inu = *n - *nz;
+    if (inu <= 0) return;
+    kk = *nz + 1;
+    s1r = yr[(kk - 1)];
+    s1i = yi[(kk - 1)];
+    yr[(kk - 1)] = s1r * csrr[(1 - 1)];
+    yi[(kk - 1)] = s1i * csrr[(1 - 1)];
+    if (inu == 1) return;
+    kk = *nz + 2;
+    s2r = yr[(kk - 1)];
+    s2i = yi[(kk - 1)];
+    yr[(kk - 1)] = s2r * csrr[(1 - 1)];
+    yi[(kk - 1)] = s2i * csrr[(1 - 1)];
+    if (inu == 2) return;
+    t2 = *fnu + (double)((float)(kk - 1));
+    ckr = t2 * rzr;
+    cki = t2 * rzi;
+    kflag = 1;
+    goto L250;
+L290:;
+    koded = 2;
+    iflag = 1;
+    kflag = 2;
+    goto L120;
+L300:;
+    s1r = coefr;
+    s1i = coefi;
+    s2r = coefr;
+    s2i = coefi;
+    goto L210;
+L310:;
+    *nz = -2;
+    return;
+}
@@ -0,0 +1,171 @@
+/* Generated by scripts/f77-to-c.py from amos/zbuni.f, do not edit. */
+
+#include "amos.h"
+
+void zbuni_(double *zr, double *zi, double *fnu, int *kode, int *n, double *yr, double *yi, int *nz, int *nui, int *nlast, double *fnul, double *tol, double *elim, double *alim)
+{
+    double ascle = 0;
+    double ax = 0;
+    double ay = 0;
+    double bry[3];
+    double c1i = 0;
+    double c1m = 0;
+    double c1r = 0;
+    double csclr = 0;
+    double cscrr = 0;
+    double cyi[2];
+    double cyr[2];
+    double dfnu = 0;
+    double fnui = 0;
+    double gnu = 0;
+    int i = 0;
+    int iflag = 0;
+    int iform = 0;
+    int k = 0;
+    int nl = 0;
+    int nw = 0;
+    double raz = 0;
+    double rzi = 0;
+    double rzr = 0;
+    double s1i = 0;
+    double s1r = 0;
+    double s2i = 0;
+    double s2r = 0;
+    double sti = 0;
+    double str = 0;
+    int i1_;
+    int i2_;
+
+    *nz = 0;
+    ax = fabs(*zr) * 1.7321e0;
+    ay = fabs(*zi);
+    iform = 1;
+    if (ay > ax) iform = 2;
+    if (*nui == 0) goto L60;
+    fnui = (double)((float)(*nui));
+    dfnu = *fnu + (double)((float)(*n - 1));
+    gnu = dfnu + fnui;
+    if (iform == 2) goto L10;
+    zuni1_(zr, zi, &gnu, kode, &(int){2}, cyr, cyi, &nw, nlast, fnul, tol, elim, alim);
+    goto L20;
+L10:;
+    zuni2_(zr, zi, &gnu, kode, &(int){2}, cyr, cyi, &nw, nlast, fnul, tol, elim, alim);
+L20:;
+    if (nw < 0) goto L50;
+    if (nw != 0) goto L90;
+    str = azabs_(&cyr[(1 - 1)], &cyi[(1 - 1)]);
+    bry[(1 - 1)] = 1.0e+3 * d1mach_(&(int){1}) / *tol;
+    bry[(2 - 1)] = 1.0e0 / bry[(1 - 1)];
+    bry[(3 - 1)] = bry[(2 - 1)];
+    iflag = 2;
+    ascle = bry[(2 - 1)];
+    csclr = 1.0e0;
+    if (str > bry[(1 - 1)]) goto L21;
+    iflag = 1;
+    ascle = bry[(1 - 1)];
+    csclr = 1.0e0 / *tol;
+    goto L25;
+L21:;
+    if (str < bry[(2 - 1)]) goto L25;
+    iflag = 3;
+    ascle = bry[(3 - 1)];
+    csclr = *tol;
+L25:;
+    cscrr = 1.0e0 / csclr;
+    s1r = cyr[(2 - 1)] * csclr;
+    s1i = cyi[(2 - 1)] * csclr;
+    s2r = cyr[(1 - 1)] * csclr;
+    s2i = cyi[(1 - 1)] * csclr;
+    raz = 1.0e0 / azabs_(zr, zi);
+    str = *zr * raz;
+    sti = -*zi * raz;
+    rzr = (str + str) * raz;
+    rzi = (sti + sti) * raz;
+    i = 1;
+    for (i1_ = *nui - i + 1; i1_ > 0; --i1_, i += 1) {
+        str = s2r;
+        sti = s2i;
+        s2r = (dfnu + fnui) * (rzr * str - rzi * sti) + s1r;
+        s2i = (dfnu + fnui) * (rzr * sti + rzi * str) + s1i;
+        s1r = str;
+        s1i = sti;
+        fnui = fnui - 1.0e0;
+        if (iflag >= 3) goto L30;
+        str = s2r * cscrr;
+        sti = s2i * cscrr;
+        c1r = fabs(str);
+        c1i = fabs(sti);
+        c1m = dmax(c1r, c1i);
+        if (c1m <= ascle) goto L30;
+        iflag = iflag + 1;
+        ascle = bry[(iflag - 1)];
+        s1r = s1r * cscrr;
+        s1i = s1i * cscrr;
+        s2r = str;
+        s2i = sti;
+        csclr = csclr * *tol;
+        cscrr = 1.0e0 / csclr;
+        s1r = s1r * csclr;
+        s1i = s1i * csclr;
+        s2r = s2r * csclr;
+        s2i = s2i * csclr;
+    L30:;
+    }
+    yr[(*n - 1)] = s2r * cscrr;
+    yi[(*n - 1)] = s2i * cscrr;
+    if (*n == 1) return;
+    nl = *n - 1;
+    fnui = (double)((float)(nl));
+    k = nl;
+    i = 1;
+    for (i2_ = nl - i + 1; i2_ > 0; --i2_, i += 1) {
+        str = s2r;
+        sti = s2i;
+        s2r = (*fnu + fnui) * (rzr * str - rzi * sti) + s1r;
+        s2i = (*fnu + fnui) * (rzr * sti + rzi * str) + s1i;
+        s1r = str;
+        s1i = sti;
+        str = s2r * cscrr;
+        sti = s2i * cscrr;
+        yr[(k - 1)] = str;
+        yi[(k - 1)] = sti;
+        fnui = fnui - 1.0e0;
+        k = k - 1;
+        if (iflag >= 3) goto L40;
+        c1r = fabs(str);
+        c1i = fabs(sti);
+        c1m = dmax(c1r, c1i);
+        if (c1m <= ascle) goto L40;
+        iflag = iflag + 1;
+        ascle = bry[(iflag - 1)];
+        s1r = s1r * cscrr;
+        s1i = s1i * cscrr;
+        s2r = str;
+        s2i = sti;
+        csclr = csclr * *tol;
+        cscrr = 1.0e0 / csclr;
+        s1r = s1r * csclr;
+        s1i = s1i * csclr;
+        s2r = s2r * csclr;
+        s2i = s2i * csclr;
+    L40:;
+    }
+    return;
+L50:;
+    *nz = -1;
+    if (nw == (-2)) *nz = -2;
+    return;
+L60:;
+    if (iform == 2) goto L70;
+    zuni1_(zr, zi, fnu, kode, n, yr, yi, &nw, nlast, fnul, tol, elim, alim);
+    goto L80;
+L70:;
+    zuni2_(zr, zi, fnu, kode, n, yr, yi, &nw, nlast, fnul, tol, elim, alim);
+L80:;
+    if (nw < 0) goto L50;
+    *nz = nw;
+    return;
+L90:;
+    *nlast = *n;
+    return;
+}
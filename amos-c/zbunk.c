@@ -0,0 +1,20 @@
+/* Generated by scripts/f77-to-c.py from amos/zbunk.f, do not edit. */
+
+#include "amos.h"
+
+void zbunk_(double *zr, double *zi, double *fnu, int *kode, int *mr, int *n, double *yr, double *yi, int *nz, double *tol, double *elim, double *alim)
+{
+    double ax = 0;
+    double ay = 0;
+
+    *nz = 0;
+    ax = fabs(*zr) * 1.7321e0;
+    ay = fabs(*zi);
+    if (ay > ax) goto L10;
+    zunk1_(zr, zi, fnu, kode, mr, n, yr, yi, nz, tol, elim, alim);
+    goto L20;
+L10:;
+    zunk2_(zr, zi, fnu, kode, mr, n, yr, yi, nz, tol, elim, alim);
+L20:;
+    return;
+}
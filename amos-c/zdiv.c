@@ -0,0 +1,21 @@
+/* Generated by scripts/f77-to-c.py from amos/zdiv.f, do not edit. */
+
+#include "amos.h"
+
+void zdiv_(double *ar, double *ai, double *br, double *bi, double *cr, double *ci)
+{
+    double bm = 0;
+    double ca = 0;
+    double cb = 0;
+    double cc = 0;
+    double cd = 0;
+
+    bm = 1.0e0 / azabs_(br, bi);
+    cc = *br * bm;
+    cd = *bi * bm;
+    ca = (*ar * cc + *ai * cd) * bm;
+    cb = (*ai * cc - *ar * cd) * bm;
+    *cr = ca;
+    *ci = cb;
+    return;
+}
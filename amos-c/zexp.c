@@ -0,0 +1,17 @@
+/* Generated by scripts/f77-to-c.py from amos/zexp.f, do not edit. */
+
+#include "amos.h"
+
+void azexp_(double *ar, double *ai, double *br, double *bi)
+{
+    double ca = 0;
+    double cb = 0;
+    double zm = 0;
+
+    zm = exp(*ar);
+    ca = zm * cos(*ai);
+    cb = zm * sin(*ai);
+    *br = ca;
+    *bi = cb;
+    return;
+}
@@ -0,0 +1,141 @@
+/* Generated by scripts/f77-to-c.py from amos/zkscl.f, do not edit. */
+
+#include "amos.h"
+
+void zkscl_(double *zrr, double *zri, double *fnu, int *n, double *yr, double *yi, int *nz, double *rzr, double *rzi, double *ascle, double *tol, double *elim)
+{
+    double acs = 0;
+    double alas = 0;
+    double as = 0;
+    double celmr = 0;
+    double cki = 0;
+    double ckr = 0;
+    double csi = 0;
+    double csr = 0;
+    double cyi[2];
+    double cyr[2];
+    double elm = 0;
+    double fn = 0;
+    double helim = 0;
+    int i = 0;
+    int ic = 0;
+    int idum = 0;
+    int kk = 0;
+    int nn = 0;
+    int nw = 0;
+    double s1i = 0;
+    double s1r = 0;
+    double s2i = 0;
+    double s2r = 0;
+    double str = 0;
+    double zdi = 0;
+    double zdr = 0;
+    static double zeroi = 0.0e0;
+    static double zeror = 0.0e0;
+    int i1_;
+    int i2_;
+    int i3_;
+
+    *nz = 0;
+    ic = 0;
+    nn = imin(2, *n);
+    i = 1;
+    for (i1_ = nn - i + 1; i1_ > 0; --i1_, i += 1) {
+        s1r = yr[(i - 1)];
+        s1i = yi[(i - 1)];
+        cyr[(i - 1)] = s1r;
+        cyi[(i - 1)] = s1i;
+        as = azabs_(&s1r, &s1i);
+        acs = -*zrr + log(as);
+        *nz = *nz + 1;
+        yr[(i - 1)] = zeror;
+        yi[(i - 1)] = zeroi;
+        if (acs < (-*elim)) goto L10;
+        azlog_(&s1r, &s1i, &csr, &csi, &idum);
+        csr = csr - *zrr;
+        csi = csi - *zri;
+        str = exp(csr) / *tol;
+        csr = str * cos(csi);
+        csi = str * sin(csi);
+        zuchk_(&csr, &csi, &nw, ascle, tol);
+        if (nw != 0) goto L10;
+        yr[(i - 1)] = csr;
+        yi[(i - 1)] = csi;
+        ic = i;
+        *nz = *nz - 1;
+    L10:;
+    }
+    if (*n == 1) return;
+    if (ic > 1) goto L20;
+    yr[(1 - 1)] = zeror;
+    yi[(1 - 1)] = zeroi;
+    *nz = 2;
+L20:;
+    if (*n == 2) return;
+    if (*nz == 0) return;
+    fn = *fnu + 1.0e0;
+    ckr = fn * *rzr;
+    cki = fn * *rzi;
+    s1r = cyr[(1 - 1)];
+    s1i = cyi[(1 - 1)];
+    s2r = cyr[(2 - 1)];
+    s2i = cyi[(2 - 1)];
+    helim = 0.5e0 * *elim;
+    elm = exp(-*elim);
+    celmr = elm;
+    zdr = *zrr;
+    zdi = *zri;
+    i = 3;
+    for (i2_ = *n - i + 1; i2_ > 0; --i2_, i += 1) {
+        kk = i;
+        csr = s2r;
+        csi = s2i;
+        s2r = ckr * csr - cki * csi + s1r;
+        s2i = cki * csr + ckr * csi + s1i;
+        s1r = csr;
+        s1i = csi;
+        ckr = ckr + *rzr;
+        cki = cki + *rzi;
+        as = azabs_(&s2r, &s2i);
+        alas = log(as);
+        acs = -zdr + alas;
+        *nz = *nz + 1;
+        yr[(i - 1)] = zeror;
+        yi[(i - 1)] = zeroi;
+        if (acs < (-*elim)) goto L25;
+        azlog_(&s2r, &s2i, &csr, &csi, &idum);
+        csr = csr - zdr;
+        csi = csi - zdi;
+        str = exp(csr) / *tol;
+        csr = str * cos(csi);
+        csi = str * sin(csi);
+        zuchk_(&csr, &csi, &nw, ascle, tol);
+        if (nw != 0) goto L25;
+        yr[(i - 1)] = csr;
+        yi[(i - 1)] = csi;
+        *nz = *nz - 1;
+        if (ic == kk - 1) goto L40;
+        ic = kk;
+        goto L30;
+    L25:;
+        if (alas < helim) goto L30;
+        zdr = zdr - *elim;
+        s1r = s1r * celmr;
+        s1i = s1i * celmr;
+        s2r = s2r * celmr;
+        s2i = s2i * celmr;
+    L30:;
+    }
+    *nz = *n;
+    if (ic == *n) *nz = *n - 1;
+    goto L45;
+L40:;
+    *nz = kk - 2;
+L45:;
+    i = 1;
+    for (i3_ = *nz - i + 1; i3_ > 0; --i3_, i += 1) {
+        yr[(i - 1)] = zeror;
+        yi[(i - 1)] = zeroi;
+    }
+    return;
+}
@@ -0,0 +1,44 @@
+/* Generated by scripts/f77-to-c.py from amos/zlog.f, do not edit. */
+
+#include "amos.h"
+
+void azlog_(double *ar, double *ai, double *br, double *bi, int *ierr)
+{
+    static double dhpi = 1.570796326794896619231321696e+0;
+    static double dpi = 3.141592653589793238462643383e+0;
+    double dtheta = 0;
+    double zm = 0;
+
+    *ierr = 0;
+    if (*ar == 0.0e+0) goto L10;
+    if (*ai == 0.0e+0) goto L20;
+    dtheta = atan(*ai / *ar);
+    if (dtheta <= 0.0e+0) goto L40;
+    if (*ar < 0.0e+0) dtheta = dtheta - dpi;
+    goto L50;
+L10:;
+    if (*ai == 0.0e+0) goto L60;
+    *bi = dhpi;
+    *br = log(fabs(*ai));
+    if (*ai < 0.0e+0) *bi = -*bi;
+    return;
+L20:;
+    if (*ar > 0.0e+0) goto L30;
+    *br = log(fabs(*ar));
+    *bi = dpi;
+    return;
+L30:;
+    *br = log(*ar);
+    *bi = 0.0e+0;
+    return;
+L40:;
+    if (*ar < 0.0e+0) dtheta = dtheta + dpi;
+L50:;
+    zm = azabs_(ar, ai);
+    *br = log(zm);
+    *bi = dtheta;
+    return;
+L60:;
+    *ierr = 1;
+    return;
+}
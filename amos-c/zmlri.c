@@ -0,0 +1,235 @@
+/* Generated by scripts/f77-to-c.py from amos/zmlri.f, do not edit. */
+
+#include "amos.h"
+
+void zmlri_(double *zr, double *zi, double *fnu, int *kode, int *n, double *yr, double *yi, int *nz, double *tol)
+{
+    double ack = 0;
+    double ak = 0;
+    double ap = 0;
+    double at = 0;
+    double az = 0;
+    double bk = 0;
+    double cki = 0;
+    double ckr = 0;
+    double cnormi = 0;
+    double cnormr = 0;
+    static double conei = 0.0e0;
+    static double coner = 1.0e0;
+    double fkap = 0;
+    double fkk = 0;
+    double flam = 0;
+    double fnf = 0;
+    int i = 0;
+    int iaz = 0;
+    int idum = 0;
+    int ifnu = 0;
+    int inu = 0;
+    int itime = 0;
+    int k = 0;
+    int kk = 0;
+    int km = 0;
+    int m = 0;
+    double p1i = 0;
+    double p1r = 0;
+    double p2i = 0;
+    double p2r = 0;
+    double pti = 0;
+    double ptr = 0;
+    double raz = 0;
+    double rho = 0;
+    double rho2 = 0;
+    double rzi = 0;
+    double rzr = 0;
+    double scle = 0;
+    double sti = 0;
+    double str = 0;
+    double sumi = 0;
+    double sumr = 0;
+    double tfnf = 0;
+    double tst = 0;
+    static double zeroi = 0.0e0;
+    static double zeror = 0.0e0;
+    int i1_;
+    int i2_;
+    int i3_;
+    int i4_;
+    int i5_;
+    int i6_;
+
+    scle = d1mach_(&(int){1}) / *tol;
+    *nz = 0;
+    az = azabs_(zr, zi);
+    iaz = (int)((float)(az));
+    ifnu = (int)((float)(*fnu));
+    inu = ifnu + *n - 1;
+    at = (double)((float)(iaz)) + 1.0e0;
+    raz = 1.0e0 / az;
+    str = *zr * raz;
+    sti = -*zi * raz;
+    ckr = str * at * raz;
+    cki = sti * at * raz;
+    rzr = (str + str) * raz;
+    rzi = (sti + sti) * raz;
+    p1r = zeror;
+    p1i = zeroi;
+    p2r = coner;
+    p2i = conei;
+    ack = (at + 1.0e0) * raz;
+    rho = ack + sqrt(ack * ack - 1.0e0);
+    rho2 = rho * rho;
+    tst = (rho2 + rho2) / ((rho2 - 1.0e0) * (rho - 1.0e0));
+    tst = tst / *tol;
+    ak = at;
+    i = 1;
+    for (i1_ = 80 - i + 1; i1_ > 0; --i1_, i += 1) {
+        ptr = p2r;
+        pti = p2i;
+        p2r = p1r - (ckr * ptr - cki * pti);
+        p2i = p1i - (cki * ptr + ckr * pti);
+        p1r = ptr;
+        p1i = pti;
+        ckr = ckr + rzr;
+        cki = cki + rzi;
+        ap = azabs_(&p2r, &p2i);
+        if (ap > tst * ak * ak) goto L20;
+        ak = ak + 1.0e0;
+    }
+    goto L110;
+L20:;
+    i = i + 1;
+    k = 0;
+    if (inu < iaz) goto L40;
+    p1r = zeror;
+    p1i = zeroi;
+    p2r = coner;
+    p2i = conei;
+    at = (double)((float)(inu)) + 1.0e0;
+    str = *zr * raz;
+    sti = -*zi * raz;
+    ckr = str * at * raz;
+    cki = sti * at * raz;
+    ack = at * raz;
+    tst = sqrt(ack / *tol);
+    itime = 1;
+    k = 1;
+    for (i2_ = 80 - k + 1; i2_ > 0; --i2_, k += 1) {
+        ptr = p2r;
+        pti = p2i;
+        p2r = p1r - (ckr * ptr - cki * pti);
+        p2i = p1i - (ckr * pti + cki * ptr);
+        p1r = ptr;
+        p1i = pti;
+        ckr = ckr + rzr;
+        cki = cki + rzi;
+        ap = azabs_(&p2r, &p2i);
+        if (ap < tst) goto L30;
+        if (itime == 2) goto L40;
+        ack = azabs_(&ckr, &cki);
+        flam = ack + sqrt(ack * ack - 1.0e0);
+        fkap = ap / azabs_(&p1r, &p1i);
+        rho = dmin(flam, fkap);
+        tst = tst * sqrt(rho / (rho * rho - 1.0e0));
+        itime = 2;
+    L30:;
+    }
+    goto L110;
+L40:;
+    k = k + 1;
+    kk = imax(i + iaz, k + inu);
+    fkk = (double)((float)(kk));
+    p1r = zeror;
+    p1i = zeroi;
+    p2r = scle;
+    p2i = zeroi;
+    fnf = *fnu - (double)((float)(ifnu));
+    tfnf = fnf + fnf;
+    bk = dgamln_(&(double){fkk + tfnf + 1.0e0}, &idum) - dgamln_(&(double){fkk + 1.0e0}, &idum) - dgamln_(&(double){tfnf + 1.0e0}, &idum);
+    bk = exp(bk);
+    sumr = zeror;
+    sumi = zeroi;
+    km = kk - inu;
+    i = 1;
+    for (i3_ = km - i + 1; i3_ > 0; --i3_, i += 1) {
+        ptr = p2r;
+        pti = p2i;
+        p2r = p1r + (fkk + fnf) * (rzr * ptr - rzi * pti);
+        p2i = p1i + (fkk + fnf) * (rzi * ptr + rzr * pti);
+        p1r = ptr;
+        p1i = pti;
+        ak = 1.0e0 - tfnf / (fkk + tfnf);
+        ack = bk * ak;
+        sumr = sumr + (ack + bk) * p1r;
+        sumi = sumi + (ack + bk) * p1i;
+        bk = ack;
+        fkk = fkk - 1.0e0;
+    }
+    yr[(*n - 1)] = p2r;
+    yi[(*n - 1)] = p2i;
+    if (*n == 1) goto L70;
+    i = 2;
+    for (i4_ = *n - i + 1; i4_ > 0; --i4_, i += 1) {
+        ptr = p2r;
+        pti = p2i;
+        p2r = p1r + (fkk + fnf) * (rzr * ptr - rzi * pti);
+        p2i = p1i + (fkk + fnf) * (rzi * ptr + rzr * pti);
+        p1r = ptr;
+        p1i = pti;
+        ak = 1.0e0 - tfnf / (fkk + tfnf);
+        ack = bk * ak;
+        sumr = sumr + (ack + bk) * p1r;
+        sumi = sumi + (ack + bk) * p1i;
+        bk = ack;
+        fkk = fkk - 1.0e0;
+        m = *n - i + 1;
+        yr[(m - 1)] = p2r;
+        yi[(m - 1)] = p2i;
+    }
+L70:;
+    if (ifnu <= 0) goto L90;
+    i = 1;
+    for (i5_ = ifnu - i + 1; i5_ > 0; --i5_, i += 1) {
+        ptr = p2r;
+        pti = p2i;
+        p2r = p1r + (fkk + fnf) * (rzr * ptr - rzi * pti);
+        p2i = p1i + (fkk + fnf) * (rzr * pti + rzi * ptr);
+        p1r = ptr;
+        p1i = pti;
+        ak = 1.0e0 - tfnf / (fkk + tfnf);
+        ack = bk * ak;
+        sumr = sumr + (ack + bk) * p1r;
+        sumi = sumi + (ack + bk) * p1i;
+        bk = ack;
+        fkk = fkk - 1.0e0;
+    }
+L90:;
+    ptr = *zr;
+    pti = *zi;
+    if (*kode == 2) ptr = zeror;
+    azlog_(&rzr, &rzi, &str, &sti, &idum);
+    p1r = -fnf * str + ptr;
+    p1i = -fnf * sti + pti;
+    ap = dgamln_(&(double){1.0e0 + fnf}, &idum);
+    ptr = p1r - ap;
+    pti = p1i;
+    p2r = p2r + sumr;
+    p2i = p2i + sumi;
+    ap = azabs_(&p2r, &p2i);
+    p1r = 1.0e0 / ap;
+    azexp_(&ptr, &pti, &str, &sti);
+    ckr = str * p1r;
+    cki = sti * p1r;
+    ptr = p2r * p1r;
+    pti = -p2i * p1r;
+    zmlt_(&ckr, &cki, &ptr, &pti, &cnormr, &cnormi);
+    i = 1;
+    for (i6_ = *n - i + 1; i6_ > 0; --i6_, i += 1) {
+        str = yr[(i - 1)] * cnormr - yi[(i - 1)] * cnormi;
+        yi[(i - 1)] = yr[(i - 1)] * cnormi + yi[(i - 1)] * cnormr;
+        yr[(i - 1)] = str;
+    }
+    return;
+L110:;
+    *nz = -2;
+    return;
+}
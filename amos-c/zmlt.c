@@ -0,0 +1,15 @@
+/* Generated by scripts/f77-to-c.py from amos/zmlt.f, do not edit. */
+
+#include "amos.h"
+
+void zmlt_(double *ar, double *ai, double *br, double *bi, double *cr, double *ci)
+{
+    double ca = 0;
+    double cb = 0;
+
+    ca = *ar * *br - *ai * *bi;
+    cb = *ar * *bi + *ai * *br;
+    *cr = ca;
+    *ci = cb;
+    return;
+}
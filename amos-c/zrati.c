@@ -0,0 +1,157 @@
+/* Generated by scripts/f77-to-c.py from amos/zrati.f, do not edit. */
+
+#include "amos.h"
+
+void zrati_(double *zr, double *zi, double *fnu, int *n, double *cyr, double *cyi, double *tol)
+{
+    double ak = 0;
+    double amagz = 0;
+    double ap1 = 0;
+    double ap2 = 0;
+    double arg = 0;
+    double az = 0;
+    double cdfnui = 0;
+    double cdfnur = 0;
+    static double conei = 0.0e0;
+    static double coner = 1.0e0;
+    static double czeroi = 0.0e0;
+    static double czeror = 0.0e0;
+    double dfnu = 0;
+    double fdnu = 0;
+    double flam = 0;
+    double fnup = 0;
+    int i = 0;
+    int id = 0;
+    int idnu = 0;
+    int inu = 0;
+    int itime = 0;
+    int k = 0;
+    int kk = 0;
+    int magz = 0;
+    double p1i = 0;
+    double p1r = 0;
+    double p2i = 0;
+    double p2r = 0;
+    double pti = 0;
+    double ptr = 0;
+    double rak = 0;
+    double rap1 = 0;
+    double rho = 0;
+    static double rt2 = 1.41421356237309505e0;
+    double rzi = 0;
+    double rzr = 0;
+    double t1i = 0;
+    double t1r = 0;
+    double test = 0;
+    double test1 = 0;
+    double tti = 0;
+    double ttr = 0;
+    int i1_;
+    int i2_;
+
+    az = azabs_(zr, zi);
+    inu = (int)((float)(*fnu));
+    idnu = inu + *n - 1;
+    magz = (int)((float)(az));
+    amagz = (double)((float)(magz + 1));
+    fdnu = (double)((float)(idnu));
+    fnup = dmax(amagz, fdnu);
+    id = idnu - magz - 1;
+    itime = 1;
+    k = 1;
+    ptr = 1.0e0 / az;
+    rzr = ptr * (*zr + *zr) * ptr;
+    rzi = -ptr * (*zi + *zi) * ptr;
+    t1r = rzr * fnup;
+    t1i = rzi * fnup;
+    p2r = -t1r;
+    p2i = -t1i;
+    p1r = coner;
+    p1i = conei;
+    t1r = t1r + rzr;
+    t1i = t1i + rzi;
+    if (id > 0) id = 0;
+    ap2 = azabs_(&p2r, &p2i);
+    ap1 = azabs_(&p1r, &p1i);
+    arg = (ap2 + ap2) / (ap1 * *tol);
+    test1 = sqrt(arg);
+    test = test1;
+    rap1 = 1.0e0 / ap1;
+    p1r = p1r * rap1;
+    p1i = p1i * rap1;
+    p2r = p2r * rap1;
+    p2i = p2i * rap1;
+    ap2 = ap2 * rap1;
+L10:;
+    k = k + 1;
+    ap1 = ap2;
+    ptr = p2r;
+    pti = p2i;
+    p2r = p1r - (t1r * ptr - t1i * pti);
+    p2i = p1i - (t1r * pti + t1i * ptr);
+    p1r = ptr;
+    p1i = pti;
+    t1r = t1r + rzr;
+    t1i = t1i + rzi;
+    ap2 = azabs_(&p2r, &p2i);
+    if (ap1 <= test) goto L10;
+    if (itime == 2) goto L20;
+    ak = azabs_(&t1r, &t1i) * 0.5e0;
+    flam = ak + sqrt(ak * ak - 1.0e0);
+    rho = dmin(ap2 / ap1, flam);
+    test = test1 * sqrt(rho / (rho * rho - 1.0e0));
+    itime = 2;
+    goto L10;
+L20:;
+    kk = k + 1 - id;
+    ak = (double)((float)(kk));
+    t1r = ak;
+    t1i = czeroi;
+    dfnu = *fnu + (double)((float)(*n - 1));
+    p1r = 1.0e0 / ap2;
+    p1i = czeroi;
+    p2r = czeror;
+    p2i = czeroi;
+    i = 1;
+    for (i1_ = kk - i + 1; i1_ > 0; --i1_, i += 1) {
+        ptr = p1r;
+        pti = p1i;
+        rap1 = dfnu + t1r;
+        ttr = rzr * rap1;
+        tti = rzi * rap1;
+        p1r = (ptr * ttr - pti * tti) + p2r;
+        p1i = (ptr * tti + pti * ttr) + p2i;
+        p2r = ptr;
+        p2i = pti;
+        t1r = t1r - coner;
+    }
+    if (p1r != czeror || p1i != czeroi) goto L40;
+    p1r = *tol;
+    p1i = *tol;
+L40:;
+    zdiv_(&p2r, &p2i, &p1r, &p1i, &cyr[(*n - 1)], &cyi[(*n - 1)]);
+    if (*n == 1) return;
+    k = *n - 1;
+    ak = (double)((float)(k));
+    t1r = ak;
+    t1i = czeroi;
+    cdfnur = *fnu * rzr;
+    cdfnui = *fnu * rzi;
+    i = 2;
+    for (i2_ = *n - i + 1; i2_ > 0; --i2_, i += 1) {
+        ptr = cdfnur + (t1r * rzr - t1i * rzi) + cyr[(k + 1 - 1)];
+        pti = cdfnui + (t1r * rzi + t1i * rzr) + cyi[(k + 1 - 1)];
+        ak = azabs_(&ptr, &pti);
+        if (ak != czeror) goto L50;
+        ptr = *tol;
+        pti = *tol;
+        ak = *tol * rt2;
+    L50:;
+        rak = coner / ak;
+        cyr[(k - 1)] = rak * ptr * rak;
+        cyi[(k - 1)] = -rak * pti * rak;
+        t1r = t1r - coner;
+        k = k - 1;
+    }
+    return;
+}
@@ -0,0 +1,47 @@
+/* Generated by scripts/f77-to-c.py from amos/zs1s2.f, do not edit. */
+
+#include "amos.h"
+
+void zs1s2_(double *zrr, double *zri, double *s1r, double *s1i, double *s2r, double *s2i, int *nz, double *ascle, double *alim, int *iuf)
+{
+    double aa = 0;
+    double aln = 0;
+    double as1 = 0;
+    double as2 = 0;
+    double c1i = 0;
+    double c1r = 0;
+    int idum = 0;
+    double s1di = 0;
+    double s1dr = 0;
+    static double zeroi = 0.0e0;
+    static double zeror = 0.0e0;
+
+    *nz = 0;
+    as1 = azabs_(s1r, s1i);
+    as2 = azabs_(s2r, s2i);
+    if (*s1r == 0.0e0 && *s1i == 0.0e0) goto L10;
+    if (as1 == 0.0e0) goto L10;
+    aln = -*zrr - *zrr + log(as1);
+    s1dr = *s1r;
+    s1di = *s1i;
+    *s1r = zeror;
+    *s1i = zeroi;
+    as1 = zeror;
+    if (aln < (-*alim)) goto L10;
+    azlog_(&s1dr, &s1di, &c1r, &c1i, &idum);
+    c1r = c1r - *zrr - *zrr;
+    c1i = c1i - *zri - *zri;
+    azexp_(&c1r, &c1i, s1r, s1i);
+    as1 = azabs_(s1r, s1i);
+    *iuf = *iuf + 1;
+L10:;
+    aa = dmax(as1, as2);
+    if (aa > *ascle) return;
+    *s1r = zeror;
+    *s1i = zeroi;
+    *s2r = zeror;
+    *s2i = zeroi;
+    *nz = 1;
+    *iuf = 0;
+    return;
+}
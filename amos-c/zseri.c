@@ -0,0 +1,216 @@
+/* Generated by scripts/f77-to-c.py from amos/zseri.f, do not edit. */
+
+#include "amos.h"
+
+void zseri_(double *zr, double *zi, double *fnu, int *kode, int *n, double *yr, double *yi, int *nz, double *tol, double *elim, double *alim)
+{
+    double aa = 0;
+    double acz = 0;
+    double ak = 0;
+    double ak1i = 0;
+    double ak1r = 0;
+    double arm = 0;
+    double ascle = 0;
+    double atol = 0;
+    double az = 0;
+    double cki = 0;
+    double ckr = 0;
+    double coefi = 0;
+    double coefr = 0;
+    static double conei = 0.0e0;
+    static double coner = 1.0e0;
+    double crscr = 0;
+    double czi = 0;
+    double czr = 0;
+    double dfnu = 0;
+    double fnup = 0;
+    double hzi = 0;
+    double hzr = 0;
+    int i = 0;
+    int ib = 0;
+    int idum = 0;
+    int iflag = 0;
+    int il = 0;
+    int k = 0;
+    int l = 0;
+    int m = 0;
+    int nn = 0;
+    int nw = 0;
+    double raz = 0;
+    double rs = 0;
+    double rtr1 = 0;
+    double rzi = 0;
+    double rzr = 0;
+    double s = 0;
+    double s1i = 0;
+    double s1r = 0;
+    double s2i = 0;
+    double s2r = 0;
+    double ss = 0;
+    double sti = 0;
+    double str = 0;
+    double wi[2];
+    double wr[2];
+    static double zeroi = 0.0e0;
+    static double zeror = 0.0e0;
+    int i1_;
+    int i2_;
+    int i3_;
+    int i4_;
+
+    *nz = 0;
+    az = azabs_(zr, zi);
+    if (az == 0.0e0) goto L160;
+    arm = 1.0e+3 * d1mach_(&(int){1});
+    rtr1 = sqrt(arm);
+    crscr = 1.0e0;
+    iflag = 0;
+    if (az < arm) goto L150;
+    hzr = 0.5e0 * *zr;
+    hzi = 0.5e0 * *zi;
+    czr = zeror;
+    czi = zeroi;
+    if (az <= rtr1) goto L10;
+    zmlt_(&hzr, &hzi, &hzr, &hzi, &czr, &czi);
+L10:;
+    acz = azabs_(&czr, &czi);
+    nn = *n;
+    azlog_(&hzr, &hzi, &ckr, &cki, &idum);
+L20:;
+    dfnu = *fnu + (double)((float)(nn - 1));
+    fnup = dfnu + 1.0e0;
+    ak1r = ckr * dfnu;
+    ak1i = cki * dfnu;
+    ak = dgamln_(&fnup, &idum);
+    ak1r = ak1r - ak;
+    if (*kode == 2) ak1r = ak1r - *zr;
+    if (ak1r > (-*elim)) goto L40;
+L30:;
+    *nz = *nz + 1;
+    yr[(nn - 1)] = zeror;
+    yi[(nn - 1)] = zeroi;
+    if (acz > dfnu) goto L190;
+    nn = nn - 1;
+    if (nn == 0) return;
+    goto L20;
+L40:;
+    if (ak1r > (-*alim)) goto L50;
+    iflag = 1;
+    ss = 1.0e0 / *tol;
+    crscr = *tol;
+    ascle = arm * ss;
+L50:;
+    aa = exp(ak1r);
+    if (iflag == 1) aa = aa * ss;
+    coefr = aa * cos(ak1i);
+    coefi = aa * sin(ak1i);
+    atol = *tol * acz / fnup;
+    il = imin(2, nn);
+    i = 1;
+    for (i1_ = il - i + 1; i1_ > 0; --i1_, i += 1) {
+        dfnu = *fnu + (double)((float)(nn - i));
+        fnup = dfnu + 1.0e0;
+        s1r = coner;
+        s1i = conei;
+        if (acz < *tol * fnup) goto L70;
+        ak1r = coner;
+        ak1i = conei;
+        ak = fnup + 2.0e0;
+        s = fnup;
+        aa = 2.0e0;
+    L60:;
+        rs = 1.0e0 / s;
+        str = ak1r * czr - ak1i * czi;
+        sti = ak1r * czi + ak1i * czr;
+        ak1r = str * rs;
+        ak1i = sti * rs;
+        s1r = s1r + ak1r;
+        s1i = s1i + ak1i;
+        s = s + ak;
+        ak = ak + 2.0e0;
+        aa = aa * acz * rs;
+        if (aa > atol) goto L60;
+    L70:;
+        s2r = s1r * coefr - s1i * coefi;
+        s2i = s1r * coefi + s1i * coefr;
+        wr[(i - 1)] = s2r;
+        wi[(i - 1)] = s2i;
+        if (iflag == 0) goto L80;
+        zuchk_(&s2r, &s2i, &nw, &ascle, tol);
+        if (nw != 0) goto L30;
+    L80:;
+        m = nn - i + 1;
+        yr[(m - 1)] = s2r * crscr;
+        yi[(m - 1)] = s2i * crscr;
+        if (i == il) goto L90;
+        zdiv_(&coefr, &coefi, &hzr, &hzi, &str, &sti);
+        coefr = str * dfnu;
+        coefi = sti * dfnu;
+    L90:;
+    }
+    if (nn <= 2) return;
+    k = nn - 2;
+    ak = (double)((float)(k));
+    raz = 1.0e0 / az;
+    str = *zr * raz;
+    sti = -*zi * raz;
+    rzr = (str + str) * raz;
+    rzi = (sti + sti) * raz;
+    if (iflag == 1) goto L120;
+    ib = 3;
+L100:;
+    i = ib;
+    for (i2_ = nn - i + 1; i2_ > 0; --i2_, i += 1) {
+        yr[(k - 1)] = (ak + *fnu) * (rzr * yr[(k + 1 - 1)] - rzi * yi[(k + 1 - 1)]) + yr[(k + 2 - 1)];
+        yi[(k - 1)] = (ak + *fnu) * (rzr * yi[(k + 1 - 1)] + rzi * yr[(k + 1 - 1)]) + yi[(k + 2 - 1)];
+        ak = ak - 1.0e0;
+        k = k - 1;
+    }
+    return;
+L120:;
+    s1r = wr[(1 - 1)];
+    s1i = wi[(1 - 1)];
+    s2r = wr[(2 - 1)];
+    s2i = wi[(2 - 1)];
+    l = 3;
+    for (i3_ = nn - l + 1; i3_ > 0; --i3_, l += 1) {
+        ckr = s2r;
+        cki = s2i;
+        s2r = s1r + (ak + *fnu) * (rzr * ckr - rzi * cki);
+        s2i = s1i + (ak + *fnu) * (rzr * cki + rzi * ckr);
+        s1r = ckr;
+        s1i = cki;
+        ckr = s2r * crscr;
+        cki = s2i * crscr;
+        yr[(k - 1)] = ckr;
+        yi[(k - 1)] = cki;
+        ak = ak - 1.0e0;
+        k = k - 1;
+        if (azabs_(&ckr, &cki) > ascle) goto L140;
+    }
+    return;
+L140:;
+    ib = l + 1;
+    if (ib > nn) return;
+    goto L100;
+L150:;
+    *nz = *n;
+    if (*fnu == 0.0e0) *nz = *nz - 1;
+L160:;
+    yr[(1 - 1)] = zeror;
+    yi[(1 - 1)] = zeroi;
+    if (*fnu != 0.0e0) goto L170;
+    yr[(1 - 1)] = coner;
+    yi[(1 - 1)] = conei;
+L170:;
+    if (*n == 1) return;
+    i = 2;
+    for (i4_ = *n - i + 1; i4_ > 0; --i4_, i += 1) {
+        yr[(i - 1)] = zeror;
+        yi[(i - 1)] = zeroi;
+    }
+    return;
+L190:;
+    *nz = -*nz;
+    return;
+}
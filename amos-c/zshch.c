@@ -0,0 +1,21 @@
+/* Generated by scripts/f77-to-c.py from amos/zshch.f, do not edit. */
+
+#include "amos.h"
+
+void zshch_(double *zr, double *zi, double *cshr, double *cshi, double *cchr, double *cchi)
+{
+    double ch = 0;
+    double cn = 0;
+    double sh = 0;
+    double sn = 0;
+
+    sh = sinh(*zr);
+    ch = cosh(*zr);
+    sn = sin(*zi);
+    cn = cos(*zi);
+    *cshr = sh * cn;
+    *cshi = ch * sn;
+    *cchr = ch * cn;
+    *cchi = sh * sn;
+    return;
+}
@@ -0,0 +1,50 @@
+/* Generated by scripts/f77-to-c.py from amos/zsqrt.f, do not edit. */
+
+#include "amos.h"
+
+void azsqrt_(double *ar, double *ai, double *br, double *bi)
+{
+    static double dpi = 3.141592653589793238462643383e+0;
+    static double drt = 7.071067811865475244008443621e-1;
+    double dtheta = 0;
+    double zm = 0;
+
+    zm = azabs_(ar, ai);
+    zm = sqrt(zm);
+    if (*ar == 0.0e+0) goto L10;
+    if (*ai == 0.0e+0) goto L20;
+    dtheta = atan(*ai / *ar);
+    if (dtheta <= 0.0e+0) goto L40;
+    if (*ar < 0.0e+0) dtheta = dtheta - dpi;
+    goto L50;
+L10:;
+    if (*ai > 0.0e+0) goto L60;
+    if (*ai < 0.0e+0) goto L70;
+    *br = 0.0e+0;
+    *bi = 0.0e+0;
+    return;
+L20:;
+    if (*ar > 0.0e+0) goto L30;
+    *br = 0.0e+0;
+    *bi = sqrt(fabs(*ar));
+    return;
+L30:;
+    *br = sqrt(*ar);
+    *bi = 0.0e+0;
+    return;
+L40:;
+    if (*ar < 0.0e+0) dtheta = dtheta + dpi;
+L50:;
+    dtheta = dtheta * 0.5e+0;
+    *br = zm * cos(dtheta);
+    *bi = zm * sin(dtheta);
+    return;
+L60:;
+    *br = zm * drt;
+    *bi = zm * drt;
+    return;
+L70:;
+    *br = zm * drt;
+    *bi = -zm * drt;
+    return;
+}
@@ -0,0 +1,21 @@
+/* Generated by scripts/f77-to-c.py from amos/zuchk.f, do not edit. */
+
+#include "amos.h"
+
+void zuchk_(double *yr, double *yi, int *nz, double *ascle, double *tol)
+{
+    double ss = 0;
+    double st = 0;
+    double wi = 0;
+    double wr = 0;
+
+    *nz = 0;
+    wr = fabs(*yr);
+    wi = fabs(*yi);
+    st = dmin(wr, wi);
+    if (st > *ascle) return;
+    ss = dmax(wr, wi);
+    st = st / *tol;
+    if (ss < st) *nz = 1;
+    return;
+}
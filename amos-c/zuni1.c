@@ -0,0 +1,214 @@
+/* Generated by scripts/f77-to-c.py from amos/zuni1.f, do not edit. */
+
+#include "amos.h"
+
+void zuni1_(double *zr, double *zi, double *fnu, int *kode, int *n, double *yr, double *yi, int *nz, int *nlast, double *fnul, double *tol, double *elim, double *alim)
+{
+    double aphi = 0;
+    double ascle = 0;
+    double bry[3];
+    double c1r = 0;
+    double c2i = 0;
+    double c2m = 0;
+    double c2r = 0;
+    static double coner = 1.0e0;
+    double crsc = 0;
+    double cscl = 0;
+    double csrr[3];
+    double cssr[3];
+    double cwrki[16];
+    double cwrkr[16];
+    double cyi[2];
+    double cyr[2];
+    double fn = 0;
+    int i = 0;
+    int iflag = 0;
+    int init = 0;
+    int k = 0;
+    int m = 0;
+    int nd = 0;
+    int nn = 0;
+    int nuf = 0;
+    int nw = 0;
+    double phii = 0;
+    double phir = 0;
+    double rast = 0;
+    double rs1 = 0;
+    double rzi = 0;
+    double rzr = 0;
+    double s1i = 0;
+    double s1r = 0;
+    double s2i = 0;
+    double s2r = 0;
+    double sti = 0;
+    double str = 0;
+    double sumi = 0;
+    double sumr = 0;
+    static double zeroi = 0.0e0;
+    static double zeror = 0.0e0;
+    double zeta1i = 0;
+    double zeta1r = 0;
+    double zeta2i = 0;
+    double zeta2r = 0;
+    int i1_;
+    int i2_;
+    int i3_;
+
+    *nz = 0;
+    nd = *n;
+    *nlast = 0;
+    cscl = 1.0e0 / *tol;
+    crsc = *tol;
+    cssr[(1 - 1)] = cscl;
+    cssr[(2 - 1)] = coner;
+    cssr[(3 - 1)] = crsc;
+    csrr[(1 - 1)] = crsc;
+    csrr[(2 - 1)] = coner;
+    csrr[(3 - 1)] = cscl;
+    bry[(1 - 1)] = 1.0e+3 * d1mach_(&(int){1}) / *tol;
+    fn = dmax(*fnu, 1.0e0);
+    init = 0;
+    zunik_(zr, zi, &fn, &(int){1}, &(int){1}, tol, &init, &phir, &phii, &zeta1r, &zeta1i, &zeta2r, &zeta2i, &sumr, &sumi, cwrkr, cwrki);
+    if (*kode == 1) goto L10;
+    str = *zr + zeta2r;
+    sti = *zi + zeta2i;
+    rast = fn / azabs_(&str, &sti);
+    str = str * rast * rast;
+    sti = -sti * rast * rast;
+    s1r = -zeta1r + str;
+    s1i = -zeta1i + sti;
+    goto L20;
+L10:;
+    s1r = -zeta1r + zeta2r;
+    s1i = -zeta1i + zeta2i;
+L20:;
+    rs1 = s1r;
+    if (fabs(rs1) > *elim) goto L130;
+L30:;
+    nn = imin(2, nd);
+    i = 1;
+    for (i1_ = nn - i + 1; i1_ > 0; --i1_, i += 1) {
+        fn = *fnu + (double)((float)(nd - i));
+        init = 0;
+        zunik_(zr, zi, &fn, &(int){1}, &(int){0}, tol, &init, &phir, &phii, &zeta1r, &zeta1i, &zeta2r, &zeta2i, &sumr, &sumi, cwrkr, cwrki);
+        if (*kode == 1) goto L40;
+        str = *zr + zeta2r;
+        sti = *zi + zeta2i;
+        rast = fn / azabs_(&str, &sti);
+        str = str * rast * rast;
+        sti = -sti * rast * rast;
+        s1r = -zeta1r + str;
+        s1i = -zeta1i + sti + *zi;
+        goto L50;
+    L40:;
+        s1r = -zeta1r + zeta2r;
+        s1i = -zeta1i + zeta2i;
+    L50:;
+        rs1 = s1r;
+        if (fabs(rs1) > *elim) goto L110;
+        if (i == 1) iflag = 2;
+        if (fabs(rs1) < *alim) goto L60;
+        aphi = azabs_(&phir, &phii);
+        rs1 = rs1 + log(aphi);
+        if (fabs(rs1) > *elim) goto L110;
+        if (i == 1) iflag = 1;
+        if (rs1 < 0.0e0) goto L60;
+        if (i == 1) iflag = 3;
+    L60:;
+        s2r = phir * sumr - phii * sumi;
+        s2i = phir * sumi + phii * sumr;
+        str = exp(s1r) * cssr[(iflag - 1)];
+        s1r = str * cos(s1i);
+        s1i = str * sin(s1i);
+        str = s2r * s1r - s2i * s1i;
+        s2i = s2r * s1i + s2i * s1r;
+        s2r = str;
+        if (iflag != 1) goto L70;
+        zuchk_(&s2r, &s2i, &nw, &bry[(1 - 1)], tol);
+        if (nw != 0) goto L110;
+    L70:;
+        cyr[(i - 1)] = s2r;
+        cyi[(i - 1)] = s2i;
+        m = nd - i + 1;
+        yr[(m - 1)] = s2r * csrr[(iflag - 1)];
+        yi[(m - 1)] = s2i * csrr[(iflag - 1)];
+    }
+    if (nd <= 2) goto L100;
+    rast = 1.0e0 / azabs_(zr, zi);
+    str = *zr * rast;
+    sti = -*zi * rast;
+    rzr = (str + str) * rast;
+    rzi = (sti + sti) * rast;
+    bry[(2 - 1)] = 1.0e0 / bry[(1 - 1)];
+    bry[(3 - 1)] = d1mach_(&(int){2});
+    s1r = cyr[(1 - 1)];
+    s1i = cyi[(1 - 1)];
+    s2r = cyr[(2 - 1)];
+    s2i = cyi[(2 - 1)];
+    c1r = csrr[(iflag - 1)];
+    ascle = bry[(iflag - 1)];
+    k = nd - 2;
+    fn = (double)((float)(k));
+    i = 3;
+    for (i2_ = nd - i + 1; i2_ > 0; --i2_, i += 1) {
+        c2r = s2r;
+        c2i = s2i;
+        s2r = s1r + (*fnu + fn) * (rzr * c2r - rzi * c2i);
+        s2i = s1i + (*fnu + fn) * (rzr * c2i + rzi * c2r);
+        s1r = c2r;
+        s1i = c2i;
+        c2r = s2r * c1r;
+        c2i = s2i * c1r;
+        yr[(k - 1)] = c2r;
+        yi[(k - 1)] = c2i;
+        k = k - 1;
+        fn = fn - 1.0e0;
+        if (iflag >= 3) goto L90;
+        str = fabs(c2r);
+        sti = fabs(c2i);
+        c2m = dmax(str, sti);
+        if (c2m <= ascle) goto L90;
+        iflag = iflag + 1;
+        ascle = bry[(iflag - 1)];
+        s1r = s1r * c1r;
+        s1i = s1i * c1r;
+        s2r = c2r;
+        s2i = c2i;
+        s1r = s1r * cssr[(iflag - 1)];
+        s1i = s1i * cssr[(iflag - 1)];
+        s2r = s2r * cssr[(iflag - 1)];
+        s2i = s2i * cssr[(iflag - 1)];
+        c1r = csrr[(iflag - 1)];
+    L90:;
+    }
+L100:;
+    return;
+L110:;
+    if (rs1 > 0.0e0) goto L120;
+    yr[(nd - 1)] = zeror;
+    yi[(nd - 1)] = zeroi;
+    *nz = *nz + 1;
+    nd = nd - 1;
+    if (nd == 0) goto L100;
+    zuoik_(zr, zi, fnu, kode, &(int){1}, &nd, yr, yi, &nuf, tol, elim, alim);
+    if (nuf < 0) goto L120;
+    nd = nd - nuf;
+    *nz = *nz + nuf;
+    if (nd == 0) goto L100;
+    fn = *fnu + (double)((float)(nd - 1));
+    if (fn >= *fnul) goto L30;
+    *nlast = nd;
+    return;
+L120:;
+    *nz = -1;
+    return;
+L130:;
+    if (rs1 > 0.0e0) goto L120;
+    *nz = *n;
+    i = 1;
+    for (i3_ = *n - i + 1; i3_ > 0; --i3_, i += 1) {
+        yr[(i - 1)] = zeror;
+        yi[(i - 1)] = zeroi;
+    }
+    return;
+}
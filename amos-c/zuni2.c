@@ -0,0 +1,285 @@
+/* Generated by scripts/f77-to-c.py from amos/zuni2.f, do not edit. */
+
+#include "amos.h"
+
+void zuni2_(double *zr, double *zi, double *fnu, int *kode, int *n, double *yr, double *yi, int *nz, int *nlast, double *fnul, double *tol, double *elim, double *alim)
+{
+    double aarg = 0;
+    static double aic = 1.265512123484645396e+00;
+    double aii = 0;
+    double air = 0;
+    double ang = 0;
+    double aphi = 0;
+    double argi = 0;
+    double argr = 0;
+    double ascle = 0;
+    double asumi = 0;
+    double asumr = 0;
+    double bry[3];
+    double bsumi = 0;
+    double bsumr = 0;
+    double c1r = 0;
+    double c2i = 0;
+    double c2m = 0;
+    double c2r = 0;
+    double car = 0;
+    double cidi = 0;
+    static double cipi[4] = {
+        0.0e0, 1.0e0, 0.0e0,
+        -1.0e0,
+    };
+    static double cipr[4] = {
+        1.0e0, 0.0e0, -1.0e0,
+        0.0e0,
+    };
+    static double coner = 1.0e0;
+    double crsc = 0;
+    double cscl = 0;
+    double csrr[3];
+    double cssr[3];
+    double cyi[2];
+    double cyr[2];
+    double daii = 0;
+    double dair = 0;
+    double fn = 0;
+    static double hpi = 1.57079632679489662e+00;
+    int i = 0;
+    int idum = 0;
+    int iflag = 0;
+    int in = 0;
+    int inu = 0;
+    int j = 0;
+    int k = 0;
+    int nai = 0;
+    int nd = 0;
+    int ndai = 0;
+    int nn = 0;
+    int nuf = 0;
+    int nw = 0;
+    double phii = 0;
+    double phir = 0;
+    double rast = 0;
+    double raz = 0;
+    double rs1 = 0;
+    double rzi = 0;
+    double rzr = 0;
+    double s1i = 0;
+    double s1r = 0;
+    double s2i = 0;
+    double s2r = 0;
+    double sar = 0;
+    double sti = 0;
+    double str = 0;
+    double zbi = 0;
+    double zbr = 0;
+    static double zeroi = 0.0e0;
+    static double zeror = 0.0e0;
+    double zeta1i = 0;
+    double zeta1r = 0;
+    double zeta2i = 0;
+    double zeta2r = 0;
+    double zni = 0;
+    double znr = 0;
+    int i1_;
+    int i2_;
+    int i3_;
+
+    *nz = 0;
+    nd = *n;
+    *nlast = 0;
+    cscl = 1.0e0 / *tol;
+    crsc = *tol;
+    cssr[(1 - 1)] = cscl;
+    cssr[(2 - 1)] = coner;
+    cssr[(3 - 1)] = crsc;
+    csrr[(1 - 1)] = crsc;
+    csrr[(2 - 1)] = coner;
+    csrr[(3 - 1)] = cscl;
+    bry[(1 - 1)] = 1.0e+3 * d1mach_(&(int){1}) / *tol;
+    znr = *zi;
+    zni = -*zr;
+    zbr = *zr;
+    zbi = *zi;
+    cidi = -coner;
+    inu = (int)((float)(*fnu));
+    ang = hpi * (*fnu - (double)((float)(inu)));
+    c2r = cos(ang);
+    c2i = sin(ang);
+    car = c2r;
+    sar = c2i;
+    in = inu + *n - 1;
+    in = (in) % (4) + 1;
+    str = c2r * cipr[(in - 1)] - c2i * cipi[(in - 1)];
+    c2i = c2r * cipi[(in - 1)] + c2i * cipr[(in - 1)];
+    c2r = str;
+    if (*zi > 0.0e0) goto L10;
+    znr = -znr;
+    zbi = -zbi;
+    cidi = -cidi;
+    c2i = -c2i;
+L10:;
+    fn = dmax(*fnu, 1.0e0);
+    zunhj_(&znr, &zni, &fn, &(int){1}, tol, &phir, &phii, &argr, &argi, &zeta1r, &zeta1i, &zeta2r, &zeta2i, &asumr, &asumi, &bsumr, &bsumi);
+    if (*kode == 1) goto L20;
+    str = zbr + zeta2r;
+    sti = zbi + zeta2i;
+    rast = fn / azabs_(&str, &sti);
+    str = str * rast * rast;
+    sti = -sti * rast * rast;
+    s1r = -zeta1r + str;
+    s1i = -zeta1i + sti;
+    goto L30;
+L20:;
+    s1r = -zeta1r + zeta2r;
+    s1i = -zeta1i + zeta2i;
+L30:;
+    rs1 = s1r;
+    if (fabs(rs1) > *elim) goto L150;
+L40:;
+    nn = imin(2, nd);
+    i = 1;
+    for (i1_ = nn - i + 1; i1_ > 0; --i1_, i += 1) {
+        fn = *fnu + (double)((float)(nd - i));
+        zunhj_(&znr, &zni, &fn, &(int){0}, tol, &phir, &phii, &argr, &argi, &zeta1r, &zeta1i, &zeta2r, &zeta2i, &asumr, &asumi, &bsumr, &bsumi);
+        if (*kode == 1) goto L50;
+        str = zbr + zeta2r;
+        sti = zbi + zeta2i;
+        rast = fn / azabs_(&str, &sti);
+        str = str * rast * rast;
+        sti = -sti * rast * rast;
+        s1r = -zeta1r + str;
+        s1i = -zeta1i + sti + fabs(*zi);
+        goto L60;
+    L50:;
+        s1r = -zeta1r + zeta2r;
+        s1i = -zeta1i + zeta2i;
+    L60:;
+        rs1 = s1r;
+        if (fabs(rs1) > *elim) goto L120;
+        if (i == 1) iflag = 2;
+        if (fabs(rs1) < *alim) goto L70;
+        aphi = azabs_(&phir, &phii);
+        aarg = azabs_(&argr, &argi);
+        rs1 = rs1 + log(aphi) - 0.25e0 * log(aarg) - aic;
+        if (fabs(rs1) > *elim) goto L120;
+        if (i == 1) iflag = 1;
+        if (rs1 < 0.0e0) goto L70;
+        if (i == 1) iflag = 3;
+    L70:;
+        zairy_(&argr, &argi, &(int){0}, &(int){2}, &air, &aii, &nai, &idum);
+        zairy_(&argr, &argi, &(int){1}, &(int){2}, &dair, &daii, &ndai, &idum);
+        str = dair * bsumr - daii * bsumi;
+        sti = dair * bsumi + daii * bsumr;
+        str = str + (air * asumr - aii * asumi);
+        sti = sti + (air * asumi + aii * asumr);
+        s2r = phir * str - phii * sti;
+        s2i = phir * sti + phii * str;
+        str = exp(s1r) * cssr[(iflag - 1)];
+        s1r = str * cos(s1i);
+        s1i = str * sin(s1i);
+        str = s2r * s1r - s2i * s1i;
+        s2i = s2r * s1i + s2i * s1r;
+        s2r = str;
+        if (iflag != 1) goto L80;
+        zuchk_(&s2r, &s2i, &nw, &bry[(1 - 1)], tol);
+        if (nw != 0) goto L120;
+    L80:;
+        if (*zi <= 0.0e0) s2i = -s2i;
+        str = s2r * c2r - s2i * c2i;
+        s2i = s2r * c2i + s2i * c2r;
+        s2r = str;
+        cyr[(i - 1)] = s2r;
+        cyi[(i - 1)] = s2i;
+        j = nd - i + 1;
+        yr[(j - 1)] = s2r * csrr[(iflag - 1)];
+        yi[(j - 1)] = s2i * csrr[(iflag - 1)];
+        str = -c2i * cidi;
+        c2i = c2r * cidi;
+        c2r = str;
+    }
+    if (nd <= 2) goto L110;
+    raz = 1.0e0 / azabs_(zr, zi);
+    str = *zr * raz;
+    sti = -*zi * raz;
+    rzr = (str + str) * raz;
+    rzi = (sti + sti) * raz;
+    bry[(2 - 1)] = 1.0e0 / bry[(1 - 1)];
+    bry[(3 - 1)] = d1mach_(&(int){2});
+    s1r = cyr[(1 - 1)];
+    s1i = cyi[(1 - 1)];
+    s2r = cyr[(2 - 1)];
+    s2i = cyi[(2 - 1)];
+    c1r = csrr[(iflag - 1)];
+    ascle = bry[(iflag - 1)];
+    k = nd - 2;
+    fn = (double)((float)(k));
+    i = 3;
+    for (i2_ = nd - i + 1; i2_ > 0; --i2_, i += 1) {
+        c2r = s2r;
+        c2i = s2i;
+        s2r = s1r + (*fnu + fn) * (rzr * c2r - rzi * c2i);
+        s2i = s1i + (*fnu + fn) * (rzr * c2i + rzi * c2r);
+        s1r = c2r;
+        s1i = c2i;
+        c2r = s2r * c1r;
+        c2i = s2i * c1r;
+        yr[(k - 1)] = c2r;
+        yi[(k - 1)] = c2i;
+        k = k - 1;
+        fn = fn - 1.0e0;
+        if (iflag >= 3) goto L100;
+        str = fabs(c2r);
+        sti = fabs(c2i);
+        c2m = dmax(str, sti);
+        if (c2m <= ascle) goto L100;
+        iflag = iflag + 1;
+        ascle = bry[(iflag - 1)];
+        s1r = s1r * c1r;
+        s1i = s1i * c1r;
+        s2r = c2r;
+        s2i = c2i;
+        s1r = s1r * cssr[(iflag - 1)];
+        s1i = s1i * cssr[(iflag - 1)];
+        s2r = s2r * cssr[(iflag - 1)];
+        s2i = s2i * cssr[(iflag - 1)];
+        c1r = csrr[(iflag - 1)];
+    L100:;
+    }
+L110:;
+    return;
+L120:;
+    if (rs1 > 0.0e0) goto L140;
+    yr[(nd - 1)] = zeror;
+    yi[(nd - 1)] = zeroi;
+    *nz = *nz + 1;
+    nd = nd - 1;
+    if (nd == 0) goto L110;
+    zuoik_(zr, zi, fnu, kode, &(int){1}, &nd, yr, yi, &nuf, tol, elim, alim);
+    if (nuf < 0) goto L140;
+    nd = nd - nuf;
+    *nz = *nz + nuf;
+    if (nd == 0) goto L110;
+    fn = *fnu + (double)((float)(nd - 1));
+    if (fn < *fnul) goto L130;
+    in = inu + nd - 1;
+    in = (in) % (4) + 1;
+    c2r = car * cipr[(in - 1)] - sar * cipi[(in - 1)];
+    c2i = car * cipi[(in - 1)] + sar * cipr[(in - 1)];
+    if (*zi <= 0.0e0) c2i = -c2i;
+    goto L40;
+L130:;
+    *nlast = nd;
+    return;
+L140:;
+    *nz = -1;
+    return;
+L150:;
+    if (rs1 > 0.0e0) goto L140;
+    *nz = *n;
+    i = 1;
+    for (i3_ = *n - i + 1; i3_ > 0; --i3_, i += 1) {
+        yr[(i - 1)] = zeror;
+        yi[(i - 1)] = zeroi;
+    }
+    return;
+}
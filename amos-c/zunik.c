@@ -0,0 +1,175 @@
+/* Generated by scripts/f77-to-c.py from amos/zunik.f, do not edit. */
+
+#include "amos.h"
+
+void zunik_(double *zrr, double *zri, double *fnu, int *ikflg, int *ipmtr, double *tol, int *init, double *phir, double *phii, double *zeta1r, double *zeta1i, double *zeta2r, double *zeta2i, double *sumr, double *sumi, double *cwrkr, double *cwrki)
+{
+    double ac = 0;
+    static double c[120] = {
+        1.00000000000000000e+00, -2.08333333333333333e-01, 1.25000000000000000e-01,
+        3.34201388888888889e-01, -4.01041666666666667e-01, 7.03125000000000000e-02,
+        -1.02581259645061728e+00, 1.84646267361111111e+00, -8.91210937500000000e-01,
+        7.32421875000000000e-02, 4.66958442342624743e+00, -1.12070026162229938e+01,
+        8.78912353515625000e+00, -2.36408691406250000e+00, 1.12152099609375000e-01,
+        -2.82120725582002449e+01, 8.46362176746007346e+01, -9.18182415432400174e+01,
+        4.25349987453884549e+01, -7.36879435947963170e+00, 2.27108001708984375e-01,
+        2.12570130039217123e+02, -7.65252468141181642e+02, 1.05999045252799988e+03,
+        -6.99579627376132541e+02, 2.18190511744211590e+02, -2.64914304869515555e+01,
+        5.72501420974731445e-01, -1.91945766231840700e+03, 8.06172218173730938e+03,
+        -1.35865500064341374e+04, 1.16553933368645332e+04, -5.30564697861340311e+03,
+        1.20090291321635246e+03, -1.08090919788394656e+02, 1.72772750258445740e+00,
+        2.02042913309661486e+04, -9.69805983886375135e+04, 1.92547001232531532e+05,
+        -2.03400177280415534e+05, 1.22200464983017460e+05, -4.11926549688975513e+04,
+        7.10951430248936372e+03, -4.93915304773088012e+02, 6.07404200127348304e+00,
+        -2.42919187900551333e+05, 1.31176361466297720e+06, -2.99801591853810675e+06,
+        3.76327129765640400e+06, -2.81356322658653411e+06, 1.26836527332162478e+06,
+        -3.31645172484563578e+05, 4.52187689813627263e+04, -2.49983048181120962e+03,
+        2.43805296995560639e+01, 3.28446985307203782e+06, -1.97068191184322269e+07,
+        5.09526024926646422e+07, -7.41051482115326577e+07, 6.63445122747290267e+07,
+        -3.75671766607633513e+07, 1.32887671664218183e+07, -2.78561812808645469e+06,
+        3.08186404612662398e+05, -1.38860897537170405e+04, 1.10017140269246738e+02,
+        -4.93292536645099620e+07, 3.25573074185765749e+08, -9.39462359681578403e+08,
+        1.55359689957058006e+09, -1.62108055210833708e+09, 1.10684281682301447e+09,
+        -4.95889784275030309e+08, 1.42062907797533095e+08, -2.44740627257387285e+07,
+        2.24376817792244943e+06, -8.40054336030240853e+04, 5.51335896122020586e+02,
+        8.14789096118312115e+08, -5.86648149205184723e+09, 1.86882075092958249e+10,
+        -3.46320433881587779e+10, 4.12801855797539740e+10, -3.30265997498007231e+10,
+        1.79542137311556001e+10, -6.56329379261928433e+09, 1.55927986487925751e+09,
+        -2.25105661889415278e+08, 1.73951075539781645e+07, -5.49842327572288687e+05,
+        3.03809051092238427e+03, -1.46792612476956167e+10, 1.14498237732025810e+11,
+        -3.99096175224466498e+11, 8.19218669548577329e+11, -1.09837515608122331e+12,
+        1.00815810686538209e+12, -6.45364869245376503e+11, 2.87900649906150589e+11,
+        -8.78670721780232657e+10, 1.76347306068349694e+10, -2.16716498322379509e+09,
+        1.43157876718888981e+08, -3.87183344257261262e+06, 1.82577554742931747e+04,
+        2.86464035717679043e+11, -2.40629790002850396e+12, 9.10934118523989896e+12,
+        -2.05168994109344374e+13, 3.05651255199353206e+13, -3.16670885847851584e+13,
+        2.33483640445818409e+13, -1.23204913055982872e+13, 4.61272578084913197e+12,
+        -1.19655288019618160e+12, 2.05914503232410016e+11, -2.18229277575292237e+10,
+        1.24700929351271032e+09, -2.91883881222208134e+07, 1.18838426256783253e+05,
+    };
+    static double con[2] = {
+        3.98942280401432678e-01, 1.25331413731550025e+00,
+    };
+    static double conei = 0.0e0;
+    static double coner = 1.0e0;
+    double crfni = 0;
+    double crfnr = 0;
+    int i = 0;
+    int idum = 0;
+    int j = 0;
+    int k = 0;
+    int l = 0;
+    double rfn = 0;
+    double si = 0;
+    double sr = 0;
+    double sri = 0;
+    double srr = 0;
+    double sti = 0;
+    double str = 0;
+    double t2i = 0;
+    double t2r = 0;
+    double test = 0;
+    double ti = 0;
+    double tr = 0;
+    static double zeroi = 0.0e0;
+    static double zeror = 0.0e0;
+    double zni = 0;
+    double znr = 0;
+    int i1_;
+    int i2_;
+    int i3_;
+    int i4_;
+
+    if (*init != 0) goto L40;
+    rfn = 1.0e0 / *fnu;
+    test = d1mach_(&(int){1}) * 1.0e+3;
+    ac = *fnu * test;
+    if (fabs(*zrr) > ac || fabs(*zri) > ac) goto L15;
+    *zeta1r = 2.0e0 * fabs(log(test)) + *fnu;
+    *zeta1i = 0.0e0;
+    *zeta2r = *fnu;
+    *zeta2i = 0.0e0;
+    *phir = 1.0e0;
+    *phii = 0.0e0;
+    return;
+L15:;
+    tr = *zrr * rfn;
+    ti = *zri * rfn;
+    sr = coner + (tr * tr - ti * ti);
+    si = conei + (tr * ti + ti * tr);
+    azsqrt_(&sr, &si, &srr, &sri);
+    str = coner + srr;
+    sti = conei + sri;
+    zdiv_(&str, &sti, &tr, &ti, &znr, &zni);
+    azlog_(&znr, &zni, &str, &sti, &idum);
+    *zeta1r = *fnu * str;
+    *zeta1i = *fnu * sti;
+    *zeta2r = *fnu * srr;
+    *zeta2i = *fnu * sri;
+    zdiv_(&coner, &conei, &srr, &sri, &tr, &ti);
+    srr = tr * rfn;
+    sri = ti * rfn;
+    azsqrt_(&srr, &sri, &cwrkr[(16 - 1)], &cwrki[(16 - 1)]);
+    *phir = cwrkr[(16 - 1)] * con[(*ikflg - 1)];
+    *phii = cwrki[(16 - 1)] * con[(*ikflg - 1)];
+    if (*ipmtr != 0) return;
+    zdiv_(&coner, &conei, &sr, &si, &t2r, &t2i);
+    cwrkr[(1 - 1)] = coner;
+    cwrki[(1 - 1)] = conei;
+    crfnr = coner;
+    crfni = conei;
+    ac = 1.0e0;
+    l = 1;
+    k = 2;
+    for (i1_ = 15 - k + 1; i1_ > 0; --i1_, k += 1) {
+        sr = zeror;
+        si = zeroi;
+        j = 1;
+        for (i2_ = k - j + 1; i2_ > 0; --i2_, j += 1) {
+            l = l + 1;
+            str = sr * t2r - si * t2i + c[(l - 1)];
+            si = sr * t2i + si * t2r;
+            sr = str;
+        }
+        str = crfnr * srr - crfni * sri;
+        crfni = crfnr * sri + crfni * srr;
+        crfnr = str;
+        cwrkr[(k - 1)] = crfnr * sr - crfni * si;
+        cwrki[(k - 1)] = crfnr * si + crfni * sr;
+        ac = ac * rfn;
+        test = fabs(cwrkr[(k - 1)]) + fabs(cwrki[(k - 1)]);
+        if (ac < *tol && test < *tol) goto L30;
+    }
+    k = 15;
+L30:;
+    *init = k;
+L40:;
+    if (*ikflg == 2) goto L60;
+    sr = zeror;
+    si = zeroi;
+    i = 1;
+    for (i3_ = *init - i + 1; i3_ > 0; --i3_, i += 1) {
+        sr = sr + cwrkr[(i - 1)];
+        si = si + cwrki[(i - 1)];
+    }
+    *sumr = sr;
+    *sumi = si;
+    *phir = cwrkr[(16 - 1)] * con[(1 - 1)];
+    *phii = cwrki[(16 - 1)] * con[(1 - 1)];
+    return;
+L60:;
+    sr = zeror;
+    si = zeroi;
+    tr = coner;
+    i = 1;
+    for (i4_ = *init - i + 1; i4_ > 0; --i4_, i += 1) {
+        sr = sr + tr * cwrkr[(i - 1)];
+        si = si + tr * cwrki[(i - 1)];
+        tr = -tr;
+    }
+    *sumr = sr;
+    *sumi = si;
+    *phir = cwrkr[(16 - 1)] * con[(2 - 1)];
+    *phii = cwrki[(16 - 1)] * con[(2 - 1)];
+    return;
+}
@@ -0,0 +1,433 @@
+/* Generated by scripts/f77-to-c.py from amos/zunk1.f, do not edit. */
+
+#include "amos.h"
+
+void zunk1_(double *zr, double *zi, double *fnu, int *kode, int *mr, int *n, double *yr, double *yi, int *nz, double *tol, double *elim, double *alim)
+{
+    double ang = 0;
+    double aphi = 0;
+    double asc = 0;
+    double ascle = 0;
+    double bry[3];
+    double c1i = 0;
+    double c1r = 0;
+    double c2i = 0;
+    double c2m = 0;
+    double c2r = 0;
+    double cki = 0;
+    double ckr = 0;
+    static double coner = 1.0e0;
+    double crsc = 0;
+    double cscl = 0;
+    double csgni = 0;
+    double cspni = 0;
+    double cspnr = 0;
+    double csr = 0;
+    double csrr[3];
+    double cssr[3];
+    double cwrki[48];
+    double cwrkr[48];
+    double cyi[2];
+    double cyr[2];
+    double fmr = 0;
+    double fn = 0;
+    double fnf = 0;
+    int i = 0;
+    int ib = 0;
+    int ic = 0;
+    int iflag = 0;
+    int ifn = 0;
+    int il = 0;
+    int init[2];
+    int initd = 0;
+    int inu = 0;
+    int ipard = 0;
+    int iuf = 0;
+    int j = 0;
+    int k = 0;
+    int kdflg = 0;
+    int kflag = 0;
+    int kk = 0;
+    int m = 0;
+    int nw = 0;
+    double phidi = 0;
+    double phidr = 0;
+    double phii[2];
+    double phir[2];
+    static double pi = 3.14159265358979324e0;
+    double rast = 0;
+    double razr = 0;
+    double rs1 = 0;
+    double rzi = 0;
+    double rzr = 0;
+    double s1i = 0;
+    double s1r = 0;
+    double s2i = 0;
+    double s2r = 0;
+    double sgn = 0;
+    double sti = 0;
+    double str = 0;
+    double sumdi = 0;
+    double sumdr = 0;
+    double sumi[2];
+    double sumr[2];
+    static double zeroi = 0.0e0;
+    static double zeror = 0.0e0;
+    double zet1di = 0;
+    double zet1dr = 0;
+    double zet2di = 0;
+    double zet2dr = 0;
+    double zeta1i[2];
+    double zeta1r[2];
+    double zeta2i[2];
+    double zeta2r[2];
+    double zri = 0;
+    double zrr = 0;
+    int i1_;
+    int i2_;
+    int i3_;
+    int i4_;
+    int i5_;
+
+    kdflg = 1;
+    *nz = 0;
+    cscl = 1.0e0 / *tol;
+    crsc = *tol;
+    cssr[(1 - 1)] = cscl;
+    cssr[(2 - 1)] = coner;
+    cssr[(3 - 1)] = crsc;
+    csrr[(1 - 1)] = crsc;
+    csrr[(2 - 1)] = coner;
+    csrr[(3 - 1)] = cscl;
+    bry[(1 - 1)] = 1.0e+3 * d1mach_(&(int){1}) / *tol;
+    bry[(2 - 1)] = 1.0e0 / bry[(1 - 1)];
+    bry[(3 - 1)] = d1mach_(&(int){2});
+    zrr = *zr;
+    zri = *zi;
+    if (*zr >= 0.0e0) goto L10;
+    zrr = -*zr;
+    zri = -*zi;
+L10:;
+    j = 2;
+    i = 1;
+    for (i1_ = *n - i + 1; i1_ > 0; --i1_, i += 1) {
+        j = 3 - j;
+        fn = *fnu + (double)((float)(i - 1));
+        init[(j - 1)] = 0;
+        zunik_(&zrr, &zri, &fn, &(int){2}, &(int){0}, tol, &init[(j - 1)], &phir[(j - 1)], &phii[(j - 1)], &zeta1r[(j - 1)], &zeta1i[(j - 1)], &zeta2r[(j - 1)], &zeta2i[(j - 1)], &sumr[(j - 1)], &sumi[(j - 1)], &cwrkr[(1 - 1) + (j - 1) * 16], &cwrki[(1 - 1) + (j - 1) * 16]);
+        if (*kode == 1) goto L20;
+        str = zrr + zeta2r[(j - 1)];
+        sti = zri + zeta2i[(j - 1)];
+        rast = fn / azabs_(&str, &sti);
+        str = str * rast * rast;
+        sti = -sti * rast * rast;
+        s1r = zeta1r[(j - 1)] - str;
+        s1i = zeta1i[(j - 1)] - sti;
+        goto L30;
+    L20:;
+        s1r = zeta1r[(j - 1)] - zeta2r[(j - 1)];
+        s1i = zeta1i[(j - 1)] - zeta2i[(j - 1)];
+    L30:;
+        rs1 = s1r;
+        if (fabs(rs1) > *elim) goto L60;
+        if (kdflg == 1) kflag = 2;
+        if (fabs(rs1) < *alim) goto L40;
+        aphi = azabs_(&phir[(j - 1)], &phii[(j - 1)]);
+        rs1 = rs1 + log(aphi);
+        if (fabs(rs1) > *elim) goto L60;
+        if (kdflg == 1) kflag = 1;
+        if (rs1 < 0.0e0) goto L40;
+        if (kdflg == 1) kflag = 3;
+    L40:;
+        s2r = phir[(j - 1)] * sumr[(j - 1)] - phii[(j - 1)] * sumi[(j - 1)];
+        s2i = phir[(j - 1)] * sumi[(j - 1)] + phii[(j - 1)] * sumr[(j - 1)];
+        str = exp(s1r) * cssr[(kflag - 1)];
+        s1r = str * cos(s1i);
+        s1i = str * sin(s1i);
+        str = s2r * s1r - s2i * s1i;
+        s2i = s1r * s2i + s2r * s1i;
+        s2r = str;
+        if (kflag != 1) goto L50;
+        zuchk_(&s2r, &s2i, &nw, &bry[(1 - 1)], tol);
+        if (nw != 0) goto L60;
+    L50:;
+        cyr[(kdflg - 1)] = s2r;
+        cyi[(kdflg - 1)] = s2i;
+        yr[(i - 1)] = s2r * csrr[(kflag - 1)];
+        yi[(i - 1)] = s2i * csrr[(kflag - 1)];
+        if (kdflg == 2) goto L75;
+        kdflg = 2;
+        goto L70;
+    L60:;
+        if (rs1 > 0.0e0) goto L300;
+        if (*zr < 0.0e0) goto L300;
+        kdflg = 1;
+        yr[(i - 1)] = zeror;
+        yi[(i - 1)] = zeroi;
+        *nz = *nz + 1;
+        if (i == 1) goto L70;
+        if ((yr[(i - 1 - 1)] == zeror) && (yi[(i - 1 - 1)] == zeroi)) goto L70;
+        yr[(i - 1 - 1)] = zeror;
+        yi[(i - 1 - 1)] = zeroi;
+        *nz = *nz + 1;
+    L70:;
+    }
+    i = *n;
+L75:;
+    razr = 1.0e0 / azabs_(&zrr, &zri);
+    str = zrr * razr;
+    sti = -zri * razr;
+    rzr = (str + str) * razr;
+    rzi = (sti + sti) * razr;
+    ckr = fn * rzr;
+    cki = fn * rzi;
+    ib = i + 1;
+    if (*n < ib) goto L160;
+    fn = *fnu + (double)((float)(*n - 1));
+    ipard = 1;
+    if (*mr != 0) ipard = 0;
+    initd = 0;
+    zunik_(&zrr, &zri, &fn, &(int){2}, &ipard, tol, &initd, &phidr, &phidi, &zet1dr, &zet1di, &zet2dr, &zet2di, &sumdr, &sumdi, &cwrkr[(1 - 1) + (3 - 1) * 16], &cwrki[(1 - 1) + (3 - 1) * 16]);
+    if (*kode == 1) goto L80;
+    str = zrr + zet2dr;
+    sti = zri + zet2di;
+    rast = fn / azabs_(&str, &sti);
+    str = str * rast * rast;
+    sti = -sti * rast * rast;
+    s1r = zet1dr - str;
+    s1i = zet1di - sti;
+    goto L90;
+L80:;
+    s1r = zet1dr - zet2dr;
+    s1i = zet1di - zet2di;
+L90:;
+    rs1 = s1r;
+    if (fabs(rs1) > *elim) goto L95;
+    if (fabs(rs1) < *alim) goto L100;
+    aphi = azabs_(&phidr, &phidi);
+    rs1 = rs1 + log(aphi);
+    if (fabs(rs1) < *elim) goto L100;
+L95:;
+    if (fabs(rs1) > 0.0e0) goto L300;
+    if (*zr < 0.0e0) goto L300;
+    *nz = *n;
+    i = 1;
+    for (i2_ = *n - i + 1; i2_ > 0; --i2_, i += 1) {
+        yr[(i - 1)] = zeror;
+        yi[(i - 1)] = zeroi;
+    }
+    return;
+L100:;
+    s1r = cyr[(1 - 1)];
+    s1i = cyi[(1 - 1)];
+    s2r = cyr[(2 - 1)];
+    s2i = cyi[(2 - 1)];
+    c1r = csrr[(kflag - 1)];
+    ascle = bry[(kflag - 1)];
+    i = ib;
+    for (i3_ = *n - i + 1; i3_ > 0; --i3_, i += 1) {
+        c2r = s2r;
+        c2i = s2i;
+        s2r = ckr * c2r - cki * c2i + s1r;
+        s2i = ckr * c2i + cki * c2r + s1i;
+        s1r = c2r;
+        s1i = c2i;
+        ckr = ckr + rzr;
+        cki = cki + rzi;
+        c2r = s2r * c1r;
+        c2i = s2i * c1r;
+        yr[(i - 1)] = c2r;
+        yi[(i - 1)] = c2i;
+        if (kflag >= 3) goto L120;
+        str = fabs(c2r);
+        sti = fabs(c2i);
+        c2m = dmax(str, sti);
+        if (c2m <= ascle) goto L120;
+        kflag = kflag + 1;
+        ascle = bry[(kflag - 1)];
+        s1r = s1r * c1r;
+        s1i = s1i * c1r;
+        s2r = c2r;
+        s2i = c2i;
+        s1r = s1r * cssr[(kflag - 1)];
+        s1i = s1i * cssr[(kflag - 1)];
+        s2r = s2r * cssr[(kflag - 1)];
+        s2i = s2i * cssr[(kflag - 1)];
+        c1r = csrr[(kflag - 1)];
+    L120:;
+    }
+L160:;
+    if (*mr == 0) return;
+    *nz = 0;
+    fmr = (double)((float)(*mr));
+    sgn = -d_sign(pi, fmr);
+    csgni = sgn;
+    inu = (int)((float)(*fnu));
+    fnf = *fnu - (double)((float)(inu));
+    ifn = inu + *n - 1;
+    ang = fnf * sgn;
+    cspnr = cos(ang);
+    cspni = sin(ang);
+    if ((ifn) % (2) == 0) goto L170;
+    cspnr = -cspnr;
+    cspni = -cspni;
+L170:;
+    asc = bry[(1 - 1)];
+    iuf = 0;
+    kk = *n;
+    kdflg = 1;
+    ib = ib - 1;
+    ic = ib - 1;
+    k = 1;
+    for (i4_ = *n - k + 1; i4_ > 0; --i4_, k += 1) {
+        fn = *fnu + (double)((float)(kk - 1));
+        m = 3;
+        if (*n > 2) goto L175;
+    L172:;
+        initd = init[(j - 1)];
+        phidr = phir[(j - 1)];
+        phidi = phii[(j - 1)];
+        zet1dr = zeta1r[(j - 1)];
+        zet1di = zeta1i[(j - 1)];
+        zet2dr = zeta2r[(j - 1)];
+        zet2di = zeta2i[(j - 1)];
+        sumdr = sumr[(j - 1)];
+        sumdi = sumi[(j - 1)];
+        m = j;
+        j = 3 - j;
+        goto L180;
+    L175:;
+        if ((kk == *n) && (ib < *n)) goto L180;
+        if ((kk == ib) || (kk == ic)) goto L172;
+        initd = 0;
+    L180:;
+        zunik_(&zrr, &zri, &fn, &(int){1}, &(int){0}, tol, &initd, &phidr, &phidi, &zet1dr, &zet1di, &zet2dr, &zet2di, &sumdr, &sumdi, &cwrkr[(1 - 1) + (m - 1) * 16], &cwrki[(1 - 1) + (m - 1) * 16]);
+        if (*kode == 1) goto L200;
+        str = zrr + zet2dr;
+        sti = zri + zet2di;
+        rast = fn / azabs_(&str, &sti);
+        str = str * rast * rast;
+        sti = -sti * rast * rast;
+        s1r = -zet1dr + str;
+        s1i = -zet1di + sti;
+        goto L210;
+    L200:;
+        s1r = -zet1dr + zet2dr;
+        s1i = -zet1di + zet2di;
+    L210:;
+        rs1 = s1r;
+        if (fabs(rs1) > *elim) goto L260;
+        if (kdflg == 1) iflag = 2;
+        if (fabs(rs1) < *alim) goto L220;
+        aphi = azabs_(&phidr, &phidi);
+        rs1 = rs1 + log(aphi);
+        if (fabs(rs1) > *elim) goto L260;
+        if (kdflg == 1) iflag = 1;
+        if (rs1 < 0.0e0) goto L220;
+        if (kdflg == 1) iflag = 3;
+    L220:;
+        str = phidr * sumdr - phidi * sumdi;
+        sti = phidr * sumdi + phidi * sumdr;
+        s2r = -csgni * sti;
+        s2i = csgni * str;
+        str = exp(s1r) * cssr[(iflag - 1)];
+        s1r = str * cos(s1i);
+        s1i = str * sin(s1i);
+        str = s2r * s1r - s2i * s1i;
+        s2i = s2r * s1i + s2i * s1r;
+        s2r = str;
+        if (iflag != 1) goto L230;
+        zuchk_(&s2r, &s2i, &nw, &bry[(1 - 1)], tol);
+        if (nw == 0) goto L230;
+        s2r = zeror;
+        s2i = zeroi;
+    L230:;
+        cyr[(kdflg - 1)] = s2r;
+        cyi[(kdflg - 1)] = s2i;
+        c2r = s2r;
+        c2i = s2i;
+        s2r = s2r * csrr[(iflag - 1)];
+        s2i = s2i * csrr[(iflag - 1)];
+        s1r = yr[(kk - 1)];
+        s1i = yi[(kk - 1)];
+        if (*kode == 1) goto L250;
+        zs1s2_(&zrr, &zri, &s1r, &s1i, &s2r, &s2i, &nw, &asc, alim, &iuf);
+        *nz = *nz + nw;
+    L250:;
+        yr[(kk - 1)] = s1r * cspnr - s1i * cspni + s2r;
+        yi[(kk - 1)] = cspnr * s1i + cspni * s1r + s2i;
+        kk = kk - 1;
+        cspnr = -cspnr;
+        cspni = -cspni;
+        if (c2r != 0.0e0 || c2i != 0.0e0) goto L255;
+        kdflg = 1;
+        goto L270;
+    L255:;
+        if (kdflg == 2) goto L275;
+        kdflg = 2;
+        goto L270;
+    L260:;
+        if (rs1 > 0.0e0) goto L300;
+        s2r = zeror;
+        s2i = zeroi;
+        goto L230;
+    L270:;
+    }
+    k = *n;
+L275:;
+    il = *n - k;
+    if (il == 0) return;
+    s1r = cyr[(1 - 1)];
+    s1i = cyi[(1 - 1)];
+    s2r = cyr[(2 - 1)];
+    s2i = cyi[(2 - 1)];
+    csr = csrr[(iflag - 1)];
+    ascle = bry[(iflag - 1)];
+    fn = (double)((float)(inu + il));
+    i = 1;
+    for (i5_ = il - i + 1; i5_ > 0; --i5_, i += 1) {
+        c2r = s2r;
+        c2i = s2i;
+        s2r = s1r + (fn + fnf) * (rzr * c2r - rzi * c2i);
+        s2i = s1i + (fn + fnf) * (rzr * c2i + rzi * c2r);
+        s1r = c2r;
+        s1i = c2i;
+        fn = fn - 1.0e0;
+        c2r = s2r * csr;
+        c2i = s2i * csr;
+        ckr = c2r;
+        cki = c2i;
+        c1r = yr[(kk - 1)];
+        c1i = yi[(kk - 1)];
+        if (*kode == 1) goto L280;
+        zs1s2_(&zrr, &zri, &c1r, &c1i, &c2r, &c2i, &nw, &asc, alim, &iuf);
+        *nz = *nz + nw;
+    L280:;
+        yr[(kk - 1)] = c1r * cspnr - c1i * cspni + c2r;
+        yi[(kk - 1)] = c1r * cspni + c1i * cspnr + c2i;
+        kk = kk - 1;
+        cspnr = -cspnr;
+        cspni = -cspni;
+        if (iflag >= 3) goto L290;
+        c2r = fabs(ckr);
+        c2i = fabs(cki);
+        c2m = dmax(c2r, c2i);
+        if (c2m <= ascle) goto L290;
+        iflag = iflag + 1;
+        ascle = bry[(iflag - 1)];
+        s1r = s1r * csr;
+        s1i = s1i * csr;
+        s2r = ckr;
+        s2i = cki;
+        s1r = s1r * cssr[(iflag - 1)];
+        s1i = s1i * cssr[(iflag - 1)];
+        s2r = s2r * cssr[(iflag - 1)];
+        s2i = s2i * cssr[(iflag - 1)];
+        csr = csrr[(iflag - 1)];
+    L290:;
+    }
+    return;
+L300:;
+    *nz = -1;
+    return;
+}
@@ -0,0 +1,526 @@
+/* Generated by scripts/f77-to-c.py from amos/zunk2.f, do not edit. */
+
+#include "amos.h"
+
+void zunk2_(double *zr, double *zi, double *fnu, int *kode, int *mr, int *n, double *yr, double *yi, int *nz, double *tol, double *elim, double *alim)
+{
+    double aarg = 0;
+    static double aic = 1.26551212348464539e+00;
+    double aii = 0;
+    double air = 0;
+    double ang = 0;
+    double aphi = 0;
+    double argdi = 0;
+    double argdr = 0;
+    double argi[2];
+    double argr[2];
+    double asc = 0;
+    double ascle = 0;
+    double asumdi = 0;
+    double asumdr = 0;
+    double asumi[2];
+    double asumr[2];
+    double bry[3];
+    double bsumdi = 0;
+    double bsumdr = 0;
+    double bsumi[2];
+    double bsumr[2];
+    double c1i = 0;
+    double c1r = 0;
+    double c2i = 0;
+    double c2m = 0;
+    double c2r = 0;
+    double car = 0;
+    static double cipi[4] = {
+        0.0e0, -1.0e0, 0.0e0,
+        1.0e0,
+    };
+    static double cipr[4] = {
+        1.0e0, 0.0e0, -1.0e0,
+        0.0e0,
+    };
+    double cki = 0;
+    double ckr = 0;
+    static double coner = 1.0e0;
+    static double cr1i = 1.73205080756887729e0;
+    static double cr1r = 1.0e0;
+    static double cr2i = -8.66025403784438647e-01;
+    static double cr2r = -0.5e0;
+    double crsc = 0;
+    double cscl = 0;
+    double csgni = 0;
+    double csi = 0;
+    double cspni = 0;
+    double cspnr = 0;
+    double csr = 0;
+    double csrr[3];
+    double cssr[3];
+    double cyi[2];
+    double cyr[2];
+    double daii = 0;
+    double dair = 0;
+    double fmr = 0;
+    double fn = 0;
+    double fnf = 0;
+    static double hpi = 1.57079632679489662e+00;
+    int i = 0;
+    int ib = 0;
+    int ic = 0;
+    int idum = 0;
+    int iflag = 0;
+    int ifn = 0;
+    int il = 0;
+    int in = 0;
+    int inu = 0;
+    int ipard = 0;
+    int iuf = 0;
+    int j = 0;
+    int k = 0;
+    int kdflg = 0;
+    int kflag = 0;
+    int kk = 0;
+    int nai = 0;
+    int ndai = 0;
+    int nw = 0;
+    double phidi = 0;
+    double phidr = 0;
+    double phii[2];
+    double phir[2];
+    static double pi = 3.14159265358979324e+00;
+    double pti = 0;
+    double ptr = 0;
+    double rast = 0;
+    double razr = 0;
+    double rs1 = 0;
+    double rzi = 0;
+    double rzr = 0;
+    double s1i = 0;
+    double s1r = 0;
+    double s2i = 0;
+    double s2r = 0;
+    double sar = 0;
+    double sgn = 0;
+    double sti = 0;
+    double str = 0;
+    double yy = 0;
+    double zbi = 0;
+    double zbr = 0;
+    static double zeroi = 0.0e0;
+    static double zeror = 0.0e0;
+    double zet1di = 0;
+    double zet1dr = 0;
+    double zet2di = 0;
+    double zet2dr = 0;
+    double zeta1i[2];
+    double zeta1r[2];
+    double zeta2i[2];
+    double zeta2r[2];
+    double zni = 0;
+    double znr = 0;
+    double zri = 0;
+    double zrr = 0;
+    int i1_;
+    int i2_;
+    int i3_;
+    int i4_;
+    int i5_;
+
+    kdflg = 1;
+    *nz = 0;
+    cscl = 1.0e0 / *tol;
+    crsc = *tol;
+    cssr[(1 - 1)] = cscl;
+    cssr[(2 - 1)] = coner;
+    cssr[(3 - 1)] = crsc;
+    csrr[(1 - 1)] = crsc;
+    csrr[(2 - 1)] = coner;
+    csrr[(3 - 1)] = cscl;
+    bry[(1 - 1)] = 1.0e+3 * d1mach_(&(int){1}) / *tol;
+    bry[(2 - 1)] = 1.0e0 / bry[(1 - 1)];
+    bry[(3 - 1)] = d1mach_(&(int){2});
+    zrr = *zr;
+    zri = *zi;
+    if (*zr >= 0.0e0) goto L10;
+    zrr = -*zr;
+    zri = -*zi;
+L10:;
+    yy = zri;
+    znr = zri;
+    zni = -zrr;
+    zbr = zrr;
+    zbi = zri;
+    inu = (int)((float)(*fnu));
+    fnf = *fnu - (double)((float)(inu));
+    ang = -hpi * fnf;
+    car = cos(ang);
+    sar = sin(ang);
+    c2r = hpi * sar;
+    c2i = -hpi * car;
+    kk = (inu) % (4) + 1;
+    str = c2r * cipr[(kk - 1)] - c2i * cipi[(kk - 1)];
+    sti = c2r * cipi[(kk - 1)] + c2i * cipr[(kk - 1)];
+    csr = cr1r * str - cr1i * sti;
+    csi = cr1r * sti + cr1i * str;
+    if (yy > 0.0e0) goto L20;
+    znr = -znr;
+    zbi = -zbi;
+L20:;
+    j = 2;
+    i = 1;
+    for (i1_ = *n - i + 1; i1_ > 0; --i1_, i += 1) {
+        j = 3 - j;
+        fn = *fnu + (double)((float)(i - 1));
+        zunhj_(&znr, &zni, &fn, &(int){0}, tol, &phir[(j - 1)], &phii[(j - 1)], &argr[(j - 1)], &argi[(j - 1)], &zeta1r[(j - 1)], &zeta1i[(j - 1)], &zeta2r[(j - 1)], &zeta2i[(j - 1)], &asumr[(j - 1)], &asumi[(j - 1)], &bsumr[(j - 1)], &bsumi[(j - 1)]);
+        if (*kode == 1) goto L30;
+        str = zbr + zeta2r[(j - 1)];
+        sti = zbi + zeta2i[(j - 1)];
+        rast = fn / azabs_(&str, &sti);
+        str = str * rast * rast;
+        sti = -sti * rast * rast;
+        s1r = zeta1r[(j - 1)] - str;
+        s1i = zeta1i[(j - 1)] - sti;
+        goto L40;
+    L30:;
+        s1r = zeta1r[(j - 1)] - zeta2r[(j - 1)];
+        s1i = zeta1i[(j - 1)] - zeta2i[(j - 1)];
+    L40:;
+        rs1 = s1r;
+        if (fabs(rs1) > *elim) goto L70;
+        if (kdflg == 1) kflag = 2;
+        if (fabs(rs1) < *alim) goto L50;
+        aphi = azabs_(&phir[(j - 1)], &phii[(j - 1)]);
+        aarg = azabs_(&argr[(j - 1)], &argi[(j - 1)]);
+        rs1 = rs1 + log(aphi) - 0.25e0 * log(aarg) - aic;
+        if (fabs(rs1) > *elim) goto L70;
+        if (kdflg == 1) kflag = 1;
+        if (rs1 < 0.0e0) goto L50;
+        if (kdflg == 1) kflag = 3;
+    L50:;
+        c2r = argr[(j - 1)] * cr2r - argi[(j - 1)] * cr2i;
+        c2i = argr[(j - 1)] * cr2i + argi[(j - 1)] * cr2r;
+        zairy_(&c2r, &c2i, &(int){0}, &(int){2}, &air, &aii, &nai, &idum);
+        zairy_(&c2r, &c2i, &(int){1}, &(int){2}, &dair, &daii, &ndai, &idum);
+        str = dair * bsumr[(j - 1)] - daii * bsumi[(j - 1)];
+        sti = dair * bsumi[(j - 1)] + daii * bsumr[(j - 1)];
+        ptr = str * cr2r - sti * cr2i;
+        pti = str * cr2i + sti * cr2r;
+        str = ptr + (air * asumr[(j - 1)] - aii * asumi[(j - 1)]);
+        sti = pti + (air * asumi[(j - 1)] + aii * asumr[(j - 1)]);
+        ptr = str * phir[(j - 1)] - sti * phii[(j - 1)];
+        pti = str * phii[(j - 1)] + sti * phir[(j - 1)];
+        s2r = ptr * csr - pti * csi;
+        s2i = ptr * csi + pti * csr;
+        str = exp(s1r) * cssr[(kflag - 1)];
+        s1r = str * cos(s1i);
+        s1i = str * sin(s1i);
+        str = s2r * s1r - s2i * s1i;
+        s2i = s1r * s2i + s2r * s1i;
+        s2r = str;
+        if (kflag != 1) goto L60;
+        zuchk_(&s2r, &s2i, &nw, &bry[(1 - 1)], tol);
+        if (nw != 0) goto L70;
+    L60:;
+        if (yy <= 0.0e0) s2i = -s2i;
+        cyr[(kdflg - 1)] = s2r;
+        cyi[(kdflg - 1)] = s2i;
+        yr[(i - 1)] = s2r * csrr[(kflag - 1)];
+        yi[(i - 1)] = s2i * csrr[(kflag - 1)];
+        str = csi;
+        csi = -csr;
+        csr = str;
+        if (kdflg == 2) goto L85;
+        kdflg = 2;
+        goto L80;
+    L70:;
+        if (rs1 > 0.0e0) goto L320;
+        if (*zr < 0.0e0) goto L320;
+        kdflg = 1;
+        yr[(i - 1)] = zeror;
+        yi[(i - 1)] = zeroi;
+        *nz = *nz + 1;
+        str = csi;
+        csi = -csr;
+        csr = str;
+        if (i == 1) goto L80;
+        if ((yr[(i - 1 - 1)] == zeror) && (yi[(i - 1 - 1)] == zeroi)) goto L80;
+        yr[(i - 1 - 1)] = zeror;
+        yi[(i - 1 - 1)] = zeroi;
+        *nz = *nz + 1;
+    L80:;
+    }
+    i = *n;
+L85:;
+    razr = 1.0e0 / azabs_(&zrr, &zri);
+    str = zrr * razr;
+    sti = -zri * razr;
+    rzr = (str + str) * razr;
+    rzi = (sti + sti) * razr;
+    ckr = fn * rzr;
+    cki = fn * rzi;
+    ib = i + 1;
+    if (*n < ib) goto L180;
+    fn = *fnu + (double)((float)(*n - 1));
+    ipard = 1;
+    if (*mr != 0) ipard = 0;
+    zunhj_(&znr, &zni, &fn, &ipard, tol, &phidr, &phidi, &argdr, &argdi, &zet1dr, &zet1di, &zet2dr, &zet2di, &asumdr, &asumdi, &bsumdr, &bsumdi);
+    if (*kode == 1) goto L90;
+    str = zbr + zet2dr;
+    sti = zbi + zet2di;
+    rast = fn / azabs_(&str, &sti);
+    str = str * rast * rast;
+    sti = -sti * rast * rast;
+    s1r = zet1dr - str;
+    s1i = zet1di - sti;
+    goto L100;
+L90:;
+    s1r = zet1dr - zet2dr;
+    s1i = zet1di - zet2di;
+L100:;
+    rs1 = s1r;
+    if (fabs(rs1) > *elim) goto L105;
+    if (fabs(rs1) < *alim) goto L120;
+    aphi = azabs_(&phidr, &phidi);
+    rs1 = rs1 + log(aphi);
+    if (fabs(rs1) < *elim) goto L120;
+L105:;
+    if (rs1 > 0.0e0) goto L320;
+    if (*zr < 0.0e0) goto L320;
+    *nz = *n;
+    i = 1;
+    for (i2_ = *n - i + 1; i2_ > 0; --i2_, i += 1) {
+        yr[(i - 1)] = zeror;
+        yi[(i - 1)] = zeroi;
+    }
+    return;
+L120:;
+    s1r = cyr[(1 - 1)];
+    s1i = cyi[(1 - 1)];
+    s2r = cyr[(2 - 1)];
+    s2i = cyi[(2 - 1)];
+    c1r = csrr[(kflag - 1)];
+    ascle = bry[(kflag - 1)];
+    i = ib;
+    for (i3_ = *n - i + 1; i3_ > 0; --i3_, i += 1) {
+        c2r = s2r;
+        c2i = s2i;
+        s2r = ckr * c2r - cki * c2i + s1r;
+        s2i = ckr * c2i + cki * c2r + s1i;
+        s1r = c2r;
+        s1i = c2i;
+        ckr = ckr + rzr;
+        cki = cki + rzi;
+        c2r = s2r * c1r;
+        c2i = s2i * c1r;
+        yr[(i - 1)] = c2r;
+        yi[(i - 1)] = c2i;
+        if (kflag >= 3) goto L130;
+        str = fabs(c2r);
+        sti = fabs(c2i);
+        c2m = dmax(str, sti);
+        if (c2m <= ascle) goto L130;
+        kflag = kflag + 1;
+        ascle = bry[(kflag - 1)];
+        s1r = s1r * c1r;
+        s1i = s1i * c1r;
+        s2r = c2r;
+        s2i = c2i;
+        s1r = s1r * cssr[(kflag - 1)];
+        s1i = s1i * cssr[(kflag - 1)];
+        s2r = s2r * cssr[(kflag - 1)];
+        s2i = s2i * cssr[(kflag - 1)];
+        c1r = csrr[(kflag - 1)];
+    L130:;
+    }
+L180:;
+    if (*mr == 0) return;
+    *nz = 0;
+    fmr = (double)((float)(*mr));
+    sgn = -d_sign(pi, fmr);
+    csgni = sgn;
+    if (yy <= 0.0e0) csgni = -csgni;
+    ifn = inu + *n - 1;
+    ang = fnf * sgn;
+    cspnr = cos(ang);
+    cspni = sin(ang);
+    if ((ifn) % (2) == 0) goto L190;
+    cspnr = -cspnr;
+    cspni = -cspni;
+L190:;
+    csr = sar * csgni;
+    csi = car * csgni;
+    in = (ifn) % (4) + 1;
+    c2r = cipr[(in - 1)];
+    c2i = cipi[(in - 1)];
+    str = csr * c2r + csi * c2i;
+    csi = -csr * c2i + csi * c2r;
+    csr = str;
+    asc = bry[(1 - 1)];
+    iuf = 0;
+    kk = *n;
+    kdflg = 1;
+    ib = ib - 1;
+    ic = ib - 1;
+    k = 1;
+    for (i4_ = *n - k + 1; i4_ > 0; --i4_, k += 1) {
+        fn = *fnu + (double)((float)(kk - 1));
+        if (*n > 2) goto L175;
+    L172:;
+        phidr = phir[(j - 1)];
+        phidi = phii[(j - 1)];
+        argdr = argr[(j - 1)];
+        argdi = argi[(j - 1)];
+        zet1dr = zeta1r[(j - 1)];
+        zet1di = zeta1i[(j - 1)];
+        zet2dr = zeta2r[(j - 1)];
+        zet2di = zeta2i[(j - 1)];
+        asumdr = asumr[(j - 1)];
+        asumdi = asumi[(j - 1)];
+        bsumdr = bsumr[(j - 1)];
+        bsumdi = bsumi[(j - 1)];
+        j = 3 - j;
+        goto L210;
+    L175:;
+        if ((kk == *n) && (ib < *n)) goto L210;
+        if ((kk == ib) || (kk == ic)) goto L172;
+        zunhj_(&znr, &zni, &fn, &(int){0}, tol, &phidr, &phidi, &argdr, &argdi, &zet1dr, &zet1di, &zet2dr, &zet2di, &asumdr, &asumdi, &bsumdr, &bsumdi);
+    L210:;
+        if (*kode == 1) goto L220;
+        str = zbr + zet2dr;
+        sti = zbi + zet2di;
+        rast = fn / azabs_(&str, &sti);
+        str = str * rast * rast;
+        sti = -sti * rast * rast;
+        s1r = -zet1dr + str;
+        s1i = -zet1di + sti;
+        goto L230;
+    L220:;
+        s1r = -zet1dr + zet2dr;
+        s1i = -zet1di + zet2di;
+    L230:;
+        rs1 = s1r;
+        if (fabs(rs1) > *elim) goto L280;
+        if (kdflg == 1) iflag = 2;
+        if (fabs(rs1) < *alim) goto L240;
+        aphi = azabs_(&phidr, &phidi);
+        aarg = azabs_(&argdr, &argdi);
+        rs1 = rs1 + log(aphi) - 0.25e0 * log(aarg) - aic;
+        if (fabs(rs1) > *elim) goto L280;
+        if (kdflg == 1) iflag = 1;
+        if (rs1 < 0.0e0) goto L240;
+        if (kdflg == 1) iflag = 3;
+    L240:;
+        zairy_(&argdr, &argdi, &(int){0}, &(int){2}, &air, &aii, &nai, &idum);
+        zairy_(&argdr, &argdi, &(int){1}, &(int){2}, &dair, &daii, &ndai, &idum);
+        str = dair * bsumdr - daii * bsumdi;
+        sti = dair * bsumdi + daii * bsumdr;
+        str = str + (air * asumdr - aii * asumdi);
+        sti = sti + (air * asumdi + aii * asumdr);
+        ptr = str * phidr - sti * phidi;
+        pti = str * phidi + sti * phidr;
+        s2r = ptr * csr - pti * csi;
+        s2i = ptr * csi + pti * csr;
+        str = exp(s1r) * cssr[(iflag - 1)];
+        s1r = str * cos(s1i);
+        s1i = str * sin(s1i);
+        str = s2r * s1r - s2i * s1i;
+        s2i = s2r * s1i + s2i * s1r;
+        s2r = str;
+        if (iflag != 1) goto L250;
+        zuchk_(&s2r, &s2i, &nw, &bry[(1 - 1)], tol);
+        if (nw == 0) goto L250;
+        s2r = zeror;
+        s2i = zeroi;
+    L250:;
+        if (yy <= 0.0e0) s2i = -s2i;
+        cyr[(kdflg - 1)] = s2r;
+        cyi[(kdflg - 1)] = s2i;
+        c2r = s2r;
+        c2i = s2i;
+        s2r = s2r * csrr[(iflag - 1)];
+        s2i = s2i * csrr[(iflag - 1)];
+        s1r = yr[(kk - 1)];
+        s1i = yi[(kk - 1)];
+        if (*kode == 1) goto L270;
+        zs1s2_(&zrr, &zri, &s1r, &s1i, &s2r, &s2i, &nw, &asc, alim, &iuf);
+        *nz = *nz + nw;
+    L270:;
+        yr[(kk - 1)] = s1r * cspnr - s1i * cspni + s2r;
+        yi[(kk - 1)] = s1r * cspni + s1i * cspnr + s2i;
+        kk = kk - 1;
+        cspnr = -cspnr;
+        cspni = -cspni;
+        str = csi;
+        csi = -csr;
+        csr = str;
+        if (c2r != 0.0e0 || c2i != 0.0e0) goto L255;
+        kdflg = 1;
+        goto L290;
+    L255:;
+        if (kdflg == 2) goto L295;
+        kdflg = 2;
+        goto L290;
+    L280:;
+        if (rs1 > 0.0e0) goto L320;
+        s2r = zeror;
+        s2i = zeroi;
+        goto L250;
+    L290:;
+    }
+    k = *n;
+L295:;
+    il = *n - k;
+    if (il == 0) return;
+    s1r = cyr[(1 - 1)];
+    s1i = cyi[(1 - 1)];
+    s2r = cyr[(2 - 1)];
+    s2i = cyi[(2 - 1)];
+    csr = csrr[(iflag - 1)];
+    ascle = bry[(iflag - 1)];
+    fn = (double)((float)(inu + il));
+    i = 1;
+    for (i5_ = il - i + 1; i5_ > 0; --i5_, i += 1) {
+        c2r = s2r;
+        c2i = s2i;
+        s2r = s1r + (fn + fnf) * (rzr * c2r - rzi * c2i);
+        s2i = s1i + (fn + fnf) * (rzr * c2i + rzi * c2r);
+        s1r = c2r;
+        s1i = c2i;
+        fn = fn - 1.0e0;
+        c2r = s2r * csr;
+        c2i = s2i * csr;
+        ckr = c2r;
+        cki = c2i;
+        c1r = yr[(kk - 1)];
+        c1i = yi[(kk - 1)];
+        if (*kode == 1) goto L300;
+        zs1s2_(&zrr, &zri, &c1r, &c1i, &c2r, &c2i, &nw, &asc, alim, &iuf);
+        *nz = *nz + nw;
+    L300:;
+        yr[(kk - 1)] = c1r * cspnr - c1i * cspni + c2r;
+        yi[(kk - 1)] = c1r * cspni + c1i * cspnr + c2i;
+        kk = kk - 1;
+        cspnr = -cspnr;
+        cspni = -cspni;
+        if (iflag >= 3) goto L310;
+        c2r = fabs(ckr);
+        c2i = fabs(cki);
+        c2m = dmax(c2r, c2i);
+        if (c2m <= ascle) goto L310;
+        iflag = iflag + 1;
+        ascle = bry[(iflag - 1)];
+        s1r = s1r * csr;
+        s1i = s1i * csr;
+        s2r = ckr;
+        s2i = cki;
+        s1r = s1r * cssr[(iflag - 1)];
+        s1i = s1i * cssr[(iflag - 1)];
+        s2r = s2r * cssr[(iflag - 1)];
+        s2i = s2i * cssr[(iflag - 1)];
+        csr = csrr[(iflag - 1)];
+    L310:;
+    }
+    return;
+L320:;
+    *nz = -1;
+    return;
+}
@@ -0,0 +1,191 @@
+/* Generated by scripts/f77-to-c.py from amos/zuoik.f, do not edit. */
+
+#include "amos.h"
+
+void zuoik_(double *zr, double *zi, double *fnu, int *kode, int *ikflg, int *n, double *yr, double *yi, int *nuf, double *tol, double *elim, double *alim)
+{
+    double aarg = 0;
+    static double aic = 1.265512123484645396e+00;
+    double aphi = 0;
+    double argi = 0;
+    double argr = 0;
+    double ascle = 0;
+    double asumi = 0;
+    double asumr = 0;
+    double ax = 0;
+    double ay = 0;
+    double bsumi = 0;
+    double bsumr = 0;
+    double cwrki[16];
+    double cwrkr[16];
+    double czi = 0;
+    double czr = 0;
+    double fnn = 0;
+    double gnn = 0;
+    double gnu = 0;
+    int i = 0;
+    int idum = 0;
+    int iform = 0;
+    int init = 0;
+    int nn = 0;
+    int nw = 0;
+    double phii = 0;
+    double phir = 0;
+    double rcz = 0;
+    double sti = 0;
+    double str = 0;
+    double sumi = 0;
+    double sumr = 0;
+    double zbi = 0;
+    double zbr = 0;
+    static double zeroi = 0.0e0;
+    static double zeror = 0.0e0;
+    double zeta1i = 0;
+    double zeta1r = 0;
+    double zeta2i = 0;
+    double zeta2r = 0;
+    double zni = 0;
+    double znr = 0;
+    double zri = 0;
+    double zrr = 0;
+    int i1_;
+
+    *nuf = 0;
+    nn = *n;
+    zrr = *zr;
+    zri = *zi;
+    if (*zr >= 0.0e0) goto L10;
+    zrr = -*zr;
+    zri = -*zi;
+L10:;
+    zbr = zrr;
+    zbi = zri;
+    ax = fabs(*zr) * 1.7321e0;
+    ay = fabs(*zi);
+    iform = 1;
+    if (ay > ax) iform = 2;
+    gnu = dmax(*fnu, 1.0e0);
+    if (*ikflg == 1) goto L20;
+    fnn = (double)((float)(nn));
+    gnn = *fnu + fnn - 1.0e0;
+    gnu = dmax(gnn, fnn);
+L20:;
+    if (iform == 2) goto L30;
+    init = 0;
+    zunik_(&zrr, &zri, &gnu, ikflg, &(int){1}, tol, &init, &phir, &phii, &zeta1r, &zeta1i, &zeta2r, &zeta2i, &sumr, &sumi, cwrkr, cwrki);
+    czr = -zeta1r + zeta2r;
+    czi = -zeta1i + zeta2i;
+    goto L50;
+L30:;
+    znr = zri;
+    zni = -zrr;
+    if (*zi > 0.0e0) goto L40;
+    znr = -znr;
+L40:;
+    zunhj_(&znr, &zni, &gnu, &(int){1}, tol, &phir, &phii, &argr, &argi, &zeta1r, &zeta1i, &zeta2r, &zeta2i, &asumr, &asumi, &bsumr, &bsumi);
+    czr = -zeta1r + zeta2r;
+    czi = -zeta1i + zeta2i;
+    aarg = azabs_(&argr, &argi);
+L50:;
+    if (*kode == 1) goto L60;
+    czr = czr - zbr;
+    czi = czi - zbi;
+L60:;
+    if (*ikflg == 1) goto L70;
+    czr = -czr;
+    czi = -czi;
+L70:;
+    aphi = azabs_(&phir, &phii);
+    rcz = czr;
+    if (rcz > *elim) goto L210;
+    if (rcz < *alim) goto L80;
+    rcz = rcz + log(aphi);
+    if (iform == 2) rcz = rcz - 0.25e0 * log(aarg) - aic;
+    if (rcz > *elim) goto L210;
+    goto L130;
+L80:;
+    if (rcz < (-*elim)) goto L90;
+    if (rcz > (-*alim)) goto L130;
+    rcz = rcz + log(aphi);
+    if (iform == 2) rcz = rcz - 0.25e0 * log(aarg) - aic;
+    if (rcz > (-*elim)) goto L110;
+L90:;
+    i = 1;
+    for (i1_ = nn - i + 1; i1_ > 0; --i1_, i += 1) {
+        yr[(i - 1)] = zeror;
+        yi[(i - 1)] = zeroi;
+    }
+    *nuf = nn;
+    return;
+L110:;
+    ascle = 1.0e+3 * d1mach_(&(int){1}) / *tol;
+    azlog_(&phir, &phii, &str, &sti, &idum);
+    czr = czr + str;
+    czi = czi + sti;
+    if (iform == 1) goto L120;
+    azlog_(&argr, &argi, &str, &sti, &idum);
+    czr = czr - 0.25e0 * str - aic;
+    czi = czi - 0.25e0 * sti;
+L120:;
+    ax = exp(rcz) / *tol;
+    ay = czi;
+    czr = ax * cos(ay);
+    czi = ax * sin(ay);
+    zuchk_(&czr, &czi, &nw, &ascle, tol);
+    if (nw != 0) goto L90;
+L130:;
+    if (*ikflg == 2) return;
+    if (*n == 1) return;
+L140:;
+    gnu = *fnu + (double)((float)(nn - 1));
+    if (iform == 2) goto L150;
+    init = 0;
+    zunik_(&zrr, &zri, &gnu, ikflg, &(int){1}, tol, &init, &phir, &phii, &zeta1r, &zeta1i, &zeta2r, &zeta2i, &sumr, &sumi, cwrkr, cwrki);
+    czr = -zeta1r + zeta2r;
+    czi = -zeta1i + zeta2i;
+    goto L160;
+L150:;
+    zunhj_(&znr, &zni, &gnu, &(int){1}, tol, &phir, &phii, &argr, &argi, &zeta1r, &zeta1i, &zeta2r, &zeta2i, &asumr, &asumi, &bsumr, &bsumi);
+    czr = -zeta1r + zeta2r;
+    czi = -zeta1i + zeta2i;
+    aarg = azabs_(&argr, &argi);
+L160:;
+    if (*kode == 1) goto L170;
+    czr = czr - zbr;
+    czi = czi - zbi;
+L170:;
+    aphi = azabs_(&phir, &phii);
+    rcz = czr;
+    if (rcz < (-*elim)) goto L180;
+    if (rcz > (-*alim)) return;
+    rcz = rcz + log(aphi);
+    if (iform == 2) rcz = rcz - 0.25e0 * log(aarg) - aic;
+    if (rcz > (-*elim)) goto L190;
+L180:;
+    yr[(nn - 1)] = zeror;
+    yi[(nn - 1)] = zeroi;
+    nn = nn - 1;
+    *nuf = *nuf + 1;
+    if (nn == 0) return;
+    goto L140;
+L190:;
+    ascle = 1.0e+3 * d1mach_(&(int){1}) / *tol;
+    azlog_(&phir, &phii, &str, &sti, &idum);
+    czr = czr + str;
+    czi = czi + sti;
+    if (iform == 1) goto L200;
+    azlog_(&argr, &argi, &str, &sti, &idum);
+    czr = czr - 0.25e0 * str - aic;
+    czi = czi - 0.25e0 * sti;
+L200:;
+    ax = exp(rcz) / *tol;
+    ay = czi;
+    czr = ax * cos(ay);
+    czi = ax * sin(ay);
+    zuchk_(&czr, &czi, &nw, &ascle, tol);
+    if (nw != 0) goto L180;
+    return;
+L210:;
+    *nuf = -1;
+    return;
+}
@@ -0,0 +1,87 @@
+/* Generated by scripts/f77-to-c.py from amos/zwrsk.f, do not edit. */
+
+#include "amos.h"
+
+void zwrsk_(double *zrr, double *zri, double *fnu, int *kode, int *n, double *yr, double *yi, int *nz, double *cwr, double *cwi, double *tol, double *elim, double *alim)
+{
+    double act = 0;
+    double acw = 0;
+    double ascle = 0;
+    double c1i = 0;
+    double c1r = 0;
+    double c2i = 0;
+    double c2r = 0;
+    double cinui = 0;
+    double cinur = 0;
+    double csclr = 0;
+    double cti = 0;
+    double ctr = 0;
+    int i = 0;
+    int nw = 0;
+    double pti = 0;
+    double ptr = 0;
+    double ract = 0;
+    double sti = 0;
+    double str = 0;
+    int i1_;
+
+    *nz = 0;
+    zbknu_(zrr, zri, fnu, kode, &(int){2}, cwr, cwi, &nw, tol, elim, alim);
+    if (nw != 0) goto L50;
+    zrati_(zrr, zri, fnu, n, yr, yi, tol);
+    cinur = 1.0e0;
+    cinui = 0.0e0;
+    if (*kode == 1) goto L10;
+    cinur = cos(*zri);
+    cinui = sin(*zri);
+L10:;
+    acw = azabs_(&cwr[(2 - 1)], &cwi[(2 - 1)]);
+    ascle = 1.0e+3 * d1mach_(&(int){1}) / *tol;
+    csclr = 1.0e0;
+    if (acw > ascle) goto L20;
+    csclr = 1.0e0 / *tol;
+    goto L30;
+L20:;
+    ascle = 1.0e0 / ascle;
+    if (acw < ascle) goto L30;
+    csclr = *tol;
+L30:;
+    c1r = cwr[(1 - 1)] * csclr;
+    c1i = cwi[(1 - 1)] * csclr;
+    c2r = cwr[(2 - 1)] * csclr;
+    c2i = cwi[(2 - 1)] * csclr;
+    str = yr[(1 - 1)];
+    sti = yi[(1 - 1)];
+    ptr = str * c1r - sti * c1i;
+    pti = str * c1i + sti * c1r;
+    ptr = ptr + c2r;
+    pti = pti + c2i;
+    ctr = *zrr * ptr - *zri * pti;
+    cti = *zrr * pti + *zri * ptr;
+    act = azabs_(&ctr, &cti);
+    ract = 1.0e0 / act;
+    ctr = ctr * ract;
+    cti = -cti * ract;
+    ptr = cinur * ract;
+    pti = cinui * ract;
+    cinur = ptr * ctr - pti * cti;
+    cinui = ptr * cti + pti * ctr;
+    yr[(1 - 1)] = cinur * csclr;
+    yi[(1 - 1)] = cinui * csclr;
+    if (*n == 1) return;
+    i = 2;
+    for (i1_ = *n - i + 1; i1_ > 0; --i1_, i += 1) {
+        ptr = str * cinur - sti * cinui;
+        cinui = str * cinui + sti * cinur;
+        cinur = ptr;
+        str = yr[(i - 1)];
+        sti = yi[(i - 1)];
+        yr[(i - 1)] = cinur * csclr;
+        yi[(i - 1)] = cinui * csclr;
+    }
+    return;
+L50:;
+    *nz = -1;
+    if (nw == (-2)) *nz = -2;
+    return;
+}
@@ -0,0 +1,328 @@
+// Benchmarks every public function with criterion, for small and large |z|, and for positive and
+// negative orders, where the reflection formulas of the wrappers take an extra AMOS call, e.g., to
+// zbesk_ for iv, see reflect in src/zbesi.rs. The sequence functions are timed for n = 1 and for
+// n = SEQUENCE, to compare with as many scalar calls. Run with
+//
+//     cargo bench --bench functions -- --save-baseline main
+//
+// on the reference revision, and then compare against the stored baseline with
+//
+//     cargo bench --bench functions -- --baseline main
+//
+// which reports the regressions beyond the noise threshold, see scripts/compare-benchmarks.sh.
+// A filter selects benchmarks by name, e.g., `cargo bench --bench functions -- zbesi/iv_vec`.
+use amos_rs::{bessel_ratio, cephes, log_bessel, zairy, zbesh, zbesi, zbesj, zbesk, zbesy};
+use criterion::measurement::WallTime;
+use criterion::{criterion_group, criterion_main, BenchmarkGroup, BenchmarkId, Criterion};
+use num::complex::Complex;
+use std::hint::black_box;
+use std::time::Duration;
+
+const SEQUENCE: i32 = 16;
+
+// The orders and arguments, labelled by the size of |z| and the sign of the order
+struct Case {
+    size: &'static str,
+    sign: &'static str,
+    nu: f64,
+    z: Complex<f64>,
+}
+
+impl Case {
+    fn id(&self, name: &str) -> BenchmarkId {
+        BenchmarkId::new(name, format!("{}/{}", self.size, self.sign))
+    }
+}
+
+const CASES: [Case; 4] = [
+    Case {
+        size: "small_z",
+        sign: "positive_nu",
+        nu: 1.3,
+        z: Complex::new(0.5, 0.7),
+    },
+    Case {
+        size: "small_z",
+        sign: "negative_nu",
+        nu: -1.3,
+        z: Complex::new(0.5, 0.7),
+    },
+    Case {
+        size: "large_z",
+        sign: "positive_nu",
+        nu: 1.3,
+        z: Complex::new(40.0, 30.0),
+    },
+    Case {
+        size: "large_z",
+        sign: "negative_nu",
+        nu: -1.3,
+        z: Complex::new(40.0, 30.0),
+    },
+];
+
+type General = fn(f64, Complex<f64>, i32) -> Complex<f64>;
+type GeneralVec = fn(f64, Complex<f64>, i32, i32) -> Vec<Complex<f64>>;
+type Named = fn(f64, Complex<f64>) -> Complex<f64>;
+type NamedVec = fn(f64, Complex<f64>, i32) -> Vec<Complex<f64>>;
+type Real = fn(f64, f64) -> f64;
+type RealVec = fn(f64, f64, i32) -> Vec<f64>;
+type Airy = fn(Complex<f64>) -> Complex<f64>;
+type Cephes = fn(f64) -> f64;
+
+// The functions of a family, where the pairs are the unscaled and scaled functions
+struct Family {
+    name: &'static str,
+    names: [&'static str; 2],
+    general: General,
+    general_vec: GeneralVec,
+    scalar: [Named; 2],
+    vec: [NamedVec; 2],
+    real: [Real; 2],
+    real_vec: [RealVec; 2],
+}
+
+const FAMILIES: [Family; 4] = [
+    Family {
+        name: "zbesi",
+        names: ["iv", "ive"],
+        general: zbesi::zbesi,
+        general_vec: zbesi::zbesi_vec,
+        scalar: [zbesi::iv, zbesi::ive],
+        vec: [zbesi::iv_vec, zbesi::ive_vec],
+        real: [zbesi::iv_real, zbesi::ive_real],
+        real_vec: [zbesi::iv_real_vec, zbesi::ive_real_vec],
+    },
+    Family {
+        name: "zbesj",
+        names: ["jv", "jve"],
+        general: zbesj::zbesj,
+        general_vec: zbesj::zbesj_vec,
+        scalar: [zbesj::jv, zbesj::jve],
+        vec: [zbesj::jv_vec, zbesj::jve_vec],
+        real: [zbesj::jv_real, zbesj::jve_real],
+        real_vec: [zbesj::jv_real_vec, zbesj::jve_real_vec],
+    },
+    Family {
+        name: "zbesk",
+        names: ["kv", "kve"],
+        general: zbesk::zbesk,
+        general_vec: zbesk::zbesk_vec,
+        scalar: [zbesk::kv, zbesk::kve],
+        vec: [zbesk::kv_vec, zbesk::kve_vec],
+        real: [zbesk::kv_real, zbesk::kve_real],
+        real_vec: [zbesk::kv_real_vec, zbesk::kve_real_vec],
+    },
+    Family {
+        name: "zbesy",
+        names: ["yv", "yve"],
+        general: zbesy::zbesy,
+        general_vec: zbesy::zbesy_vec,
+        scalar: [zbesy::yv, zbesy::yve],
+        vec: [zbesy::yv_vec, zbesy::yve_vec],
+        real: [zbesy::yv_real, zbesy::yve_real],
+        real_vec: [zbesy::yv_real_vec, zbesy::yve_real_vec],
+    },
+];
+
+// Each function is timed for about a second, which is enough for calls of at most microseconds
+fn group<'a>(c: &'a mut Criterion, name: &str) -> BenchmarkGroup<'a, WallTime> {
+    let mut group = c.benchmark_group(name);
+
+    group.warm_up_time(Duration::from_millis(500));
+    group.measurement_time(Duration::from_secs(1));
+
+    group
+}
+
+fn bench_bessel(c: &mut Criterion) {
+    for family in &FAMILIES {
+        let mut group = group(c, family.name);
+
+        for case in &CASES {
+            let (nu, z, x) = (case.nu, case.z, case.z.norm());
+
+            for k in 0..2 {
+                let name = family.names[k];
+                let kode = k as i32 + 1;
+
+                group.bench_function(case.id(&format!("{}_kode{kode}", family.name)), |b| {
+                    b.iter(|| (family.general)(black_box(nu), black_box(z), kode))
+                });
+                group.bench_function(case.id(name), |b| {
+                    b.iter(|| (family.scalar[k])(black_box(nu), black_box(z)))
+                });
+                group.bench_function(case.id(&format!("{name}_real")), |b| {
+                    b.iter(|| (family.real[k])(black_box(nu), black_box(x)))
+                });
+
+                for n in [1, SEQUENCE] {
+                    let general_vec = format!("{}_vec_kode{kode}_n{n}", family.name);
+
+                    group.bench_function(case.id(&general_vec), |b| {
+                        b.iter(|| (family.general_vec)(black_box(nu), black_box(z), kode, n))
+                    });
+                    group.bench_function(case.id(&format!("{name}_vec_n{n}")), |b| {
+                        b.iter(|| (family.vec[k])(black_box(nu), black_box(z), n))
+                    });
+                    group.bench_function(case.id(&format!("{name}_real_vec_n{n}")), |b| {
+                        b.iter(|| (family.real_vec[k])(black_box(nu), black_box(x), n))
+                    });
+                }
+            }
+        }
+
+        group.finish();
+    }
+}
+
+fn bench_hankel(c: &mut Criterion) {
+    let mut group = group(c, "zbesh");
+
+    let functions: [(&str, NamedVec); 4] = [
+        ("hankel1", zbesh::hankel1),
+        ("hankel1e", zbesh::hankel1e),
+        ("hankel2", zbesh::hankel2),
+        ("hankel2e", zbesh::hankel2e),
+    ];
+
+    for case in &CASES {
+        let (nu, z) = (case.nu, case.z);
+
+        for n in [1, SEQUENCE] {
+            group.bench_function(case.id(&format!("zbesh_n{n}")), |b| {
+                b.iter(|| zbesh::zbesh(black_box(nu), black_box(z), 1, 1, n))
+            });
+
+            for (name, f) in functions {
+                group.bench_function(case.id(&format!("{name}_n{n}")), |b| {
+                    b.iter(|| f(black_box(nu), black_box(z), n))
+                });
+            }
+        }
+    }
+
+    group.finish();
+}
+
+fn bench_airy(c: &mut Criterion) {
+    let mut group = group(c, "zairy");
+
+    let functions: [(&str, Airy); 8] = [
+        ("ai", zairy::ai),
+        ("aie", zairy::aie),
+        ("aip", zairy::aip),
+        ("aipe", zairy::aipe),
+        ("bi", zairy::bi),
+        ("bie", zairy::bie),
+        ("bip", zairy::bip),
+        ("bipe", zairy::bipe),
+    ];
+
+    // There is no order, so only the arguments of the cases are used
+    for case in CASES.iter().filter(|case| case.nu > 0.0) {
+        let z = case.z;
+        let id = |name: &str| BenchmarkId::new(name, case.size);
+
+        group.bench_function(id("zairy"), |b| b.iter(|| zairy::zairy(black_box(z), 0, 1)));
+        group.bench_function(id("zbiry"), |b| b.iter(|| zairy::zbiry(black_box(z), 0, 1)));
+
+        for (name, f) in functions {
+            group.bench_function(id(name), |b| b.iter(|| f(black_box(z))));
+        }
+    }
+
+    group.finish();
+}
+
+fn bench_log_bessel(c: &mut Criterion) {
+    let mut group = group(c, "log_bessel");
+
+    let real: [(&str, Real); 6] = [
+        ("log_iv", log_bessel::log_iv),
+        ("log_kv", log_bessel::log_kv),
+        ("iv_ratio", bessel_ratio::iv_ratio),
+        ("kv_ratio", bessel_ratio::kv_ratio),
+        ("iv_ratio_inv", bessel_ratio::iv_ratio_inv),
+        ("kv_ratio_inv", bessel_ratio::kv_ratio_inv),
+    ];
+
+    // The ratios are inverted for r = 0.5 as r is in [0, 1] for iv_ratio, and larger than 1 for
+    // kv_ratio, where the inverses are only defined for nu > -1/2
+    for case in &CASES {
+        let (nu, z) = (case.nu, case.z);
+        let x = z.norm();
+
+        group.bench_function(case.id("log_hankel"), |b| {
+            b.iter(|| log_bessel::log_hankel(black_box(nu), black_box(z), 1))
+        });
+        group.bench_function(case.id("log_hankel1"), |b| {
+            b.iter(|| log_bessel::log_hankel1(black_box(nu), black_box(z)))
+        });
+        group.bench_function(case.id("log_hankel2"), |b| {
+            b.iter(|| log_bessel::log_hankel2(black_box(nu), black_box(z)))
+        });
+
+        for (name, f) in real {
+            let (nu, x) = match name {
+                "iv_ratio_inv" => (nu.abs(), 0.5),
+                "kv_ratio_inv" => (nu.abs(), 1.0 + 1.0 / x),
+                _ => (nu, x),
+            };
+
+            group.bench_function(case.id(name), |b| b.iter(|| f(black_box(nu), black_box(x))));
+        }
+    }
+
+    group.finish();
+}
+
+fn bench_cephes(c: &mut Criterion) {
+    let mut group = group(c, "cephes");
+
+    let functions: [(&str, Cephes); 12] = [
+        ("i0", cephes::i0),
+        ("i0e", cephes::i0e),
+        ("i1", cephes::i1),
+        ("i1e", cephes::i1e),
+        ("k0", cephes::k0),
+        ("k0e", cephes::k0e),
+        ("k1", cephes::k1),
+        ("k1e", cephes::k1e),
+        ("j0", cephes::j0),
+        ("j1", cephes::j1),
+        ("y0", cephes::y0),
+        ("y1", cephes::y1),
+    ];
+
+    // The series and the asymptotic expansions of Cephes, with integer orders for jn and yn, where
+    // the negative orders are reflected
+    for (label, x) in [("small_x", 0.5), ("large_x", 50.0)] {
+        let id = |name: &str| BenchmarkId::new(name, label);
+
+        for (name, f) in functions {
+            group.bench_function(id(name), |b| b.iter(|| f(black_box(x))));
+        }
+
+        for n in [5, -5] {
+            group.bench_function(id(&format!("jn_n{n}")), |b| {
+                b.iter(|| cephes::jn(n, black_box(x)))
+            });
+            group.bench_function(id(&format!("yn_n{n}")), |b| {
+                b.iter(|| cephes::yn(n, black_box(x)))
+            });
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_bessel,
+    bench_hankel,
+    bench_airy,
+    bench_log_bessel,
+    bench_cephes
+);
+criterion_main!(benches);
@@ -1,20 +1,478 @@
-use cmake;
 use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+// Symbols declared in src/bindings.rs, which must be present in a system-provided AMOS library
+const AMOS_SYMBOLS: [&str; 8] = [
+    "zbesh_", "zbesi_", "zbesj_", "zbesk_", "zbesy_", "zairy_", "zbiry_", "dgamln_",
+];
+
+// AMOS routines called by ZBESI, ZBESJ, ZAIRY and ZBIRY, which are compiled for every family. The
+// sources are amos/<routine>.f and amos-c/<routine>.c, except for the machine constants, see
+// fortran_source and c_source.
+const COMMON_ROUTINES: [&str; 28] = [
+    "d1mach", "i1mach", "dgamln", "zabs", "zacai", "zairy", "zasyi", "zbinu", "zbknu", "zbuni",
+    "zdiv", "zexp", "zkscl", "zlog", "zmlri", "zmlt", "zrati", "zs1s2", "zseri", "zshch", "zsqrt",
+    "zuchk", "zunhj", "zuni1", "zuni2", "zunik", "zuoik", "zwrsk",
+];
+
+// Additionally called by ZBESK and ZBESH for the analytic continuation to the left half plane
+const CONTINUATION_ROUTINES: [&str; 4] = ["zacon", "zbunk", "zunk1", "zunk2"];
 
 fn main() {
-    let dst = cmake::build("amos");
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-env-changed=AMOS_LIB_DIR");
+
+    let lib_dir = env::var_os("AMOS_LIB_DIR").map(PathBuf::from);
+
+    let routines = amos_routines();
+
+    // Without any of the family features there is nothing to build or link
+    if routines.is_empty() {
+        return;
+    }
+
+    // There is no Fortran or C toolchain for wasm32 and bare metal targets, where the Rust port in
+    // src/native replaces the library, unless a cross-compiled library is given in AMOS_LIB_DIR
+    let target_arch = env::var("CARGO_CFG_TARGET_ARCH").unwrap_or_default();
+    let target_os = env::var("CARGO_CFG_TARGET_OS").unwrap_or_default();
+
+    if target_arch == "wasm32" || target_os == "none" {
+        // A cross-compiled library, e.g., of the C translation in amos-c/, is linked as is, and
+        // any runtime libraries it needs are left to the application
+        match lib_dir {
+            _ if env::var_os("CARGO_FEATURE_PURE_RUST").is_some() => (),
+            Some(lib_dir) => {
+                println!("cargo:rustc-link-search=native={}", lib_dir.display());
+                println!("cargo:rustc-link-lib=static=amos");
+            }
+            None => panic!(
+                "Building for {} requires the pure-rust feature or AMOS_LIB_DIR",
+                env::var("TARGET").unwrap_or_default()
+            ),
+        }
+
+        return;
+    }
+
+    if env::var_os("CARGO_FEATURE_C_BACKEND").is_some() {
+        build_amos_c(None, &routines);
+    } else if lib_dir.is_some() || env::var_os("CARGO_FEATURE_SYSTEM_AMOS").is_some() {
+        link_system_amos(lib_dir, &routines);
+    } else {
+        build_amos(&routines);
+    }
+
+    // With the backend-comparison feature the C translation is also built with prefixed symbols,
+    // such that tests/test_backends.rs can compare it against the backend linked above
+    if env::var_os("CARGO_FEATURE_BACKEND_COMPARISON").is_some() {
+        build_amos_c(Some("amos_c_"), &routines);
+    }
+}
+
+// The AMOS routines needed by the enabled family features, see Cargo.toml. Negative orders are
+// handled in the Rust wrappers by reflection, such that I also needs K, and J and Y need each
+// other. ZBESY calls ZBESH.
+fn amos_routines() -> Vec<&'static str> {
+    let feature = |name: &str| env::var_os(format!("CARGO_FEATURE_{name}")).is_some();
+
+    let bessel_i = feature("BESSEL_I");
+    let bessel_k = bessel_i || feature("BESSEL_K");
+    let bessel_jy = feature("BESSEL_J") || feature("BESSEL_Y");
+    let hankel = bessel_jy || feature("HANKEL");
+    let airy = feature("AIRY");
+
+    let mut routines = Vec::new();
+
+    for (enabled, routine) in [
+        (bessel_i, "zbesi"),
+        (bessel_k, "zbesk"),
+        (bessel_jy, "zbesj"),
+        (bessel_jy, "zbesy"),
+        (hankel, "zbesh"),
+        (airy, "zbiry"),
+    ] {
+        if enabled {
+            routines.push(routine);
+        }
+    }
+
+    if routines.is_empty() {
+        return routines;
+    }
+
+    routines.extend(COMMON_ROUTINES);
+
+    if bessel_k || hankel {
+        routines.extend(CONTINUATION_ROUTINES);
+    }
+
+    routines.sort();
+    routines
+}
+
+fn fortran_source(routine: &str) -> String {
+    match routine.ends_with("mach") {
+        true => format!("{routine}.f90"),
+        false => format!("{routine}.f"),
+    }
+}
+
+// The machine constants of d1mach.f90 and i1mach.f90 are translated together in machine.c
+fn c_source(routine: &str) -> String {
+    match routine.ends_with("mach") {
+        true => "machine.c".to_string(),
+        false => format!("{routine}.c"),
+    }
+}
+
+// Fortran compiler used to build AMOS, as identified by CMake
+struct FortranCompiler {
+    // CMAKE_Fortran_COMPILER_ID, e.g., GNU, LLVMFlang or IntelLLVM
+    id: String,
+    path: String,
+    // CMAKE_Fortran_IMPLICIT_LINK_LIBRARIES and CMAKE_Fortran_IMPLICIT_LINK_DIRECTORIES, i.e., the
+    // libraries and search paths the compiler adds when linking a Fortran program
+    implicit_libs: Vec<String>,
+    implicit_dirs: Vec<String>,
+}
+
+impl FortranCompiler {
+    // Reads the file written by amos/CMakeLists.txt with one key=value pair per line, where lists
+    // are separated by semicolons
+    fn from_cmake(path: &Path) -> Self {
+        let contents = fs::read_to_string(path)
+            .unwrap_or_else(|err| panic!("Failed to read {}: {err}", path.display()));
+
+        let value = |key: &str| {
+            contents
+                .lines()
+                .find_map(|line| line.strip_prefix(&format!("{key}=")))
+                .unwrap_or("")
+                .to_string()
+        };
+        let list = |key: &str| {
+            value(key)
+                .split(';')
+                .filter(|s| !s.is_empty())
+                .map(String::from)
+                .collect()
+        };
+
+        Self {
+            id: value("id"),
+            path: value("path"),
+            implicit_libs: list("implicit_libs"),
+            implicit_dirs: list("implicit_dirs"),
+        }
+    }
+
+    // Without CMake, e.g., for a static system-provided AMOS library, we guess the compiler ID
+    // from the name of the compiler in AMOS_FORTRAN_COMPILER, defaulting to gfortran.
+    fn from_env() -> Self {
+        let path = env::var("AMOS_FORTRAN_COMPILER").unwrap_or_else(|_| "gfortran".to_string());
+        let name = Path::new(&path)
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or("");
+
+        let id = if name.starts_with("gfortran") {
+            "GNU"
+        } else if name.starts_with("flang") {
+            "LLVMFlang"
+        } else if name.starts_with("ifx") {
+            "IntelLLVM"
+        } else if name.starts_with("ifort") {
+            "Intel"
+        } else {
+            ""
+        };
+
+        Self {
+            id: id.to_string(),
+            path,
+            implicit_libs: Vec::new(),
+            implicit_dirs: Vec::new(),
+        }
+    }
+
+    // Runtime libraries needed to link Fortran code into a Rust binary. For unknown compilers we
+    // fall back to the implicit libraries reported by CMake, excluding those of the C runtime that
+    // the Rust toolchain links anyway.
+    fn runtime_libs(&self) -> Vec<String> {
+        let libs: &[&str] = match self.id.as_str() {
+            "GNU" => &["gfortran"],
+            "LLVMFlang" | "Flang" => &["FortranRuntime", "FortranDecimal"],
+            "IntelLLVM" | "Intel" => &["ifport", "ifcoremt", "imf", "svml", "intlc"],
+            _ => {
+                return self
+                    .implicit_libs
+                    .iter()
+                    .filter(|lib| {
+                        !["c", "m", "dl", "pthread", "rt", "gcc", "gcc_s", "util"]
+                            .contains(&lib.as_str())
+                    })
+                    .cloned()
+                    .collect();
+            }
+        };
+
+        libs.iter().map(|lib| lib.to_string()).collect()
+    }
+}
+
+fn build_amos(routines: &[&str]) {
+    println!("cargo:rerun-if-changed=amos");
+    println!("cargo:rerun-if-env-changed=AMOS_FORTRAN_COMPILER");
+
+    let mut config = cmake::Config::new("amos");
+
+    let sources: Vec<String> = routines
+        .iter()
+        .map(|routine| fortran_source(routine))
+        .collect();
+    config.define("AMOS_SOURCES", sources.join(";"));
+
+    // CMake picks the Fortran compiler from FC or by searching the path, unless it is given
+    // explicitly
+    if let Some(compiler) = env::var_os("AMOS_FORTRAN_COMPILER") {
+        config.define("CMAKE_Fortran_COMPILER", compiler);
+    }
+
+    let dst = config.build();
 
     println!(
         "cargo:rustc-link-search=native={}",
         dst.join("amos").display()
     );
 
-    if env::consts::OS.eq("macos") {
-        // Hack to locate gfortran on Mac with gcc installed via Homebrew
-        println!("cargo:rustc-link-search=/usr/local/lib/gcc/current/");
-    }
+    let compiler = FortranCompiler::from_cmake(&dst.join("amos").join("fortran-compiler.txt"));
 
     println!("cargo:rustc-link-lib=static=amos");
-    // Link using gfortran instead of gcc, note this must come after the above command
-    println!("cargo:rustc-link-lib=gfortran");
+    // Link the Fortran runtime, note this must come after the above command
+    link_fortran_runtime(&compiler);
+}
+
+fn link_fortran_runtime(compiler: &FortranCompiler) {
+    // Exposed to the crate, see tests/test_fortran_compiler.rs
+    println!("cargo:rustc-env=AMOS_FORTRAN_COMPILER_ID={}", compiler.id);
+
+    if compiler.id == "GNU" {
+        if env::consts::OS.eq("macos") {
+            // Hack to locate gfortran on Mac with gcc installed via Homebrew
+            println!("cargo:rustc-link-search=/usr/local/lib/gcc/current/");
+        }
+    } else {
+        // The C compiler used as linker by rustc does not know where the runtime libraries of
+        // other Fortran compilers are installed
+        for dir in &compiler.implicit_dirs {
+            println!("cargo:rustc-link-search=native={dir}");
+        }
+    }
+
+    if env::var_os("CARGO_FEATURE_STATIC_FORTRAN_RUNTIME").is_some() {
+        if compiler.id != "GNU" {
+            panic!(
+                "The static-fortran-runtime feature requires gfortran, but AMOS is built with \
+                 {} ({})",
+                compiler.path, compiler.id
+            );
+        }
+
+        link_static_fortran_runtime(&compiler.path);
+        return;
+    }
+
+    let libs = compiler.runtime_libs();
+
+    if libs.is_empty() {
+        println!(
+            "cargo:warning=Unknown Fortran compiler {} ({}), no runtime libraries are linked",
+            compiler.path, compiler.id
+        );
+    }
+
+    for lib in libs {
+        println!("cargo:rustc-link-lib={lib}");
+    }
+}
+
+// Links libgfortran and libquadmath statically, so that binaries do not depend on the Fortran
+// runtime being installed. The archives are located by asking the compiler used to build AMOS. Not
+// all platforms provide libquadmath (e.g., aarch64), and it is then skipped.
+fn link_static_fortran_runtime(compiler: &str) {
+    let libgfortran = print_file_name(compiler, "libgfortran.a").unwrap_or_else(|| {
+        panic!(
+            "The static-fortran-runtime feature requires libgfortran.a, which {} did not find",
+            compiler
+        )
+    });
+
+    println!(
+        "cargo:rustc-link-search=native={}",
+        libgfortran.parent().unwrap().display()
+    );
+    println!("cargo:rustc-link-lib=static=gfortran");
+
+    if let Some(libquadmath) = print_file_name(compiler, "libquadmath.a") {
+        println!(
+            "cargo:rustc-link-search=native={}",
+            libquadmath.parent().unwrap().display()
+        );
+        println!("cargo:rustc-link-lib=static=quadmath");
+    }
+}
+
+// Runs `compiler -print-file-name=name`, which prints the full path of the library if found, and
+// only the name otherwise
+fn print_file_name(compiler: &str, name: &str) -> Option<PathBuf> {
+    let output = Command::new(compiler)
+        .arg(format!("-print-file-name={name}"))
+        .output()
+        .unwrap_or_else(|err| panic!("Failed to run {compiler}: {err}"));
+
+    let path = PathBuf::from(String::from_utf8_lossy(&output.stdout).trim());
+
+    match path.is_absolute() && path.is_file() {
+        true => Some(path),
+        false => None,
+    }
+}
+
+// Compiles the f2c-style C translation of AMOS in amos-c/, generated by scripts/f77-to-c.py from
+// the sources in amos/. Without a prefix this provides the symbols in src/bindings.rs in place of
+// the Fortran library, and requires neither CMake nor a Fortran compiler.
+fn build_amos_c(prefix: Option<&str>, routines: &[&str]) {
+    println!("cargo:rerun-if-changed=amos-c");
+
+    let mut sources: Vec<PathBuf> = routines
+        .iter()
+        .map(|routine| Path::new("amos-c").join(c_source(routine)))
+        .collect();
+    sources.sort();
+    sources.dedup();
+
+    let mut build = cc::Build::new();
+    build.files(sources).include("amos-c");
+
+    match prefix {
+        Some(prefix) => {
+            build.define("AMOS_PREFIX", prefix);
+            build.compile("amos_c");
+        }
+        None => build.compile("amos"),
+    };
+}
+
+// Links against an existing AMOS library, either found in AMOS_LIB_DIR or by pkg-config, instead
+// of building it from source. This requires neither CMake nor a Fortran compiler.
+fn link_system_amos(lib_dir: Option<PathBuf>, routines: &[&str]) {
+    let lib = match lib_dir {
+        Some(lib_dir) => {
+            let lib = find_library(&lib_dir).unwrap_or_else(|| {
+                panic!(
+                    "AMOS_LIB_DIR is set to {}, but no AMOS library was found there",
+                    lib_dir.display()
+                )
+            });
+
+            println!("cargo:rustc-link-search=native={}", lib_dir.display());
+
+            match is_static(&lib) {
+                true => println!("cargo:rustc-link-lib=static=amos"),
+                false => println!("cargo:rustc-link-lib=dylib=amos"),
+            };
+
+            lib
+        }
+        None => probe_pkg_config(),
+    };
+
+    verify_symbols(&lib, routines);
+
+    // A static AMOS library has been compiled with a Fortran compiler, and its runtime must be
+    // linked in as well. A shared library carries this dependency itself.
+    if is_static(&lib) {
+        println!("cargo:rerun-if-env-changed=AMOS_FORTRAN_COMPILER");
+
+        link_fortran_runtime(&FortranCompiler::from_env());
+    }
+}
+
+#[cfg(feature = "system-amos")]
+fn probe_pkg_config() -> PathBuf {
+    let library = pkg_config::Config::new()
+        .probe("amos")
+        .unwrap_or_else(|err| {
+            panic!("Failed to locate AMOS via pkg-config, consider setting AMOS_LIB_DIR: {err}")
+        });
+
+    library
+        .link_paths
+        .iter()
+        .find_map(|lib_dir| find_library(lib_dir))
+        .unwrap_or_else(|| {
+            panic!(
+                "pkg-config found AMOS, but no library exists in {:?}",
+                library.link_paths
+            )
+        })
+}
+
+#[cfg(not(feature = "system-amos"))]
+fn probe_pkg_config() -> PathBuf {
+    unreachable!("pkg-config is only used with the system-amos feature")
+}
+
+// Returns the path to the AMOS library in lib_dir, preferring a shared library as the linker does
+fn find_library(lib_dir: &Path) -> Option<PathBuf> {
+    [
+        "libamos.so",
+        "libamos.dylib",
+        "amos.dll",
+        "libamos.a",
+        "amos.lib",
+    ]
+    .iter()
+    .map(|name| lib_dir.join(name))
+    .find(|lib| lib.is_file())
+}
+
+fn is_static(lib: &Path) -> bool {
+    matches!(
+        lib.extension().and_then(|ext| ext.to_str()),
+        Some("a") | Some("lib")
+    )
+}
+
+// Checks that the library defines the symbols in src/bindings.rs of the enabled families. This
+// catches libraries built with a different name mangling (e.g., without the trailing underscore, or
+// in upper case) when building rather than as unresolved symbols when linking the final binary. The
+// symbol names are stored as null-terminated strings in the symbol tables of static and shared
+// libraries alike.
+fn verify_symbols(lib: &Path, routines: &[&str]) {
+    let bytes = fs::read(lib)
+        .unwrap_or_else(|err| panic!("Failed to read AMOS library {}: {err}", lib.display()));
+
+    let missing: Vec<&str> = AMOS_SYMBOLS
+        .iter()
+        .copied()
+        .filter(|symbol| routines.contains(&symbol.trim_end_matches('_')))
+        .filter(|symbol| {
+            let symbol = format!("{symbol}\0");
+
+            !bytes
+                .windows(symbol.len())
+                .any(|window| window == symbol.as_bytes())
+        })
+        .collect();
+
+    if !missing.is_empty() {
+        panic!(
+            "The AMOS library {} is missing the symbols {:?}",
+            lib.display(),
+            missing
+        );
+    }
 }
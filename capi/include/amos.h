@@ -0,0 +1,82 @@
+#ifndef AMOS_H
+#define AMOS_H
+
+/* Generated by cbindgen from src/capi.rs, do not edit */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef enum {
+  AMOS_STATUS_OK = 0,
+  AMOS_STATUS_INPUT_ERROR = 1,
+  AMOS_STATUS_OVERFLOW = 2,
+  AMOS_STATUS_LOSS_OF_SIGNIFICANCE = 3,
+  AMOS_STATUS_COMPLETE_LOSS_OF_SIGNIFICANCE = 4,
+  AMOS_STATUS_TERMINATION_CONDITION_NOT_MET = 5,
+} AmosStatus;
+
+typedef struct {
+  double re;
+  double im;
+} AmosComplex;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+AmosStatus amos_iv(double nu, AmosComplex z, AmosComplex *cy);
+
+AmosStatus amos_ive(double nu, AmosComplex z, AmosComplex *cy);
+
+AmosStatus amos_jv(double nu, AmosComplex z, AmosComplex *cy);
+
+AmosStatus amos_jve(double nu, AmosComplex z, AmosComplex *cy);
+
+AmosStatus amos_kv(double nu, AmosComplex z, AmosComplex *cy);
+
+AmosStatus amos_kve(double nu, AmosComplex z, AmosComplex *cy);
+
+AmosStatus amos_yv(double nu, AmosComplex z, AmosComplex *cy);
+
+AmosStatus amos_yve(double nu, AmosComplex z, AmosComplex *cy);
+
+AmosStatus amos_hankel1(double nu, AmosComplex z, AmosComplex *cy);
+
+AmosStatus amos_hankel1e(double nu, AmosComplex z, AmosComplex *cy);
+
+AmosStatus amos_hankel2(double nu, AmosComplex z, AmosComplex *cy);
+
+AmosStatus amos_hankel2e(double nu, AmosComplex z, AmosComplex *cy);
+
+AmosStatus amos_iv_seq(double nu, AmosComplex z, int n, AmosComplex *cy);
+
+AmosStatus amos_ive_seq(double nu, AmosComplex z, int n, AmosComplex *cy);
+
+AmosStatus amos_jv_seq(double nu, AmosComplex z, int n, AmosComplex *cy);
+
+AmosStatus amos_jve_seq(double nu, AmosComplex z, int n, AmosComplex *cy);
+
+AmosStatus amos_kv_seq(double nu, AmosComplex z, int n, AmosComplex *cy);
+
+AmosStatus amos_kve_seq(double nu, AmosComplex z, int n, AmosComplex *cy);
+
+AmosStatus amos_yv_seq(double nu, AmosComplex z, int n, AmosComplex *cy);
+
+AmosStatus amos_yve_seq(double nu, AmosComplex z, int n, AmosComplex *cy);
+
+AmosStatus amos_hankel1_seq(double nu, AmosComplex z, int n, AmosComplex *cy);
+
+AmosStatus amos_hankel1e_seq(double nu, AmosComplex z, int n, AmosComplex *cy);
+
+AmosStatus amos_hankel2_seq(double nu, AmosComplex z, int n, AmosComplex *cy);
+
+AmosStatus amos_hankel2e_seq(double nu, AmosComplex z, int n, AmosComplex *cy);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* AMOS_H */
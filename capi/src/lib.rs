@@ -0,0 +1,2 @@
+// The functions are defined in src/capi.rs of amos-rs, and exported from here
+pub use amos_rs::capi::*;
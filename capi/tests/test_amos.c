@@ -0,0 +1,76 @@
+// Checks the C bindings against closed forms, returning the number of failed checks
+#include <math.h>
+#include <stdio.h>
+
+#include "amos.h"
+
+static int failures = 0;
+
+static void check(const char *name, double value, double expected, double tol) {
+  if (!(fabs(value - expected) <= tol * fmax(1.0, fabs(expected)))) {
+    fprintf(stderr, "%s: got %.17g, expected %.17g\n", name, value, expected);
+    failures++;
+  }
+}
+
+static void check_status(const char *name, AmosStatus status, AmosStatus expected) {
+  if (status != expected) {
+    fprintf(stderr, "%s: got status %d, expected %d\n", name, status, expected);
+    failures++;
+  }
+}
+
+int main(void) {
+  const double pi = 3.14159265358979323846;
+  const double x = 1.7;
+  AmosComplex z = {x, 0.0};
+  AmosComplex cy;
+  AmosComplex seq[3];
+
+  // iv(-1/2, x) = sqrt(2 / (pi x)) cosh(x), which needs the reflection formula for negative orders
+  check_status("amos_iv", amos_iv(-0.5, z, &cy), AMOS_STATUS_OK);
+  check("amos_iv", cy.re, sqrt(2.0 / (pi * x)) * cosh(x), 1e-14);
+  check("amos_iv", cy.im, 0.0, 1e-14);
+
+  // kv(1/2, x) = sqrt(pi / (2 x)) exp(-x) and kve(1/2, x) = sqrt(pi / (2 x))
+  check_status("amos_kv", amos_kv(0.5, z, &cy), AMOS_STATUS_OK);
+  check("amos_kv", cy.re, sqrt(pi / (2.0 * x)) * exp(-x), 1e-14);
+  check_status("amos_kve", amos_kve(0.5, z, &cy), AMOS_STATUS_OK);
+  check("amos_kve", cy.re, sqrt(pi / (2.0 * x)), 1e-14);
+
+  // jv(1/2, x) = yv(-1/2, x) = sqrt(2 / (pi x)) sin(x)
+  check_status("amos_jv", amos_jv(0.5, z, &cy), AMOS_STATUS_OK);
+  check("amos_jv", cy.re, sqrt(2.0 / (pi * x)) * sin(x), 1e-14);
+  check_status("amos_yv", amos_yv(-0.5, z, &cy), AMOS_STATUS_OK);
+  check("amos_yv", cy.re, sqrt(2.0 / (pi * x)) * sin(x), 1e-14);
+
+  // hankel1(1/2 + k, x) for k = 0, 1, 2 from jv and yv
+  check_status("amos_hankel1_seq", amos_hankel1_seq(0.5, z, 3, seq), AMOS_STATUS_OK);
+
+  for (int k = 0; k < 3; k++) {
+    AmosComplex cy_j, cy_y;
+
+    amos_jv(0.5 + k, z, &cy_j);
+    amos_yv(0.5 + k, z, &cy_y);
+    check("amos_hankel1_seq", seq[k].re, cy_j.re, 1e-14);
+    check("amos_hankel1_seq", seq[k].im, cy_y.re, 1e-14);
+  }
+
+  // hankel1(-1/2, x) = exp(i pi / 2) hankel1(1/2, x)
+  AmosComplex cy_m;
+
+  amos_hankel1(-0.5, z, &cy_m);
+  check("amos_hankel1", cy_m.re, -seq[0].im, 1e-14);
+  check("amos_hankel1", cy_m.im, seq[0].re, 1e-14);
+
+  // Errors are returned as status codes
+  AmosComplex large = {1e3, 0.0};
+
+  check_status("amos_iv", amos_iv(0.0, large, &cy), AMOS_STATUS_OVERFLOW);
+  check_status("amos_ive", amos_ive(0.0, large, &cy), AMOS_STATUS_OK);
+  check_status("amos_kv_seq", amos_kv_seq(0.0, z, 0, seq), AMOS_STATUS_INPUT_ERROR);
+  check_status("amos_jv", amos_jv(NAN, z, &cy), AMOS_STATUS_INPUT_ERROR);
+  check_status("amos_yv", amos_yv(0.0, z, NULL), AMOS_STATUS_INPUT_ERROR);
+
+  return failures;
+}
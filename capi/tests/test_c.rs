@@ -0,0 +1,34 @@
+// Compiles tests/test_amos.c against the shared library built by cargo and runs it. The C compiler
+// is taken from CC, and defaults to cc.
+#![cfg(unix)]
+
+use std::env;
+use std::path::PathBuf;
+use std::process::Command;
+
+#[test]
+fn test_c_program() {
+    // The test binary and the shared library are both placed in target/<profile>/deps
+    let deps = env::current_exe().unwrap().parent().unwrap().to_path_buf();
+    let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let exe = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("test_amos");
+    let cc = env::var("CC").unwrap_or_else(|_| "cc".to_string());
+
+    let status = Command::new(&cc)
+        .arg(manifest_dir.join("tests/test_amos.c"))
+        .arg("-I")
+        .arg(manifest_dir.join("include"))
+        .arg("-L")
+        .arg(&deps)
+        .arg(format!("-Wl,-rpath,{}", deps.display()))
+        .args(["-lamos_capi", "-lm", "-o"])
+        .arg(&exe)
+        .status()
+        .unwrap_or_else(|err| panic!("Failed to run {}: {}", cc, err));
+
+    assert!(status.success(), "Failed to compile tests/test_amos.c");
+
+    let status = Command::new(&exe).status().unwrap();
+
+    assert!(status.success(), "{} failed: {}", exe.display(), status);
+}
@@ -0,0 +1,140 @@
+// The functions of the calculator, by the names of the unscaled wrappers in amos-rs, and the NZ and
+// IERR of the AMOS routines computing them.
+use amos_rs::{zairy, zbesh, zbesi, zbesj, zbesk, zbesy};
+use num::complex::Complex;
+
+// NZ and IERR of an AMOS routine, where ZBIRY has no NZ, as Bi does not underflow
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Codes {
+    pub nz: Option<i32>,
+    pub ierr: i32,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Function {
+    Iv,
+    Jv,
+    Kv,
+    Yv,
+    Hankel1,
+    Hankel2,
+    Ai,
+    Aip,
+    Bi,
+    Bip,
+}
+
+impl Function {
+    pub const ALL: [Function; 10] = [
+        Function::Iv,
+        Function::Jv,
+        Function::Kv,
+        Function::Yv,
+        Function::Hankel1,
+        Function::Hankel2,
+        Function::Ai,
+        Function::Aip,
+        Function::Bi,
+        Function::Bip,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Function::Iv => "iv",
+            Function::Jv => "jv",
+            Function::Kv => "kv",
+            Function::Yv => "yv",
+            Function::Hankel1 => "hankel1",
+            Function::Hankel2 => "hankel2",
+            Function::Ai => "ai",
+            Function::Aip => "aip",
+            Function::Bi => "bi",
+            Function::Bip => "bip",
+        }
+    }
+
+    pub fn find(name: &str) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|function| function.name() == name)
+    }
+
+    // The Airy functions have no order
+    pub fn has_order(&self) -> bool {
+        !matches!(
+            self,
+            Function::Ai | Function::Aip | Function::Bi | Function::Bip
+        )
+    }
+
+    // The exponentially scaled function for kode = 2, e.g., ive for iv, see the wrappers, and the
+    // codes of AMOS, which for negative orders are those of the reflection, see zbesi_ierr
+    pub fn evaluate(&self, nu: f64, z: Complex<f64>, kode: i32) -> (Complex<f64>, Codes) {
+        let (value, nz, ierr) = match self {
+            Function::Iv => zbesi::zbesi_ierr(nu, z, kode),
+            Function::Jv => zbesj::zbesj_ierr(nu, z, kode),
+            Function::Kv => zbesk::zbesk_ierr(nu, z, kode),
+            Function::Yv => zbesy::zbesy_ierr(nu, z, kode),
+            Function::Hankel1 => zbesh::zbesh_scalar(nu, z, kode, 1),
+            Function::Hankel2 => zbesh::zbesh_scalar(nu, z, kode, 2),
+            Function::Ai => zairy::zairy_ierr(z, 0, kode),
+            Function::Aip => zairy::zairy_ierr(z, 1, kode),
+            Function::Bi | Function::Bip => {
+                let id = (*self == Function::Bip) as i32;
+                let (value, ierr) = zairy::zbiry_ierr(z, id, kode);
+
+                return (value, Codes { nz: None, ierr });
+            }
+        };
+
+        (value, Codes { nz: Some(nz), ierr })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_evaluate() {
+        let z = Complex::new(1.0, 2.0);
+
+        assert_eq!(
+            Function::find("iv").unwrap().evaluate(2.5, z, 1).0,
+            zbesi::iv(2.5, z)
+        );
+        assert_eq!(Function::Iv.evaluate(2.5, z, 2).0, zbesi::ive(2.5, z));
+        assert_eq!(
+            Function::Hankel2.evaluate(-0.3, z, 2).0,
+            zbesh::hankel2e(-0.3, z, 1)[0]
+        );
+        assert_eq!(Function::Bip.evaluate(0.0, z, 1).0, zairy::bip(z));
+        assert!(Function::find("ive").is_none());
+    }
+
+    #[test]
+    fn test_codes() {
+        let z = Complex::new(1.0, 2.0);
+
+        for function in Function::ALL {
+            let (_, codes) = function.evaluate(2.5, z, 1);
+
+            assert_eq!(codes.ierr, 0, "{}", function.name());
+            assert_eq!(
+                codes.nz.is_none(),
+                matches!(function, Function::Bi | Function::Bip)
+            );
+        }
+
+        // Overflow, and underflow of K for large real arguments
+        let z = Complex::new(800.0, 0.0);
+
+        assert_eq!(Function::Iv.evaluate(0.0, z, 1).1.ierr, 2);
+        assert_eq!(Function::Iv.evaluate(0.0, z, 2).1.ierr, 0);
+        assert_eq!(Function::Kv.evaluate(0.0, z, 1).1.nz, Some(1));
+
+        // The input error of NaN is reported by the wrappers without calling AMOS
+        assert_eq!(Function::Jv.evaluate(f64::NAN, z, 1).1.ierr, 1);
+    }
+}
@@ -0,0 +1,172 @@
+// RGB images as binary PPM (P6), as in accuracy/src/heatmap.rs, or as PNG. The PNG is written without
+// compression, with the image data in stored deflate blocks, so that no image or compression library
+// is needed.
+use std::io::{self, Write};
+
+pub type Rgb = [u8; 3];
+
+// Writes the row-major pixels, where the first row is the top of the image
+pub fn write_ppm(
+    out: &mut impl Write,
+    width: usize,
+    height: usize,
+    pixels: &[Rgb],
+) -> io::Result<()> {
+    assert_eq!(pixels.len(), width * height);
+
+    write!(out, "P6\n{width} {height}\n255\n")?;
+
+    for pixel in pixels {
+        out.write_all(pixel)?;
+    }
+
+    Ok(())
+}
+
+// CRC-32 of the PNG chunks, see the PNG specification, annex D
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0_u32;
+
+    for &byte in bytes {
+        crc ^= byte as u32;
+
+        for _ in 0..8 {
+            crc = match crc & 1 {
+                1 => (crc >> 1) ^ 0xedb88320,
+                _ => crc >> 1,
+            };
+        }
+    }
+
+    !crc
+}
+
+// Adler-32 checksum of the zlib stream, see RFC 1950
+fn adler32(bytes: &[u8]) -> u32 {
+    let (mut a, mut b) = (1_u32, 0_u32);
+
+    for &byte in bytes {
+        a = (a + byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+
+    (b << 16) | a
+}
+
+fn write_chunk(out: &mut impl Write, kind: &[u8; 4], data: &[u8]) -> io::Result<()> {
+    let mut chunk = Vec::with_capacity(data.len() + 4);
+    chunk.extend_from_slice(kind);
+    chunk.extend_from_slice(data);
+
+    out.write_all(&(data.len() as u32).to_be_bytes())?;
+    out.write_all(&chunk)?;
+    out.write_all(&crc32(&chunk).to_be_bytes())
+}
+
+// The zlib stream of the data in stored deflate blocks of at most 65535 bytes, see RFC 1951 section
+// 3.2.4
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let mut stream = vec![0x78, 0x01];
+    let blocks: Vec<&[u8]> = match data.is_empty() {
+        true => vec![&[]],
+        false => data.chunks(65535).collect(),
+    };
+
+    for (i, block) in blocks.iter().enumerate() {
+        let last = (i + 1 == blocks.len()) as u8;
+        let len = block.len() as u16;
+
+        stream.push(last);
+        stream.extend_from_slice(&len.to_le_bytes());
+        stream.extend_from_slice(&(!len).to_le_bytes());
+        stream.extend_from_slice(block);
+    }
+
+    stream.extend_from_slice(&adler32(data).to_be_bytes());
+
+    stream
+}
+
+pub fn write_png(
+    out: &mut impl Write,
+    width: usize,
+    height: usize,
+    pixels: &[Rgb],
+) -> io::Result<()> {
+    assert_eq!(pixels.len(), width * height);
+
+    out.write_all(b"\x89PNG\r\n\x1a\n")?;
+
+    // 8 bits per channel of RGB, without interlacing
+    let mut header = Vec::with_capacity(13);
+    header.extend_from_slice(&(width as u32).to_be_bytes());
+    header.extend_from_slice(&(height as u32).to_be_bytes());
+    header.extend_from_slice(&[8, 2, 0, 0, 0]);
+
+    write_chunk(out, b"IHDR", &header)?;
+
+    // Each row starts with the filter type, which is none
+    let mut data = Vec::with_capacity(height * (1 + 3 * width));
+
+    for row in pixels.chunks(width.max(1)).take(height) {
+        data.push(0);
+        data.extend(row.iter().flatten());
+    }
+
+    write_chunk(out, b"IDAT", &zlib_stored(&data))?;
+    write_chunk(out, b"IEND", &[])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checksums() {
+        assert_eq!(crc32(b"IEND"), 0xae426082);
+        assert_eq!(crc32(b"123456789"), 0xcbf43926);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e60398);
+    }
+
+    #[test]
+    fn test_zlib_stored() {
+        let data: Vec<u8> = (0..70000).map(|i| (i % 251) as u8).collect();
+        let stream = zlib_stored(&data);
+
+        // Two blocks, of which the second is the last
+        assert_eq!(stream.len(), 2 + 2 * 5 + data.len() + 4);
+        assert_eq!(&stream[2..7], &[0, 0xff, 0xff, 0, 0]);
+        assert_eq!(stream[2 + 5 + 65535], 1);
+        assert_eq!(&stream[stream.len() - 4..], &adler32(&data).to_be_bytes());
+    }
+
+    #[test]
+    fn test_images() {
+        let pixels = [
+            [255, 0, 0],
+            [0, 255, 0],
+            [0, 0, 255],
+            [1, 2, 3],
+            [4, 5, 6],
+            [7, 8, 9],
+        ];
+
+        let mut ppm = Vec::new();
+        write_ppm(&mut ppm, 3, 2, &pixels).unwrap();
+
+        assert_eq!(&ppm[..11], b"P6\n3 2\n255\n");
+        assert_eq!(ppm.len(), 11 + 18);
+
+        let mut png = Vec::new();
+        write_png(&mut png, 3, 2, &pixels).unwrap();
+
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+        assert_eq!(&png[12..16], b"IHDR");
+        assert_eq!(&png[16..29], &[0, 0, 0, 3, 0, 0, 0, 2, 8, 2, 0, 0, 0]);
+        assert_eq!(&png[png.len() - 12..], b"\0\0\0\0IEND\xae\x42\x60\x82");
+
+        // The rows of the IDAT chunk follow the zlib and block headers
+        let idat = &png[33 + 8..];
+        assert_eq!(&idat[7..17], &[0, 255, 0, 0, 0, 255, 0, 0, 0, 255]);
+    }
+}
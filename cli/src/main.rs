@@ -0,0 +1,234 @@
+// A calculator for the Bessel and Airy functions of amos-rs. The function is named as the unscaled
+// wrapper, and is evaluated for every order and argument, which may be lists and ranges, see
+// parse.rs, with the exponentially scaled function, e.g., ive for iv, selected by --scaled
+// (kode = 2). The values are written as a table, CSV or JSON, see output.rs, along with the NZ and
+// IERR of AMOS with --diagnostics:
+//
+//     cargo run -p amos-cli -- iv --nu 2.5 --z 1+2i
+//     cargo run -p amos-cli -- hankel1 --nu 0:10:0.5 --z 2@0.5 --scaled --format csv
+//     cargo run -p amos-cli -- ai --z -10:10:0.1 --format json --diagnostics
+//
+// The table subcommand prints tables of real arguments instead, see table.rs, and the portrait
+// subcommand renders a function over the complex plane, see portrait.rs.
+mod functions;
+mod image;
+mod output;
+mod parse;
+mod portrait;
+mod table;
+
+use functions::Function;
+use num::complex::Complex;
+use output::{Format, Row};
+use std::env;
+use std::io::{self, BufWriter, Write};
+use std::process;
+
+const USAGE: &str =
+    "Usage: amos iv|jv|kv|yv|hankel1|hankel2|ai|aip|bi|bip [--nu NU,...] --z Z,... \
+[--scaled] [--format table|csv|json] [--diagnostics]
+
+NU and Z are lists of values or ranges START:STOP:STEP, where Z is complex, e.g., 1+2i, -i or 2@0.5
+in polar form with the angle in radians. The Airy functions take no order.
+
+Usage: amos table --x X,... [--nu NU,...] [--functions iv,ive,kv,kve,jv,yv] [--digits N] \
+[--format latex|markdown|csv]
+
+Usage: amos portrait FUNCTION [--nu NU] [--re MIN,MAX] [--im MIN,MAX] [--size N|WIDTH,HEIGHT] \
+[--scaled] [--out FILE.png|FILE.ppm]";
+
+struct Options {
+    function: Function,
+    orders: Vec<f64>,
+    arguments: Vec<Complex<f64>>,
+    // 2 for the exponentially scaled functions
+    kode: i32,
+    format: Format,
+    diagnostics: bool,
+}
+
+fn parse_args(args: &[String]) -> Option<Options> {
+    let mut args = args.iter();
+
+    let mut options = Options {
+        function: Function::find(args.next()?)?,
+        orders: Vec::new(),
+        arguments: Vec::new(),
+        kode: 1,
+        format: Format::Table,
+        diagnostics: false,
+    };
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--scaled" => options.kode = 2,
+            "--diagnostics" => options.diagnostics = true,
+            "--nu" => options.orders = parse::parse_reals(args.next()?)?,
+            "--z" => options.arguments = parse::parse_complexes(args.next()?)?,
+            "--format" => options.format = Format::find(args.next()?)?,
+            _ => return None,
+        }
+    }
+
+    // The order is required exactly for the functions that have one
+    match (options.function.has_order(), options.orders.is_empty()) {
+        (true, true) | (false, false) => return None,
+        _ => {}
+    }
+
+    match options.arguments.is_empty() {
+        true => None,
+        false => Some(options),
+    }
+}
+
+fn evaluate(options: &Options) -> Vec<Row> {
+    let function = options.function;
+
+    // The Airy functions are evaluated once per argument
+    let orders = match function.has_order() {
+        true => options.orders.iter().map(|&nu| Some(nu)).collect(),
+        false => vec![None],
+    };
+
+    let mut rows = Vec::with_capacity(orders.len() * options.arguments.len());
+
+    for nu in orders {
+        for &z in &options.arguments {
+            let (value, codes) = function.evaluate(nu.unwrap_or(0.0), z, options.kode);
+
+            rows.push(Row {
+                nu,
+                z,
+                value,
+                codes: match options.diagnostics {
+                    true => Some(codes),
+                    false => None,
+                },
+            });
+        }
+    }
+
+    rows
+}
+
+fn write(f: impl FnOnce(&mut BufWriter<io::Stdout>) -> io::Result<()>) {
+    let mut out = BufWriter::new(io::stdout());
+
+    f(&mut out).and_then(|_| out.flush()).unwrap_or_else(|err| {
+        eprintln!("Failed to write: {err}");
+        process::exit(1);
+    });
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    if args.first().map(String::as_str) == Some("table") {
+        let options = table::parse_args(&args[1..]).unwrap_or_else(|| {
+            eprintln!("{USAGE}");
+            process::exit(2);
+        });
+
+        return write(|out| table::write(out, &options));
+    }
+
+    if args.first().map(String::as_str) == Some("portrait") {
+        let options = portrait::parse_args(&args[1..]).unwrap_or_else(|| {
+            eprintln!("{USAGE}");
+            process::exit(2);
+        });
+
+        let errors = portrait::write(&options).unwrap_or_else(|err| {
+            eprintln!("Failed to write {}: {err}", options.out.display());
+            process::exit(1);
+        });
+
+        println!(
+            "Wrote {}, with IERR != 0 at {errors} of {} points",
+            options.out.display(),
+            options.width * options.height
+        );
+
+        return;
+    }
+
+    let options = parse_args(&args).unwrap_or_else(|| {
+        eprintln!("{USAGE}");
+        process::exit(2);
+    });
+
+    // The scaled functions are named with an e, as in amos-rs, e.g., ive and hankel1e
+    let name = match options.kode {
+        2 => format!("{}e", options.function.name()),
+        _ => options.function.name().to_string(),
+    };
+
+    let rows = evaluate(&options);
+
+    write(|out| output::write(out, options.format, &name, &rows));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_args() {
+        let options = parse_args(&args(&[
+            "hankel1", "--nu", "0:1:0.5", "--z", "1+2i,3", "--scaled", "--format", "json",
+        ]))
+        .unwrap();
+
+        assert_eq!(options.function, Function::Hankel1);
+        assert_eq!(options.orders, vec![0.0, 0.5, 1.0]);
+        assert_eq!(options.arguments.len(), 2);
+        assert_eq!(options.kode, 2);
+        assert_eq!(options.format, Format::Json);
+        assert!(!options.diagnostics);
+
+        assert!(parse_args(&args(&["ai", "--z", "1", "--diagnostics"])).is_some());
+        assert!(parse_args(&args(&["ai", "--nu", "1", "--z", "1"])).is_none());
+        assert!(parse_args(&args(&["iv", "--z", "1"])).is_none());
+        assert!(parse_args(&args(&["iv", "--nu", "1"])).is_none());
+        assert!(parse_args(&args(&["iv", "--nu", "1", "--z", "1", "--format", "xml"])).is_none());
+        assert!(parse_args(&args(&["ive", "--nu", "1", "--z", "1"])).is_none());
+        assert!(parse_args(&args(&["iv", "--nu", "1", "--z"])).is_none());
+        assert!(parse_args(&args(&[])).is_none());
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let options = parse_args(&args(&[
+            "iv",
+            "--nu",
+            "0,2.5",
+            "--z",
+            "1+2i,-3",
+            "--diagnostics",
+        ]))
+        .unwrap();
+        let rows = evaluate(&options);
+
+        assert_eq!(rows.len(), 4);
+        assert_eq!(rows[1].nu, Some(0.0));
+        assert_eq!(rows[1].z, Complex::new(-3.0, 0.0));
+        assert_eq!(
+            rows[2].value,
+            amos_rs::zbesi::iv(2.5, Complex::new(1.0, 2.0))
+        );
+        assert_eq!(rows[3].codes.unwrap().ierr, 0);
+
+        let options = parse_args(&args(&["bi", "--z", "0:2:1"])).unwrap();
+        let rows = evaluate(&options);
+
+        assert_eq!(rows.len(), 3);
+        assert!(rows
+            .iter()
+            .all(|row| row.nu.is_none() && row.codes.is_none()));
+    }
+}
@@ -0,0 +1,283 @@
+// Writes the values as an aligned table, as CSV with the columns
+//
+//     function,nu,z_re,z_im,value_re,value_im[,nz,ierr]
+//
+// in the format of tests/dat, or as a JSON array of objects with the same fields, where complex
+// numbers are [re, im] pairs. The Airy functions have no nu column, and the NZ and IERR are written
+// with --diagnostics only. Values that are not finite are written as NaN and inf in the table and
+// CSV, and as null in JSON, as are the missing NZ of ZBIRY.
+use crate::functions::Codes;
+use num::complex::Complex;
+use std::io::{self, Write};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Format {
+    Table,
+    Csv,
+    Json,
+}
+
+impl Format {
+    pub fn find(name: &str) -> Option<Self> {
+        match name {
+            "table" => Some(Format::Table),
+            "csv" => Some(Format::Csv),
+            "json" => Some(Format::Json),
+            _ => None,
+        }
+    }
+}
+
+pub struct Row {
+    pub nu: Option<f64>,
+    pub z: Complex<f64>,
+    pub value: Complex<f64>,
+    pub codes: Option<Codes>,
+}
+
+// The input as given for moderate magnitudes, and in scientific notation otherwise
+fn format_real(x: f64) -> String {
+    match x == 0.0 || (1e-4..1e6).contains(&x.abs()) {
+        true => format!("{x}"),
+        false => format!("{x:e}"),
+    }
+}
+
+fn format_complex(z: Complex<f64>) -> String {
+    let sign = match z.im.is_sign_negative() {
+        true => '-',
+        false => '+',
+    };
+
+    format!("{}{sign}{}i", format_real(z.re), format_real(z.im.abs()))
+}
+
+fn json_number(x: f64) -> String {
+    match x.is_finite() {
+        true => format!("{x:e}"),
+        false => "null".to_string(),
+    }
+}
+
+pub fn write(out: &mut impl Write, format: Format, function: &str, rows: &[Row]) -> io::Result<()> {
+    match format {
+        Format::Table => write_table(out, rows),
+        Format::Csv => write_csv(out, function, rows),
+        Format::Json => write_json(out, function, rows),
+    }
+}
+
+fn write_table(out: &mut impl Write, rows: &[Row]) -> io::Result<()> {
+    let Some(first) = rows.first() else {
+        return Ok(());
+    };
+
+    let mut header = vec!["nu", "z", "re", "im", "nz", "ierr"];
+
+    if first.codes.is_none() {
+        header.truncate(4);
+    }
+
+    if first.nu.is_none() {
+        header.remove(0);
+    }
+
+    let mut cells = vec![header.iter().map(|h| h.to_string()).collect::<Vec<_>>()];
+
+    for row in rows {
+        let mut line = Vec::with_capacity(header.len());
+
+        if let Some(nu) = row.nu {
+            line.push(format_real(nu));
+        }
+
+        line.push(format_complex(row.z));
+        line.push(format!("{:.15e}", row.value.re));
+        line.push(format!("{:.15e}", row.value.im));
+
+        if let Some(codes) = row.codes {
+            line.push(codes.nz.map_or("-".to_string(), |nz| nz.to_string()));
+            line.push(codes.ierr.to_string());
+        }
+
+        cells.push(line);
+    }
+
+    // Right aligned, as the exponents make the numbers differ in length
+    let widths: Vec<usize> = (0..header.len())
+        .map(|j| cells.iter().map(|line| line[j].len()).max().unwrap())
+        .collect();
+
+    for line in &cells {
+        let padded: Vec<String> = line
+            .iter()
+            .zip(&widths)
+            .map(|(cell, &width)| format!("{cell:>width$}"))
+            .collect();
+
+        writeln!(out, "{}", padded.join("  "))?;
+    }
+
+    Ok(())
+}
+
+fn write_csv(out: &mut impl Write, function: &str, rows: &[Row]) -> io::Result<()> {
+    let Some(first) = rows.first() else {
+        return Ok(());
+    };
+
+    let nu = match first.nu {
+        Some(_) => "nu,",
+        None => "",
+    };
+    let codes = match first.codes {
+        Some(_) => ",nz,ierr",
+        None => "",
+    };
+
+    writeln!(out, "function,{nu}z_re,z_im,value_re,value_im{codes}")?;
+
+    for row in rows {
+        write!(out, "{function},")?;
+
+        if let Some(nu) = row.nu {
+            write!(out, "{nu:e},")?;
+        }
+
+        write!(
+            out,
+            "{:e},{:e},{:e},{:e}",
+            row.z.re, row.z.im, row.value.re, row.value.im
+        )?;
+
+        if let Some(codes) = row.codes {
+            let nz = codes.nz.map_or(String::new(), |nz| nz.to_string());
+
+            write!(out, ",{nz},{}", codes.ierr)?;
+        }
+
+        writeln!(out)?;
+    }
+
+    Ok(())
+}
+
+fn write_json(out: &mut impl Write, function: &str, rows: &[Row]) -> io::Result<()> {
+    writeln!(out, "[")?;
+
+    for (i, row) in rows.iter().enumerate() {
+        let mut fields = vec![format!("\"function\": \"{function}\"")];
+
+        if let Some(nu) = row.nu {
+            fields.push(format!("\"nu\": {}", json_number(nu)));
+        }
+
+        fields.push(format!(
+            "\"z\": [{}, {}]",
+            json_number(row.z.re),
+            json_number(row.z.im)
+        ));
+        fields.push(format!(
+            "\"value\": [{}, {}]",
+            json_number(row.value.re),
+            json_number(row.value.im)
+        ));
+
+        if let Some(codes) = row.codes {
+            let nz = codes.nz.map_or("null".to_string(), |nz| nz.to_string());
+
+            fields.push(format!("\"nz\": {nz}"));
+            fields.push(format!("\"ierr\": {}", codes.ierr));
+        }
+
+        let comma = match i + 1 < rows.len() {
+            true => ",",
+            false => "",
+        };
+
+        writeln!(out, "  {{{}}}{comma}", fields.join(", "))?;
+    }
+
+    writeln!(out, "]")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rows() -> Vec<Row> {
+        vec![
+            Row {
+                nu: Some(2.5),
+                z: Complex::new(1.0, -2.0),
+                value: Complex::new(0.25, 1e-20),
+                codes: Some(Codes {
+                    nz: Some(0),
+                    ierr: 0,
+                }),
+            },
+            Row {
+                nu: Some(-1e7),
+                z: Complex::new(1e-5, 0.0),
+                value: Complex::new(f64::NAN, f64::INFINITY),
+                codes: Some(Codes { nz: None, ierr: 4 }),
+            },
+        ]
+    }
+
+    fn written(format: Format, rows: &[Row]) -> String {
+        let mut out = Vec::new();
+        write(&mut out, format, "iv", rows).unwrap();
+
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_formats() {
+        let table = written(Format::Table, &rows());
+        let lines: Vec<&str> = table.lines().collect();
+
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].trim_start().starts_with("nu"));
+        assert!(lines[1].contains("1-2i"));
+        assert!(lines[1].contains("2.500000000000000e-1"));
+        assert!(lines[2].contains("-1e7"));
+        assert!(lines[2].contains("1e-5+0i"));
+        assert!(lines.iter().all(|line| line.len() == lines[0].len()));
+
+        assert_eq!(
+            written(Format::Csv, &rows()),
+            "function,nu,z_re,z_im,value_re,value_im,nz,ierr\n\
+             iv,2.5e0,1e0,-2e0,2.5e-1,1e-20,0,0\n\
+             iv,-1e7,1e-5,0e0,NaN,inf,,4\n"
+        );
+        assert_eq!(
+            written(Format::Json, &rows()),
+            "[\n  {\"function\": \"iv\", \"nu\": 2.5e0, \"z\": [1e0, -2e0], \"value\": [2.5e-1, \
+             1e-20], \"nz\": 0, \"ierr\": 0},\n  {\"function\": \"iv\", \"nu\": -1e7, \"z\": \
+             [1e-5, 0e0], \"value\": [null, null], \"nz\": null, \"ierr\": 4}\n]\n"
+        );
+
+        // The Airy functions have no order
+        let airy = vec![Row {
+            nu: None,
+            codes: None,
+            ..rows().remove(0)
+        }];
+
+        assert_eq!(
+            written(Format::Csv, &airy),
+            "function,z_re,z_im,value_re,value_im\niv,1e0,-2e0,2.5e-1,1e-20\n"
+        );
+        assert_eq!(
+            written(Format::Table, &airy)
+                .lines()
+                .next()
+                .unwrap()
+                .split_whitespace()
+                .count(),
+            3
+        );
+        assert_eq!(written(Format::Json, &[]), "[\n]\n");
+    }
+}
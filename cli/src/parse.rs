@@ -0,0 +1,185 @@
+// Parsing of the orders and arguments. Complex numbers are written as 1.5, 2i, -i, 1+2i or 1e-3-2e4i,
+// with j accepted for i, or in polar form as R@THETA with THETA in radians, e.g., 2@0.785. A list of
+// values is separated by commas, where each entry is a value or a range START:STOP:STEP, including
+// STOP if it is reached, e.g., 0:10:0.5. The complex ranges run along the line from START to STOP,
+// with STEP the distance between the points, e.g., 1i:10+1i:0.5.
+use num::complex::Complex;
+
+// The largest number of values of a range, which guards against, e.g., 0:1:1e-12
+const MAX_RANGE: usize = 1_000_000;
+
+// The values must be finite, as AMOS does not check for NaN, see src/amos_errors.rs of amos-rs
+fn finite(x: f64) -> Option<f64> {
+    Some(x).filter(|x| x.is_finite())
+}
+
+pub fn parse_real(value: &str) -> Option<f64> {
+    finite(value.trim().parse().ok()?)
+}
+
+// The imaginary part with its sign, where the coefficient may be left out, e.g., -i
+fn parse_imag(value: &str) -> Option<f64> {
+    match value {
+        "" | "+" => Some(1.0),
+        "-" => Some(-1.0),
+        _ => parse_real(value),
+    }
+}
+
+pub fn parse_complex(value: &str) -> Option<Complex<f64>> {
+    let value = value.trim();
+
+    if let Some((r, theta)) = value.split_once('@') {
+        let (r, theta) = (parse_real(r)?, parse_real(theta)?);
+
+        return match r >= 0.0 {
+            true => Some(Complex::from_polar(r, theta)),
+            false => None,
+        };
+    }
+
+    let body = match value.strip_suffix(['i', 'j']) {
+        Some(body) => body,
+        None => return Some(Complex::new(parse_real(value)?, 0.0)),
+    };
+
+    // The sign separating the parts is the last one that is neither leading nor of an exponent
+    let bytes = body.as_bytes();
+    let split = (1..bytes.len())
+        .rev()
+        .find(|&k| matches!(bytes[k], b'+' | b'-') && !matches!(bytes[k - 1], b'e' | b'E'));
+
+    match split {
+        Some(k) => Some(Complex::new(
+            parse_real(&body[..k])?,
+            parse_imag(&body[k..])?,
+        )),
+        None => Some(Complex::new(0.0, parse_imag(body)?)),
+    }
+}
+
+// The number of points of a range of the given length, where rounding errors in length / step are
+// allowed for, so that STOP is included
+fn range_count(length: f64, step: f64) -> Option<usize> {
+    let count = (length / step * (1.0 + 1e-12)).floor() + 1.0;
+
+    match step > 0.0 && length >= 0.0 && count <= MAX_RANGE as f64 {
+        true => Some(count as usize),
+        false => None,
+    }
+}
+
+pub fn parse_reals(value: &str) -> Option<Vec<f64>> {
+    let mut values = Vec::new();
+
+    for entry in value.split(',') {
+        match entry.split(':').collect::<Vec<_>>().as_slice() {
+            [x] => values.push(parse_real(x)?),
+            [start, stop, step] => {
+                let (start, stop, step) =
+                    (parse_real(start)?, parse_real(stop)?, parse_real(step)?);
+                let count = range_count(stop - start, step)?;
+
+                values.extend((0..count).map(|k| start + k as f64 * step));
+            }
+            _ => return None,
+        }
+    }
+
+    Some(values)
+}
+
+pub fn parse_complexes(value: &str) -> Option<Vec<Complex<f64>>> {
+    let mut values = Vec::new();
+
+    for entry in value.split(',') {
+        match entry.split(':').collect::<Vec<_>>().as_slice() {
+            [z] => values.push(parse_complex(z)?),
+            [start, stop, step] => {
+                let (start, stop) = (parse_complex(start)?, parse_complex(stop)?);
+                let step = parse_real(step)?;
+                let length = (stop - start).norm();
+                let count = range_count(length, step)?;
+
+                // A range of a single point has no direction
+                let step = match length > 0.0 {
+                    true => step * (stop - start) / length,
+                    false => Complex::new(0.0, 0.0),
+                };
+
+                values.extend((0..count).map(|k| start + k as f64 * step));
+            }
+            _ => return None,
+        }
+    }
+
+    Some(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::FRAC_PI_2;
+
+    #[test]
+    fn test_parse_complex() {
+        let c = |re, im| Some(Complex::new(re, im));
+
+        assert_eq!(parse_complex("1.5"), c(1.5, 0.0));
+        assert_eq!(parse_complex("-2"), c(-2.0, 0.0));
+        assert_eq!(parse_complex("2i"), c(0.0, 2.0));
+        assert_eq!(parse_complex("-i"), c(0.0, -1.0));
+        assert_eq!(parse_complex("1+2i"), c(1.0, 2.0));
+        assert_eq!(parse_complex("-1-j"), c(-1.0, -1.0));
+        assert_eq!(parse_complex(" 1e-3-2e4i "), c(1e-3, -2e4));
+        assert_eq!(parse_complex("1E+2+3e-1i"), c(100.0, 0.3));
+        assert_eq!(parse_complex("2e-3i"), c(0.0, 2e-3));
+
+        let z = parse_complex("2@1.5707963267948966").unwrap();
+        assert!((z - Complex::from_polar(2.0, FRAC_PI_2)).norm() == 0.0);
+
+        for value in [
+            "", "i+1", "1+2ii", "nan", "inf", "1e400", "1+nani", "-1@0", "1@", "x",
+        ] {
+            assert_eq!(parse_complex(value), None, "{value}");
+        }
+    }
+
+    #[test]
+    fn test_parse_ranges() {
+        assert_eq!(parse_reals("2.5"), Some(vec![2.5]));
+        assert_eq!(parse_reals("0:1:0.5,-3"), Some(vec![0.0, 0.5, 1.0, -3.0]));
+
+        // The stop is included despite 0.1 not being exact
+        assert_eq!(parse_reals("0:1:0.1").unwrap().len(), 11);
+        assert_eq!(parse_reals("0:0.95:0.1").unwrap().len(), 10);
+
+        for value in [
+            "1:0:0.5",
+            "0:1:0",
+            "0:1:-1",
+            "0:1",
+            "0:1:1:1",
+            "0:1:1e-12",
+            "",
+        ] {
+            assert_eq!(parse_reals(value), None, "{value}");
+        }
+
+        assert_eq!(
+            parse_complexes("1i:3+1i:1,2@0"),
+            Some(vec![
+                Complex::new(0.0, 1.0),
+                Complex::new(1.0, 1.0),
+                Complex::new(2.0, 1.0),
+                Complex::new(3.0, 1.0),
+                Complex::new(2.0, 0.0),
+            ])
+        );
+        assert_eq!(
+            parse_complexes("1+i:1+i:1"),
+            Some(vec![Complex::new(1.0, 1.0)])
+        );
+        assert_eq!(parse_complexes("0:1:0"), None);
+    }
+}
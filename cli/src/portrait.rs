@@ -0,0 +1,286 @@
+// Phase portraits of the functions over a rectangle of the complex plane, by domain coloring, e.g.,
+//
+//     amos portrait hankel1 --nu 0.5 --re -5,5 --im -5,5 --size 800,800 --out hankel1.png
+//
+// The hue is the argument of the value, with red for positive real values, through yellow, green,
+// cyan and blue, and the lightness increases with log10 of the magnitude, from black at zeros to
+// white at poles and overflow, where magnitudes of 1 have the full colors. Branch cuts thus show as
+// jumps in the hue, and the exponential growth of, e.g., iv as a fade to white. Values that are not
+// finite, where AMOS did no computation, are gray, and the points where the routine computing the
+// value reports IERR != 0, see functions.rs, are hatched with black diagonal lines. The image is
+// written as PNG or PPM by the extension of the output file, see image.rs.
+use crate::functions::Function;
+use crate::image::{self, Rgb};
+use crate::parse;
+use num::complex::Complex;
+use std::f64::consts::PI;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::PathBuf;
+
+const NOT_FINITE: Rgb = [128, 128, 128];
+const HATCH: Rgb = [0, 0, 0];
+
+// Every fourth diagonal, two pixels wide
+const HATCH_PERIOD: usize = 8;
+const HATCH_WIDTH: usize = 2;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Format {
+    Png,
+    Ppm,
+}
+
+pub struct Options {
+    pub function: Function,
+    pub nu: f64,
+    // 2 for the exponentially scaled functions
+    pub kode: i32,
+    pub re: (f64, f64),
+    pub im: (f64, f64),
+    pub width: usize,
+    pub height: usize,
+    pub out: PathBuf,
+    pub format: Format,
+}
+
+fn parse_interval(value: &str) -> Option<(f64, f64)> {
+    match parse::parse_reals(value)?.as_slice() {
+        &[min, max] if min < max => Some((min, max)),
+        _ => None,
+    }
+}
+
+fn parse_size(value: &str) -> Option<(usize, usize)> {
+    let size: Vec<usize> = value
+        .split(',')
+        .map(|v| v.trim().parse().ok().filter(|&n| (1..=10000).contains(&n)))
+        .collect::<Option<_>>()?;
+
+    match size.as_slice() {
+        [n] => Some((*n, *n)),
+        [width, height] => Some((*width, *height)),
+        _ => None,
+    }
+}
+
+pub fn parse_args(args: &[String]) -> Option<Options> {
+    let mut args = args.iter();
+    let function = Function::find(args.next()?)?;
+
+    let mut options = Options {
+        function,
+        nu: 0.0,
+        kode: 1,
+        re: (-5.0, 5.0),
+        im: (-5.0, 5.0),
+        width: 400,
+        height: 400,
+        out: PathBuf::from(format!("{}.png", function.name())),
+        format: Format::Png,
+    };
+    let mut nu = None;
+
+    while let Some(arg) = args.next() {
+        if arg == "--scaled" {
+            options.kode = 2;
+            continue;
+        }
+
+        let value = args.next()?;
+
+        match arg.as_str() {
+            "--nu" => nu = Some(parse::parse_real(value)?),
+            "--re" => options.re = parse_interval(value)?,
+            "--im" => options.im = parse_interval(value)?,
+            "--size" => (options.width, options.height) = parse_size(value)?,
+            "--out" => options.out = PathBuf::from(value),
+            _ => return None,
+        }
+    }
+
+    // The order is required exactly for the functions that have one
+    match (function.has_order(), nu) {
+        (true, Some(nu)) => options.nu = nu,
+        (false, None) => {}
+        _ => return None,
+    }
+
+    options.format = match options.out.extension()?.to_str()? {
+        "png" => Format::Png,
+        "ppm" => Format::Ppm,
+        _ => return None,
+    };
+
+    Some(options)
+}
+
+// Hue, saturation and lightness to RGB, with the hue in [0, 1)
+fn hsl(h: f64, s: f64, l: f64) -> Rgb {
+    let c = (1.0 - (2.0 * l - 1.0).abs()) * s;
+    let h = 6.0 * h.rem_euclid(1.0);
+    let x = c * (1.0 - (h % 2.0 - 1.0).abs());
+
+    let (r, g, b) = match h as usize {
+        0 => (c, x, 0.0),
+        1 => (x, c, 0.0),
+        2 => (0.0, c, x),
+        3 => (0.0, x, c),
+        4 => (x, 0.0, c),
+        _ => (c, 0.0, x),
+    };
+
+    let m = l - c / 2.0;
+
+    [r, g, b].map(|v| ((v + m) * 255.0).round().clamp(0.0, 255.0) as u8)
+}
+
+pub fn color(value: Complex<f64>) -> Rgb {
+    if value.is_nan() {
+        return NOT_FINITE;
+    }
+
+    // Infinite values are white, as are finite values of large magnitude
+    let lightness = match value.is_infinite() {
+        true => 1.0,
+        false => 0.5 + value.norm().log10().atan() / PI,
+    };
+
+    let hue = match value.is_infinite() {
+        true => 0.0,
+        false => value.arg() / (2.0 * PI),
+    };
+
+    hsl(hue, 1.0, lightness)
+}
+
+// The argument at the center of pixel (i, j), where row 0 is the top, i.e., the largest Im z
+fn point(options: &Options, i: usize, j: usize) -> Complex<f64> {
+    let (re, im) = (options.re, options.im);
+
+    Complex::new(
+        re.0 + (j as f64 + 0.5) / options.width as f64 * (re.1 - re.0),
+        im.1 - (i as f64 + 0.5) / options.height as f64 * (im.1 - im.0),
+    )
+}
+
+// The pixels, and the number of points where AMOS reports an error
+pub fn render(options: &Options) -> (Vec<Rgb>, usize) {
+    let mut pixels = Vec::with_capacity(options.width * options.height);
+    let mut errors = 0;
+
+    for i in 0..options.height {
+        for j in 0..options.width {
+            let z = point(options, i, j);
+            let (value, codes) = options.function.evaluate(options.nu, z, options.kode);
+            let ierr = codes.ierr;
+
+            let hatched = ierr != 0 && (i + j) % HATCH_PERIOD < HATCH_WIDTH;
+
+            if ierr != 0 {
+                errors += 1;
+            }
+
+            pixels.push(match hatched {
+                true => HATCH,
+                false => color(value),
+            });
+        }
+    }
+
+    (pixels, errors)
+}
+
+pub fn write(options: &Options) -> io::Result<usize> {
+    let (pixels, errors) = render(options);
+    let mut out = BufWriter::new(File::create(&options.out)?);
+    let (width, height) = (options.width, options.height);
+
+    match options.format {
+        Format::Png => image::write_png(&mut out, width, height, &pixels)?,
+        Format::Ppm => image::write_ppm(&mut out, width, height, &pixels)?,
+    }
+
+    out.flush()?;
+
+    Ok(errors)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_args() {
+        let options = parse_args(&args(&[
+            "iv", "--nu", "1.5", "--re", "-1,3", "--size", "30,20", "--scaled", "--out", "iv.ppm",
+        ]))
+        .unwrap();
+
+        assert_eq!(options.function, Function::Iv);
+        assert_eq!(options.nu, 1.5);
+        assert_eq!(options.kode, 2);
+        assert_eq!(options.re, (-1.0, 3.0));
+        assert_eq!(options.im, (-5.0, 5.0));
+        assert_eq!((options.width, options.height), (30, 20));
+        assert_eq!(options.format, Format::Ppm);
+
+        let options = parse_args(&args(&["bi"])).unwrap();
+
+        assert_eq!(options.out, PathBuf::from("bi.png"));
+        assert_eq!(options.format, Format::Png);
+
+        assert!(parse_args(&args(&["iv"])).is_none());
+        assert!(parse_args(&args(&["ai", "--nu", "1"])).is_none());
+        assert!(parse_args(&args(&["iv", "--nu", "1", "--re", "1,-1"])).is_none());
+        assert!(parse_args(&args(&["iv", "--nu", "1", "--size", "0"])).is_none());
+        assert!(parse_args(&args(&["iv", "--nu", "1", "--out", "iv.jpg"])).is_none());
+    }
+
+    #[test]
+    fn test_color() {
+        // Red, yellow, green, cyan, blue and magenta at magnitude 1
+        assert_eq!(color(Complex::new(1.0, 0.0)), [255, 0, 0]);
+        assert_eq!(color(Complex::from_polar(1.0, PI / 3.0)), [255, 255, 0]);
+        assert_eq!(color(Complex::from_polar(1.0, 2.0 * PI / 3.0)), [0, 255, 0]);
+        assert_eq!(color(Complex::new(-1.0, 0.0)), [0, 255, 255]);
+        assert_eq!(
+            color(Complex::from_polar(1.0, -2.0 * PI / 3.0)),
+            [0, 0, 255]
+        );
+        assert_eq!(color(Complex::from_polar(1.0, -PI / 3.0)), [255, 0, 255]);
+
+        assert_eq!(color(Complex::new(0.0, 0.0)), [0, 0, 0]);
+        assert_eq!(color(Complex::new(f64::INFINITY, 0.0)), [255, 255, 255]);
+        assert_eq!(color(Complex::new(f64::NAN, 0.0)), NOT_FINITE);
+
+        // Lighter with the magnitude
+        let [r, g, b] = color(Complex::new(10.0, 0.0));
+        assert_eq!((r, g, b), (255, 128, 128));
+    }
+
+    #[test]
+    fn test_render() {
+        let options = Options {
+            re: (0.0, 800.0),
+            im: (-1.0, 1.0),
+            width: 8,
+            height: 2,
+            ..parse_args(&args(&["iv", "--nu", "0"])).unwrap()
+        };
+        let (pixels, errors) = render(&options);
+
+        assert_eq!(point(&options, 0, 0), Complex::new(50.0, 0.5));
+        assert_eq!(pixels.len(), 16);
+
+        // I overflows for Re z > 700, which is the last column, where pixel (1, 7) is on a hatch
+        assert_eq!(errors, 2);
+        assert_eq!(pixels[7], NOT_FINITE);
+        assert_eq!(pixels[15], HATCH);
+        assert!(pixels[..7].iter().all(|&pixel| pixel != NOT_FINITE));
+    }
+}
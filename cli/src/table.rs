@@ -0,0 +1,331 @@
+// Printed tables in the style of Abramowitz and Stegun, chapter 9, of the functions of a real
+// argument x over a grid, with a column per function and order, e.g.,
+//
+//     amos table --x 0:5:0.1 --nu 0,1,2 --functions iv,ive,kv,kve --digits 10 --format latex
+//
+// The auxiliary columns ive and kve are e^{-x} I_nu(x) and e^{x} K_nu(x), computed by the scaled
+// wrappers rather than by scaling I and K, so that they stay finite where I overflows and K
+// underflows. For x < 0 ive is scaled by e^{-|x|}, see src/zbesi.rs of amos-rs. The values are
+// rounded to the given number of significant digits, and printed as fixed point numbers in [0.1, 10),
+// and otherwise as a mantissa in [1, 10) preceded by the power of ten in parentheses, e.g., (-3)1.234
+// for 1.234e-3, as in the printed tables. The x column has as many decimals as the grid needs.
+use crate::parse;
+use amos_rs::{zbesi, zbesj, zbesk, zbesy};
+use std::io::{self, Write};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Format {
+    Latex,
+    Markdown,
+    Csv,
+}
+
+#[derive(Clone, Copy)]
+pub struct Column {
+    pub name: &'static str,
+    // The header of order nu is {scaling} {symbol}_{nu}(x)
+    symbol: &'static str,
+    scaling: &'static str,
+    f: fn(f64, f64) -> f64,
+}
+
+pub const COLUMNS: [Column; 6] = [
+    Column::new("iv", "I", "", zbesi::iv_real),
+    Column::new("ive", "I", "e^{-x}", zbesi::ive_real),
+    Column::new("kv", "K", "", zbesk::kv_real),
+    Column::new("kve", "K", "e^{x}", zbesk::kve_real),
+    Column::new("jv", "J", "", zbesj::jv_real),
+    Column::new("yv", "Y", "", zbesy::yv_real),
+];
+
+impl Column {
+    const fn new(
+        name: &'static str,
+        symbol: &'static str,
+        scaling: &'static str,
+        f: fn(f64, f64) -> f64,
+    ) -> Self {
+        Self {
+            name,
+            symbol,
+            scaling,
+            f,
+        }
+    }
+
+    pub fn find(name: &str) -> Option<Self> {
+        COLUMNS.iter().copied().find(|column| column.name == name)
+    }
+
+    fn header(&self, nu: f64) -> String {
+        match self.scaling {
+            "" => format!("{}_{{{nu}}}(x)", self.symbol),
+            scaling => format!("{scaling} {}_{{{nu}}}(x)", self.symbol),
+        }
+    }
+}
+
+pub struct Options {
+    pub columns: Vec<Column>,
+    pub orders: Vec<f64>,
+    pub x: Vec<f64>,
+    pub digits: usize,
+    pub format: Format,
+}
+
+pub fn parse_args(args: &[String]) -> Option<Options> {
+    let mut options = Options {
+        columns: ["iv", "kv", "jv", "yv"]
+            .iter()
+            .map(|name| Column::find(name).unwrap())
+            .collect(),
+        orders: vec![0.0, 1.0],
+        x: Vec::new(),
+        digits: 10,
+        format: Format::Markdown,
+    };
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        let value = args.next()?;
+
+        match arg.as_str() {
+            "--functions" => {
+                options.columns = value
+                    .split(',')
+                    .map(|name| Column::find(name.trim()))
+                    .collect::<Option<_>>()?
+            }
+            "--nu" => options.orders = parse::parse_reals(value)?,
+            "--x" => options.x = parse::parse_reals(value)?,
+            // The doubles hold 17 significant digits
+            "--digits" => options.digits = value.parse().ok().filter(|d| (1..=17).contains(d))?,
+            "--format" => {
+                options.format = match value.as_str() {
+                    "latex" => Format::Latex,
+                    "markdown" => Format::Markdown,
+                    "csv" => Format::Csv,
+                    _ => return None,
+                }
+            }
+            _ => return None,
+        }
+    }
+
+    match options.x.is_empty() {
+        true => None,
+        false => Some(options),
+    }
+}
+
+// The mantissa and the power of ten of x rounded to the given significant digits, where the
+// rounding may carry into the exponent, e.g., 9.99996 to 1.0000e1
+fn scientific(x: f64, digits: usize) -> (String, i32) {
+    let s = format!("{:.*e}", digits - 1, x);
+    let (mantissa, exponent) = s.split_once('e').unwrap();
+
+    (mantissa.to_string(), exponent.parse().unwrap())
+}
+
+fn format_value(x: f64, digits: usize, format: Format) -> String {
+    if !x.is_finite() {
+        return format!("{x}");
+    }
+
+    let (mantissa, exponent) = scientific(x, digits);
+
+    match (format, x != 0.0 && !(-1..=0).contains(&exponent)) {
+        (Format::Csv, _) => format!("{mantissa}e{exponent}"),
+        (_, false) => format!("{:.*}", (digits as i32 - 1 - exponent).max(0) as usize, x),
+        (Format::Latex, true) => format!("$({exponent})\\,{mantissa}$"),
+        (Format::Markdown, true) => format!("({exponent}){mantissa}"),
+    }
+}
+
+// The fewest decimals, up to 12, that represent every x of the grid, e.g., 1 for 0:5:0.1 despite
+// the rounding errors of the range
+fn decimals(x: &[f64]) -> usize {
+    (0..12)
+        .find(|&d| {
+            let scale = 10.0_f64.powi(d as i32);
+
+            x.iter()
+                .all(|&x| ((x * scale).round() / scale - x).abs() <= 1e-9 * x.abs().max(1.0))
+        })
+        .unwrap_or(12)
+}
+
+pub fn write(out: &mut impl Write, options: &Options) -> io::Result<()> {
+    let format = options.format;
+    let d = decimals(&options.x);
+
+    let columns: Vec<(Column, f64)> = options
+        .columns
+        .iter()
+        .flat_map(|&column| options.orders.iter().map(move |&nu| (column, nu)))
+        .collect();
+
+    let header: Vec<String> = match format {
+        Format::Csv => ["x".to_string()]
+            .into_iter()
+            .chain(
+                columns
+                    .iter()
+                    .map(|(column, nu)| format!("{}_{nu}", column.name)),
+            )
+            .collect(),
+        _ => ["$x$".to_string()]
+            .into_iter()
+            .chain(
+                columns
+                    .iter()
+                    .map(|(column, nu)| format!("${}$", column.header(*nu))),
+            )
+            .collect(),
+    };
+
+    let rows: Vec<Vec<String>> =
+        options
+            .x
+            .iter()
+            .map(|&x| {
+                [format!("{x:.d$}")]
+                    .into_iter()
+                    .chain(columns.iter().map(|(column, nu)| {
+                        format_value((column.f)(*nu, x), options.digits, format)
+                    }))
+                    .collect()
+            })
+            .collect();
+
+    match format {
+        Format::Csv => {
+            writeln!(out, "{}", header.join(","))?;
+
+            for row in &rows {
+                writeln!(out, "{}", row.join(","))?;
+            }
+        }
+        Format::Markdown => {
+            writeln!(out, "| {} |", header.join(" | "))?;
+            writeln!(out, "|{}", " --: |".repeat(header.len()))?;
+
+            for row in &rows {
+                writeln!(out, "| {} |", row.join(" | "))?;
+            }
+        }
+        Format::Latex => {
+            writeln!(out, "\\begin{{tabular}}{{{}}}", "r".repeat(header.len()))?;
+            writeln!(out, "\\hline")?;
+            writeln!(out, "{} \\\\", header.join(" & "))?;
+            writeln!(out, "\\hline")?;
+
+            for row in &rows {
+                writeln!(out, "{} \\\\", row.join(" & "))?;
+            }
+
+            writeln!(out, "\\hline")?;
+            writeln!(out, "\\end{{tabular}}")?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    fn written(args_: &[&str]) -> String {
+        let mut out = Vec::new();
+        write(&mut out, &parse_args(&args(args_)).unwrap()).unwrap();
+
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_format_value() {
+        // e^{-1} I_0(1), I_0(1), K_0(10) and I_0(10)
+        assert_eq!(
+            format_value(zbesi::ive_real(0.0, 1.0), 10, Format::Markdown),
+            "0.4657596076"
+        );
+        assert_eq!(
+            format_value(zbesi::iv_real(0.0, 1.0), 10, Format::Markdown),
+            "1.266065878"
+        );
+        assert_eq!(
+            format_value(zbesk::kv_real(0.0, 10.0), 10, Format::Markdown),
+            "(-5)1.778006232"
+        );
+        assert_eq!(
+            format_value(zbesk::kv_real(0.0, 10.0), 10, Format::Csv),
+            "1.778006232e-5"
+        );
+        assert_eq!(
+            format_value(zbesi::iv_real(0.0, 10.0), 6, Format::Latex),
+            "$(3)\\,2.81572$"
+        );
+
+        // The rounding carries into the exponent
+        assert_eq!(format_value(9.99996, 4, Format::Markdown), "(1)1.000");
+        assert_eq!(format_value(0.099996, 4, Format::Markdown), "0.1000");
+        assert_eq!(format_value(-0.5, 3, Format::Markdown), "-0.500");
+        assert_eq!(format_value(0.0, 3, Format::Markdown), "0.00");
+        assert_eq!(format_value(f64::NAN, 3, Format::Latex), "NaN");
+    }
+
+    #[test]
+    fn test_decimals() {
+        assert_eq!(decimals(&parse::parse_reals("0:5:0.1").unwrap()), 1);
+        assert_eq!(decimals(&[0.0, 2.0, 100.0]), 0);
+        assert_eq!(decimals(&[0.125]), 3);
+    }
+
+    #[test]
+    fn test_write() {
+        assert_eq!(
+            written(&[
+                "--x",
+                "1:2:1",
+                "--nu",
+                "0",
+                "--functions",
+                "ive,kve",
+                "--digits",
+                "4"
+            ]),
+            "| $x$ | $e^{-x} I_{0}(x)$ | $e^{x} K_{0}(x)$ |\n\
+             | --: | --: | --: |\n\
+             | 1 | 0.4658 | 1.144 |\n\
+             | 2 | 0.3085 | 0.8416 |\n"
+        );
+        assert_eq!(
+            written(&[
+                "--x",
+                "0.5",
+                "--nu",
+                "1",
+                "--functions",
+                "jv",
+                "--format",
+                "csv"
+            ]),
+            "x,jv_1\n0.5,2.422684577e-1\n"
+        );
+
+        let latex = written(&["--x", "0:1:0.5", "--format", "latex"]);
+
+        assert!(latex.starts_with("\\begin{tabular}{rrrrrrrrr}\n\\hline\n$x$ & $I_{0}(x)$ & "));
+        assert!(latex.contains("\n0.5 & "));
+        assert!(latex.ends_with(" \\\\\n\\hline\n\\end{tabular}\n"));
+
+        assert!(parse_args(&args(&["--nu", "0"])).is_none());
+        assert!(parse_args(&args(&["--x", "1", "--functions", "hankel1"])).is_none());
+        assert!(parse_args(&args(&["--x", "1", "--digits", "18"])).is_none());
+    }
+}
@@ -0,0 +1,199 @@
+"""Reference values computed with mpmath, independently of AMOS. Without arguments, mpmath.csv is
+written for a structured grid of orders and complex arguments. The arguments are rounded to doubles
+before the functions are evaluated, and the values are then correctly rounded to doubles, so that
+the only error measured by tests/test_data.rs and tests/test_mpmath.rs is that of the crate.
+
+With the arguments POINTS REFERENCE, the values are computed for the points written by
+`cargo xtask points`, and written to REFERENCE for `cargo xtask test-data --reference`, see
+xtask/src/main.rs.
+"""
+
+import csv
+import sys
+
+import mpmath
+
+from test_data import row, save
+
+
+# Evaluates at 50 digits and again at twice the precision, which is doubled until the two agree to
+# 40 digits. mpmath does not always detect cancellation, e.g., in hankel1 = besselj + i bessely in
+# the upper half plane, where the terms grow as exp(|z|) while the sum decays.
+def evaluate(func, *args):
+    dps = 50
+
+    while True:
+        with mpmath.workdps(dps):
+            cy = mpmath.mpc(func(*args))
+
+        with mpmath.workdps(2 * dps):
+            cy_2 = mpmath.mpc(func(*args))
+
+            if abs(cy - cy_2) <= mpmath.mpf(10) ** -40 * abs(cy_2):
+                return cy_2
+
+        dps *= 2
+
+
+# Both integer and fractional orders, where negative orders are handled by reflection in the Rust
+# wrappers
+orders = [0, 0.5, 1, 2.3, 7.5, 25, 60.2, -0.7, -1, -3.3, -12.5]
+
+# Moduli spanning the power series, the uniform asymptotic expansions for large orders and the
+# asymptotic expansions for large arguments
+moduli = [0.05, 0.5, 1.5, 4, 10, 30, 80]
+airy_moduli = [0.05, 0.5, 1.5, 4, 10, 30]
+
+# Arguments k pi / 8 for k = -7, ..., 8, including the negative real axis at the top of the cut
+angles = range(-7, 9)
+
+
+def arguments(moduli):
+    for r in moduli:
+        for k in angles:
+            zr = float(r * mpmath.cospi(mpmath.mpf(k) / 8))
+            zi = float(r * mpmath.sinpi(mpmath.mpf(k) / 8))
+
+            yield zr, zi, mpmath.mpc(zr, zi)
+
+
+# The tolerances per region, which must match BESSEL_TOLERANCES and AIRY_TOLERANCES in
+# tests/test_mpmath.rs
+def bessel_tolerance(nu, z):
+    if abs(z) <= 1:
+        return "6e-14"
+    elif abs(z) < abs(nu):
+        return "1.2e-13"
+    elif abs(z) <= 20:
+        return "8e-14"
+    else:
+        return "7e-14"
+
+
+def airy_tolerance(z):
+    if abs(z) <= 1:
+        return "2e-15"
+    elif abs(z) <= 5:
+        return "4e-13"
+    else:
+        return "5e-13"
+
+
+def bessel_rows(name, func):
+    for nu in orders:
+        for zr, zi, z in arguments(moduli):
+            cy = evaluate(func, nu, z)
+
+            yield row(name, nu, 0, complex(zr, zi), cy, bessel_tolerance(nu, z), "mpmath")
+
+
+def airy_rows(name, func, derivative):
+    for zr, zi, z in arguments(airy_moduli):
+        cy = evaluate(lambda z: func(z, derivative=derivative), z)
+
+        yield row(name, 0, 0, complex(zr, zi), cy, airy_tolerance(z), "mpmath")
+
+
+bessel = [
+    ("iv", mpmath.besseli),
+    ("kv", mpmath.besselk),
+    ("jv", mpmath.besselj),
+    ("yv", mpmath.bessely),
+    ("hankel1", mpmath.hankel1),
+    ("hankel2", mpmath.hankel2),
+]
+
+airy = [
+    ("ai", mpmath.airyai, 0),
+    ("aip", mpmath.airyai, 1),
+    ("bi", mpmath.airybi, 0),
+    ("bip", mpmath.airybi, 1),
+]
+
+
+def grid():
+    rows = [r for name, func in bessel for r in bessel_rows(name, func)]
+    rows += [
+        r for name, func, derivative in airy for r in airy_rows(name, func, derivative)
+    ]
+
+    save("mpmath.csv", rows)
+
+
+# The scaling factors of the functions ending in e, see src/zbesi.rs and the other wrappers
+def scaled(func, factor):
+    return lambda nu, z: func(nu, z) * factor(z)
+
+
+def airy_scaled(func, derivative, sign):
+    zeta = lambda z: mpmath.mpf(2) / 3 * z * mpmath.sqrt(z)
+
+    return lambda z: func(z, derivative=derivative) * mpmath.exp(sign(zeta(z)))
+
+
+functions = {
+    "iv": mpmath.besseli,
+    "ive": scaled(mpmath.besseli, lambda z: mpmath.exp(-abs(z.real))),
+    "jv": mpmath.besselj,
+    "jve": scaled(mpmath.besselj, lambda z: mpmath.exp(-abs(z.imag))),
+    "kv": mpmath.besselk,
+    "kve": scaled(mpmath.besselk, mpmath.exp),
+    "yv": mpmath.bessely,
+    "yve": scaled(mpmath.bessely, lambda z: mpmath.exp(-abs(z.imag))),
+    "hankel1": mpmath.hankel1,
+    "hankel1e": scaled(mpmath.hankel1, lambda z: mpmath.exp(-1j * z)),
+    "hankel2": mpmath.hankel2,
+    "hankel2e": scaled(mpmath.hankel2, lambda z: mpmath.exp(1j * z)),
+}
+
+# The functions of real arguments are the real parts of those of complex arguments
+functions.update({f"{name}_real": functions[name] for name in list(functions)[:8]})
+
+airy_functions = {
+    "ai": lambda z: mpmath.airyai(z),
+    "aie": airy_scaled(mpmath.airyai, 0, lambda zeta: zeta),
+    "aip": lambda z: mpmath.airyai(z, derivative=1),
+    "aipe": airy_scaled(mpmath.airyai, 1, lambda zeta: zeta),
+    "bi": lambda z: mpmath.airybi(z),
+    "bie": airy_scaled(mpmath.airybi, 0, lambda zeta: -abs(zeta.real)),
+    "bip": lambda z: mpmath.airybi(z, derivative=1),
+    "bipe": airy_scaled(mpmath.airybi, 1, lambda zeta: -abs(zeta.real)),
+}
+
+
+def reference(points_file, reference_file):
+    with open(points_file) as f:
+        points = list(csv.DictReader(f))
+
+    with open(reference_file, "w") as f:
+        f.write("function,nu,n_index,z_re,z_im,expected_re,expected_im,source\n")
+
+        for point in points:
+            name = point["function"]
+            nu, n_index = float(point["nu"]), int(point["n_index"])
+            z = mpmath.mpc(float(point["z_re"]), float(point["z_im"]))
+
+            # The member n_index of the sequence starting at order nu
+            order = nu + n_index if nu >= 0 else nu - n_index
+
+            if name in airy_functions:
+                cy = evaluate(airy_functions[name], z)
+            else:
+                cy = evaluate(functions[name], order, z)
+
+            if name.endswith("_real"):
+                cy = mpmath.mpc(cy.real, 0)
+
+            # The points are written back as they are, such that they match exactly
+            columns = ["function", "nu", "n_index", "z_re", "z_im"]
+            f.write(
+                ",".join(point[c] for c in columns)
+                + f",{float(cy.real)!r},{float(cy.imag)!r},mpmath\n"
+            )
+
+
+if __name__ == "__main__":
+    if len(sys.argv) == 3:
+        reference(sys.argv[1], sys.argv[2])
+    else:
+        grid()
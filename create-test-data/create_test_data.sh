@@ -0,0 +1,47 @@
+#!/usr/bin/env bash
+# Regenerates the test data of each family in tests/dat from the points drawn by `cargo xtask
+# points`, see xtask/src/main.rs. The reference values are computed with mpmath, and taken from the
+# existing data files with --existing instead, which reproduces them without Python. Extra arguments
+# are passed on to cargo, e.g., --features c-backend.
+set -euo pipefail
+
+cd "$(dirname "$0")/.."
+
+existing=0
+
+if [ "${1:-}" = "--existing" ]; then
+    existing=1
+    shift
+fi
+
+work="$(mktemp -d)"
+trap 'rm -rf "$work"' EXIT
+
+# The data files with their functions and sampling options, where the unscaled Airy functions
+# overflow for large |z|
+families=(
+    "zbesi iv,ive,iv_real,ive_real"
+    "zbesj jv,jve,jv_real,jve_real"
+    "zbesk kv,kve,kv_real,kve_real"
+    "zbesy yv,yve,yv_real,yve_real"
+    "zbesh hankel1,hankel1e,hankel2,hankel2e"
+    "zairy ai,aie,aip,aipe,bi,bie,bip,bipe --large 0"
+)
+
+for family in "${families[@]}"; do
+    read -r name functions options <<< "$family"
+    echo "Generating tests/dat/$name.csv"
+
+    # shellcheck disable=SC2086
+    sampling=(--functions "$functions" $options)
+
+    if [ "$existing" -eq 1 ]; then
+        cp "tests/dat/$name.csv" "$work/$name.csv"
+    else
+        cargo run -q "$@" -p xtask -- points "${sampling[@]}" --out "$work/points.csv"
+        python3 create-test-data/create_mpmath_test_data.py "$work/points.csv" "$work/$name.csv"
+    fi
+
+    cargo run -q "$@" -p xtask -- test-data "${sampling[@]}" --reference "$work/$name.csv" \
+        --out "tests/dat/$name.csv"
+done
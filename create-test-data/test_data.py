@@ -0,0 +1,41 @@
+"""Writes the test data format read by tests/common/mod.rs, i.e., CSV files with the columns below
+and a row per reference value. The expected value is the member n_index of the sequence starting at
+order nu, i.e., of order nu + n_index, or nu - n_index for negative nu, and tolerance bounds the
+relative error of the crate.
+"""
+
+COLUMNS = [
+    "function",
+    "nu",
+    "n_index",
+    "z_re",
+    "z_im",
+    "expected_re",
+    "expected_im",
+    "tolerance",
+    "source",
+]
+
+
+def row(function, nu, n_index, z, expected, tolerance, source):
+    z, expected = complex(z), complex(expected)
+
+    return [
+        function,
+        f"{float(nu):.18e}",
+        str(n_index),
+        f"{z.real:.18e}",
+        f"{z.imag:.18e}",
+        f"{expected.real:.18e}",
+        f"{expected.imag:.18e}",
+        tolerance,
+        source,
+    ]
+
+
+def save(filename, rows):
+    with open(filename, "w") as f:
+        f.write(",".join(COLUMNS) + "\n")
+
+        for r in rows:
+            f.write(",".join(r) + "\n")
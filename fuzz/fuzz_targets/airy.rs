@@ -0,0 +1,25 @@
+// The Airy functions through zairy and zbiry and the named functions, which have no order
+#![no_main]
+
+use amos_fuzz::{assert_same, Input};
+use amos_rs::zairy::*;
+use libfuzzer_sys::fuzz_target;
+use num::complex::Complex;
+
+type Named = fn(Complex<f64>) -> Complex<f64>;
+
+// Indexed by id and kode - 1
+const AI: [[(&str, Named); 2]; 2] = [[("ai", ai), ("aie", aie)], [("aip", aip), ("aipe", aipe)]];
+const BI: [[(&str, Named); 2]; 2] = [[("bi", bi), ("bie", bie)], [("bip", bip), ("bipe", bipe)]];
+
+fuzz_target!(|input: Input| {
+    let (z, kode) = (input.z(), input.kode());
+
+    for id in 0..=1 {
+        let (name, named) = AI[id as usize][(kode - 1) as usize];
+        assert_same(name, &input, &[zairy(z, id, kode)], &[named(z)]);
+
+        let (name, named) = BI[id as usize][(kode - 1) as usize];
+        assert_same(name, &input, &[zbiry(z, id, kode)], &[named(z)]);
+    }
+});
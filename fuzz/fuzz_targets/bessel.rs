@@ -0,0 +1,122 @@
+// I, J, K and Y through the general functions, the named functions, the functions of real
+// arguments and the C API
+#![no_main]
+
+use amos_fuzz::{
+    assert_same, capi_scalar, capi_sequence, check_real, check_status, Input, Scalar, Sequence,
+};
+use amos_rs::capi::*;
+use amos_rs::{zbesi, zbesj, zbesk, zbesy};
+use libfuzzer_sys::fuzz_target;
+use num::complex::Complex;
+
+type General = fn(f64, Complex<f64>, i32) -> Complex<f64>;
+type GeneralVec = fn(f64, Complex<f64>, i32, i32) -> Vec<Complex<f64>>;
+type Named = fn(f64, Complex<f64>) -> Complex<f64>;
+type NamedVec = fn(f64, Complex<f64>, i32) -> Vec<Complex<f64>>;
+type RealVec = fn(f64, f64, i32) -> Vec<f64>;
+
+// The functions of a family, where the pairs are indexed by kode - 1
+struct Family {
+    name: &'static str,
+    general: General,
+    general_vec: GeneralVec,
+    scalar: [Named; 2],
+    vec: [NamedVec; 2],
+    real: [fn(f64, f64) -> f64; 2],
+    real_vec: [RealVec; 2],
+    capi: [Scalar; 2],
+    capi_seq: [Sequence; 2],
+}
+
+const FAMILIES: [Family; 4] = [
+    Family {
+        name: "iv",
+        general: zbesi::zbesi,
+        general_vec: zbesi::zbesi_vec,
+        scalar: [zbesi::iv, zbesi::ive],
+        vec: [zbesi::iv_vec, zbesi::ive_vec],
+        real: [zbesi::iv_real, zbesi::ive_real],
+        real_vec: [zbesi::iv_real_vec, zbesi::ive_real_vec],
+        capi: [amos_iv, amos_ive],
+        capi_seq: [amos_iv_seq, amos_ive_seq],
+    },
+    Family {
+        name: "jv",
+        general: zbesj::zbesj,
+        general_vec: zbesj::zbesj_vec,
+        scalar: [zbesj::jv, zbesj::jve],
+        vec: [zbesj::jv_vec, zbesj::jve_vec],
+        real: [zbesj::jv_real, zbesj::jve_real],
+        real_vec: [zbesj::jv_real_vec, zbesj::jve_real_vec],
+        capi: [amos_jv, amos_jve],
+        capi_seq: [amos_jv_seq, amos_jve_seq],
+    },
+    Family {
+        name: "kv",
+        general: zbesk::zbesk,
+        general_vec: zbesk::zbesk_vec,
+        scalar: [zbesk::kv, zbesk::kve],
+        vec: [zbesk::kv_vec, zbesk::kve_vec],
+        real: [zbesk::kv_real, zbesk::kve_real],
+        real_vec: [zbesk::kv_real_vec, zbesk::kve_real_vec],
+        capi: [amos_kv, amos_kve],
+        capi_seq: [amos_kv_seq, amos_kve_seq],
+    },
+    Family {
+        name: "yv",
+        general: zbesy::zbesy,
+        general_vec: zbesy::zbesy_vec,
+        scalar: [zbesy::yv, zbesy::yve],
+        vec: [zbesy::yv_vec, zbesy::yve_vec],
+        real: [zbesy::yv_real, zbesy::yve_real],
+        real_vec: [zbesy::yv_real_vec, zbesy::yve_real_vec],
+        capi: [amos_yv, amos_yve],
+        capi_seq: [amos_yv_seq, amos_yve_seq],
+    },
+];
+
+fuzz_target!(|input: Input| {
+    let (nu, z, kode, n) = (input.nu.0, input.z(), input.kode(), input.n());
+    let k = (kode - 1) as usize;
+
+    for family in &FAMILIES {
+        let name = family.name;
+
+        let cy = [(family.general)(nu, z, kode)];
+        let (status, cy_c) = capi_scalar(family.capi[k], nu, z);
+
+        assert_same(name, &input, &cy, &[(family.scalar[k])(nu, z)]);
+        assert_same(name, &input, &cy, &[cy_c]);
+        check_status(name, &input, status, &cy);
+
+        let cy = (family.general_vec)(nu, z, kode, n);
+        let (status, cy_c) = capi_sequence(family.capi_seq[k], nu, z, n);
+
+        assert_same(name, &input, &cy, &(family.vec[k])(nu, z, n));
+        assert_same(name, &input, &cy, &cy_c);
+        check_status(name, &input, status, &cy);
+
+        // The C API rejects n < 1, for which the Rust functions panic
+        if input.n_capi() < 1 {
+            let (status, _) = capi_sequence(family.capi_seq[k], nu, z, input.n_capi());
+
+            assert_eq!(status, AmosStatus::InputError, "{name}: {input:?}");
+        }
+
+        let x = Complex::new(input.re.0, 0.0);
+
+        check_real(
+            name,
+            &input,
+            &[(family.real[k])(nu, x.re)],
+            &[(family.general)(nu, x, kode)],
+        );
+        check_real(
+            name,
+            &input,
+            &(family.real_vec[k])(nu, x.re, n),
+            &(family.general_vec)(nu, x, kode, n),
+        );
+    }
+});
@@ -0,0 +1,19 @@
+// The Cephes functions of orders 0 and 1 and of integer order, where the latter call the AMOS
+// wrappers above order 1000
+#![no_main]
+
+use amos_fuzz::Input;
+use amos_rs::cephes::*;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: (Input, i32)| {
+    let (input, n) = input;
+    let x = input.re.0;
+
+    for f in [i0, i0e, i1, i1e, k0, k0e, k1, k1e, j0, j1, y0, y1] {
+        f(x);
+    }
+
+    jn(n, x);
+    yn(n, x);
+});
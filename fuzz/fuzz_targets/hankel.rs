@@ -0,0 +1,59 @@
+// The Hankel functions through zbesh, the named functions and the C API
+#![no_main]
+
+use amos_fuzz::{assert_same, capi_scalar, capi_sequence, check_status, Input, Scalar, Sequence};
+use amos_rs::capi::*;
+use amos_rs::zbesh;
+use libfuzzer_sys::fuzz_target;
+use num::complex::Complex;
+
+type Named = fn(f64, Complex<f64>, i32) -> Vec<Complex<f64>>;
+
+// Indexed by m - 1 and kode - 1
+const NAMED: [[(&str, Named, Scalar, Sequence); 2]; 2] = [
+    [
+        ("hankel1", zbesh::hankel1, amos_hankel1, amos_hankel1_seq),
+        (
+            "hankel1e",
+            zbesh::hankel1e,
+            amos_hankel1e,
+            amos_hankel1e_seq,
+        ),
+    ],
+    [
+        ("hankel2", zbesh::hankel2, amos_hankel2, amos_hankel2_seq),
+        (
+            "hankel2e",
+            zbesh::hankel2e,
+            amos_hankel2e,
+            amos_hankel2e_seq,
+        ),
+    ],
+];
+
+fuzz_target!(|input: Input| {
+    let (nu, z, kode, n) = (input.nu.0, input.z(), input.kode(), input.n());
+
+    for m in 1..=2 {
+        let (name, named, capi, capi_seq) = NAMED[(m - 1) as usize][(kode - 1) as usize];
+
+        let cy = zbesh::zbesh(nu, z, kode, m, n);
+        let (status, cy_c) = capi_sequence(capi_seq, nu, z, n);
+
+        assert_same(name, &input, &cy, &named(nu, z, n));
+        assert_same(name, &input, &cy, &cy_c);
+        check_status(name, &input, status, &cy);
+
+        // The first member is computed without allocating
+        let (status, cy_c) = capi_scalar(capi, nu, z);
+
+        assert_same(name, &input, &zbesh::zbesh(nu, z, kode, m, 1), &[cy_c]);
+        check_status(name, &input, status, &[cy_c]);
+
+        if input.n_capi() < 1 {
+            let (status, _) = capi_sequence(capi_seq, nu, z, input.n_capi());
+
+            assert_eq!(status, AmosStatus::InputError, "{name}: {input:?}");
+        }
+    }
+});
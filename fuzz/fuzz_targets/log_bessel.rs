@@ -0,0 +1,33 @@
+// The logarithms and ratios of the Bessel functions, which are NaN outside of their domains
+#![no_main]
+
+use amos_fuzz::{assert_same, Input};
+use amos_rs::bessel_ratio::*;
+use amos_rs::log_bessel::*;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: Input| {
+    let (nu, z, x) = (input.nu.0, input.z(), input.re.0);
+
+    assert_same(
+        "log_hankel1",
+        &input,
+        &[log_hankel(nu, z, 1)],
+        &[log_hankel1(nu, z)],
+    );
+    assert_same(
+        "log_hankel2",
+        &input,
+        &[log_hankel(nu, z, 2)],
+        &[log_hankel2(nu, z)],
+    );
+
+    log_iv(nu, x);
+    log_kv(nu, x);
+
+    // x is the ratio for the inverses
+    iv_ratio(nu, x);
+    kv_ratio(nu, x);
+    iv_ratio_inv(nu, x);
+    kv_ratio_inv(nu, x);
+});
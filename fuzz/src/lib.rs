@@ -0,0 +1,189 @@
+// Inputs and checks shared by the fuzz targets in fuzz_targets/, which feed arbitrary orders,
+// arguments, kode and n to every public function of amos-rs and fail on any panic or crash. They
+// are run with cargo-fuzz on a nightly toolchain from the repository root, e.g.,
+//
+//     cargo +nightly fuzz run bessel --features c-backend
+//
+// where the features select the AMOS backend as for amos-rs. cargo-fuzz builds with
+// AddressSanitizer, which also catches out of bounds accesses in the C translation of AMOS, where
+// Miri cannot follow the calls into foreign code. libFuzzer aborts on a panic, so the arguments
+// that the wrappers reject by design, i.e., kode and m other than 1 and 2 and n < 1, are only
+// passed to the C API, which reports them as an input error instead.
+use amos_rs::capi::{AmosComplex, AmosStatus};
+use arbitrary::{Arbitrary, Result, Unstructured};
+use num::complex::Complex;
+use std::os::raw::c_int;
+
+// Values that random bits hardly ever hit
+const SPECIAL: [f64; 22] = [
+    f64::NAN,
+    f64::INFINITY,
+    f64::NEG_INFINITY,
+    0.0,
+    -0.0,
+    // The smallest subnormal and normal numbers
+    5e-324,
+    -5e-324,
+    f64::MIN_POSITIVE,
+    f64::MAX,
+    f64::MIN,
+    f64::EPSILON,
+    1.0,
+    -1.0,
+    // exp overflows just above 709.78
+    709.0,
+    710.0,
+    -710.0,
+    // The limits of |z| and nu + n - 1 for IERR=3 and 4, and the largest integer, see
+    // amos/zbesi.f lines 92-105
+    1.3e8,
+    -1.3e8,
+    1.8e16,
+    -1.8e16,
+    2147483647.0,
+    2147483648.0,
+];
+
+// The sequences are limited to MAX_N members, as larger n only makes the allocations larger
+pub const MAX_N: i32 = 1 << 16;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Float(pub f64);
+
+impl<'a> Arbitrary<'a> for Float {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let value = match u.int_in_range(0..=3)? {
+            0 => *u.choose(&SPECIAL)?,
+            // Integers and half-integers, which the reflection formulas for negative orders treat
+            // separately
+            1 => u.int_in_range(-200..=200)? as f64 / 2.0,
+            // Moderate values, where most of AMOS is exercised
+            2 => u.int_in_range(-(1 << 20)..=1 << 20)? as f64 / 1024.0,
+            _ => f64::from_bits(u.arbitrary()?),
+        };
+
+        Ok(Float(value))
+    }
+}
+
+// The number of members of a sequence, which is below 1 for the input errors of the C API
+#[derive(Clone, Copy, Debug)]
+pub struct Count(pub c_int);
+
+impl<'a> Arbitrary<'a> for Count {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let n = match u.int_in_range(0..=2)? {
+            0 => u.int_in_range(1..=4)?,
+            1 => u.int_in_range(1..=MAX_N)?,
+            _ => *u.choose(&[0, -1, c_int::MIN])?,
+        };
+
+        Ok(Count(n))
+    }
+}
+
+#[derive(Debug, Arbitrary)]
+pub struct Input {
+    pub nu: Float,
+    pub re: Float,
+    pub im: Float,
+    scaled: bool,
+    count: Count,
+}
+
+impl Input {
+    pub fn z(&self) -> Complex<f64> {
+        Complex::new(self.re.0, self.im.0)
+    }
+
+    pub fn kode(&self) -> i32 {
+        match self.scaled {
+            true => 2,
+            false => 1,
+        }
+    }
+
+    // n as passed to the C API, which may be invalid
+    pub fn n_capi(&self) -> c_int {
+        self.count.0
+    }
+
+    // n as passed to the Rust functions, which panic for n < 1
+    pub fn n(&self) -> i32 {
+        self.count.0.max(1)
+    }
+}
+
+pub type Scalar = unsafe extern "C" fn(f64, AmosComplex, *mut AmosComplex) -> AmosStatus;
+pub type Sequence = unsafe extern "C" fn(f64, AmosComplex, c_int, *mut AmosComplex) -> AmosStatus;
+
+// The output is initialized to NaN, which the C API leaves as is for its own input errors
+pub fn capi_scalar(f: Scalar, nu: f64, z: Complex<f64>) -> (AmosStatus, Complex<f64>) {
+    let mut cy = AmosComplex {
+        re: f64::NAN,
+        im: f64::NAN,
+    };
+
+    let status = unsafe { f(nu, z.into(), &mut cy) };
+
+    (status, cy.into())
+}
+
+pub fn capi_sequence(
+    f: Sequence,
+    nu: f64,
+    z: Complex<f64>,
+    n: c_int,
+) -> (AmosStatus, Vec<Complex<f64>>) {
+    let nan = AmosComplex {
+        re: f64::NAN,
+        im: f64::NAN,
+    };
+    let mut cy = vec![nan; n.max(1) as usize];
+
+    let status = unsafe { f(nu, z.into(), n, cy.as_mut_ptr()) };
+
+    (status, cy.into_iter().map(Complex::from).collect())
+}
+
+// The results are NaN exactly when AMOS did no computation, see no_computation_done in
+// src/amos_errors.rs, whereas the computed results may still be infinite
+pub fn check_status(name: &str, input: &Input, status: AmosStatus, cy: &[Complex<f64>]) {
+    let computed = matches!(status, AmosStatus::Ok | AmosStatus::LossOfSignificance);
+
+    for cy in cy {
+        let consistent = match computed {
+            true => !cy.is_nan(),
+            false => cy.re.is_nan() && cy.im.is_nan(),
+        };
+
+        assert!(consistent, "{name}: {cy} with {status:?} for {input:?}");
+    }
+}
+
+// The Rust functions and the C API compute the same values, so NaN is compared as equal
+pub fn assert_same(name: &str, input: &Input, a: &[Complex<f64>], b: &[Complex<f64>]) {
+    assert_eq!(a.len(), b.len(), "{name}: lengths differ for {input:?}");
+
+    for (a, b) in a.iter().zip(b) {
+        let same = |a: f64, b: f64| a == b || (a.is_nan() && b.is_nan());
+
+        assert!(
+            same(a.re, b.re) && same(a.im, b.im),
+            "{name}: {a} and {b} differ for {input:?}"
+        );
+    }
+}
+
+// The functions of real arguments return NaN where the result is complex, and the real part of the
+// complex function otherwise
+pub fn check_real(name: &str, input: &Input, real: &[f64], cy: &[Complex<f64>]) {
+    assert_eq!(real.len(), cy.len(), "{name}: lengths differ for {input:?}");
+
+    for (&real, cy) in real.iter().zip(cy) {
+        assert!(
+            real.is_nan() || real == cy.re,
+            "{name}: {real} is not the real part of {cy} for {input:?}"
+        );
+    }
+}
@@ -0,0 +1,87 @@
+"""Bessel functions of complex argument from amos-rs.
+
+The functions follow their scipy.special counterparts: the arguments are broadcast against each
+other, real arguments give real results, with NaN where the result would be complex, and scalar
+arguments give scalar results.
+"""
+
+import numpy as np
+
+from . import _amos
+
+__all__ = [
+    "iv",
+    "ive",
+    "jv",
+    "jve",
+    "kv",
+    "kve",
+    "yv",
+    "yve",
+    "hankel1",
+    "hankel1e",
+    "hankel2",
+    "hankel2e",
+]
+
+
+def _apply(real, complex_, v, z):
+    v, z = np.broadcast_arrays(np.asarray(v, dtype=np.float64), np.asarray(z))
+    shape = v.shape
+    v = np.ravel(v)
+
+    if real is None or np.iscomplexobj(z):
+        out = complex_(v, np.ravel(z).astype(np.complex128))
+    else:
+        out = real(v, np.ravel(z).astype(np.float64))
+
+    return out.reshape(shape)[()]
+
+
+def iv(v, z):
+    return _apply(_amos.iv_real, _amos.iv, v, z)
+
+
+def ive(v, z):
+    return _apply(_amos.ive_real, _amos.ive, v, z)
+
+
+def jv(v, z):
+    return _apply(_amos.jv_real, _amos.jv, v, z)
+
+
+def jve(v, z):
+    return _apply(_amos.jve_real, _amos.jve, v, z)
+
+
+def kv(v, z):
+    return _apply(_amos.kv_real, _amos.kv, v, z)
+
+
+def kve(v, z):
+    return _apply(_amos.kve_real, _amos.kve, v, z)
+
+
+def yv(v, z):
+    return _apply(_amos.yv_real, _amos.yv, v, z)
+
+
+def yve(v, z):
+    return _apply(_amos.yve_real, _amos.yve, v, z)
+
+
+# The Hankel functions are complex also for real arguments
+def hankel1(v, z):
+    return _apply(None, _amos.hankel1, v, z)
+
+
+def hankel1e(v, z):
+    return _apply(None, _amos.hankel1e, v, z)
+
+
+def hankel2(v, z):
+    return _apply(None, _amos.hankel2, v, z)
+
+
+def hankel2e(v, z):
+    return _apply(None, _amos.hankel2e, v, z)
@@ -0,0 +1,247 @@
+// Elementwise kernels over one-dimensional arrays of equal length, exported as amos_rs._amos. The
+// broadcasting of the arguments and the choice between the real and complex functions, following
+// scipy.special, is done in amos_rs/__init__.py. The GIL is released while computing.
+use amos_rs::{zbesh, zbesi, zbesj, zbesk, zbesy};
+use numpy::{Complex64, IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+fn map_real<'py>(
+    py: Python<'py>,
+    v: PyReadonlyArray1<'py, f64>,
+    x: PyReadonlyArray1<'py, f64>,
+    f: fn(f64, f64) -> f64,
+) -> PyResult<Bound<'py, PyArray1<f64>>> {
+    let (v, x) = (v.as_array(), x.as_array());
+
+    if v.len() != x.len() {
+        return Err(PyValueError::new_err("v and x must have the same length"));
+    }
+
+    let cy: Vec<f64> = py.detach(|| v.iter().zip(x.iter()).map(|(&v, &x)| f(v, x)).collect());
+
+    Ok(cy.into_pyarray(py))
+}
+
+fn map_complex<'py>(
+    py: Python<'py>,
+    v: PyReadonlyArray1<'py, f64>,
+    z: PyReadonlyArray1<'py, Complex64>,
+    f: fn(f64, Complex64) -> Complex64,
+) -> PyResult<Bound<'py, PyArray1<Complex64>>> {
+    let (v, z) = (v.as_array(), z.as_array());
+
+    if v.len() != z.len() {
+        return Err(PyValueError::new_err("v and z must have the same length"));
+    }
+
+    let cy: Vec<Complex64> = py.detach(|| v.iter().zip(z.iter()).map(|(&v, &z)| f(v, z)).collect());
+
+    Ok(cy.into_pyarray(py))
+}
+
+#[pyfunction]
+fn iv<'py>(
+    py: Python<'py>,
+    v: PyReadonlyArray1<'py, f64>,
+    z: PyReadonlyArray1<'py, Complex64>,
+) -> PyResult<Bound<'py, PyArray1<Complex64>>> {
+    map_complex(py, v, z, zbesi::iv)
+}
+
+#[pyfunction]
+fn ive<'py>(
+    py: Python<'py>,
+    v: PyReadonlyArray1<'py, f64>,
+    z: PyReadonlyArray1<'py, Complex64>,
+) -> PyResult<Bound<'py, PyArray1<Complex64>>> {
+    map_complex(py, v, z, zbesi::ive)
+}
+
+#[pyfunction]
+fn iv_real<'py>(
+    py: Python<'py>,
+    v: PyReadonlyArray1<'py, f64>,
+    x: PyReadonlyArray1<'py, f64>,
+) -> PyResult<Bound<'py, PyArray1<f64>>> {
+    map_real(py, v, x, zbesi::iv_real)
+}
+
+#[pyfunction]
+fn ive_real<'py>(
+    py: Python<'py>,
+    v: PyReadonlyArray1<'py, f64>,
+    x: PyReadonlyArray1<'py, f64>,
+) -> PyResult<Bound<'py, PyArray1<f64>>> {
+    map_real(py, v, x, zbesi::ive_real)
+}
+
+#[pyfunction]
+fn jv<'py>(
+    py: Python<'py>,
+    v: PyReadonlyArray1<'py, f64>,
+    z: PyReadonlyArray1<'py, Complex64>,
+) -> PyResult<Bound<'py, PyArray1<Complex64>>> {
+    map_complex(py, v, z, zbesj::jv)
+}
+
+#[pyfunction]
+fn jve<'py>(
+    py: Python<'py>,
+    v: PyReadonlyArray1<'py, f64>,
+    z: PyReadonlyArray1<'py, Complex64>,
+) -> PyResult<Bound<'py, PyArray1<Complex64>>> {
+    map_complex(py, v, z, zbesj::jve)
+}
+
+#[pyfunction]
+fn jv_real<'py>(
+    py: Python<'py>,
+    v: PyReadonlyArray1<'py, f64>,
+    x: PyReadonlyArray1<'py, f64>,
+) -> PyResult<Bound<'py, PyArray1<f64>>> {
+    map_real(py, v, x, zbesj::jv_real)
+}
+
+#[pyfunction]
+fn jve_real<'py>(
+    py: Python<'py>,
+    v: PyReadonlyArray1<'py, f64>,
+    x: PyReadonlyArray1<'py, f64>,
+) -> PyResult<Bound<'py, PyArray1<f64>>> {
+    map_real(py, v, x, zbesj::jve_real)
+}
+
+#[pyfunction]
+fn kv<'py>(
+    py: Python<'py>,
+    v: PyReadonlyArray1<'py, f64>,
+    z: PyReadonlyArray1<'py, Complex64>,
+) -> PyResult<Bound<'py, PyArray1<Complex64>>> {
+    map_complex(py, v, z, zbesk::kv)
+}
+
+#[pyfunction]
+fn kve<'py>(
+    py: Python<'py>,
+    v: PyReadonlyArray1<'py, f64>,
+    z: PyReadonlyArray1<'py, Complex64>,
+) -> PyResult<Bound<'py, PyArray1<Complex64>>> {
+    map_complex(py, v, z, zbesk::kve)
+}
+
+#[pyfunction]
+fn kv_real<'py>(
+    py: Python<'py>,
+    v: PyReadonlyArray1<'py, f64>,
+    x: PyReadonlyArray1<'py, f64>,
+) -> PyResult<Bound<'py, PyArray1<f64>>> {
+    map_real(py, v, x, zbesk::kv_real)
+}
+
+#[pyfunction]
+fn kve_real<'py>(
+    py: Python<'py>,
+    v: PyReadonlyArray1<'py, f64>,
+    x: PyReadonlyArray1<'py, f64>,
+) -> PyResult<Bound<'py, PyArray1<f64>>> {
+    map_real(py, v, x, zbesk::kve_real)
+}
+
+#[pyfunction]
+fn yv<'py>(
+    py: Python<'py>,
+    v: PyReadonlyArray1<'py, f64>,
+    z: PyReadonlyArray1<'py, Complex64>,
+) -> PyResult<Bound<'py, PyArray1<Complex64>>> {
+    map_complex(py, v, z, zbesy::yv)
+}
+
+#[pyfunction]
+fn yve<'py>(
+    py: Python<'py>,
+    v: PyReadonlyArray1<'py, f64>,
+    z: PyReadonlyArray1<'py, Complex64>,
+) -> PyResult<Bound<'py, PyArray1<Complex64>>> {
+    map_complex(py, v, z, zbesy::yve)
+}
+
+#[pyfunction]
+fn yv_real<'py>(
+    py: Python<'py>,
+    v: PyReadonlyArray1<'py, f64>,
+    x: PyReadonlyArray1<'py, f64>,
+) -> PyResult<Bound<'py, PyArray1<f64>>> {
+    map_real(py, v, x, zbesy::yv_real)
+}
+
+#[pyfunction]
+fn yve_real<'py>(
+    py: Python<'py>,
+    v: PyReadonlyArray1<'py, f64>,
+    x: PyReadonlyArray1<'py, f64>,
+) -> PyResult<Bound<'py, PyArray1<f64>>> {
+    map_real(py, v, x, zbesy::yve_real)
+}
+
+#[pyfunction]
+fn hankel1<'py>(
+    py: Python<'py>,
+    v: PyReadonlyArray1<'py, f64>,
+    z: PyReadonlyArray1<'py, Complex64>,
+) -> PyResult<Bound<'py, PyArray1<Complex64>>> {
+    map_complex(py, v, z, |v, z| zbesh::hankel1(v, z, 1)[0])
+}
+
+#[pyfunction]
+fn hankel1e<'py>(
+    py: Python<'py>,
+    v: PyReadonlyArray1<'py, f64>,
+    z: PyReadonlyArray1<'py, Complex64>,
+) -> PyResult<Bound<'py, PyArray1<Complex64>>> {
+    map_complex(py, v, z, |v, z| zbesh::hankel1e(v, z, 1)[0])
+}
+
+#[pyfunction]
+fn hankel2<'py>(
+    py: Python<'py>,
+    v: PyReadonlyArray1<'py, f64>,
+    z: PyReadonlyArray1<'py, Complex64>,
+) -> PyResult<Bound<'py, PyArray1<Complex64>>> {
+    map_complex(py, v, z, |v, z| zbesh::hankel2(v, z, 1)[0])
+}
+
+#[pyfunction]
+fn hankel2e<'py>(
+    py: Python<'py>,
+    v: PyReadonlyArray1<'py, f64>,
+    z: PyReadonlyArray1<'py, Complex64>,
+) -> PyResult<Bound<'py, PyArray1<Complex64>>> {
+    map_complex(py, v, z, |v, z| zbesh::hankel2e(v, z, 1)[0])
+}
+
+#[pymodule]
+fn _amos(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(iv, m)?)?;
+    m.add_function(wrap_pyfunction!(ive, m)?)?;
+    m.add_function(wrap_pyfunction!(iv_real, m)?)?;
+    m.add_function(wrap_pyfunction!(ive_real, m)?)?;
+    m.add_function(wrap_pyfunction!(jv, m)?)?;
+    m.add_function(wrap_pyfunction!(jve, m)?)?;
+    m.add_function(wrap_pyfunction!(jv_real, m)?)?;
+    m.add_function(wrap_pyfunction!(jve_real, m)?)?;
+    m.add_function(wrap_pyfunction!(kv, m)?)?;
+    m.add_function(wrap_pyfunction!(kve, m)?)?;
+    m.add_function(wrap_pyfunction!(kv_real, m)?)?;
+    m.add_function(wrap_pyfunction!(kve_real, m)?)?;
+    m.add_function(wrap_pyfunction!(yv, m)?)?;
+    m.add_function(wrap_pyfunction!(yve, m)?)?;
+    m.add_function(wrap_pyfunction!(yv_real, m)?)?;
+    m.add_function(wrap_pyfunction!(yve_real, m)?)?;
+    m.add_function(wrap_pyfunction!(hankel1, m)?)?;
+    m.add_function(wrap_pyfunction!(hankel1e, m)?)?;
+    m.add_function(wrap_pyfunction!(hankel2, m)?)?;
+    m.add_function(wrap_pyfunction!(hankel2e, m)?)?;
+
+    Ok(())
+}
@@ -0,0 +1,50 @@
+import amos_rs
+import numpy as np
+import pytest
+import scipy.special
+
+NAMES = amos_rs.__all__
+
+rng = np.random.default_rng(2022)
+
+# Both amos-rs and scipy.special call AMOS, and differ only in the handling of negative orders
+V = rng.uniform(-10, 10, 2000)
+X = rng.uniform(-20, 20, 2000)
+Z = X + 1j * rng.uniform(-20, 20, 2000)
+
+
+def assert_close(actual, desired):
+    np.testing.assert_allclose(actual, desired, rtol=1e-10, atol=1e-14)
+
+
+@pytest.mark.parametrize("name", NAMES)
+def test_complex(name):
+    assert_close(getattr(amos_rs, name)(V, Z), getattr(scipy.special, name)(V, Z))
+
+
+@pytest.mark.parametrize("name", NAMES)
+def test_real(name):
+    actual = getattr(amos_rs, name)(V, X)
+    desired = getattr(scipy.special, name)(V, X)
+
+    assert actual.dtype == desired.dtype
+    assert_close(actual, desired)
+
+
+@pytest.mark.parametrize("name", NAMES)
+def test_broadcasting(name):
+    v = np.array([[0.0], [-0.5], [1.3]])
+    z = np.array([0.5, 1.0 + 1.0j, 7.0])
+    actual = getattr(amos_rs, name)(v, z)
+
+    assert actual.shape == (3, 3)
+    assert_close(actual, getattr(scipy.special, name)(v, z))
+
+
+def test_scalar():
+    cy = amos_rs.iv(0.0, 1.0)
+
+    assert np.ndim(cy) == 0
+    assert cy == pytest.approx(scipy.special.iv(0.0, 1.0), rel=1e-14)
+    assert np.isnan(amos_rs.iv(0.5, -1.0))
+    assert amos_rs.kv(0.0, 0.0) == np.inf
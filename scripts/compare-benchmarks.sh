@@ -0,0 +1,43 @@
+#!/usr/bin/env bash
+# Compares the benchmarks in benches/functions.rs of the working tree against a revision, master by
+# default, which must have them as well. The revision is benchmarked in a temporary worktree and
+# stored as the criterion baseline "base" in target/criterion, and the exit status is 1 if criterion
+# reports a regression beyond its noise threshold. The remaining arguments are passed on to cargo,
+# e.g.,
+#
+#     scripts/compare-benchmarks.sh master --features c-backend
+set -euo pipefail
+
+cd "$(dirname "$0")/.."
+
+revision=master
+
+if [ $# -gt 0 ] && [ "${1#-}" = "$1" ]; then
+    revision="$1"
+    shift
+fi
+
+root="$PWD"
+worktree="$(mktemp -d)"
+
+# Both runs store their results in the same place, whereas the builds are kept apart
+export CRITERION_HOME="$root/target/criterion"
+
+git worktree add --detach "$worktree" "$revision"
+trap 'git worktree remove --force "$worktree"' EXIT
+
+echo "Benchmarking $revision"
+(
+    cd "$worktree"
+    CARGO_TARGET_DIR="$root/target/bench-baseline" \
+        cargo bench --bench functions "$@" -- --save-baseline base
+)
+
+echo "Benchmarking the working tree against $revision"
+cargo bench --bench functions "$@" -- --baseline base | tee "$root/target/bench-comparison.txt"
+
+if grep -q "Performance has regressed" "$root/target/bench-comparison.txt"; then
+    echo "Regressions against $revision:"
+    grep -B 2 "Performance has regressed" "$root/target/bench-comparison.txt"
+    exit 1
+fi
@@ -0,0 +1,991 @@
+#!/usr/bin/env python3
+# Translates the fixed-form Fortran 77 sources of AMOS in amos/ to C in amos-c/, in the style of
+# f2c: all arguments are passed by reference, arrays are flattened and indexed from zero, and
+# symbols are lower case with a trailing underscore. Only the subset of Fortran 77 used by AMOS is
+# supported, i.e., logical IF, GO TO, DO loops, CALL, RETURN, DATA and type declarations.
+#
+# Usage: scripts/f77-to-c.py [amos-dir] [output-dir]
+#
+# The machine constants in d1mach.f90, i1mach.f90 and r1mach.f90 are Fortran 90 and are written by
+# hand in amos-c/machine.c.
+
+import pathlib
+import re
+import sys
+
+INTRINSICS = {
+    "DABS": "fabs",
+    "ABS": "fabs",
+    "DSQRT": "sqrt",
+    "SQRT": "sqrt",
+    "DEXP": "exp",
+    "EXP": "exp",
+    "DLOG": "log",
+    "ALOG": "log",
+    "DLOG10": "log10",
+    "ALOG10": "log10",
+    "DSIN": "sin",
+    "SIN": "sin",
+    "DCOS": "cos",
+    "COS": "cos",
+    "DTAN": "tan",
+    "DATAN": "atan",
+    "DATAN2": "atan2",
+    "DSINH": "sinh",
+    "DCOSH": "cosh",
+}
+
+LOGICAL_OPS = {
+    ".EQ.": "==",
+    ".NE.": "!=",
+    ".LT.": "<",
+    ".LE.": "<=",
+    ".GT.": ">",
+    ".GE.": ">=",
+}
+
+TYPE_RANK = {"int": 0, "float": 1, "double": 2}
+
+
+class Error(Exception):
+    pass
+
+
+# Source lines and statements
+
+
+def read_statements(path):
+    statements = []
+
+    for line in path.read_text().splitlines():
+        if not line.strip() or line[0] in "Cc*!":
+            continue
+
+        line = line[:72].ljust(6)
+        label = line[:5].strip()
+
+        if line[5] not in " 0":
+            statements[-1][1] += line[6:]
+        else:
+            statements.append([int(label) if label else None, line[6:]])
+
+    # Blanks are insignificant in fixed-form Fortran, and AMOS has no character constants
+    return [(label, text.replace(" ", "").upper()) for label, text in statements]
+
+
+def matching_paren(text, start):
+    depth = 0
+
+    for i in range(start, len(text)):
+        if text[i] == "(":
+            depth += 1
+        elif text[i] == ")":
+            depth -= 1
+
+            if depth == 0:
+                return i
+
+    raise Error(f"Unbalanced parentheses in {text}")
+
+
+def split_top_level(text, sep):
+    parts, depth, start = [], 0, 0
+
+    for i, c in enumerate(text):
+        if c == "(":
+            depth += 1
+        elif c == ")":
+            depth -= 1
+        elif c == sep and depth == 0:
+            parts.append(text[start:i])
+            start = i + 1
+
+    parts.append(text[start:])
+
+    return parts
+
+
+def find_top_level(text, sep):
+    return len(split_top_level(text, sep)) > 1
+
+
+# Expressions
+
+
+TOKEN_RE = re.compile(
+    r"(?P<op>\*\*|[-+*/(),])"
+    r"|(?P<dot>\.(EQ|NE|LT|LE|GT|GE|AND|OR|NOT|TRUE|FALSE)\.)"
+    r"|(?P<name>[A-Z][A-Z0-9_]*)"
+)
+NUMBER_RE = re.compile(r"(\d+(\.(?!(EQ|NE|LT|LE|GT|GE|AND|OR)\.)\d*)?|\.\d+)([DE][-+]?\d+)?")
+
+
+def tokenize(text):
+    tokens, pos = [], 0
+
+    while pos < len(text):
+        m = NUMBER_RE.match(text, pos)
+
+        if m and m.group(0):
+            tokens.append(("num", m.group(0)))
+            pos = m.end()
+            continue
+
+        m = TOKEN_RE.match(text, pos)
+
+        if not m:
+            raise Error(f"Cannot tokenize {text[pos:]!r} in {text}")
+
+        kind = m.lastgroup
+        tokens.append((kind, m.group(0)))
+        pos = m.end()
+
+    return tokens
+
+
+class Parser:
+    def __init__(self, text):
+        self.tokens = tokenize(text)
+        self.pos = 0
+        self.text = text
+
+    def peek(self):
+        return self.tokens[self.pos] if self.pos < len(self.tokens) else (None, None)
+
+    def take(self, value=None):
+        token = self.peek()
+
+        if value is not None and token[1] != value:
+            raise Error(f"Expected {value} in {self.text}")
+
+        self.pos += 1
+
+        return token
+
+    def parse(self):
+        node = self.expr()
+
+        if self.pos != len(self.tokens):
+            raise Error(f"Trailing tokens in {self.text}")
+
+        return node
+
+    def expr(self):
+        node = self.conjunction()
+
+        while self.peek()[1] == ".OR.":
+            self.take()
+            node = ("log", "||", node, self.conjunction())
+
+        return node
+
+    def conjunction(self):
+        node = self.negation()
+
+        while self.peek()[1] == ".AND.":
+            self.take()
+            node = ("log", "&&", node, self.negation())
+
+        return node
+
+    def negation(self):
+        if self.peek()[1] == ".NOT.":
+            self.take()
+
+            return ("not", self.negation())
+
+        return self.relation()
+
+    def relation(self):
+        node = self.additive()
+
+        if self.peek()[1] in LOGICAL_OPS:
+            op = LOGICAL_OPS[self.take()[1]]
+            node = ("rel", op, node, self.additive())
+
+        return node
+
+    def additive(self):
+        if self.peek()[1] in ("+", "-"):
+            op = self.take()[1]
+            node = self.term()
+
+            if op == "-":
+                node = ("neg", node)
+        else:
+            node = self.term()
+
+        while self.peek()[1] in ("+", "-"):
+            op = self.take()[1]
+            node = ("bin", op, node, self.term())
+
+        return node
+
+    def term(self):
+        node = self.factor()
+
+        while self.peek()[1] in ("*", "/"):
+            op = self.take()[1]
+            node = ("bin", op, node, self.factor())
+
+        return node
+
+    def factor(self):
+        node = self.primary()
+
+        if self.peek()[1] == "**":
+            self.take()
+            node = ("pow", node, self.factor())
+
+        return node
+
+    def primary(self):
+        kind, value = self.take()
+
+        if value in ("-", "+"):
+            node = self.factor()
+
+            return ("neg", node) if value == "-" else node
+
+        if kind == "num":
+            return ("num", value)
+
+        if value == "(":
+            node = self.expr()
+            self.take(")")
+
+            return ("paren", node)
+
+        if kind == "name":
+            if self.peek()[1] == "(":
+                self.take()
+                args = []
+
+                if self.peek()[1] != ")":
+                    args.append(self.expr())
+
+                    while self.peek()[1] == ",":
+                        self.take()
+                        args.append(self.expr())
+
+                self.take(")")
+
+                return ("call", value, args)
+
+            return ("var", value)
+
+        raise Error(f"Unexpected {value} in {self.text}")
+
+
+def parse_expr(text):
+    return Parser(text).parse()
+
+
+# Program units
+
+
+class Unit:
+    def __init__(self, kind, name, args, rtype):
+        self.kind = kind
+        self.name = name
+        self.args = args
+        self.rtype = rtype
+        self.types = {}
+        self.dims = {}
+        self.data = {}
+        self.externals = set()
+        self.body = []
+
+
+def implicit_type(name):
+    return "int" if name[0] in "IJKLMN" else "float"
+
+
+def parse_declarations(unit, text, rtype):
+    for item in split_top_level(text, ","):
+        m = re.fullmatch(r"([A-Z][A-Z0-9_]*)(\((.*)\))?", item)
+
+        if not m:
+            raise Error(f"Cannot parse declaration {item}")
+
+        name = m.group(1)
+
+        if rtype is not None:
+            unit.types[name] = rtype
+
+        if m.group(3):
+            unit.dims[name] = split_top_level(m.group(3), ",")
+
+
+def parse_data(unit, text):
+    # DATA names /values/ [,] names /values/ ...
+    pos = 0
+
+    while pos < len(text):
+        start = text.index("/", pos)
+        end = text.index("/", start + 1)
+        names = split_top_level(text[pos:start], ",")
+        values = []
+
+        for value in text[start + 1 : end].split(","):
+            m = re.fullmatch(r"(\d+)\*(.*)", value)
+
+            if m:
+                values += [m.group(2)] * int(m.group(1))
+            else:
+                values.append(value)
+
+        targets = []
+
+        for name in (n for n in names if n):
+            m = re.fullmatch(r"([A-Z][A-Z0-9_]*)(\((.*)\))?", name)
+
+            if m.group(3):
+                targets.append((m.group(1), [int(i) for i in m.group(3).split(",")]))
+            elif m.group(1) in unit.dims:
+                size = array_size(unit, m.group(1))
+                targets += [(m.group(1), [i + 1]) for i in range(size)]
+            else:
+                targets.append((m.group(1), None))
+
+        if len(targets) != len(values):
+            raise Error(f"DATA statement with {len(targets)} names and {len(values)} values")
+
+        for (name, index), value in zip(targets, values):
+            unit.data.setdefault(name, {})[None if index is None else tuple(index)] = value
+
+        pos = end + 1
+
+        if pos < len(text) and text[pos] == ",":
+            pos += 1
+
+
+def array_size(unit, name):
+    size = 1
+
+    for dim in unit.dims[name]:
+        size *= int(dim)
+
+    return size
+
+
+def parse_units(path):
+    units, unit = [], None
+
+    for label, text in read_statements(path):
+        m = re.fullmatch(
+            r"(SUBROUTINE|DOUBLEPRECISIONFUNCTION|INTEGERFUNCTION|REALFUNCTION)"
+            r"([A-Z][A-Z0-9_]*)(\((.*)\))?",
+            text,
+        )
+
+        if m and unit is None:
+            kind = "subroutine" if m.group(1) == "SUBROUTINE" else "function"
+            rtype = {
+                "SUBROUTINE": None,
+                "DOUBLEPRECISIONFUNCTION": "double",
+                "INTEGERFUNCTION": "int",
+                "REALFUNCTION": "float",
+            }[m.group(1)]
+            args = [a for a in (m.group(4) or "").split(",") if a]
+            unit = Unit(kind, m.group(2), args, rtype)
+            continue
+
+        if unit is None:
+            raise Error(f"Statement outside program unit in {path}: {text}")
+
+        if text == "END":
+            units.append(unit)
+            unit = None
+        elif text.startswith("DOUBLEPRECISION") and not find_top_level(text, "="):
+            parse_declarations(unit, text[len("DOUBLEPRECISION") :], "double")
+        elif text.startswith("INTEGER") and not find_top_level(text, "="):
+            parse_declarations(unit, text[len("INTEGER") :], "int")
+        elif text.startswith("REAL") and not find_top_level(text, "="):
+            parse_declarations(unit, text[len("REAL") :], "float")
+        elif text.startswith("DIMENSION") and not find_top_level(text, "="):
+            parse_declarations(unit, text[len("DIMENSION") :], None)
+        elif text.startswith("EXTERNAL") and not find_top_level(text, "="):
+            unit.externals.update(text[len("EXTERNAL") :].split(","))
+        elif text.startswith("SAVE") and not find_top_level(text, "="):
+            pass
+        elif text.startswith("DATA") and not find_top_level(text, "="):
+            unit.body.append((label, ("data",)))
+            parse_data(unit, text[len("DATA") :])
+        else:
+            unit.body.append((label, parse_statement(text)))
+
+    if unit is not None:
+        raise Error(f"Missing END in {path}")
+
+    return units
+
+
+def parse_statement(text):
+    if text.startswith("IF("):
+        close = matching_paren(text, 2)
+        rest = text[close + 1 :]
+
+        if rest and rest[0] != "=":
+            return ("if", parse_expr(text[3:close]), parse_statement(rest))
+
+    m = re.fullmatch(r"DO(\d+),?([A-Z][A-Z0-9_]*)=(.*)", text)
+
+    if m and find_top_level(m.group(3), ","):
+        bounds = split_top_level(m.group(3), ",")
+
+        return (
+            "do",
+            int(m.group(1)),
+            m.group(2),
+            [parse_expr(b) for b in bounds],
+        )
+
+    m = re.fullmatch(r"GOTO(\d+)", text)
+
+    if m:
+        return ("goto", int(m.group(1)))
+
+    if text == "CONTINUE":
+        return ("continue",)
+
+    if text == "RETURN":
+        return ("return",)
+
+    if text == "STOP":
+        return ("stop",)
+
+    m = re.fullmatch(r"CALL([A-Z][A-Z0-9_]*)(\((.*)\))?", text)
+
+    if m and not find_top_level(text, "="):
+        args = split_top_level(m.group(3), ",") if m.group(3) else []
+
+        return ("call", m.group(1), [parse_expr(a) for a in args if a])
+
+    parts = split_top_level(text, "=")
+
+    if len(parts) == 2:
+        return ("assign", parse_expr(parts[0]), parse_expr(parts[1]))
+
+    raise Error(f"Unsupported statement {text}")
+
+
+# Code generation
+
+
+class Translator:
+    def __init__(self, unit, signatures):
+        self.unit = unit
+        self.signatures = signatures
+        self.used = set()
+        self.labels = set()
+        self.loop_counter = 0
+
+    def type_of(self, name):
+        if name == self.unit.name and self.unit.kind == "function":
+            return self.unit.rtype
+
+        return self.unit.types.get(name, implicit_type(name))
+
+    def is_array(self, name):
+        return name in self.unit.dims
+
+    def is_function(self, name):
+        return not self.is_array(name) and (
+            name in self.signatures or name in self.unit.externals
+        )
+
+    def c_name(self, name):
+        if name == self.unit.name and self.unit.kind == "function":
+            return "ret_val"
+
+        return name.lower()
+
+    def is_dummy(self, name):
+        return name in self.unit.args
+
+    # Type of an expression, used for integer division, exponentiation and temporaries
+    def expr_type(self, node):
+        kind = node[0]
+
+        if kind == "num":
+            text = node[1]
+
+            if "D" in text:
+                return "double"
+
+            if "." in text or "E" in text:
+                return "float"
+
+            return "int"
+
+        if kind == "var":
+            return self.type_of(node[1])
+
+        if kind == "call":
+            name = node[1]
+
+            if self.is_array(name):
+                return self.type_of(name)
+
+            if name in INTRINSICS:
+                return "double"
+
+            if name in ("INT", "MIN0", "MAX0", "IABS", "MOD", "NINT", "IDINT"):
+                return "int"
+
+            if name in ("FLOAT", "SNGL", "REAL"):
+                return "float"
+
+            if name in ("DBLE", "DMAX1", "DMIN1", "DSIGN", "DMOD"):
+                return "double"
+
+            if name in ("MAX", "MIN", "SIGN"):
+                return max((self.expr_type(a) for a in node[2]), key=TYPE_RANK.get)
+
+            if name in self.signatures:
+                return self.signatures[name][0]
+
+            return self.type_of(name)
+
+        if kind in ("paren", "neg"):
+            return self.expr_type(node[1])
+
+        if kind == "bin":
+            return max(self.expr_type(node[2]), self.expr_type(node[3]), key=TYPE_RANK.get)
+
+        if kind == "pow":
+            return max(self.expr_type(node[1]), self.expr_type(node[2]), key=TYPE_RANK.get)
+
+        return "int"
+
+    def number(self, text):
+        if "D" in text:
+            text = text.replace("D", "e")
+
+            return text if "." in text or "e" in text else text + ".0"
+
+        if "." in text or "E" in text:
+            text = text.replace("E", "e")
+
+            if text.endswith("."):
+                text += "0"
+
+            return text + "f"
+
+        return text
+
+    def index(self, name, subscripts):
+        dims = self.unit.dims[name]
+
+        if len(subscripts) != len(dims):
+            raise Error(f"Wrong number of subscripts for {name}")
+
+        stride, terms = 1, []
+
+        for i, subscript in enumerate(subscripts):
+            term = f"({self.expr(subscript)} - 1)"
+
+            if i > 0:
+                if not dims[i - 1].isdigit():
+                    raise Error(f"Adjustable leading dimension of {name}")
+
+                stride *= int(dims[i - 1])
+                term = f"{term} * {stride}"
+
+            terms.append(term)
+
+        offset = " + ".join(terms)
+
+        return f"{self.c_name(name)}[{offset}]"
+
+    def expr(self, node):
+        kind = node[0]
+
+        if kind == "num":
+            return self.number(node[1])
+
+        if kind == "var":
+            name = node[1]
+            self.used.add(name)
+
+            if self.is_array(name):
+                return self.c_name(name)
+
+            if self.is_dummy(name):
+                return f"*{self.c_name(name)}"
+
+            return self.c_name(name)
+
+        if kind == "paren":
+            return f"({self.expr(node[1])})"
+
+        if kind == "neg":
+            return f"-{self.expr(node[1])}"
+
+        if kind == "not":
+            return f"!({self.expr(node[1])})"
+
+        if kind in ("bin", "rel", "log"):
+            return f"{self.expr(node[2])} {node[1]} {self.expr(node[3])}"
+
+        if kind == "pow":
+            base, exponent = self.expr_type(node[1]), self.expr_type(node[2])
+            a, b = self.expr(node[1]), self.expr(node[2])
+
+            if exponent == "int":
+                return f"pow_ii({a}, {b})" if base == "int" else f"pow_di({a}, {b})"
+
+            return f"pow({a}, {b})"
+
+        if kind == "call":
+            return self.call_expr(node)
+
+        raise Error(f"Unknown expression {node}")
+
+    def call_expr(self, node):
+        name, args = node[1], node[2]
+
+        if self.is_array(name):
+            self.used.add(name)
+
+            return self.index(name, args)
+
+        c_args = [self.expr(a) for a in args]
+
+        if name in INTRINSICS:
+            return f"{INTRINSICS[name]}({', '.join(c_args)})"
+
+        if name in ("INT", "IDINT"):
+            return f"(int)({c_args[0]})"
+
+        if name == "NINT":
+            return f"(int)lround({c_args[0]})"
+
+        if name in ("FLOAT", "SNGL", "REAL"):
+            return f"(float)({c_args[0]})"
+
+        if name == "DBLE":
+            return f"(double)({c_args[0]})"
+
+        if name in ("DMAX1", "DMIN1", "MAX0", "MIN0", "MAX", "MIN"):
+            func = "max" if "MAX" in name else "min"
+            prefix = "i" if self.expr_type(node) == "int" else "d"
+            result = c_args[0]
+
+            for arg in c_args[1:]:
+                result = f"{prefix}{func}({result}, {arg})"
+
+            return result
+
+        if name == "IABS":
+            return f"abs({c_args[0]})"
+
+        if name == "MOD":
+            return f"({c_args[0]}) % ({c_args[1]})"
+
+        if name in ("DSIGN", "SIGN"):
+            return f"d_sign({c_args[0]}, {c_args[1]})"
+
+        if name in self.signatures or name in self.unit.externals:
+            return f"{name.lower()}_({', '.join(self.reference(a) for a in args)})"
+
+        raise Error(f"Unknown function {name} in {self.unit.name}")
+
+    # Fortran passes arguments by reference. Expressions are stored in temporaries, for which we use
+    # compound literals.
+    def reference(self, node):
+        if node[0] == "var":
+            name = node[1]
+            self.used.add(name)
+
+            if self.is_array(name) or self.is_dummy(name):
+                return self.c_name(name)
+
+            if self.is_function(name):
+                raise Error(f"Passing function {name} as argument")
+
+            return f"&{self.c_name(name)}"
+
+        if node[0] == "call" and self.is_array(node[1]):
+            return f"&{self.expr(node)}"
+
+        ctype = self.expr_type(node)
+
+        return f"&({ctype}){{{self.expr(node)}}}"
+
+    def statement(self, stmt, indent):
+        kind = stmt[0]
+        pad = "    " * indent
+
+        if kind == "assign":
+            return [f"{pad}{self.expr(stmt[1])} = {self.expr(stmt[2])};"]
+
+        if kind == "if":
+            inner = self.statement(stmt[2], 0)
+
+            return [f"{pad}if ({self.expr(stmt[1])}) {inner[0].strip()}"]
+
+        if kind == "goto":
+            self.labels.add(stmt[1])
+
+            return [f"{pad}goto L{stmt[1]};"]
+
+        if kind == "continue":
+            return []
+
+        if kind == "data":
+            return []
+
+        if kind == "return":
+            if self.unit.kind == "function":
+                return [f"{pad}return ret_val;"]
+
+            return [f"{pad}return;"]
+
+        if kind == "stop":
+            return [f"{pad}exit(0);"]
+
+        if kind == "call":
+            args = ", ".join(self.reference(a) for a in stmt[2])
+
+            return [f"{pad}{stmt[1].lower()}_({args});"]
+
+        raise Error(f"Cannot translate {stmt}")
+
+    def body(self):
+        lines, loops, indent = [], [], 1
+
+        for label, stmt in self.unit.body:
+            # A label is only emitted where referenced by a GO TO, which is known after translating
+            # the whole body, and is therefore marked here and resolved below
+            if label is not None:
+                lines.append(("label", label, indent))
+
+            if stmt[0] == "do":
+                _, end, var, bounds = stmt
+                self.used.add(var)
+                self.loop_counter += 1
+                counter = f"i{self.loop_counter}_"
+                start, stop = self.expr(bounds[0]), self.expr(bounds[1])
+                step = self.expr(bounds[2]) if len(bounds) > 2 else "1"
+                v = self.loop_var(var)
+                pad = "    " * indent
+
+                # Fortran evaluates the trip count once before the loop
+                if step == "1":
+                    trips = f"{stop} - {v} + 1"
+                else:
+                    trips = f"({stop} - {v} + {step}) / {step}"
+
+                lines.append(("code", f"{pad}{v} = {start};"))
+                lines.append(
+                    (
+                        "code",
+                        f"{pad}for ({counter} = {trips}; {counter} > 0; --{counter}, {v} += {step}) {{",
+                    )
+                )
+                loops.append(end)
+                indent += 1
+                continue
+
+            for line in self.statement(stmt, indent):
+                lines.append(("code", line))
+
+            while loops and label is not None and loops[-1] == label:
+                loops.pop()
+                indent -= 1
+                lines.append(("code", "    " * indent + "}"))
+
+        if loops:
+            raise Error(f"Unterminated DO loop in {self.unit.name}")
+
+        out = []
+
+        for item in lines:
+            if item[0] == "label":
+                if item[1] in self.labels:
+                    out.append("    " * (item[2] - 1) + f"L{item[1]}:;")
+            else:
+                out.append(item[1])
+
+        return out
+
+    def loop_var(self, name):
+        return f"*{self.c_name(name)}" if self.is_dummy(name) else self.c_name(name)
+
+    def declarations(self):
+        unit, decls = self.unit, []
+
+        names = sorted(
+            n
+            for n in self.used | set(unit.data)
+            if not self.is_dummy(n) and not self.is_function(n) and n != unit.name
+        )
+
+        for name in names:
+            ctype = self.type_of(name)
+            storage = "static " if name in unit.data else ""
+
+            if self.is_array(name):
+                size = array_size(unit, name)
+                values = ["0"] * size
+
+                for index, value in unit.data.get(name, {}).items():
+                    values[self.flat_index(name, index)] = self.number(value)
+
+                if name in unit.data:
+                    decls.append(f"    {storage}{ctype} {self.c_name(name)}[{size}] = {{")
+
+                    for i in range(0, size, 3):
+                        decls.append("        " + ", ".join(values[i : i + 3]) + ",")
+
+                    decls.append("    };")
+                else:
+                    decls.append(f"    {ctype} {self.c_name(name)}[{size}];")
+            elif name in unit.data:
+                value = self.number(unit.data[name][None])
+                decls.append(f"    {storage}{ctype} {self.c_name(name)} = {value};")
+            else:
+                decls.append(f"    {ctype} {self.c_name(name)} = 0;")
+
+        if unit.kind == "function":
+            decls.append(f"    {unit.rtype} ret_val = 0;")
+
+        for i in range(1, self.loop_counter + 1):
+            decls.append(f"    int i{i}_;")
+
+        return decls
+
+    def flat_index(self, name, index):
+        dims = [int(d) for d in self.unit.dims[name]]
+        offset, stride = 0, 1
+
+        for i, d in zip(index, dims):
+            offset += (i - 1) * stride
+            stride *= d
+
+        return offset
+
+    def translate(self):
+        body = self.body()
+        decls = self.declarations()
+        lines = [prototype(self.unit), "{"] + decls
+
+        if decls:
+            lines.append("")
+
+        lines += body
+
+        if self.unit.kind == "function" and not body[-1:] == ["    return ret_val;"]:
+            lines.append("    return ret_val;")
+
+        lines.append("}")
+
+        return "\n".join(lines) + "\n"
+
+
+def prototype(unit):
+    params = []
+
+    for arg in unit.args:
+        ctype = unit.types.get(arg, implicit_type(arg))
+        params.append(f"{ctype} *{arg.lower()}")
+
+    rtype = unit.rtype or "void"
+
+    return f"{rtype} {unit.name.lower()}_({', '.join(params) or 'void'})"
+
+
+HEADER = """\
+/* Generated by scripts/f77-to-c.py from the Fortran sources in amos/, do not edit. */
+
+#ifndef AMOS_H
+#define AMOS_H
+
+#include <math.h>
+#include <stdlib.h>
+
+static inline int imax(int a, int b) { return a > b ? a : b; }
+static inline int imin(int a, int b) { return a < b ? a : b; }
+static inline double dmax(double a, double b) { return a > b ? a : b; }
+static inline double dmin(double a, double b) { return a < b ? a : b; }
+static inline double d_sign(double a, double b) { return b >= 0 ? fabs(a) : -fabs(a); }
+
+static inline int pow_ii(int a, int n)
+{
+    int p = 1;
+
+    if (n < 0)
+        return a == 1 ? 1 : (a == -1 ? (n % 2 ? -1 : 1) : 0);
+
+    for (; n > 0; --n)
+        p *= a;
+
+    return p;
+}
+
+static inline double pow_di(double a, int n)
+{
+    double p = 1;
+
+    if (n < 0) {
+        n = -n;
+        a = 1 / a;
+    }
+
+    for (; n > 0; n >>= 1, a *= a)
+        if (n & 1)
+            p *= a;
+
+    return p;
+}
+
+"""
+
+
+def main():
+    amos = pathlib.Path(sys.argv[1] if len(sys.argv) > 1 else "amos")
+    out = pathlib.Path(sys.argv[2] if len(sys.argv) > 2 else "amos-c")
+    out.mkdir(exist_ok=True)
+
+    sources = {path: parse_units(path) for path in sorted(amos.glob("*.f"))}
+    units = [unit for path_units in sources.values() for unit in path_units]
+
+    # The machine constants are functions of an integer, see amos-c/machine.c
+    signatures = {"D1MACH": ("double",), "I1MACH": ("int",), "R1MACH": ("float",)}
+    signatures.update({unit.name: (unit.rtype or "void",) for unit in units})
+
+    # With AMOS_PREFIX defined, e.g., as amos_c_, all symbols are prefixed so that the translation
+    # can be linked alongside the Fortran library
+    names = ["d1mach_", "i1mach_", "r1mach_"] + [unit.name.lower() + "_" for unit in units]
+    header = [HEADER, "#ifdef AMOS_PREFIX", "#define AMOS_PASTE(a, b) a##b"]
+    header.append("#define AMOS_NAME(a, b) AMOS_PASTE(a, b)")
+    header += [f"#define {name} AMOS_NAME(AMOS_PREFIX, {name})" for name in names]
+    header.append("#endif\n")
+    header.append("double d1mach_(int *i);")
+    header.append("int i1mach_(int *i);")
+    header.append("float r1mach_(int *i);")
+
+    for unit in units:
+        header.append(prototype(unit) + ";")
+
+    header.append("\n#endif")
+    (out / "amos.h").write_text("\n".join(header) + "\n")
+
+    for path, path_units in sources.items():
+        text = [
+            f"/* Generated by scripts/f77-to-c.py from amos/{path.name}, do not edit. */",
+            "",
+            '#include "amos.h"',
+        ]
+
+        for unit in path_units:
+            text.append("")
+            text.append(Translator(unit, signatures).translate().rstrip("\n"))
+
+        (out / (path.stem + ".c")).write_text("\n".join(text) + "\n")
+
+
+if __name__ == "__main__":
+    main()
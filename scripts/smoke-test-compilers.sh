@@ -0,0 +1,25 @@
+#!/usr/bin/env bash
+# Builds AMOS with each Fortran compiler found on the path and runs the smoke test in
+# tests/test_fortran_compiler.rs against it. Each compiler gets its own target directory, so the
+# builds do not invalidate each other.
+set -euo pipefail
+
+cd "$(dirname "$0")/.."
+
+found=0
+
+for compiler in gfortran flang-new flang ifx ifort; do
+    if command -v "$compiler" > /dev/null; then
+        found=1
+        echo "Testing with $compiler"
+
+        AMOS_FORTRAN_COMPILER="$(command -v "$compiler")" \
+            CARGO_TARGET_DIR="target/fortran-$compiler" \
+            cargo test --test test_fortran_compiler -- --nocapture
+    fi
+done
+
+if [ "$found" -eq 0 ]; then
+    echo "No Fortran compiler found"
+    exit 1
+fi
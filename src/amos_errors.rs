@@ -1,6 +1,63 @@
-use std::os::raw::c_int;
+use core::ffi::c_int;
 
 use log::warn;
+#[cfg(any(
+    feature = "bessel-i",
+    feature = "bessel-j",
+    feature = "bessel-k",
+    feature = "bessel-y",
+    feature = "hankel",
+    feature = "airy"
+))]
+use num::complex::Complex;
+
+// The IERR of two AMOS calls combined into one, where an error in which no computation is done
+// takes precedence over a loss of significance (IERR=3). Only the reflections of I, J and Y make a
+// second call.
+#[cfg(any(feature = "bessel-i", feature = "bessel-j", feature = "bessel-y"))]
+pub(crate) fn combine_ierr(ierr: c_int, ierr_2: c_int) -> c_int {
+    match (ierr, ierr_2) {
+        (0 | 3, 0 | 3) => ierr.max(ierr_2),
+        (0 | 3, _) => ierr_2,
+        _ => ierr,
+    }
+}
+
+// AMOS does not check its input for NaN, which it converts to integers when sizing loops and
+// indexing its work arrays, so that it may read and write out of bounds. NaN orders and arguments
+// are therefore reported as an input error (IERR=1) without calling AMOS. Infinite arguments, for
+// which AMOS computes |z| as NaN and returns NaN with IERR=0, are reported as beyond the largest
+// |z| it accepts (IERR=4). Infinite orders already give IERR=4 in AMOS.
+#[cfg(any(
+    feature = "bessel-i",
+    feature = "bessel-j",
+    feature = "bessel-k",
+    feature = "bessel-y",
+    feature = "hankel",
+    feature = "airy"
+))]
+pub(crate) fn input_ierr(nu: f64, z: Complex<f64>) -> c_int {
+    match (nu.is_nan() || z.is_nan(), z.is_infinite()) {
+        (true, _) => 1,
+        (false, true) => 4,
+        (false, false) => 0,
+    }
+}
+
+// AMOS does no computation for IERR=1, 2, 4 and 5, see amos/zbesi.f lines 42-60, and leaves the
+// output as it was, so the wrappers set it to NaN rather than return the zeros they initialize it
+// with
+#[cfg(any(
+    feature = "bessel-i",
+    feature = "bessel-j",
+    feature = "bessel-k",
+    feature = "bessel-y",
+    feature = "hankel",
+    feature = "airy"
+))]
+pub(crate) fn no_computation_done(ierr: c_int) -> bool {
+    matches!(ierr, 1 | 2 | 4 | 5)
+}
 
 pub fn handle_ierr(func: &str, nz: c_int, ierr: c_int) {
     if nz == 0 && ierr == 0 {
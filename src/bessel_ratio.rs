@@ -0,0 +1,381 @@
+// Ratios iv(nu + 1, x) / iv(nu, x) and kv(nu + 1, x) / kv(nu, x) for real x, computed by continued
+// fractions so that neither function is evaluated on its own. This avoids the overflow and
+// underflow of the quotient of iv or kv for large arguments or orders.
+//
+// The ratio of the I functions uses the Gauss continued fraction (DLMF 10.33.1) for x <= nu + 20,
+// and the Perron continued fraction (Gautschi and Slavik, Math. Comp. 32 (1978)) otherwise, the
+// latter converging in a handful of terms for large x. This is the same fraction that amos/zrati.f
+// evaluates by backward recurrence. The ratio of the K functions uses Steed's algorithm for the
+// continued fraction CF2 of Temme (J. Comput. Phys. 19 (1975)) for x >= 2 or nu >= 10, and the
+// quotient of the scaled AMOS functions otherwise, where the fraction converges slowly.
+//
+// Compared with mpmath, both ratios are accurate to a few ulps when computed by the continued
+// fractions. The quotient of the AMOS functions for x < 2 is accurate to about 1e-15 relative, or
+// 1e-13 when kve itself overflows and the ratio is found from log_kv.
+
+use crate::log_bessel::log_kv;
+use crate::zbesi::ive_real;
+use crate::zbesk::kve_real;
+#[cfg(not(feature = "std"))]
+use num::Float;
+
+// Modified Lentz's method replaces vanishing denominators by a tiny number
+const TINY: f64 = 1e-300;
+const MAX_ITER: usize = 1_000_000;
+const MAX_NEWTON_ITER: usize = 100;
+
+// Evaluates b_0 + a_1 / (b_1 + a_2 / (b_2 + ...)) by the modified Lentz's method, where
+// terms(k) returns (a_k, b_k).
+fn lentz(b_0: f64, terms: impl Fn(f64) -> (f64, f64)) -> f64 {
+    let mut f = if b_0 == 0.0 { TINY } else { b_0 };
+    let mut c = f;
+    let mut d = 0.0;
+
+    for k in 1..MAX_ITER {
+        let (a_k, b_k) = terms(k as f64);
+
+        d = b_k + a_k * d;
+        c = b_k + a_k / c;
+
+        if d == 0.0 {
+            d = TINY;
+        }
+
+        if c == 0.0 {
+            c = TINY;
+        }
+
+        d = 1.0 / d;
+        let delta = c * d;
+        f *= delta;
+
+        // Once NaN, e.g., for infinite orders, the fraction stays NaN
+        if (delta - 1.0).abs() < f64::EPSILON || f.is_nan() {
+            break;
+        }
+    }
+
+    f
+}
+
+// iv(nu + 1, x) / iv(nu, x) for nu > -1 and x > 0
+fn iv_ratio_cf(nu: f64, x: f64) -> f64 {
+    match x <= nu + 20.0 {
+        // iv(nu + 1, x) / iv(nu, x) = 1 / (2 (nu + 1) / x + 1 / (2 (nu + 2) / x + ...))
+        true => 1.0 / lentz(2.0 * (nu + 1.0) / x, |k| (1.0, 2.0 * (nu + k + 1.0) / x)),
+        // iv(nu + 1, x) / iv(nu, x)
+        //      = x / (2 nu + 2 + x - (2 nu + 3) x / (2 nu + 3 + 2 x - (2 nu + 5) x / (...)))
+        false => {
+            x / lentz(2.0 * nu + 2.0 + x, |k| {
+                (
+                    -(2.0 * nu + 1.0 + 2.0 * k) * x,
+                    2.0 * nu + 2.0 + k + 2.0 * x,
+                )
+            })
+        }
+    }
+}
+
+// kv(nu + 1, x) / kv(nu, x) for x > 0 by Steed's algorithm, see Numerical Recipes (3rd ed.)
+// section 6.6.2. The fraction holds for all nu, but converges slowly for small x and nu.
+fn kv_ratio_cf(nu: f64, x: f64) -> f64 {
+    let a_1 = 0.25 - nu * nu;
+    let mut a = -a_1;
+    let mut b = 2.0 * (1.0 + x);
+    let mut d = 1.0 / b;
+    let mut delta_h = d;
+    let mut h = d;
+
+    for i in 2..MAX_ITER {
+        a -= 2.0 * (i - 1) as f64;
+        b += 2.0;
+        d = 1.0 / (b + a * d);
+        delta_h *= b * d - 1.0;
+        h += delta_h;
+
+        if (delta_h / h).abs() < f64::EPSILON || h.is_nan() {
+            break;
+        }
+    }
+
+    (nu + 0.5 + x - a_1 * h) / x
+}
+
+pub fn iv_ratio(nu: f64, x: f64) -> f64 {
+    if x < 0.0 {
+        // iv(n, -x) = (-1)^n iv(n, x), and for non-integer nu iv(nu, x) is complex
+        return match nu.floor() == nu {
+            true => -iv_ratio(nu, -x),
+            false => f64::NAN,
+        };
+    }
+
+    if nu <= -1.0 {
+        // iv(-n, x) = iv(n, x) gives iv(-n + 1, x) / iv(-n, x) = 1 / (iv(n, x) / iv(n - 1, x))
+        if nu.floor() == nu {
+            return 1.0 / iv_ratio(-nu - 1.0, x);
+        }
+
+        // The continued fractions do not hold as iv(nu, x) changes sign for nu < -1
+        return ive_real(nu + 1.0, x) / ive_real(nu, x);
+    }
+
+    if x == 0.0 {
+        return 0.0;
+    }
+
+    if x.is_infinite() {
+        return 1.0;
+    }
+
+    // For tiny x the leading term 2 (nu + 1) / x of the fraction overflows, and the ratio is
+    // x / (2 (nu + 1)), the leading term of the power series
+    if !(2.0 * (nu + 1.0) / x).is_finite() {
+        return x / (2.0 * (nu + 1.0));
+    }
+
+    iv_ratio_cf(nu, x)
+}
+
+pub fn kv_ratio(nu: f64, x: f64) -> f64 {
+    if x < 0.0 {
+        return f64::NAN;
+    }
+
+    // kv(-nu, x) = kv(nu, x) gives kv(nu + 1, x) / kv(nu, x) = 1 / (kv(-nu, x) / kv(-nu - 1, x))
+    if nu < -0.5 {
+        return 1.0 / kv_ratio(-nu - 1.0, x);
+    }
+
+    if x == 0.0 {
+        return match nu == -0.5 {
+            true => 1.0,
+            false => f64::INFINITY,
+        };
+    }
+
+    if x.is_infinite() {
+        return 1.0;
+    }
+
+    if x >= 2.0 || nu >= 10.0 {
+        // The rounding errors in the fraction grow with nu, and are damped by taking a single step
+        // of the forward recurrence kv(nu + 1, x) = kv(nu - 1, x) + (2 nu / x) kv(nu, x).
+        return match nu >= 0.5 {
+            true => 2.0 * nu / x + 1.0 / kv_ratio_cf(nu - 1.0, x),
+            false => kv_ratio_cf(nu, x),
+        };
+    }
+
+    // kve(nu, x) = exp(x) kv(nu, x), where the scaling cancels in the ratio
+    let cy_0 = kve_real(nu, x);
+    let cy_1 = kve_real(nu + 1.0, x);
+
+    match cy_0.is_normal() && cy_1.is_normal() {
+        true => cy_1 / cy_0,
+        false => (log_kv(nu + 1.0, x) - log_kv(nu, x)).exp(),
+    }
+}
+
+// Safeguarded Newton iteration for f(x) = r on (0, inf), where f_df returns f(x) and f'(x), and f is
+// monotonically increasing or decreasing. The iterate is kept within the bracket of the root found
+// so far, and we fall back to bisection (or doubling) when a Newton step leaves the bracket.
+fn invert_ratio(r: f64, x_0: f64, increasing: bool, f_df: impl Fn(f64) -> (f64, f64)) -> f64 {
+    let mut lo = 0.0;
+    let mut hi = f64::INFINITY;
+    let mut x = x_0;
+
+    for _ in 0..MAX_NEWTON_ITER {
+        let (f, df) = f_df(x);
+
+        if f == r {
+            return x;
+        }
+
+        if (f > r) == increasing {
+            hi = x;
+        } else {
+            lo = x;
+        }
+
+        let mut x_new = x - (f - r) / df;
+
+        if x_new.is_nan() || x_new <= lo || x_new >= hi {
+            x_new = match hi.is_finite() {
+                true => 0.5 * (lo + hi),
+                false => 2.0 * x,
+            };
+        }
+
+        if (x_new - x).abs() <= 2.0 * f64::EPSILON * x_new {
+            return x_new;
+        }
+
+        x = x_new;
+    }
+
+    x
+}
+
+// Solves iv_ratio(nu, x) = r for x, e.g., the maximum likelihood estimate of the concentration of
+// the von Mises-Fisher distribution in dimension d is iv_ratio_inv(d / 2 - 1, r) for the mean
+// resultant length r. The ratio increases from 0 to 1 for nu >= -1/2, and NaN is returned for
+// nu < -1/2 or r outside [0, 1].
+pub fn iv_ratio_inv(nu: f64, r: f64) -> f64 {
+    if nu.is_nan() || nu < -0.5 || !(0.0..=1.0).contains(&r) {
+        return f64::NAN;
+    }
+
+    if r == 0.0 {
+        return 0.0;
+    }
+
+    if r == 1.0 {
+        return f64::INFINITY;
+    }
+
+    // Initial guess from Banerjee et al., J. Mach. Learn. Res. 6 (2005)
+    let d = 2.0 * (nu + 1.0);
+    let x_0 = r * (d - r * r) / (1.0 - r * r);
+
+    // d/dx (iv(nu + 1, x) / iv(nu, x)) = 1 - ratio^2 - (2 nu + 1) / x ratio
+    invert_ratio(r, x_0, true, |x| {
+        let ratio = iv_ratio(nu, x);
+
+        (ratio, 1.0 - ratio * ratio - (2.0 * nu + 1.0) / x * ratio)
+    })
+}
+
+// Solves kv_ratio(nu, x) = r for x. The ratio decreases from inf to 1 for nu > -1/2, and NaN is
+// returned for nu <= -1/2 or r < 1.
+pub fn kv_ratio_inv(nu: f64, r: f64) -> f64 {
+    if nu.is_nan() || nu <= -0.5 || r.is_nan() || r < 1.0 {
+        return f64::NAN;
+    }
+
+    if r == 1.0 {
+        return f64::INFINITY;
+    }
+
+    if r.is_infinite() {
+        return 0.0;
+    }
+
+    // Initial guess from inverting the approximation r = (c + sqrt(c^2 + x^2)) / x with
+    // c = nu + 1/2, which is exact for nu = 1/2 and holds asymptotically for small and large x.
+    let c = nu + 0.5;
+    let x_0 = 2.0 * r * c / (r * r - 1.0);
+
+    // d/dx (kv(nu + 1, x) / kv(nu, x)) = ratio^2 - (2 nu + 1) / x ratio - 1
+    invert_ratio(r, x_0, false, |x| {
+        let ratio = kv_ratio(nu, x);
+
+        (ratio, ratio * ratio - (2.0 * nu + 1.0) / x * ratio - 1.0)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_iv_ratio() {
+        for nu in [-0.5, 0.0, 0.3, 2.5, 40.0] {
+            for x in [1e-3, 0.7, 5.0, 30.0, 200.0] {
+                assert_relative_eq!(
+                    iv_ratio(nu, x),
+                    ive_real(nu + 1.0, x) / ive_real(nu, x),
+                    max_relative = 1e-13
+                );
+            }
+        }
+
+        // Reflection for negative orders
+        assert_relative_eq!(iv_ratio(-3.0, 1.5), 1.0 / iv_ratio(2.0, 1.5));
+        assert_relative_eq!(iv_ratio(3.0, -1.5), -iv_ratio(3.0, 1.5));
+        assert_relative_eq!(
+            iv_ratio(-1.7, 1.5),
+            ive_real(-0.7, 1.5) / ive_real(-1.7, 1.5),
+            max_relative = 1e-13
+        );
+
+        // Subnormal arguments, where the continued fraction overflows
+        assert_eq!(iv_ratio(0.0, 1e-320), 5e-321);
+        assert_relative_eq!(iv_ratio_inv(0.0, 1e-320), 2e-320);
+    }
+
+    #[test]
+    fn test_iv_ratio_half_integer() {
+        // iv(1/2, x) / iv(-1/2, x) = tanh(x) and iv(3/2, x) / iv(1/2, x) = coth(x) - 1 / x
+        for x in [1e-3, 0.7, 30.0, 1e3, 1e8] {
+            assert_relative_eq!(iv_ratio(-0.5, x), x.tanh(), max_relative = 1e-14);
+            assert_relative_eq!(
+                iv_ratio(0.5, x),
+                1.0 / x.tanh() - 1.0 / x,
+                max_relative = 1e-14 / x.min(1.0).powi(2)
+            );
+        }
+    }
+
+    #[test]
+    fn test_kv_ratio() {
+        for nu in [-0.3, 0.0, 0.3, 2.5, 9.0, 40.0] {
+            for x in [1e-3, 0.7, 5.0, 30.0, 200.0] {
+                assert_relative_eq!(
+                    kv_ratio(nu, x),
+                    kve_real(nu + 1.0, x) / kve_real(nu, x),
+                    max_relative = 1e-13
+                );
+            }
+        }
+
+        // kv(3/2, x) / kv(1/2, x) = 1 + 1 / x
+        for x in [1e-3, 0.7, 30.0, 1e3, 1e8] {
+            assert_relative_eq!(kv_ratio(0.5, x), 1.0 + 1.0 / x, max_relative = 1e-14);
+            assert_relative_eq!(kv_ratio(-0.5, x), 1.0, max_relative = 1e-14);
+            assert_relative_eq!(
+                kv_ratio(-1.5, x),
+                1.0 / (1.0 + 1.0 / x),
+                max_relative = 1e-14
+            );
+        }
+    }
+
+    #[test]
+    fn test_ratio_domain() {
+        assert_eq!(iv_ratio(1.0, 0.0), 0.0);
+        assert_eq!(kv_ratio(1.0, 0.0), f64::INFINITY);
+        assert!(iv_ratio(0.5, -1.0).is_nan());
+        assert!(kv_ratio(0.5, -1.0).is_nan());
+
+        assert!(iv_ratio_inv(-0.7, 0.5).is_nan());
+        assert!(iv_ratio_inv(1.0, 1.5).is_nan());
+        assert_eq!(iv_ratio_inv(1.0, 0.0), 0.0);
+        assert_eq!(iv_ratio_inv(1.0, 1.0), f64::INFINITY);
+
+        assert!(kv_ratio_inv(-0.5, 1.5).is_nan());
+        assert!(kv_ratio_inv(1.0, 0.5).is_nan());
+        assert_eq!(kv_ratio_inv(1.0, 1.0), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_ratio_inv() {
+        for nu in [0.0, 0.5, 1.0, 49.0] {
+            for x in [1e-6, 0.3, 2.0, 17.0, 1e4] {
+                assert_relative_eq!(iv_ratio_inv(nu, iv_ratio(nu, x)), x, max_relative = 1e-9);
+            }
+        }
+
+        // iv_ratio(-1/2, x) = tanh(x) rounds to 1 already for moderate x
+        for x in [1e-6, 0.3, 2.0f64] {
+            assert_relative_eq!(iv_ratio_inv(-0.5, x.tanh()), x, max_relative = 1e-9);
+        }
+
+        for nu in [-0.3, 0.0, 0.5, 1.0, 49.0] {
+            for x in [1e-6, 0.3, 2.0, 17.0, 1e4] {
+                assert_relative_eq!(kv_ratio_inv(nu, kv_ratio(nu, x)), x, max_relative = 1e-9);
+            }
+        }
+    }
+}
@@ -1,96 +1,130 @@
-/* automatically generated by rust-bindgen 0.60.1 */
+// The declarations of the AMOS routines, first generated by rust-bindgen 0.60.1 and since maintained
+// by hand, so the file is not to be regenerated. Each routine is declared only with the families
+// that call it, matching the routines compiled by amos_routines in build.rs.
 
+#[cfg(all(not(feature = "pure-rust"), feature = "hankel"))]
 extern "C" {
     pub fn zbesh_(
         zr: *const f64,
         zi: *const f64,
         fnu: *const f64,
-        kode: *const ::std::os::raw::c_int,
-        m: *const ::std::os::raw::c_int,
-        n: *const ::std::os::raw::c_int,
+        kode: *const ::core::ffi::c_int,
+        m: *const ::core::ffi::c_int,
+        n: *const ::core::ffi::c_int,
         cyr: *mut f64,
         cyi: *mut f64,
-        nz: *mut ::std::os::raw::c_int,
-        ierr: *mut ::std::os::raw::c_int,
+        nz: *mut ::core::ffi::c_int,
+        ierr: *mut ::core::ffi::c_int,
     );
 }
+#[cfg(all(not(feature = "pure-rust"), feature = "bessel-i"))]
 extern "C" {
     pub fn zbesi_(
         zr: *const f64,
         zi: *const f64,
         fnu: *const f64,
-        kode: *const ::std::os::raw::c_int,
-        n: *const ::std::os::raw::c_int,
+        kode: *const ::core::ffi::c_int,
+        n: *const ::core::ffi::c_int,
         cyr: *mut f64,
         cyi: *mut f64,
-        nz: *mut ::std::os::raw::c_int,
-        ierr: *mut ::std::os::raw::c_int,
+        nz: *mut ::core::ffi::c_int,
+        ierr: *mut ::core::ffi::c_int,
     );
 }
+#[cfg(all(
+    not(feature = "pure-rust"),
+    any(feature = "bessel-j", feature = "bessel-y")
+))]
 extern "C" {
     pub fn zbesj_(
         zr: *const f64,
         zi: *const f64,
         fnu: *const f64,
-        kode: *const ::std::os::raw::c_int,
-        n: *const ::std::os::raw::c_int,
+        kode: *const ::core::ffi::c_int,
+        n: *const ::core::ffi::c_int,
         cyr: *mut f64,
         cyi: *mut f64,
-        nz: *mut ::std::os::raw::c_int,
-        ierr: *mut ::std::os::raw::c_int,
+        nz: *mut ::core::ffi::c_int,
+        ierr: *mut ::core::ffi::c_int,
     );
 }
+#[cfg(all(
+    not(feature = "pure-rust"),
+    any(feature = "bessel-i", feature = "bessel-k")
+))]
 extern "C" {
     pub fn zbesk_(
         zr: *const f64,
         zi: *const f64,
         fnu: *const f64,
-        kode: *const ::std::os::raw::c_int,
-        n: *const ::std::os::raw::c_int,
+        kode: *const ::core::ffi::c_int,
+        n: *const ::core::ffi::c_int,
         cyr: *mut f64,
         cyi: *mut f64,
-        nz: *mut ::std::os::raw::c_int,
-        ierr: *mut ::std::os::raw::c_int,
+        nz: *mut ::core::ffi::c_int,
+        ierr: *mut ::core::ffi::c_int,
     );
 }
+#[cfg(all(
+    not(feature = "pure-rust"),
+    any(feature = "bessel-j", feature = "bessel-y")
+))]
 extern "C" {
     pub fn zbesy_(
         zr: *const f64,
         zi: *const f64,
         fnu: *const f64,
-        kode: *const ::std::os::raw::c_int,
-        n: *const ::std::os::raw::c_int,
+        kode: *const ::core::ffi::c_int,
+        n: *const ::core::ffi::c_int,
         cyr: *mut f64,
         cyi: *mut f64,
-        nz: *mut ::std::os::raw::c_int,
+        nz: *mut ::core::ffi::c_int,
         cwrkr: *mut f64,
         cwrki: *mut f64,
-        ierr: *mut ::std::os::raw::c_int,
+        ierr: *mut ::core::ffi::c_int,
     );
 }
+#[cfg(all(not(feature = "pure-rust"), feature = "airy"))]
 extern "C" {
     pub fn zairy_(
         zr: *const f64,
         zi: *const f64,
-        id: *const ::std::os::raw::c_int,
-        kode: *const ::std::os::raw::c_int,
+        id: *const ::core::ffi::c_int,
+        kode: *const ::core::ffi::c_int,
         air: *mut f64,
         aii: *mut f64,
-        nz: *mut ::std::os::raw::c_int,
-        ierr: *mut ::std::os::raw::c_int,
+        nz: *mut ::core::ffi::c_int,
+        ierr: *mut ::core::ffi::c_int,
     );
 }
+#[cfg(all(not(feature = "pure-rust"), feature = "airy"))]
 extern "C" {
     pub fn zbiry_(
         zr: *const f64,
         zi: *const f64,
-        id: *const ::std::os::raw::c_int,
-        kode: *const ::std::os::raw::c_int,
+        id: *const ::core::ffi::c_int,
+        kode: *const ::core::ffi::c_int,
         bir: *mut f64,
         bii: *mut f64,
-        ierr: *mut ::std::os::raw::c_int,
+        ierr: *mut ::core::ffi::c_int,
     );
 }
+#[cfg(all(not(feature = "pure-rust"), feature = "log-bessel"))]
 extern "C" {
-    pub fn dgamln_(z: *const f64, ierr: *mut ::std::os::raw::c_int) -> f64;
+    pub fn dgamln_(z: *const f64, ierr: *mut ::core::ffi::c_int) -> f64;
 }
+
+// With the pure-rust feature the routines are the Rust port in src/native, behind the same
+// signatures
+#[cfg(all(
+    feature = "pure-rust",
+    any(
+        feature = "bessel-i",
+        feature = "bessel-k",
+        feature = "bessel-j",
+        feature = "bessel-y",
+        feature = "hankel",
+        feature = "airy"
+    )
+))]
+pub use crate::native::ffi::*;
@@ -0,0 +1,329 @@
+// C bindings with the capi feature, built as a shared and a static library by the amos-capi package
+// in capi/, where capi/include/amos.h is generated from this file by cbindgen. The functions take
+// the same arguments as their Rust counterparts, write the result to cy, and return the IERR of AMOS
+// as an AmosStatus. The sequences hold n values, and cy must point to at least n AmosComplex.
+#![allow(clippy::missing_safety_doc)]
+
+use crate::{zbesh, zbesi, zbesj, zbesk, zbesy};
+use core::ffi::c_int;
+use core::slice;
+use num::complex::Complex;
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AmosComplex {
+    pub re: f64,
+    pub im: f64,
+}
+
+impl From<AmosComplex> for Complex<f64> {
+    fn from(z: AmosComplex) -> Self {
+        Complex::new(z.re, z.im)
+    }
+}
+
+impl From<Complex<f64>> for AmosComplex {
+    fn from(z: Complex<f64>) -> Self {
+        AmosComplex { re: z.re, im: z.im }
+    }
+}
+
+// Definitions of the errors are found in amos/zbesi.f lines 42-60, where results are returned for
+// Ok and LossOfSignificance only. Underflow (NZ > 0), which sets values to zero, is not reported.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AmosStatus {
+    Ok = 0,
+    InputError = 1,
+    Overflow = 2,
+    LossOfSignificance = 3,
+    CompleteLossOfSignificance = 4,
+    TerminationConditionNotMet = 5,
+}
+
+impl From<i32> for AmosStatus {
+    fn from(ierr: i32) -> Self {
+        match ierr {
+            0 => AmosStatus::Ok,
+            1 => AmosStatus::InputError,
+            2 => AmosStatus::Overflow,
+            3 => AmosStatus::LossOfSignificance,
+            4 => AmosStatus::CompleteLossOfSignificance,
+            _ => AmosStatus::TerminationConditionNotMet,
+        }
+    }
+}
+
+// The Rust functions panic on invalid input, and unwinding out of an extern "C" function aborts,
+// so the input is checked here and reported as an input error instead
+fn is_valid(nu: f64, z: AmosComplex) -> bool {
+    !(nu.is_nan() || z.re.is_nan() || z.im.is_nan())
+}
+
+unsafe fn scalar(
+    nu: f64,
+    z: AmosComplex,
+    cy: *mut AmosComplex,
+    f: impl FnOnce(f64, Complex<f64>) -> (Complex<f64>, i32, i32),
+) -> AmosStatus {
+    if cy.is_null() || !is_valid(nu, z) {
+        return AmosStatus::InputError;
+    }
+
+    let (value, _, ierr) = f(nu, z.into());
+    *cy = value.into();
+
+    ierr.into()
+}
+
+unsafe fn sequence(
+    nu: f64,
+    z: AmosComplex,
+    n: c_int,
+    cy: *mut AmosComplex,
+    f: impl FnOnce(f64, Complex<f64>, i32) -> (Vec<Complex<f64>>, i32, i32),
+) -> AmosStatus {
+    if cy.is_null() || n < 1 || !is_valid(nu, z) {
+        return AmosStatus::InputError;
+    }
+
+    let (values, _, ierr) = f(nu, z.into(), n);
+    let cy = slice::from_raw_parts_mut(cy, n as usize);
+
+    for (cy, value) in cy.iter_mut().zip(values) {
+        *cy = value.into();
+    }
+
+    ierr.into()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn amos_iv(nu: f64, z: AmosComplex, cy: *mut AmosComplex) -> AmosStatus {
+    scalar(nu, z, cy, |nu, z| zbesi::zbesi_ierr(nu, z, 1))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn amos_ive(nu: f64, z: AmosComplex, cy: *mut AmosComplex) -> AmosStatus {
+    scalar(nu, z, cy, |nu, z| zbesi::zbesi_ierr(nu, z, 2))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn amos_jv(nu: f64, z: AmosComplex, cy: *mut AmosComplex) -> AmosStatus {
+    scalar(nu, z, cy, |nu, z| zbesj::zbesj_ierr(nu, z, 1))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn amos_jve(nu: f64, z: AmosComplex, cy: *mut AmosComplex) -> AmosStatus {
+    scalar(nu, z, cy, |nu, z| zbesj::zbesj_ierr(nu, z, 2))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn amos_kv(nu: f64, z: AmosComplex, cy: *mut AmosComplex) -> AmosStatus {
+    scalar(nu, z, cy, |nu, z| zbesk::zbesk_ierr(nu, z, 1))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn amos_kve(nu: f64, z: AmosComplex, cy: *mut AmosComplex) -> AmosStatus {
+    scalar(nu, z, cy, |nu, z| zbesk::zbesk_ierr(nu, z, 2))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn amos_yv(nu: f64, z: AmosComplex, cy: *mut AmosComplex) -> AmosStatus {
+    scalar(nu, z, cy, |nu, z| zbesy::zbesy_ierr(nu, z, 1))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn amos_yve(nu: f64, z: AmosComplex, cy: *mut AmosComplex) -> AmosStatus {
+    scalar(nu, z, cy, |nu, z| zbesy::zbesy_ierr(nu, z, 2))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn amos_hankel1(nu: f64, z: AmosComplex, cy: *mut AmosComplex) -> AmosStatus {
+    scalar(nu, z, cy, |nu, z| zbesh::zbesh_scalar(nu, z, 1, 1))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn amos_hankel1e(
+    nu: f64,
+    z: AmosComplex,
+    cy: *mut AmosComplex,
+) -> AmosStatus {
+    scalar(nu, z, cy, |nu, z| zbesh::zbesh_scalar(nu, z, 2, 1))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn amos_hankel2(nu: f64, z: AmosComplex, cy: *mut AmosComplex) -> AmosStatus {
+    scalar(nu, z, cy, |nu, z| zbesh::zbesh_scalar(nu, z, 1, 2))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn amos_hankel2e(
+    nu: f64,
+    z: AmosComplex,
+    cy: *mut AmosComplex,
+) -> AmosStatus {
+    scalar(nu, z, cy, |nu, z| zbesh::zbesh_scalar(nu, z, 2, 2))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn amos_iv_seq(
+    nu: f64,
+    z: AmosComplex,
+    n: c_int,
+    cy: *mut AmosComplex,
+) -> AmosStatus {
+    sequence(nu, z, n, cy, |nu, z, n| zbesi::zbesi_vec_ierr(nu, z, 1, n))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn amos_ive_seq(
+    nu: f64,
+    z: AmosComplex,
+    n: c_int,
+    cy: *mut AmosComplex,
+) -> AmosStatus {
+    sequence(nu, z, n, cy, |nu, z, n| zbesi::zbesi_vec_ierr(nu, z, 2, n))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn amos_jv_seq(
+    nu: f64,
+    z: AmosComplex,
+    n: c_int,
+    cy: *mut AmosComplex,
+) -> AmosStatus {
+    sequence(nu, z, n, cy, |nu, z, n| zbesj::zbesj_vec_ierr(nu, z, 1, n))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn amos_jve_seq(
+    nu: f64,
+    z: AmosComplex,
+    n: c_int,
+    cy: *mut AmosComplex,
+) -> AmosStatus {
+    sequence(nu, z, n, cy, |nu, z, n| zbesj::zbesj_vec_ierr(nu, z, 2, n))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn amos_kv_seq(
+    nu: f64,
+    z: AmosComplex,
+    n: c_int,
+    cy: *mut AmosComplex,
+) -> AmosStatus {
+    sequence(nu, z, n, cy, |nu, z, n| zbesk::zbesk_vec_ierr(nu, z, 1, n))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn amos_kve_seq(
+    nu: f64,
+    z: AmosComplex,
+    n: c_int,
+    cy: *mut AmosComplex,
+) -> AmosStatus {
+    sequence(nu, z, n, cy, |nu, z, n| zbesk::zbesk_vec_ierr(nu, z, 2, n))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn amos_yv_seq(
+    nu: f64,
+    z: AmosComplex,
+    n: c_int,
+    cy: *mut AmosComplex,
+) -> AmosStatus {
+    sequence(nu, z, n, cy, |nu, z, n| zbesy::zbesy_vec_ierr(nu, z, 1, n))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn amos_yve_seq(
+    nu: f64,
+    z: AmosComplex,
+    n: c_int,
+    cy: *mut AmosComplex,
+) -> AmosStatus {
+    sequence(nu, z, n, cy, |nu, z, n| zbesy::zbesy_vec_ierr(nu, z, 2, n))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn amos_hankel1_seq(
+    nu: f64,
+    z: AmosComplex,
+    n: c_int,
+    cy: *mut AmosComplex,
+) -> AmosStatus {
+    sequence(nu, z, n, cy, |nu, z, n| zbesh::zbesh_ierr(nu, z, 1, 1, n))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn amos_hankel1e_seq(
+    nu: f64,
+    z: AmosComplex,
+    n: c_int,
+    cy: *mut AmosComplex,
+) -> AmosStatus {
+    sequence(nu, z, n, cy, |nu, z, n| zbesh::zbesh_ierr(nu, z, 2, 1, n))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn amos_hankel2_seq(
+    nu: f64,
+    z: AmosComplex,
+    n: c_int,
+    cy: *mut AmosComplex,
+) -> AmosStatus {
+    sequence(nu, z, n, cy, |nu, z, n| zbesh::zbesh_ierr(nu, z, 1, 2, n))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn amos_hankel2e_seq(
+    nu: f64,
+    z: AmosComplex,
+    n: c_int,
+    cy: *mut AmosComplex,
+) -> AmosStatus {
+    sequence(nu, z, n, cy, |nu, z, n| zbesh::zbesh_ierr(nu, z, 2, 2, n))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_amos_hankel1_seq() {
+        let z = AmosComplex { re: 1.3, im: -0.4 };
+        let mut cy = [AmosComplex { re: 0.0, im: 0.0 }; 3];
+
+        let status = unsafe { amos_hankel1_seq(-0.3, z, 3, cy.as_mut_ptr()) };
+        let cy_h = zbesh::hankel1(-0.3, z.into(), 3);
+
+        assert_eq!(status, AmosStatus::Ok);
+
+        for (cy, cy_h) in cy.iter().zip(cy_h.iter()) {
+            assert_abs_diff_eq!(cy.re, cy_h.re);
+            assert_abs_diff_eq!(cy.im, cy_h.im);
+        }
+    }
+
+    #[test]
+    fn test_amos_status() {
+        let z = AmosComplex { re: 1.0, im: 0.0 };
+        let mut cy = AmosComplex { re: 0.0, im: 0.0 };
+
+        unsafe {
+            assert_eq!(amos_iv(f64::NAN, z, &mut cy), AmosStatus::InputError);
+            assert_eq!(
+                amos_iv(0.0, z, core::ptr::null_mut()),
+                AmosStatus::InputError
+            );
+            assert_eq!(amos_kv_seq(0.0, z, 0, &mut cy), AmosStatus::InputError);
+
+            // iv overflows for z beyond about 700
+            let z = AmosComplex { re: 1e3, im: 0.0 };
+
+            assert_eq!(amos_iv(0.0, z, &mut cy), AmosStatus::Overflow);
+            assert_eq!(amos_ive(0.0, z, &mut cy), AmosStatus::Ok);
+        }
+    }
+}
@@ -1,13 +1,51 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 pub mod amos_errors;
+#[cfg(feature = "log-bessel")]
+pub mod bessel_ratio;
 mod bindings;
+#[cfg(feature = "capi")]
+pub mod capi;
+pub mod cephes;
+#[cfg(feature = "log-bessel")]
+pub mod log_bessel;
+// Without any of the family features there is nothing to port, see build.rs
+#[cfg(all(
+    feature = "pure-rust",
+    any(
+        feature = "bessel-i",
+        feature = "bessel-k",
+        feature = "bessel-j",
+        feature = "bessel-y",
+        feature = "hankel",
+        feature = "airy"
+    )
+))]
+mod native;
+#[cfg(feature = "wasm-bindgen")]
+pub mod wasm;
+#[cfg(feature = "airy")]
+pub mod zairy;
+#[cfg(feature = "hankel")]
 pub mod zbesh;
+#[cfg(feature = "bessel-i")]
 pub mod zbesi;
+// J and Y of negative order are reflected in terms of each other
+#[cfg(any(feature = "bessel-j", feature = "bessel-y"))]
+pub mod zbesj;
+#[cfg(feature = "bessel-k")]
+pub mod zbesk;
+#[cfg(any(feature = "bessel-j", feature = "bessel-y"))]
+pub mod zbesy;
 
-#[cfg(test)]
+#[cfg(all(test, feature = "bessel-i"))]
 mod tests {
     use super::*;
     use bindings::*;
-    use std::os::raw::c_int;
+    use core::ffi::c_int;
 
     #[test]
     fn test_zbesi() {
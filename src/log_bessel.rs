@@ -0,0 +1,432 @@
+// Logarithms of the Bessel functions for arguments and orders where the functions themselves
+// overflow or underflow. The exponentially scaled AMOS functions (kode=2) are used whenever their
+// result is representable, and we fall back to the uniform asymptotic expansions in DLMF 10.41 for
+// large orders and to the leading term of the power series for small arguments.
+
+use crate::bindings::dgamln_;
+use crate::zbesh::zbesh_scalar;
+use crate::zbesi::ive_real;
+use crate::zbesk::kve_real;
+use core::f64::consts::{FRAC_PI_2, LN_2, PI};
+use core::ffi::c_int;
+use num::complex::Complex;
+#[cfg(not(feature = "std"))]
+use num::Float;
+
+// Coefficients of the polynomials u_k(p) in DLMF 10.41.10, where row k holds the coefficients of
+// p^k, p^(k + 2), ..., p^(3k). The rows are generated by the recurrence in DLMF 10.41.9.
+const DEBYE_U: [&[f64]; 7] = [
+    &[1.0],
+    &[1.0 / 8.0, -5.0 / 24.0],
+    &[9.0 / 128.0, -77.0 / 192.0, 385.0 / 1152.0],
+    &[
+        75.0 / 1024.0,
+        -4563.0 / 5120.0,
+        17017.0 / 9216.0,
+        -85085.0 / 82944.0,
+    ],
+    &[
+        3675.0 / 32768.0,
+        -96833.0 / 40960.0,
+        144001.0 / 16384.0,
+        -7436429.0 / 663552.0,
+        37182145.0 / 7962624.0,
+    ],
+    &[
+        59535.0 / 262144.0,
+        -67608983.0 / 9175040.0,
+        250881631.0 / 5898240.0,
+        -108313205.0 / 1179648.0,
+        5391411025.0 / 63700992.0,
+        -5391411025.0 / 191102976.0,
+    ],
+    &[
+        2401245.0 / 4194304.0,
+        -388895895.0 / 14680064.0,
+        1441372804469.0 / 6606028800.0,
+        -33010308331.0 / 47185920.0,
+        4445922195.0 / 4194304.0,
+        -1169936192425.0 / 1528823808.0,
+        5849680962125.0 / 27518828544.0,
+    ],
+];
+
+// Largest expansion parameter max(1, |p|^2) / |s| for which the seven terms of the uniform
+// asymptotic expansions are used. The first omitted term is then below 1e-9 relative to the
+// function value, i.e., an absolute error of 1e-9 in the logarithm, and in practice the expansions
+// are only reached for orders where the error is several orders of magnitude smaller.
+const DEBYE_MAX_EPS: f64 = 0.05;
+
+struct Debye {
+    // nu * eta in DLMF 10.41.3
+    nu_eta: Complex<f64>,
+    // log(sqrt(nu^2 + z^2))
+    log_s: Complex<f64>,
+    // sum_k u_k(p) / nu^k
+    sum_i: Complex<f64>,
+    // sum_k (-1)^k u_k(p) / nu^k
+    sum_k: Complex<f64>,
+}
+
+// Uniform asymptotic expansions of iv(nu, z) and kv(nu, z), see DLMF 10.41.3 and 10.41.4. Writing
+// s = sqrt(nu^2 + z^2) and p = nu / s, we have
+//
+//      nu * eta = s + nu * log(z / (nu + s)),
+//      log(iv(nu, z)) ~ nu * eta - log(2 pi s) / 2 + log(sum_k u_k(p) / nu^k),
+//      log(kv(nu, z)) ~ -nu * eta + log(pi / (2 s)) / 2 + log(sum_k (-1)^k u_k(p) / nu^k).
+//
+// As u_k(p) / nu^k = s^(-k) times a polynomial in p^2, these are well-defined as nu -> 0, where they
+// reduce to the large argument expansions. The logarithm of z is passed separately so that the
+// caller can choose the branch. The expansions hold for nu >= 0 and |z| < nu, or Re(z) >= 0, away
+// from the turning points z = +/- i nu, and None is returned outside this region.
+fn debye(nu: f64, z: Complex<f64>, log_z: Complex<f64>) -> Option<Debye> {
+    let s = match z.norm() <= nu {
+        true => nu * (1.0 + (z / nu).powi(2)).sqrt(),
+        false if z.re >= 0.0 => z * (1.0 + (nu / z).powi(2)).sqrt(),
+        false => return None,
+    };
+
+    let p = nu / s;
+    let eps = p.norm_sqr().max(1.0) / s.norm();
+
+    if eps.is_nan() || eps >= DEBYE_MAX_EPS {
+        return None;
+    }
+
+    let p2 = p * p;
+    let inv_s = 1.0 / s;
+
+    let mut sum_i = Complex::new(0.0, 0.0);
+    let mut sum_k = Complex::new(0.0, 0.0);
+    let mut s_k = Complex::new(1.0, 0.0);
+
+    for (k, u_k) in DEBYE_U.iter().enumerate() {
+        let term = s_k
+            * u_k
+                .iter()
+                .rev()
+                .fold(Complex::new(0.0, 0.0), |acc, &c| acc * p2 + c);
+
+        sum_i += term;
+        sum_k += if k % 2 == 0 { term } else { -term };
+        s_k *= inv_s;
+    }
+
+    Some(Debye {
+        nu_eta: s + nu * (log_z - (nu + s).ln()),
+        log_s: s.ln(),
+        sum_i,
+        sum_k,
+    })
+}
+
+// log(gamma(x)) for x > 0
+fn gamln(x: f64) -> f64 {
+    let mut ierr: c_int = 0;
+
+    unsafe { dgamln_(&x, &mut ierr as *mut c_int) }
+}
+
+// AMOS flushes results close to underflow to zero, and returns zero when no computation is done
+// due to overflow. Only results in the normal range are therefore used directly.
+fn is_trusted(cy: f64) -> bool {
+    cy.is_normal() && cy > 0.0
+}
+
+// log(iv(nu, x)) for nu > 0, where iv(-nu, x) = iv(nu, x) + (2 / pi) sin(pi nu) kv(nu, x), see
+// amos/zbesi.f. Returns NaN when iv(-nu, x) is negative.
+fn log_iv_reflected(nu: f64, x: f64) -> f64 {
+    let c = 2.0 / PI * (PI * nu).sin();
+
+    if x == 0.0 {
+        return match c > 0.0 {
+            true => f64::INFINITY,
+            false => f64::NAN,
+        };
+    }
+
+    let log_i = log_iv(nu, x);
+    let log_k = log_kv(nu, x);
+    let max = log_i.max(log_k);
+    let sum = (log_i - max).exp() + c * (log_k - max).exp();
+
+    match sum >= 0.0 {
+        true => max + sum.ln(),
+        false => f64::NAN,
+    }
+}
+
+pub fn log_iv(nu: f64, x: f64) -> f64 {
+    if x < 0.0 {
+        // iv(n, -x) = (-1)^n iv(n, x), and for non-integer nu iv(nu, x) is complex
+        return match nu % 2.0 == 0.0 {
+            true => log_iv(nu, -x),
+            false => f64::NAN,
+        };
+    }
+
+    if nu < 0.0 && nu.floor() != nu {
+        return log_iv_reflected(-nu, x);
+    }
+
+    // iv(-n, x) = iv(n, x)
+    let nu = nu.abs();
+
+    if x == 0.0 {
+        return match nu == 0.0 {
+            true => 0.0,
+            false => f64::NEG_INFINITY,
+        };
+    }
+
+    // ive(nu, x) = exp(-x) iv(nu, x)
+    let cy = ive_real(nu, x);
+
+    if is_trusted(cy) {
+        return cy.ln() + x;
+    }
+
+    let z = Complex::new(x, 0.0);
+
+    if let Some(d) = debye(nu, z, z.ln()) {
+        return (d.nu_eta - 0.5 * (2.0 * PI).ln() - 0.5 * d.log_s + d.sum_i.ln()).re;
+    }
+
+    // Leading term of the power series iv(nu, x) ~ (x / 2)^nu / gamma(nu + 1) for small x
+    if x * x < f64::EPSILON * (nu + 1.0) {
+        return nu * (0.5 * x).ln() - gamln(nu + 1.0);
+    }
+
+    cy.ln() + x
+}
+
+pub fn log_kv(nu: f64, x: f64) -> f64 {
+    if x < 0.0 {
+        return f64::NAN;
+    }
+
+    // kv(-nu, x) = kv(nu, x)
+    let nu = nu.abs();
+
+    if x == 0.0 {
+        return f64::INFINITY;
+    }
+
+    // kve(nu, x) = exp(x) kv(nu, x)
+    let cy = kve_real(nu, x);
+
+    if is_trusted(cy) {
+        return cy.ln() - x;
+    }
+
+    let z = Complex::new(x, 0.0);
+
+    if let Some(d) = debye(nu, z, z.ln()) {
+        return (-d.nu_eta + 0.5 * FRAC_PI_2.ln() - 0.5 * d.log_s + d.sum_k.ln()).re;
+    }
+
+    // Leading term kv(nu, x) ~ gamma(nu) (2 / x)^nu / 2 for small x
+    if nu > 0.0 && x * x < f64::EPSILON * nu {
+        return gamln(nu) - LN_2 + nu * (2.0 / x).ln();
+    }
+
+    cy.ln() - x
+}
+
+// Logarithm of the Hankel functions. The imaginary part is not reduced to (-pi, pi], but follows
+// from the exponential scaling, i.e., log(hankel1(nu, z)) = log(hankel1e(nu, z)) + i z.
+pub fn log_hankel(nu: f64, z: Complex<f64>, m: i32) -> Complex<f64> {
+    if !(1..=2).contains(&m) {
+        panic!("m must 1 (hankel1) or 2 (hankel2)");
+    }
+
+    let e_sign = match m {
+        1 => 1.0,
+        2 => -1.0,
+        _ => panic!("Whoops! This case should not occur."),
+    };
+
+    // hankel1(-nu, z) = exp(i pi nu) hankel1(nu, z) and hankel2(-nu, z) = exp(-i pi nu)
+    // hankel2(nu, z), see amos/zbesh.f lines 89-95
+    let rotation = match nu < 0.0 {
+        true => Complex::new(0.0, -e_sign * PI * nu),
+        false => Complex::new(0.0, 0.0),
+    };
+    let nu = nu.abs();
+
+    // hankel1e(nu, z) = exp(-i z) hankel1(nu, z) and hankel2e(nu, z) = exp(i z) hankel2(nu, z)
+    let (cy, _, _) = zbesh_scalar(nu, z, 2, m);
+    let scaling = Complex::new(0.0, e_sign) * z;
+
+    if is_trusted(cy.norm()) {
+        return cy.ln() + scaling + rotation;
+    }
+
+    // From DLMF 10.27.8 we have
+    //
+    //      hankel1(nu, z) = (2 / (pi i)) exp(-i pi nu / 2) kv(nu, -i z),
+    //      hankel2(nu, z) = -(2 / (pi i)) exp(i pi nu / 2) kv(nu, i z),
+    //
+    // where the logarithm of -/+ i z is found from the principal logarithm of z.
+    let zeta = Complex::new(0.0, -e_sign) * z;
+    let log_zeta = z.ln() - Complex::new(0.0, e_sign * FRAC_PI_2);
+
+    if let Some(d) = debye(nu, zeta, log_zeta) {
+        let log_k = -d.nu_eta + 0.5 * FRAC_PI_2.ln() - 0.5 * d.log_s + d.sum_k.ln();
+
+        return (2.0 / PI).ln() - Complex::new(0.0, e_sign * FRAC_PI_2 * (1.0 + nu))
+            + log_k
+            + rotation;
+    }
+
+    // Leading term hankel1(nu, z) ~ -i gamma(nu) (2 / z)^nu / pi for small z, and the complex
+    // conjugate expression for hankel2
+    if nu > 0.0 && z.norm_sqr() < f64::EPSILON * nu {
+        return gamln(nu) - PI.ln() - Complex::new(0.0, e_sign * FRAC_PI_2)
+            + nu * (LN_2 - z.ln())
+            + rotation;
+    }
+
+    cy.ln() + scaling + rotation
+}
+
+pub fn log_hankel1(nu: f64, z: Complex<f64>) -> Complex<f64> {
+    let m: i32 = 1;
+
+    log_hankel(nu, z, m)
+}
+
+pub fn log_hankel2(nu: f64, z: Complex<f64>) -> Complex<f64> {
+    let m: i32 = 2;
+
+    log_hankel(nu, z, m)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::zbesh::hankel1;
+    use crate::zbesi::iv_real;
+    use crate::zbesk::kv_real;
+    use approx::{assert_abs_diff_eq, assert_relative_eq};
+
+    // Compares logarithms of complex numbers modulo 2 pi i
+    fn assert_log_eq(a: Complex<f64>, b: Complex<f64>, epsilon: f64) {
+        let diff = a - b;
+        let im = diff.im - 2.0 * PI * (diff.im / (2.0 * PI)).round();
+
+        assert_relative_eq!(a.re, b.re, epsilon = epsilon, max_relative = epsilon);
+        assert_abs_diff_eq!(im, 0.0, epsilon = epsilon * a.im.abs().max(1.0));
+    }
+
+    #[test]
+    fn test_log_iv_kv_direct() {
+        for nu in [0.0, 0.3, 2.0, 7.5] {
+            for x in [0.1, 1.0, 13.7, 200.0] {
+                assert_relative_eq!(log_iv(nu, x), iv_real(nu, x).ln(), max_relative = 1e-13);
+                assert_relative_eq!(log_kv(nu, x), kv_real(nu, x).ln(), max_relative = 1e-13);
+            }
+        }
+    }
+
+    #[test]
+    fn test_log_iv_kv_overflow() {
+        // Reference values from mpmath
+        assert_relative_eq!(log_iv(1e4, 10.0), -66014.54621272362, max_relative = 1e-14);
+        assert_relative_eq!(log_kv(1e4, 10.0), 66004.6427246711, max_relative = 1e-14);
+        assert_relative_eq!(log_iv(0.0, 1e5), 99993.32459998432, max_relative = 1e-14);
+        assert_relative_eq!(log_kv(0.0, 1e5), -100005.53067262984, max_relative = 1e-14);
+        assert_relative_eq!(
+            log_iv(300.0, 1e-3),
+            -3695.1765878068622,
+            max_relative = 1e-14
+        );
+        assert_relative_eq!(log_kv(300.0, 1e-3), 3688.77965815164, max_relative = 1e-14);
+        assert_relative_eq!(
+            log_iv(2.5, 1e-200),
+            -1154.2263880507699,
+            max_relative = 1e-14
+        );
+        assert_relative_eq!(
+            log_kv(2.5, 1e-200),
+            1152.6169501383356,
+            max_relative = 1e-14
+        );
+    }
+
+    #[test]
+    fn test_log_iv_kv_half_integer() {
+        // iv(-1/2, x) = sqrt(2 / (pi x)) cosh(x) and kv(1/2, x) = sqrt(pi / (2 x)) exp(-x)
+        let x: f64 = 1e3;
+
+        assert_relative_eq!(
+            log_iv(-0.5, x),
+            0.5 * (2.0 / (PI * x)).ln() + x - LN_2,
+            max_relative = 1e-14
+        );
+
+        let x: f64 = 1e4;
+
+        assert_relative_eq!(
+            log_kv(0.5, x),
+            0.5 * (PI / (2.0 * x)).ln() - x,
+            max_relative = 1e-14
+        );
+        assert_relative_eq!(log_kv(-0.5, x), log_kv(0.5, x));
+    }
+
+    #[test]
+    fn test_log_iv_kv_domain() {
+        assert_eq!(log_iv(0.0, 0.0), 0.0);
+        assert_eq!(log_iv(1.3, 0.0), f64::NEG_INFINITY);
+        assert_eq!(log_kv(1.3, 0.0), f64::INFINITY);
+        assert!(log_kv(1.3, -1.0).is_nan());
+        assert!(log_iv(0.5, -1.0).is_nan());
+        assert!(log_iv(3.0, -1.0).is_nan());
+        assert_eq!(log_iv(-2.0, -1.5), log_iv(2.0, 1.5));
+
+        // iv(-3/2, x) = sqrt(2 / (pi x)) (sinh(x) - cosh(x) / x) is negative for small x
+        assert!(log_iv(-1.5, 0.1).is_nan());
+    }
+
+    #[test]
+    fn test_log_hankel_direct() {
+        let z = Complex::new(1.3, -0.4);
+
+        for nu in [-1.7, 0.0, 0.3, 4.5] {
+            assert_log_eq(log_hankel1(nu, z), hankel1(nu, z, 1)[0].ln(), 1e-13);
+        }
+    }
+
+    #[test]
+    fn test_log_hankel_overflow() {
+        // Reference values from mpmath
+        let z = Complex::new(10.0, 5.0);
+
+        assert_log_eq(
+            log_hankel1(200.0, z),
+            Complex::new(512.681205928864, -94.17463018107782),
+            1e-14,
+        );
+        assert_log_eq(
+            log_hankel2(200.0, z),
+            Complex::new(512.681205928864, -91.03303752748803),
+            1e-14,
+        );
+
+        let z = Complex::new(1e-4, 1e-4);
+
+        assert_log_eq(
+            log_hankel1(40.0, z),
+            Complex::new(487.7635888650402, -32.98672286256461),
+            1e-14,
+        );
+
+        // hankel1(-nu, z) = exp(i pi nu) hankel1(nu, z)
+        assert_log_eq(
+            log_hankel1(-200.5, z),
+            log_hankel1(200.5, z) + Complex::new(0.0, 200.5 * PI),
+            1e-14,
+        );
+    }
+}
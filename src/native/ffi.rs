@@ -0,0 +1,227 @@
+// The ported routines behind the signatures of the library routines in src/bindings.rs, which
+// replace them with the pure-rust feature. As in the Fortran, the outputs are left untouched when
+// ierr is set to an input or size error
+#![allow(clippy::missing_safety_doc, clippy::too_many_arguments)]
+
+#[cfg(any(
+    feature = "bessel-i",
+    feature = "bessel-k",
+    feature = "bessel-j",
+    feature = "bessel-y",
+    feature = "hankel"
+))]
+use alloc::vec::Vec;
+use core::ffi::c_int;
+#[cfg(any(
+    feature = "bessel-i",
+    feature = "bessel-k",
+    feature = "bessel-j",
+    feature = "bessel-y",
+    feature = "hankel"
+))]
+use num::complex::Complex;
+
+#[cfg(feature = "hankel")]
+pub unsafe fn zbesh_(
+    zr: *const f64,
+    zi: *const f64,
+    fnu: *const f64,
+    kode: *const c_int,
+    m: *const c_int,
+    n: *const c_int,
+    cyr: *mut f64,
+    cyi: *mut f64,
+    nz: *mut c_int,
+    ierr: *mut c_int,
+) {
+    let m = *m;
+
+    sequence(
+        zr,
+        zi,
+        fnu,
+        kode,
+        n,
+        cyr,
+        cyi,
+        nz,
+        ierr,
+        |z, fnu, kode, cy| super::zbesh(z, fnu, kode, m, cy),
+    );
+}
+
+#[cfg(feature = "bessel-i")]
+pub unsafe fn zbesi_(
+    zr: *const f64,
+    zi: *const f64,
+    fnu: *const f64,
+    kode: *const c_int,
+    n: *const c_int,
+    cyr: *mut f64,
+    cyi: *mut f64,
+    nz: *mut c_int,
+    ierr: *mut c_int,
+) {
+    sequence(zr, zi, fnu, kode, n, cyr, cyi, nz, ierr, super::zbesi);
+}
+
+#[cfg(any(feature = "bessel-j", feature = "bessel-y"))]
+pub unsafe fn zbesj_(
+    zr: *const f64,
+    zi: *const f64,
+    fnu: *const f64,
+    kode: *const c_int,
+    n: *const c_int,
+    cyr: *mut f64,
+    cyi: *mut f64,
+    nz: *mut c_int,
+    ierr: *mut c_int,
+) {
+    sequence(zr, zi, fnu, kode, n, cyr, cyi, nz, ierr, super::zbesj);
+}
+
+#[cfg(any(feature = "bessel-i", feature = "bessel-k"))]
+pub unsafe fn zbesk_(
+    zr: *const f64,
+    zi: *const f64,
+    fnu: *const f64,
+    kode: *const c_int,
+    n: *const c_int,
+    cyr: *mut f64,
+    cyi: *mut f64,
+    nz: *mut c_int,
+    ierr: *mut c_int,
+) {
+    sequence(zr, zi, fnu, kode, n, cyr, cyi, nz, ierr, super::zbesk);
+}
+
+#[cfg(any(feature = "bessel-j", feature = "bessel-y"))]
+pub unsafe fn zbesy_(
+    zr: *const f64,
+    zi: *const f64,
+    fnu: *const f64,
+    kode: *const c_int,
+    n: *const c_int,
+    cyr: *mut f64,
+    cyi: *mut f64,
+    nz: *mut c_int,
+    cwrkr: *mut f64,
+    cwrki: *mut f64,
+    ierr: *mut c_int,
+) {
+    let mut cwrk = load(n, cwrkr, cwrki);
+
+    sequence(
+        zr,
+        zi,
+        fnu,
+        kode,
+        n,
+        cyr,
+        cyi,
+        nz,
+        ierr,
+        |z, fnu, kode, cy| super::zbesy(z, fnu, kode, cy, &mut cwrk),
+    );
+    store(&cwrk, cwrkr, cwrki);
+}
+
+#[cfg(feature = "airy")]
+pub unsafe fn zairy_(
+    zr: *const f64,
+    zi: *const f64,
+    id: *const c_int,
+    kode: *const c_int,
+    air: *mut f64,
+    aii: *mut f64,
+    nz: *mut c_int,
+    ierr: *mut c_int,
+) {
+    let (ai, nz_, ierr_) = super::zairy(*zr, *zi, *id, *kode);
+    (*nz, *ierr) = (nz_, ierr_);
+
+    if ierr_ != 1 {
+        (*air, *aii) = (ai.re, ai.im);
+    }
+}
+
+#[cfg(feature = "airy")]
+pub unsafe fn zbiry_(
+    zr: *const f64,
+    zi: *const f64,
+    id: *const c_int,
+    kode: *const c_int,
+    bir: *mut f64,
+    bii: *mut f64,
+    ierr: *mut c_int,
+) {
+    let (bi, ierr_) = super::zbiry(*zr, *zi, *id, *kode);
+    *ierr = ierr_;
+
+    if ierr_ != 1 {
+        (*bir, *bii) = (bi.re, bi.im);
+    }
+}
+
+#[cfg(feature = "log-bessel")]
+pub unsafe fn dgamln_(z: *const f64, ierr: *mut c_int) -> f64 {
+    *ierr = match *z <= 0.0 {
+        true => 1,
+        false => 0,
+    };
+
+    super::dgamln(*z)
+}
+
+// Calls a routine computing a sequence of n members in cyr and cyi
+#[cfg(any(
+    feature = "bessel-i",
+    feature = "bessel-k",
+    feature = "bessel-j",
+    feature = "bessel-y",
+    feature = "hankel"
+))]
+unsafe fn sequence(
+    zr: *const f64,
+    zi: *const f64,
+    fnu: *const f64,
+    kode: *const c_int,
+    n: *const c_int,
+    cyr: *mut f64,
+    cyi: *mut f64,
+    nz: *mut c_int,
+    ierr: *mut c_int,
+    mut f: impl FnMut(Complex<f64>, f64, i32, &mut [Complex<f64>]) -> (i32, i32),
+) {
+    let mut cy = load(n, cyr, cyi);
+
+    (*nz, *ierr) = f(Complex::new(*zr, *zi), *fnu, *kode, &mut cy);
+    store(&cy, cyr, cyi);
+}
+
+#[cfg(any(
+    feature = "bessel-i",
+    feature = "bessel-k",
+    feature = "bessel-j",
+    feature = "bessel-y",
+    feature = "hankel"
+))]
+unsafe fn load(n: *const c_int, xr: *const f64, xi: *const f64) -> Vec<Complex<f64>> {
+    (0..(*n).max(0) as usize)
+        .map(|k| Complex::new(*xr.add(k), *xi.add(k)))
+        .collect()
+}
+
+#[cfg(any(
+    feature = "bessel-i",
+    feature = "bessel-k",
+    feature = "bessel-j",
+    feature = "bessel-y",
+    feature = "hankel"
+))]
+unsafe fn store(x: &[Complex<f64>], xr: *mut f64, xi: *mut f64) {
+    for (k, c) in x.iter().enumerate() {
+        *xr.add(k) = c.re;
+        *xi.add(k) = c.im;
+    }
+}
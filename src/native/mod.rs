@@ -0,0 +1,383 @@
+// A Rust port of AMOS, used instead of the linked library with the pure-rust feature. The routines
+// follow the Fortran in amos/ statement by statement, with the real and imaginary parts kept in
+// separate variables named as in the Fortran, such that the floating point operations happen in the
+// same order and the results agree with the library to a few ULPs. Fortran's GO TO's are replaced
+// by loops and early returns, and the nz codes -1 and -2 are kept as they are. The array arguments
+// are slices, whose lengths replace the Fortran N. The constants are kept as the literals of the
+// Fortran, and the DMIN1 and DMAX1 chains as they are.
+//
+// As in build.rs, only the routines needed by the enabled family features are compiled, where the
+// routines of the analytic continuation, ZACON, ZBUNK, ZUNK1 and ZUNK2, are called by ZBESK and
+// ZBESH.
+#![allow(
+    clippy::approx_constant,
+    clippy::excessive_precision,
+    clippy::manual_clamp,
+    clippy::too_many_arguments
+)]
+
+mod dgamln;
+pub(crate) mod ffi;
+mod zacai;
+#[cfg(any(
+    feature = "bessel-i",
+    feature = "bessel-k",
+    feature = "bessel-j",
+    feature = "bessel-y",
+    feature = "hankel"
+))]
+mod zacon;
+mod zairy;
+mod zasyi;
+#[cfg(any(feature = "hankel", feature = "bessel-j", feature = "bessel-y"))]
+mod zbesh;
+#[cfg(feature = "bessel-i")]
+mod zbesi;
+#[cfg(any(feature = "bessel-j", feature = "bessel-y"))]
+mod zbesj;
+#[cfg(any(feature = "bessel-i", feature = "bessel-k"))]
+mod zbesk;
+#[cfg(any(feature = "bessel-j", feature = "bessel-y"))]
+mod zbesy;
+mod zbinu;
+#[cfg(feature = "airy")]
+mod zbiry;
+mod zbknu;
+mod zbuni;
+#[cfg(any(
+    feature = "bessel-i",
+    feature = "bessel-k",
+    feature = "bessel-j",
+    feature = "bessel-y",
+    feature = "hankel"
+))]
+mod zbunk;
+mod zkscl;
+mod zmlri;
+mod zrati;
+mod zseri;
+mod zunhj;
+mod zuni1;
+mod zuni2;
+mod zunik;
+#[cfg(any(
+    feature = "bessel-i",
+    feature = "bessel-k",
+    feature = "bessel-j",
+    feature = "bessel-y",
+    feature = "hankel"
+))]
+mod zunk1;
+#[cfg(any(
+    feature = "bessel-i",
+    feature = "bessel-k",
+    feature = "bessel-j",
+    feature = "bessel-y",
+    feature = "hankel"
+))]
+mod zunk2;
+mod zuoik;
+mod zwrsk;
+
+#[cfg(feature = "log-bessel")]
+use dgamln::dgamln;
+#[cfg(feature = "airy")]
+use zairy::zairy;
+#[cfg(feature = "hankel")]
+use zbesh::zbesh;
+#[cfg(feature = "bessel-i")]
+use zbesi::zbesi;
+#[cfg(any(feature = "bessel-j", feature = "bessel-y"))]
+use zbesj::zbesj;
+#[cfg(any(feature = "bessel-i", feature = "bessel-k"))]
+use zbesk::zbesk;
+#[cfg(any(feature = "bessel-j", feature = "bessel-y"))]
+use zbesy::zbesy;
+#[cfg(feature = "airy")]
+use zbiry::zbiry;
+
+use num::complex::Complex;
+#[cfg(not(feature = "std"))]
+use num::Float;
+
+// The machine constants of amos/d1mach.f90 and amos/i1mach.f90 used by the ported routines
+fn d1mach(i: i32) -> f64 {
+    match i {
+        1 => f64::MIN_POSITIVE,
+        2 => f64::MAX,
+        3 => f64::EPSILON / 2.0,
+        4 => f64::EPSILON,
+        5 => core::f64::consts::LOG10_2,
+        _ => panic!("d1mach: i = {} out of bounds", i),
+    }
+}
+
+fn i1mach(i: i32) -> i32 {
+    match i {
+        9 => i32::MAX,
+        14 => f64::MANTISSA_DIGITS as i32,
+        15 => f64::MIN_EXP,
+        16 => f64::MAX_EXP,
+        _ => panic!("i1mach: i = {} out of bounds", i),
+    }
+}
+
+// Fortran's DBLE(FLOAT(I)), which rounds through single precision
+fn float(i: i32) -> f64 {
+    (i as f32) as f64
+}
+
+// Fortran's INT(SNGL(X)), which truncates the value rounded to single precision
+fn int_sngl(x: f64) -> i32 {
+    (x as f32) as i32
+}
+
+// The tolerances computed at the start of zbesi, zbesk and zairy, see amos/zbesi.f lines 179-191
+struct Limits {
+    // ZAIRY and ZBIRY compute TOL before the others, as in the Fortran
+    #[cfg_attr(
+        not(any(
+            feature = "bessel-i",
+            feature = "bessel-k",
+            feature = "bessel-j",
+            feature = "bessel-y",
+            feature = "hankel"
+        )),
+        allow(dead_code)
+    )]
+    tol: f64,
+    elim: f64,
+    alim: f64,
+    rl: f64,
+    fnul: f64,
+}
+
+impl Limits {
+    fn new() -> Self {
+        let tol = d1mach(4).max(1.0e-18);
+        let k1 = i1mach(15);
+        let k2 = i1mach(16);
+        let r1m5 = d1mach(5);
+        let k = k1.abs().min(k2.abs());
+        let elim = 2.303 * (float(k) * r1m5 - 3.0);
+        let k1 = i1mach(14) - 1;
+        let mut aa = r1m5 * float(k1);
+        let dig = aa.min(18.0);
+        aa *= 2.303;
+        let alim = elim + (-aa).max(-41.45);
+        let rl = 1.2 * dig + 3.0;
+        let fnul = 10.0 + 6.0 * (dig - 3.0);
+
+        Limits {
+            tol,
+            elim,
+            alim,
+            rl,
+            fnul,
+        }
+    }
+}
+
+// amos/zabs.f
+fn azabs(zr: f64, zi: f64) -> f64 {
+    let u = zr.abs();
+    let v = zi.abs();
+    let s = (u + v) * 1.0;
+
+    if s == 0.0 {
+        return 0.0;
+    }
+
+    match u > v {
+        true => {
+            let q = v / u;
+            u * (1.0 + q * q).sqrt()
+        }
+        false => {
+            let q = u / v;
+            v * (1.0 + q * q).sqrt()
+        }
+    }
+}
+
+// amos/zmlt.f
+fn zmlt(ar: f64, ai: f64, br: f64, bi: f64) -> (f64, f64) {
+    (ar * br - ai * bi, ar * bi + ai * br)
+}
+
+// amos/zdiv.f
+fn zdiv(ar: f64, ai: f64, br: f64, bi: f64) -> (f64, f64) {
+    let bm = 1.0 / azabs(br, bi);
+    let cc = br * bm;
+    let cd = bi * bm;
+
+    ((ar * cc + ai * cd) * bm, (ai * cc - ar * cd) * bm)
+}
+
+// amos/zsqrt.f
+fn azsqrt(ar: f64, ai: f64) -> (f64, f64) {
+    const DRT: f64 = 7.071067811865475244008443621e-1;
+    const DPI: f64 = 3.141592653589793238462643383;
+
+    let zm = azabs(ar, ai).sqrt();
+
+    if ar == 0.0 {
+        return match ai {
+            ai if ai > 0.0 => (zm * DRT, zm * DRT),
+            ai if ai < 0.0 => (zm * DRT, -zm * DRT),
+            _ => (0.0, 0.0),
+        };
+    }
+
+    if ai == 0.0 {
+        return match ar > 0.0 {
+            true => (ar.sqrt(), 0.0),
+            false => (0.0, ar.abs().sqrt()),
+        };
+    }
+
+    let mut dtheta = (ai / ar).atan();
+
+    match dtheta <= 0.0 {
+        true => {
+            if ar < 0.0 {
+                dtheta += DPI;
+            }
+        }
+        false => {
+            if ar < 0.0 {
+                dtheta -= DPI;
+            }
+        }
+    }
+
+    dtheta *= 0.5;
+
+    (zm * dtheta.cos(), zm * dtheta.sin())
+}
+
+// amos/zlog.f, where the callers never pass zero, for which the Fortran sets IERR=1 and leaves the
+// result undefined
+fn azlog(ar: f64, ai: f64) -> (f64, f64) {
+    const DPI: f64 = 3.141592653589793238462643383;
+    const DHPI: f64 = 1.570796326794896619231321696;
+
+    if ar == 0.0 {
+        return match ai < 0.0 {
+            true => (ai.abs().ln(), -DHPI),
+            false => (ai.abs().ln(), DHPI),
+        };
+    }
+
+    if ai == 0.0 {
+        return match ar > 0.0 {
+            true => (ar.ln(), 0.0),
+            false => (ar.abs().ln(), DPI),
+        };
+    }
+
+    let mut dtheta = (ai / ar).atan();
+
+    match dtheta <= 0.0 {
+        true => {
+            if ar < 0.0 {
+                dtheta += DPI;
+            }
+        }
+        false => {
+            if ar < 0.0 {
+                dtheta -= DPI;
+            }
+        }
+    }
+
+    (azabs(ar, ai).ln(), dtheta)
+}
+
+// amos/zexp.f
+fn azexp(ar: f64, ai: f64) -> (f64, f64) {
+    let zm = ar.exp();
+
+    (zm * ai.cos(), zm * ai.sin())
+}
+
+// amos/zshch.f, returning (sinh(z), cosh(z))
+fn zshch(zr: f64, zi: f64) -> ((f64, f64), (f64, f64)) {
+    let sh = zr.sinh();
+    let ch = zr.cosh();
+    let sn = zi.sin();
+    let cn = zi.cos();
+
+    ((sh * cn, ch * sn), (ch * cn, sh * sn))
+}
+
+// amos/zuchk.f, returning nz
+fn zuchk(yr: f64, yi: f64, ascle: f64, tol: f64) -> i32 {
+    let wr = yr.abs();
+    let wi = yi.abs();
+    let st = wr.min(wi);
+
+    if st > ascle {
+        return 0;
+    }
+
+    let ss = wr.max(wi);
+    let st = st / tol;
+
+    match ss < st {
+        true => 1,
+        false => 0,
+    }
+}
+
+// amos/zs1s2.f, returning nz
+fn zs1s2(
+    zrr: f64,
+    zri: f64,
+    s1: &mut Complex<f64>,
+    s2: &mut Complex<f64>,
+    ascle: f64,
+    alim: f64,
+    iuf: &mut i32,
+) -> i32 {
+    let mut as1 = azabs(s1.re, s1.im);
+    let as2 = azabs(s2.re, s2.im);
+
+    if !(s1.re == 0.0 && s1.im == 0.0) && as1 != 0.0 {
+        let aln = -zrr - zrr + as1.ln();
+        let s1d = *s1;
+        *s1 = Complex::new(0.0, 0.0);
+        as1 = 0.0;
+
+        if aln >= -alim {
+            let (mut c1r, mut c1i) = azlog(s1d.re, s1d.im);
+            c1r = c1r - zrr - zrr;
+            c1i = c1i - zri - zri;
+            let (s1r, s1i) = azexp(c1r, c1i);
+            *s1 = Complex::new(s1r, s1i);
+            as1 = azabs(s1r, s1i);
+            *iuf += 1;
+        }
+    }
+
+    let aa = as1.max(as2);
+
+    if aa > ascle {
+        return 0;
+    }
+
+    *s1 = Complex::new(0.0, 0.0);
+    *s2 = Complex::new(0.0, 0.0);
+    *iuf = 0;
+
+    1
+}
+
+// The nz of a routine whose call of another routine failed with nz = nw, which is -2 if the
+// failure was a lack of convergence and -1 for overflow, see e.g. amos/zbinu.f lines 106-109
+fn nz_error(nw: i32) -> i32 {
+    match nw == -2 {
+        true => -2,
+        false => -1,
+    }
+}
@@ -0,0 +1,93 @@
+// amos/zacai.f, the analytic continuation of K from Re(z) > 0 to Re(z) < 0 as in zacon for n = 1,
+// used by zairy, returning nz, or -1 on overflow and -2 on a lack of convergence
+use super::zasyi::zasyi;
+use super::zbknu::zbknu;
+use super::zmlri::zmlri;
+use super::zseri::zseri;
+use super::{azabs, d1mach, float, int_sngl, nz_error, zs1s2};
+use num::complex::Complex;
+#[cfg(not(feature = "std"))]
+use num::Float;
+
+pub(super) fn zacai(
+    zr: f64,
+    zi: f64,
+    fnu: f64,
+    kode: i32,
+    mr: i32,
+    y: &mut [Complex<f64>],
+    rl: f64,
+    tol: f64,
+    elim: f64,
+    alim: f64,
+) -> i32 {
+    const PI: f64 = 3.14159265358979324;
+
+    let n = y.len();
+    let mut nz = 0;
+    let znr = -zr;
+    let zni = -zi;
+    let az = azabs(zr, zi);
+    let dfnu = fnu + float(n as i32 - 1);
+
+    // I(fnu, -z) by the series, the asymptotic expansion or the Miller algorithm, see amos/zacai.f
+    // lines 34-54
+    if az <= 2.0 || az * az * 0.25 <= dfnu + 1.0 {
+        zseri(znr, zni, fnu, kode, y, tol, elim, alim);
+    } else {
+        let nw = match az >= rl {
+            true => zasyi(znr, zni, fnu, kode, y, rl, tol, elim, alim),
+            false => zmlri(znr, zni, fnu, kode, y, tol),
+        };
+
+        if nw < 0 {
+            return nz_error(nw);
+        }
+    }
+
+    let mut cy = [Complex::new(0.0, 0.0); 1];
+    let nw = zbknu(znr, zni, fnu, kode, &mut cy, tol, elim, alim);
+
+    if nw != 0 {
+        return nz_error(nw);
+    }
+
+    let fmr = float(mr);
+    let sgn = -PI.copysign(fmr);
+    let mut csgnr = 0.0;
+    let mut csgni = sgn;
+
+    if kode != 1 {
+        let yy = -zni;
+        csgnr = -csgni * yy.sin();
+        csgni *= yy.cos();
+    }
+
+    // The factor exp(-mp fnu), see amos/zacai.f lines 74-81
+    let inu = int_sngl(fnu);
+    let arg = (fnu - float(inu)) * sgn;
+    let mut cspnr = arg.cos();
+    let mut cspni = arg.sin();
+
+    if inu % 2 != 0 {
+        cspnr = -cspnr;
+        cspni = -cspni;
+    }
+
+    let mut c1 = cy[0];
+    let mut c2 = y[0];
+
+    if kode != 1 {
+        let mut iuf = 0;
+        let ascle = 1.0e+3 * d1mach(1) / tol;
+        let nw = zs1s2(znr, zni, &mut c1, &mut c2, ascle, alim, &mut iuf);
+        nz += nw;
+    }
+
+    y[0] = Complex::new(
+        cspnr * c1.re - cspni * c1.im + csgnr * c2.re - csgni * c2.im,
+        cspnr * c1.im + cspni * c1.re + csgnr * c2.im + csgni * c2.re,
+    );
+
+    nz
+}
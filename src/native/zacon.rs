@@ -0,0 +1,219 @@
+// amos/zacon.f, the analytic continuation of K from Re(z) > 0 to Re(z) < 0 by
+// K(fnu, z mp) = K(fnu, z) exp(-mp fnu) - mp I(fnu, z), with mp = pi mr i and mr = +1 or -1,
+// returning nz, or -1 on overflow and -2 on a lack of convergence
+use super::zbinu::zbinu;
+use super::zbknu::zbknu;
+use super::{azabs, d1mach, float, int_sngl, nz_error, zmlt, zs1s2};
+use num::complex::Complex;
+#[cfg(not(feature = "std"))]
+use num::Float;
+
+pub(super) fn zacon(
+    zr: f64,
+    zi: f64,
+    fnu: f64,
+    kode: i32,
+    mr: i32,
+    y: &mut [Complex<f64>],
+    rl: f64,
+    fnul: f64,
+    tol: f64,
+    elim: f64,
+    alim: f64,
+) -> i32 {
+    const PI: f64 = 3.14159265358979324;
+
+    let n = y.len();
+    let mut nz = 0;
+    let znr = -zr;
+    let zni = -zi;
+
+    let nw = zbinu(znr, zni, fnu, kode, y, rl, fnul, tol, elim, alim);
+
+    if nw < 0 {
+        return nz_error(nw);
+    }
+
+    let nn = n.min(2);
+    let mut cy = [Complex::new(0.0, 0.0); 2];
+    let nw = zbknu(znr, zni, fnu, kode, &mut cy[..nn], tol, elim, alim);
+
+    if nw != 0 {
+        return nz_error(nw);
+    }
+
+    let mut s1r = cy[0].re;
+    let mut s1i = cy[0].im;
+    let fmr = float(mr);
+    let sgn = -PI.copysign(fmr);
+    let mut csgnr = 0.0;
+    let mut csgni = sgn;
+
+    if kode != 1 {
+        let yy = -zni;
+        let cpn = yy.cos();
+        let spn = yy.sin();
+        (csgnr, csgni) = zmlt(csgnr, csgni, cpn, spn);
+    }
+
+    // The factor exp(-mp fnu), whose sign alternates with the order, see amos/zacon.f lines 57-66
+    let inu = int_sngl(fnu);
+    let arg = (fnu - float(inu)) * sgn;
+    let cpn = arg.cos();
+    let spn = arg.sin();
+    let mut cspnr = cpn;
+    let mut cspni = spn;
+
+    if inu % 2 != 0 {
+        cspnr = -cspnr;
+        cspni = -cspni;
+    }
+
+    let mut iuf = 0;
+    let mut c1 = Complex::new(s1r, s1i);
+    let mut c2 = y[0];
+    let ascle = 1.0e+3 * d1mach(1) / tol;
+
+    if kode != 1 {
+        let nw = zs1s2(znr, zni, &mut c1, &mut c2, ascle, alim, &mut iuf);
+        nz += nw;
+    }
+
+    let (str, sti) = zmlt(cspnr, cspni, c1.re, c1.im);
+    let (ptr, pti) = zmlt(csgnr, csgni, c2.re, c2.im);
+    y[0] = Complex::new(str + ptr, sti + pti);
+
+    if n == 1 {
+        return nz;
+    }
+
+    cspnr = -cspnr;
+    cspni = -cspni;
+    let mut s2r = cy[1].re;
+    let mut s2i = cy[1].im;
+    c1 = Complex::new(s2r, s2i);
+    c2 = y[1];
+    let mut sc2r = 0.0;
+    let mut sc2i = 0.0;
+
+    if kode != 1 {
+        let nw = zs1s2(znr, zni, &mut c1, &mut c2, ascle, alim, &mut iuf);
+        nz += nw;
+        sc2r = c1.re;
+        sc2i = c1.im;
+    }
+
+    let (str, sti) = zmlt(cspnr, cspni, c1.re, c1.im);
+    let (ptr, pti) = zmlt(csgnr, csgni, c2.re, c2.im);
+    y[1] = Complex::new(str + ptr, sti + pti);
+
+    if n == 2 {
+        return nz;
+    }
+
+    // Forward recurrence on K with the scaling of zs1s2 for kode = 2, see amos/zacon.f lines
+    // 105-197
+    cspnr = -cspnr;
+    cspni = -cspni;
+    let azn = azabs(znr, zni);
+    let razn = 1.0 / azn;
+    let str = znr * razn;
+    let sti = -zni * razn;
+    let rzr = (str + str) * razn;
+    let rzi = (sti + sti) * razn;
+    let fn_ = fnu + 1.0;
+    let mut ckr = fn_ * rzr;
+    let mut cki = fn_ * rzi;
+    let cscl = 1.0 / tol;
+    let cscr = tol;
+    let cssr = [cscl, 1.0, cscr];
+    let csrr = [cscr, 1.0, cscl];
+    let bry = [ascle, 1.0 / ascle, d1mach(2)];
+    let as2 = azabs(s2r, s2i);
+    let mut kflag = 2;
+
+    match as2 > bry[0] {
+        true => {
+            if as2 >= bry[1] {
+                kflag = 3;
+            }
+        }
+        false => kflag = 1,
+    }
+
+    let mut bscle = bry[kflag - 1];
+    s1r *= cssr[kflag - 1];
+    s1i *= cssr[kflag - 1];
+    s2r *= cssr[kflag - 1];
+    s2i *= cssr[kflag - 1];
+    let mut csr = csrr[kflag - 1];
+
+    for y in y[2..].iter_mut() {
+        let mut str = s2r;
+        let mut sti = s2i;
+        s2r = ckr * str - cki * sti + s1r;
+        s2i = ckr * sti + cki * str + s1i;
+        s1r = str;
+        s1i = sti;
+        c1 = Complex::new(s2r * csr, s2i * csr);
+        str = c1.re;
+        sti = c1.im;
+        c2 = *y;
+
+        if kode != 1 && iuf >= 0 {
+            let nw = zs1s2(znr, zni, &mut c1, &mut c2, ascle, alim, &mut iuf);
+            nz += nw;
+            let sc1r = sc2r;
+            let sc1i = sc2i;
+            sc2r = c1.re;
+            sc2i = c1.im;
+
+            if iuf == 3 {
+                iuf = -4;
+                s1r = sc1r * cssr[kflag - 1];
+                s1i = sc1i * cssr[kflag - 1];
+                s2r = sc2r * cssr[kflag - 1];
+                s2i = sc2i * cssr[kflag - 1];
+                str = sc2r;
+                sti = sc2i;
+            }
+        }
+
+        let ptr = cspnr * c1.re - cspni * c1.im;
+        let pti = cspnr * c1.im + cspni * c1.re;
+        *y = Complex::new(
+            ptr + csgnr * c2.re - csgni * c2.im,
+            pti + csgnr * c2.im + csgni * c2.re,
+        );
+        ckr += rzr;
+        cki += rzi;
+        cspnr = -cspnr;
+        cspni = -cspni;
+
+        if kflag >= 3 {
+            continue;
+        }
+
+        let ptr = c1.re.abs();
+        let pti = c1.im.abs();
+        let c1m = ptr.max(pti);
+
+        if c1m <= bscle {
+            continue;
+        }
+
+        kflag += 1;
+        bscle = bry[kflag - 1];
+        s1r *= csr;
+        s1i *= csr;
+        s2r = str;
+        s2i = sti;
+        s1r *= cssr[kflag - 1];
+        s1i *= cssr[kflag - 1];
+        s2r *= cssr[kflag - 1];
+        s2i *= cssr[kflag - 1];
+        csr = csrr[kflag - 1];
+    }
+
+    nz
+}
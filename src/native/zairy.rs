@@ -0,0 +1,284 @@
+// amos/zairy.f, Ai(z) for id = 0 and Ai'(z) for id = 1, returning (ai, nz, ierr), where ai is zero
+// on the errors for which the Fortran leaves it undefined. Only kode = 2 is used by zuni2 and zunk2
+use super::zacai::zacai;
+use super::zbknu::zbknu;
+use super::{azabs, azexp, azsqrt, d1mach, float, i1mach, Limits};
+use num::complex::Complex;
+#[cfg(not(feature = "std"))]
+use num::Float;
+
+const TTH: f64 = 6.66666666666666667e-01;
+const C1: f64 = 3.55028053887817240e-01;
+const C2: f64 = 2.58819403792806799e-01;
+
+pub(super) fn zairy(zr: f64, zi: f64, id: i32, kode: i32) -> (Complex<f64>, i32, i32) {
+    const COEF: f64 = 1.83776298473930683e-01;
+
+    let zero = Complex::new(0.0, 0.0);
+
+    if !(0..=1).contains(&id) || !(1..=2).contains(&kode) {
+        return (zero, 0, 1);
+    }
+
+    let az = azabs(zr, zi);
+    let tol = d1mach(4).max(1.0e-18);
+    let fid = float(id);
+
+    if az <= 1.0 {
+        return (zairy_series(zr, zi, az, id, kode, tol), 0, 0);
+    }
+
+    // The relation to K with fnu = 1/3 or 2/3 for |z| > 1, see amos/zairy.f lines 234-282
+    let fnu = (1.0 + fid) / 3.0;
+    let Limits { elim, alim, rl, .. } = Limits::new();
+    let alaz = az.ln();
+    let mut aa = 0.5 / tol;
+    let bb = float(i1mach(9)) * 0.5;
+    aa = aa.min(bb);
+    aa = aa.powf(TTH);
+
+    if az > aa {
+        return (zero, 0, 4);
+    }
+
+    aa = aa.sqrt();
+    let ierr = match az > aa {
+        true => 3,
+        false => 0,
+    };
+
+    let (csqr, csqi) = azsqrt(zr, zi);
+    let mut ztar = TTH * (zr * csqr - zi * csqi);
+    let mut ztai = TTH * (zr * csqi + zi * csqr);
+
+    // Re(zeta) <= 0 when Re(z) < 0, which is set explicitly to avoid round off errors
+    let mut iflag = 0;
+    let mut sfac = 1.0;
+    let ak = ztai;
+
+    if zr < 0.0 {
+        let bk = ztar;
+        let ck = -bk.abs();
+        ztar = ck;
+        ztai = ak;
+    }
+
+    if zi == 0.0 && zr <= 0.0 {
+        ztar = 0.0;
+        ztai = ak;
+    }
+
+    let mut aa = ztar;
+    let mut cy = [zero; 1];
+    let nz;
+
+    match aa >= 0.0 && zr > 0.0 {
+        true => {
+            // Underflow test for the right half plane
+            if kode != 2 && aa >= alim {
+                aa = -aa - 0.25 * alaz;
+                iflag = 2;
+                sfac = 1.0 / tol;
+
+                if aa < -elim {
+                    return (zero, 1, ierr);
+                }
+            }
+
+            nz = zbknu(ztar, ztai, fnu, kode, &mut cy, tol, elim, alim);
+        }
+        false => {
+            // Overflow test for the left half plane
+            if kode != 2 && aa <= -alim {
+                aa = -aa + 0.25 * alaz;
+                iflag = 1;
+                sfac = tol;
+
+                if aa > elim {
+                    return (zero, 0, 2);
+                }
+            }
+
+            let mr = match zi < 0.0 {
+                true => -1,
+                false => 1,
+            };
+            let nn = zacai(ztar, ztai, fnu, kode, mr, &mut cy, rl, tol, elim, alim);
+
+            if nn < 0 {
+                return match nn == -1 {
+                    true => (zero, 0, 2),
+                    false => (zero, 0, 5),
+                };
+            }
+
+            nz = nn;
+        }
+    }
+
+    let mut s1r = cy[0].re * COEF;
+    let mut s1i = cy[0].im * COEF;
+
+    if iflag == 0 {
+        let ai = match id == 1 {
+            true => Complex::new(-(zr * s1r - zi * s1i), -(zr * s1i + zi * s1r)),
+            false => Complex::new(csqr * s1r - csqi * s1i, csqr * s1i + csqi * s1r),
+        };
+
+        return (ai, nz, ierr);
+    }
+
+    s1r *= sfac;
+    s1i *= sfac;
+
+    let str = match id == 1 {
+        true => {
+            let str = -(s1r * zr - s1i * zi);
+            s1i = -(s1r * zi + s1i * zr);
+            str
+        }
+        false => {
+            let str = s1r * csqr - s1i * csqi;
+            s1i = s1r * csqi + s1i * csqr;
+            str
+        }
+    };
+
+    s1r = str;
+
+    (Complex::new(s1r / sfac, s1i / sfac), nz, ierr)
+}
+
+// The power series for |z| <= 1, see amos/zairy.f lines 151-233
+fn zairy_series(zr: f64, zi: f64, az: f64, id: i32, kode: i32, tol: f64) -> Complex<f64> {
+    let fid = float(id);
+    let mut s1r = 1.0;
+    let mut s1i = 0.0;
+    let mut s2r = 1.0;
+    let mut s2i = 0.0;
+
+    if az < tol {
+        let aa = 1.0e+3 * d1mach(1);
+        s1r = 0.0;
+        s1i = 0.0;
+
+        return match id == 1 {
+            true => {
+                let mut air = -C2;
+                let mut aii = 0.0;
+                let aa = aa.sqrt();
+
+                if az > aa {
+                    s1r = 0.5 * (zr * zr - zi * zi);
+                    s1i = zr * zi;
+                }
+
+                air += C1 * s1r;
+                aii += C1 * s1i;
+
+                Complex::new(air, aii)
+            }
+            false => {
+                if az > aa {
+                    s1r = C2 * zr;
+                    s1i = C2 * zi;
+                }
+
+                Complex::new(C1 - s1r, -s1i)
+            }
+        };
+    }
+
+    let aa = az * az;
+
+    if aa >= tol / az {
+        let mut trm1r = 1.0;
+        let mut trm1i = 0.0;
+        let mut trm2r = 1.0;
+        let mut trm2i = 0.0;
+        let mut atrm = 1.0;
+        let mut str = zr * zr - zi * zi;
+        let sti = zr * zi + zi * zr;
+        let z3r = str * zr - sti * zi;
+        let z3i = str * zi + sti * zr;
+        let az3 = az * aa;
+        let mut ak = 2.0 + fid;
+        let mut bk = 3.0 - fid - fid;
+        let ck = 4.0 - fid;
+        let dk = 3.0 + fid + fid;
+        let mut d1 = ak * dk;
+        let mut d2 = bk * ck;
+        let mut ad = d1.min(d2);
+        ak = 24.0 + 9.0 * fid;
+        bk = 30.0 - 9.0 * fid;
+
+        for _ in 1..=25 {
+            str = (trm1r * z3r - trm1i * z3i) / d1;
+            trm1i = (trm1r * z3i + trm1i * z3r) / d1;
+            trm1r = str;
+            s1r += trm1r;
+            s1i += trm1i;
+            str = (trm2r * z3r - trm2i * z3i) / d2;
+            trm2i = (trm2r * z3i + trm2i * z3r) / d2;
+            trm2r = str;
+            s2r += trm2r;
+            s2i += trm2i;
+            atrm = atrm * az3 / ad;
+            d1 += ak;
+            d2 += bk;
+            ad = d1.min(d2);
+
+            if atrm < tol * ad {
+                break;
+            }
+
+            ak += 18.0;
+            bk += 18.0;
+        }
+    }
+
+    let (mut air, mut aii) = match id == 1 {
+        true => {
+            let mut air = -s2r * C2;
+            let mut aii = -s2i * C2;
+
+            if az > tol {
+                let str = zr * s1r - zi * s1i;
+                let sti = zr * s1i + zi * s1r;
+                let cc = C1 / (1.0 + fid);
+                air += cc * (str * zr - sti * zi);
+                aii += cc * (str * zi + sti * zr);
+            }
+
+            (air, aii)
+        }
+        false => (
+            s1r * C1 - C2 * (zr * s2r - zi * s2i),
+            s1i * C1 - C2 * (zr * s2i + zi * s2r),
+        ),
+    };
+
+    if kode == 1 {
+        return Complex::new(air, aii);
+    }
+
+    let (str, sti) = azsqrt(zr, zi);
+    let ztar = TTH * (zr * str - zi * sti);
+    let ztai = TTH * (zr * sti + zi * str);
+    let (str, sti) = azexp(ztar, ztai);
+
+    match id == 1 {
+        true => {
+            let ptr = str * air - sti * aii;
+            aii = str * aii + sti * air;
+            air = ptr;
+        }
+        false => {
+            let ptr = air * str - aii * sti;
+            aii = air * sti + aii * str;
+            air = ptr;
+        }
+    }
+
+    Complex::new(air, aii)
+}
@@ -0,0 +1,185 @@
+// amos/zasyi.f, the asymptotic expansion of I for large |z|, returning nz = -1 on overflow and
+// nz = -2 if the expansion did not converge
+use super::{azabs, azexp, azsqrt, d1mach, float, int_sngl, zdiv, zmlt};
+use num::complex::Complex;
+#[cfg(not(feature = "std"))]
+use num::Float;
+
+pub(super) fn zasyi(
+    zr: f64,
+    zi: f64,
+    fnu: f64,
+    kode: i32,
+    y: &mut [Complex<f64>],
+    rl: f64,
+    tol: f64,
+    elim: f64,
+    alim: f64,
+) -> i32 {
+    const PI: f64 = 3.14159265358979324;
+    const RTPI: f64 = 0.159154943091895336;
+
+    let n = y.len();
+    let az = azabs(zr, zi);
+    let arm = 1.0e+3 * d1mach(1);
+    let rtr1 = arm.sqrt();
+    let il = n.min(2);
+    let dfnu = fnu + float((n - il) as i32);
+
+    // Overflow test, see amos/zasyi.f lines 33-45
+    let raz = 1.0 / az;
+    let mut str = zr * raz;
+    let mut sti = -zi * raz;
+    let (mut ak1r, mut ak1i) = azsqrt(RTPI * str * raz, RTPI * sti * raz);
+    let mut czr = zr;
+    let czi = zi;
+
+    if kode == 2 {
+        czr = 0.0;
+    }
+
+    if czr.abs() > elim {
+        return -1;
+    }
+
+    let dnu2 = dfnu + dfnu;
+    let mut koded = 1;
+
+    if !(czr.abs() > alim && n > 2) {
+        koded = 0;
+        (str, sti) = azexp(czr, czi);
+        (ak1r, ak1i) = zmlt(ak1r, ak1i, str, sti);
+    }
+
+    let mut fdn = 0.0;
+
+    if dnu2 > rtr1 {
+        fdn = dnu2 * dnu2;
+    }
+
+    let ezr = zr * 8.0;
+    let ezi = zi * 8.0;
+    let aez = 8.0 * az;
+    let s = tol / aez;
+    let jl = int_sngl(rl + rl) + 2;
+    let mut p1r = 0.0;
+    let mut p1i = 0.0;
+
+    // The factor exp(i pi fnu) of the term with exp(-z), see amos/zasyi.f lines 67-82
+    if zi != 0.0 {
+        let mut inu = int_sngl(fnu);
+        let arg = (fnu - float(inu)) * PI;
+        inu = inu + n as i32 - il as i32;
+        let ak = -arg.sin();
+        let mut bk = arg.cos();
+
+        if zi < 0.0 {
+            bk = -bk;
+        }
+
+        p1r = ak;
+        p1i = bk;
+
+        if inu % 2 != 0 {
+            p1r = -p1r;
+            p1i = -p1i;
+        }
+    }
+
+    for k in 1..=il {
+        let mut sqk = fdn - 1.0;
+        let atol = s * sqk.abs();
+        let mut sgn = 1.0;
+        let mut cs1r = 1.0;
+        let mut cs1i = 0.0;
+        let mut cs2r = 1.0;
+        let mut cs2i = 0.0;
+        let mut ckr = 1.0;
+        let mut cki = 0.0;
+        let mut ak = 0.0;
+        let mut aa = 1.0;
+        let mut bb = aez;
+        let mut dkr = ezr;
+        let mut dki = ezi;
+        let mut converged = false;
+
+        for _ in 1..=jl {
+            (str, sti) = zdiv(ckr, cki, dkr, dki);
+            ckr = str * sqk;
+            cki = sti * sqk;
+            cs2r += ckr;
+            cs2i += cki;
+            sgn = -sgn;
+            cs1r += ckr * sgn;
+            cs1i += cki * sgn;
+            dkr += ezr;
+            dki += ezi;
+            aa = aa * sqk.abs() / bb;
+            bb += aez;
+            ak += 8.0;
+            sqk -= ak;
+
+            if aa <= atol {
+                converged = true;
+                break;
+            }
+        }
+
+        if !converged {
+            return -2;
+        }
+
+        let mut s2r = cs1r;
+        let mut s2i = cs1i;
+
+        if zr + zr < elim {
+            let tzr = zr + zr;
+            let tzi = zi + zi;
+            (str, sti) = azexp(-tzr, -tzi);
+            (str, sti) = zmlt(str, sti, p1r, p1i);
+            (str, sti) = zmlt(str, sti, cs2r, cs2i);
+            s2r += str;
+            s2i += sti;
+        }
+
+        fdn = fdn + 8.0 * dfnu + 4.0;
+        p1r = -p1r;
+        p1i = -p1i;
+        let m = n - il + k;
+        y[m - 1] = Complex::new(s2r * ak1r - s2i * ak1i, s2r * ak1i + s2i * ak1r);
+    }
+
+    if n <= 2 {
+        return 0;
+    }
+
+    let nn = n;
+    let mut k = nn - 2;
+    let mut ak = float(k as i32);
+    str = zr * raz;
+    sti = -zi * raz;
+    let rzr = (str + str) * raz;
+    let rzi = (sti + sti) * raz;
+
+    for _ in 3..=nn {
+        let yr = (ak + fnu) * (rzr * y[k].re - rzi * y[k].im) + y[k + 1].re;
+        let yi = (ak + fnu) * (rzr * y[k].im + rzi * y[k].re) + y[k + 1].im;
+        y[k - 1] = Complex::new(yr, yi);
+        ak -= 1.0;
+        k -= 1;
+    }
+
+    if koded == 0 {
+        return 0;
+    }
+
+    let (ckr, cki) = azexp(czr, czi);
+
+    for y in y[..nn].iter_mut() {
+        let str = y.re * ckr - y.im * cki;
+        let sti = y.re * cki + y.im * ckr;
+        *y = Complex::new(str, sti);
+    }
+
+    0
+}
@@ -0,0 +1,204 @@
+// amos/zbesh.f, returning (nz, ierr)
+use super::zacon::zacon;
+use super::zbknu::zbknu;
+use super::zbunk::zbunk;
+use super::zuoik::zuoik;
+use super::{azabs, d1mach, float, i1mach, int_sngl, Limits};
+use num::complex::Complex;
+#[cfg(not(feature = "std"))]
+use num::Float;
+
+pub(crate) fn zbesh(
+    z: Complex<f64>,
+    fnu: f64,
+    kode: i32,
+    m: i32,
+    cy: &mut [Complex<f64>],
+) -> (i32, i32) {
+    const HPI: f64 = 1.57079632679489662;
+
+    let (zr, zi) = (z.re, z.im);
+    let n = cy.len() as i32;
+    let mut ierr = 0;
+    let mut nz = 0;
+
+    if (zr == 0.0 && zi == 0.0)
+        || fnu < 0.0
+        || !(1..=2).contains(&m)
+        || !(1..=2).contains(&kode)
+        || n < 1
+    {
+        return (0, 1);
+    }
+
+    let Limits {
+        tol,
+        elim,
+        alim,
+        rl,
+        fnul,
+    } = Limits::new();
+
+    let mut nn = n as usize;
+    let fn_ = fnu + float(n - 1);
+    let mm = 3 - m - m;
+    let fmm = float(mm);
+    let mut znr = fmm * zi;
+    let mut zni = -fmm * zr;
+
+    let az = azabs(zr, zi);
+    let mut aa = 0.5 / tol;
+    let bb = float(i1mach(9)) * 0.5;
+    aa = aa.min(bb);
+
+    if az > aa || fn_ > aa {
+        return (0, 4);
+    }
+
+    aa = aa.sqrt();
+
+    if az > aa || fn_ > aa {
+        ierr = 3;
+    }
+
+    // H overflows for |z| below the underflow limit, and for small |z| and large orders
+    let ufl = d1mach(1) * 1.0e+3;
+
+    if az < ufl {
+        return (0, 2);
+    }
+
+    match fnu > fnul {
+        true => {
+            // The uniform asymptotic expansions for large orders, see amos/zbesh.f lines 263-278
+            let mut mr = 0;
+
+            if !(znr >= 0.0 && (znr != 0.0 || zni >= 0.0 || m != 2)) {
+                mr = -mm;
+
+                if znr == 0.0 && zni < 0.0 {
+                    znr = -znr;
+                    zni = -zni;
+                }
+            }
+
+            let nw = zbunk(znr, zni, fnu, kode, mr, &mut cy[..nn], tol, elim, alim);
+
+            if nw < 0 {
+                return nw_error(nw);
+            }
+
+            nz += nw;
+        }
+        false => {
+            if fn_ > 1.0 {
+                match fn_ > 2.0 {
+                    true => {
+                        let nuf = zuoik(znr, zni, fnu, kode, 2, &mut cy[..nn], tol, elim, alim);
+
+                        if nuf < 0 {
+                            return (0, 2);
+                        }
+
+                        nz += nuf;
+                        nn -= nuf as usize;
+
+                        if nn == 0 {
+                            return match znr < 0.0 {
+                                true => (0, 2),
+                                false => (nz, ierr),
+                            };
+                        }
+                    }
+                    false => {
+                        if az <= tol {
+                            let arg = 0.5 * az;
+                            let aln = -fn_ * arg.ln();
+
+                            if aln > elim {
+                                return (0, 2);
+                            }
+                        }
+                    }
+                }
+            }
+
+            // K in the right half plane by zbknu, and in the left half plane by analytic
+            // continuation, see amos/zbesh.f lines 244-262
+            match znr < 0.0 || (znr == 0.0 && zni < 0.0 && m == 2) {
+                true => {
+                    let mr = -mm;
+                    let nw = zacon(
+                        znr,
+                        zni,
+                        fnu,
+                        kode,
+                        mr,
+                        &mut cy[..nn],
+                        rl,
+                        fnul,
+                        tol,
+                        elim,
+                        alim,
+                    );
+
+                    if nw < 0 {
+                        return nw_error(nw);
+                    }
+
+                    nz = nw;
+                }
+                false => nz = zbknu(znr, zni, fnu, kode, &mut cy[..nn], tol, elim, alim),
+            }
+        }
+    }
+
+    // H from K by H(m, fnu, z) = -fmm (i/hpi) (zt^fnu) K(fnu, -i fmm z) with zt = exp(-fmm hpi i),
+    // see amos/zbesh.f lines 279-330
+    let sgn = HPI.copysign(-fmm);
+    let inu = int_sngl(fnu);
+    let inuh = inu / 2;
+    let ir = inu - 2 * inuh;
+    let arg = (fnu - float(inu - ir)) * sgn;
+    let rhpi = 1.0 / sgn;
+    let mut csgni = rhpi * arg.cos();
+    let mut csgnr = -rhpi * arg.sin();
+
+    if inuh % 2 != 0 {
+        csgnr = -csgnr;
+        csgni = -csgni;
+    }
+
+    let zti = -fmm;
+    let rtol = 1.0 / tol;
+    let ascle = ufl * rtol;
+
+    for cy in cy[..nn].iter_mut() {
+        let mut aa = cy.re;
+        let mut bb = cy.im;
+        let mut atol = 1.0;
+
+        if aa.abs().max(bb.abs()) <= ascle {
+            aa *= rtol;
+            bb *= rtol;
+            atol = tol;
+        }
+
+        let str = aa * csgnr - bb * csgni;
+        let sti = aa * csgni + bb * csgnr;
+        *cy = Complex::new(str * atol, sti * atol);
+        let str = -csgni * zti;
+        csgni = csgnr * zti;
+        csgnr = str;
+    }
+
+    (nz, ierr)
+}
+
+// The (nz, ierr) of a failed zacon or zbunk call, see amos/zbesh.f lines 335-343
+fn nw_error(nw: i32) -> (i32, i32) {
+    match nw == -1 {
+        true => (0, 2),
+        false => (0, 5),
+    }
+}
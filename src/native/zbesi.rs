@@ -0,0 +1,106 @@
+// amos/zbesi.f, returning (nz, ierr)
+use super::zbinu::zbinu;
+use super::{azabs, d1mach, float, i1mach, int_sngl, Limits};
+use num::complex::Complex;
+#[cfg(not(feature = "std"))]
+use num::Float;
+
+pub(crate) fn zbesi(z: Complex<f64>, fnu: f64, kode: i32, cy: &mut [Complex<f64>]) -> (i32, i32) {
+    const PI: f64 = 3.14159265358979324;
+
+    let (zr, zi) = (z.re, z.im);
+    let n = cy.len() as i32;
+    let mut ierr = 0;
+
+    if fnu < 0.0 || !(1..=2).contains(&kode) || n < 1 {
+        return (0, 1);
+    }
+
+    let Limits {
+        tol,
+        elim,
+        alim,
+        rl,
+        fnul,
+        ..
+    } = Limits::new();
+
+    let az = azabs(zr, zi);
+    let fn_ = fnu + float(n - 1);
+    let mut aa = 0.5 / tol;
+    let bb = float(i1mach(9)) * 0.5;
+    aa = aa.min(bb);
+
+    if az > aa || fn_ > aa {
+        return (0, 4);
+    }
+
+    aa = aa.sqrt();
+
+    if az > aa || fn_ > aa {
+        ierr = 3;
+    }
+
+    let mut znr = zr;
+    let mut zni = zi;
+    let mut csgnr = 1.0;
+    let mut csgni = 0.0;
+
+    // Analytic continuation to the left half plane, see amos/zbesi.f lines 209-224
+    if zr < 0.0 {
+        znr = -zr;
+        zni = -zi;
+        let inu = int_sngl(fnu);
+        let mut arg = (fnu - float(inu)) * PI;
+
+        if zi < 0.0 {
+            arg = -arg;
+        }
+
+        csgnr = arg.cos();
+        csgni = arg.sin();
+
+        if inu % 2 != 0 {
+            csgnr = -csgnr;
+            csgni = -csgni;
+        }
+    }
+
+    let nz = zbinu(znr, zni, fnu, kode, cy, rl, fnul, tol, elim, alim);
+
+    if nz < 0 {
+        return match nz == -2 {
+            true => (0, 5),
+            false => (0, 2),
+        };
+    }
+
+    if zr >= 0.0 {
+        return (nz, ierr);
+    }
+
+    let nn = (n - nz) as usize;
+    let rtol = 1.0 / tol;
+    let ascle = d1mach(1) * rtol * 1.0e+3;
+
+    for cy in cy[..nn].iter_mut() {
+        let mut aa = cy.re;
+        let mut bb = cy.im;
+        let mut atol = 1.0;
+
+        // Scale to avoid losing the result to underflow, see amos/zbesi.f lines 243-247
+        if aa.abs().max(bb.abs()) <= ascle {
+            aa *= rtol;
+            bb *= rtol;
+            atol = tol;
+        }
+
+        let str = aa * csgnr - bb * csgni;
+        let sti = aa * csgni + bb * csgnr;
+        *cy = Complex::new(str * atol, sti * atol);
+        csgnr = -csgnr;
+        csgni = -csgni;
+    }
+
+    (nz, ierr)
+}
@@ -0,0 +1,102 @@
+// amos/zbesj.f, returning (nz, ierr)
+use super::zbinu::zbinu;
+use super::{azabs, d1mach, float, i1mach, int_sngl, Limits};
+use num::complex::Complex;
+#[cfg(not(feature = "std"))]
+use num::Float;
+
+pub(crate) fn zbesj(z: Complex<f64>, fnu: f64, kode: i32, cy: &mut [Complex<f64>]) -> (i32, i32) {
+    const HPI: f64 = 1.57079632679489662;
+
+    let (zr, zi) = (z.re, z.im);
+    let n = cy.len() as i32;
+    let mut ierr = 0;
+
+    if fnu < 0.0 || !(1..=2).contains(&kode) || n < 1 {
+        return (0, 1);
+    }
+
+    let Limits {
+        tol,
+        elim,
+        alim,
+        rl,
+        fnul,
+    } = Limits::new();
+
+    let az = azabs(zr, zi);
+    let fn_ = fnu + float(n - 1);
+    let mut aa = 0.5 / tol;
+    let bb = float(i1mach(9)) * 0.5;
+    aa = aa.min(bb);
+
+    if az > aa || fn_ > aa {
+        return (0, 4);
+    }
+
+    aa = aa.sqrt();
+
+    if az > aa || fn_ > aa {
+        ierr = 3;
+    }
+
+    // J from I by J(fnu, z) = exp(fnu hpi i) I(fnu, -i z) for Im(z) >= 0 and by
+    // J(fnu, z) = exp(-fnu hpi i) I(fnu, i z) for Im(z) < 0, see amos/zbesj.f lines 203-224
+    let mut cii = 1.0;
+    let inu = int_sngl(fnu);
+    let inuh = inu / 2;
+    let ir = inu - 2 * inuh;
+    let arg = (fnu - float(inu - ir)) * HPI;
+    let mut csgnr = arg.cos();
+    let mut csgni = arg.sin();
+
+    if inuh % 2 != 0 {
+        csgnr = -csgnr;
+        csgni = -csgni;
+    }
+
+    let mut znr = zi;
+    let mut zni = -zr;
+
+    if zi < 0.0 {
+        znr = -znr;
+        zni = -zni;
+        csgni = -csgni;
+        cii = -cii;
+    }
+
+    let nz = zbinu(znr, zni, fnu, kode, cy, rl, fnul, tol, elim, alim);
+
+    if nz < 0 {
+        return match nz == -2 {
+            true => (0, 5),
+            false => (0, 2),
+        };
+    }
+
+    let nl = (n - nz) as usize;
+    let rtol = 1.0 / tol;
+    let ascle = d1mach(1) * rtol * 1.0e+3;
+
+    for cy in cy[..nl].iter_mut() {
+        let mut aa = cy.re;
+        let mut bb = cy.im;
+        let mut atol = 1.0;
+
+        // Scale to avoid losing the result to underflow, see amos/zbesj.f lines 239-243
+        if aa.abs().max(bb.abs()) <= ascle {
+            aa *= rtol;
+            bb *= rtol;
+            atol = tol;
+        }
+
+        let str = aa * csgnr - bb * csgni;
+        let sti = aa * csgni + bb * csgnr;
+        *cy = Complex::new(str * atol, sti * atol);
+        let str = -csgni * cii;
+        csgni = csgnr * cii;
+        csgnr = str;
+    }
+
+    (nz, ierr)
+}
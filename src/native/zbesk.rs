@@ -0,0 +1,146 @@
+// amos/zbesk.f, returning (nz, ierr)
+use super::zacon::zacon;
+use super::zbknu::zbknu;
+use super::zbunk::zbunk;
+use super::zuoik::zuoik;
+use super::{azabs, d1mach, float, i1mach, Limits};
+use num::complex::Complex;
+#[cfg(not(feature = "std"))]
+use num::Float;
+
+pub(crate) fn zbesk(z: Complex<f64>, fnu: f64, kode: i32, cy: &mut [Complex<f64>]) -> (i32, i32) {
+    let (zr, zi) = (z.re, z.im);
+    let n = cy.len() as i32;
+    let mut ierr = 0;
+    let mut nz = 0;
+
+    if (zi == 0.0 && zr == 0.0) || fnu < 0.0 || !(1..=2).contains(&kode) || n < 1 {
+        return (0, 1);
+    }
+
+    let Limits {
+        tol,
+        elim,
+        alim,
+        rl,
+        fnul,
+        ..
+    } = Limits::new();
+
+    let mut nn = n as usize;
+    let az = azabs(zr, zi);
+    let fn_ = fnu + float(n - 1);
+    let mut aa = 0.5 / tol;
+    let bb = float(i1mach(9)) * 0.5;
+    aa = aa.min(bb);
+
+    if az > aa || fn_ > aa {
+        return (0, 4);
+    }
+
+    aa = aa.sqrt();
+
+    if az > aa || fn_ > aa {
+        ierr = 3;
+    }
+
+    // K overflows for |z| below the underflow limit, and for small |z| and large orders, see
+    // amos/zbesk.f lines 208-228
+    let ufl = d1mach(1) * 1.0e+3;
+
+    if az < ufl {
+        return (0, 2);
+    }
+
+    // The uniform asymptotic expansions for large orders
+    if fnu > fnul {
+        let mr = match zr >= 0.0 {
+            true => 0,
+            false => match zi < 0.0 {
+                true => -1,
+                false => 1,
+            },
+        };
+
+        let nw = zbunk(zr, zi, fnu, kode, mr, cy, tol, elim, alim);
+
+        return match nw < 0 {
+            true => nw_error(nw),
+            false => (nz + nw, ierr),
+        };
+    }
+
+    if fn_ > 1.0 {
+        match fn_ > 2.0 {
+            true => {
+                let nuf = zuoik(zr, zi, fnu, kode, 2, cy, tol, elim, alim);
+
+                if nuf < 0 {
+                    return (0, 2);
+                }
+
+                nz += nuf;
+                nn -= nuf as usize;
+
+                if nn == 0 {
+                    return match zr < 0.0 {
+                        true => (0, 2),
+                        false => (nz, ierr),
+                    };
+                }
+            }
+            false => {
+                if az <= tol {
+                    let arg = 0.5 * az;
+                    let aln = -fn_ * arg.ln();
+
+                    if aln > elim {
+                        return (0, 2);
+                    }
+                }
+            }
+        }
+    }
+
+    // Right half plane by zbknu, and the left half plane by analytic continuation
+    let nw = match zr >= 0.0 {
+        true => zbknu(zr, zi, fnu, kode, &mut cy[..nn], tol, elim, alim),
+        false => {
+            if nz != 0 {
+                return (0, 2);
+            }
+
+            let mr = match zi < 0.0 {
+                true => -1,
+                false => 1,
+            };
+
+            zacon(
+                zr,
+                zi,
+                fnu,
+                kode,
+                mr,
+                &mut cy[..nn],
+                rl,
+                fnul,
+                tol,
+                elim,
+                alim,
+            )
+        }
+    };
+
+    match nw < 0 {
+        true => nw_error(nw),
+        false => (nw, ierr),
+    }
+}
+
+// The (nz, ierr) of a failed zbknu, zacon or zbunk call, see amos/zbesk.f lines 272-276
+fn nw_error(nw: i32) -> (i32, i32) {
+    match nw == -1 {
+        true => (0, 2),
+        false => (0, 5),
+    }
+}
@@ -0,0 +1,105 @@
+// amos/zbesy.f, returning (nz, ierr), with cwrk the work space for H(2, fnu, z)
+use super::zbesh::zbesh;
+use super::{d1mach, float, i1mach};
+use num::complex::Complex;
+#[cfg(not(feature = "std"))]
+use num::Float;
+
+pub(crate) fn zbesy(
+    z: Complex<f64>,
+    fnu: f64,
+    kode: i32,
+    cy: &mut [Complex<f64>],
+    cwrk: &mut [Complex<f64>],
+) -> (i32, i32) {
+    let (zr, zi) = (z.re, z.im);
+    let n = cy.len();
+
+    if (zr == 0.0 && zi == 0.0) || fnu < 0.0 || !(1..=2).contains(&kode) || n < 1 {
+        return (0, 1);
+    }
+
+    // Y(fnu, z) = (H(1, fnu, z) - H(2, fnu, z))/(2i), see amos/zbesy.f lines 164-177
+    let hcii = 0.5;
+    let (nz1, ierr) = zbesh(z, fnu, kode, 1, cy);
+
+    if ierr != 0 && ierr != 3 {
+        return (0, ierr);
+    }
+
+    let (nz2, ierr) = zbesh(z, fnu, kode, 2, &mut cwrk[..n]);
+
+    if ierr != 0 && ierr != 3 {
+        return (0, ierr);
+    }
+
+    if kode != 2 {
+        for (cy, cwrk) in cy.iter_mut().zip(cwrk.iter()) {
+            let str = cwrk.re - cy.re;
+            let sti = cwrk.im - cy.im;
+            *cy = Complex::new(-sti * hcii, str * hcii);
+        }
+
+        return (nz1.min(nz2), ierr);
+    }
+
+    // The scaled functions differ in their scaling by exp(-2 |Im(z)|), which is applied with the
+    // scaling of zbesh undone near the underflow limit, see amos/zbesy.f lines 178-240
+    let tol = d1mach(4).max(1.0e-18);
+    let k1 = i1mach(15);
+    let k2 = i1mach(16);
+    let k = k1.abs().min(k2.abs());
+    let r1m5 = d1mach(5);
+    let elim = 2.303 * (float(k) * r1m5 - 3.0);
+    let exr = zr.cos();
+    let exi = zr.sin();
+    let mut ey = 0.0;
+    let tay = (zi + zi).abs();
+
+    if tay < elim {
+        ey = (-tay).exp();
+    }
+
+    let (c1r, c1i, c2r, c2i) = match zi < 0.0 {
+        true => (exr, exi, exr * ey, -exi * ey),
+        false => (exr * ey, exi * ey, exr, -exi),
+    };
+
+    let mut nz = 0;
+    let rtol = 1.0 / tol;
+    let ascle = d1mach(1) * rtol * 1.0e+3;
+
+    for (cy, cwrk) in cy.iter_mut().zip(cwrk.iter()) {
+        let mut aa = cwrk.re;
+        let mut bb = cwrk.im;
+        let mut atol = 1.0;
+
+        if aa.abs().max(bb.abs()) <= ascle {
+            aa *= rtol;
+            bb *= rtol;
+            atol = tol;
+        }
+
+        let mut str = (aa * c2r - bb * c2i) * atol;
+        let mut sti = (aa * c2i + bb * c2r) * atol;
+        aa = cy.re;
+        bb = cy.im;
+        atol = 1.0;
+
+        if aa.abs().max(bb.abs()) <= ascle {
+            aa *= rtol;
+            bb *= rtol;
+            atol = tol;
+        }
+
+        str -= (aa * c1r - bb * c1i) * atol;
+        sti -= (aa * c1i + bb * c1r) * atol;
+        *cy = Complex::new(-sti * hcii, str * hcii);
+
+        if str == 0.0 && sti == 0.0 && ey == 0.0 {
+            nz += 1;
+        }
+    }
+
+    (nz, ierr)
+}
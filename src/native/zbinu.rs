@@ -0,0 +1,123 @@
+// amos/zbinu.f, I for Re(z) >= 0 by the method appropriate to |z| and fnu, returning nz, or -1 on
+// overflow and -2 on a lack of convergence
+use super::zasyi::zasyi;
+use super::zbuni::zbuni;
+use super::zmlri::zmlri;
+use super::zseri::zseri;
+use super::zuoik::zuoik;
+use super::zwrsk::zwrsk;
+use super::{azabs, float, int_sngl, nz_error};
+use num::complex::Complex;
+
+pub(super) fn zbinu(
+    zr: f64,
+    zi: f64,
+    fnu: f64,
+    kode: i32,
+    cy: &mut [Complex<f64>],
+    rl: f64,
+    fnul: f64,
+    tol: f64,
+    elim: f64,
+    alim: f64,
+) -> i32 {
+    let mut nz = 0;
+    let az = azabs(zr, zi);
+    let mut nn = cy.len();
+    let mut dfnu = fnu + float(nn as i32 - 1);
+
+    // The power series for small |z|, see amos/zbinu.f lines 20-32
+    if az <= 2.0 || az * az * 0.25 <= dfnu + 1.0 {
+        let nw = zseri(zr, zi, fnu, kode, &mut cy[..nn], tol, elim, alim);
+        let inw = nw.abs();
+        nz += inw;
+        nn -= inw as usize;
+
+        if nn == 0 || nw >= 0 {
+            return nz;
+        }
+
+        dfnu = fnu + float(nn as i32 - 1);
+    }
+
+    // The asymptotic expansion for large |z|
+    if az >= rl && (dfnu <= 1.0 || az + az >= dfnu * dfnu) {
+        let nw = zasyi(zr, zi, fnu, kode, &mut cy[..nn], rl, tol, elim, alim);
+
+        return match nw < 0 {
+            true => nz_error(nw),
+            false => nz,
+        };
+    }
+
+    if !(az < rl && dfnu <= 1.0) {
+        // The overflow and underflow test on I by the uniform asymptotic expansions, see
+        // amos/zbinu.f lines 47-59
+        let nw = zuoik(zr, zi, fnu, kode, 1, &mut cy[..nn], tol, elim, alim);
+
+        if nw < 0 {
+            return nz_error(nw);
+        }
+
+        nz += nw;
+        nn -= nw as usize;
+
+        if nn == 0 {
+            return nz;
+        }
+
+        dfnu = fnu + float(nn as i32 - 1);
+
+        // The uniform asymptotic expansions for large orders, which leave nlast members of orders
+        // below fnul to the other methods, see amos/zbinu.f lines 91-103
+        if dfnu > fnul || az > fnul {
+            let nui = (int_sngl(fnul - dfnu) + 1).max(0);
+            let (nw, nlast) = zbuni(zr, zi, fnu, kode, &mut cy[..nn], nui, fnul, tol, elim, alim);
+
+            if nw < 0 {
+                return nz_error(nw);
+            }
+
+            nz += nw;
+
+            if nlast == 0 {
+                return nz;
+            }
+
+            nn = nlast as usize;
+        }
+
+        // The Wronskian normalization for large |z|, see amos/zbinu.f lines 69-90
+        if az > rl {
+            let mut cw = [Complex::new(0.0, 0.0); 2];
+            let nw = zuoik(zr, zi, fnu, kode, 2, &mut cw, tol, elim, alim);
+
+            if nw < 0 {
+                for cy in cy[..nn].iter_mut() {
+                    *cy = Complex::new(0.0, 0.0);
+                }
+
+                return nn as i32;
+            }
+
+            if nw > 0 {
+                return -1;
+            }
+
+            let nw = zwrsk(zr, zi, fnu, kode, &mut cy[..nn], tol, elim, alim);
+
+            return match nw < 0 {
+                true => nz_error(nw),
+                false => nz,
+            };
+        }
+    }
+
+    // The Miller algorithm normalized by the Neumann series
+    let nw = zmlri(zr, zi, fnu, kode, &mut cy[..nn], tol);
+
+    match nw < 0 {
+        true => nz_error(nw),
+        false => nz,
+    }
+}
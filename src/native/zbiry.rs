@@ -0,0 +1,262 @@
+// amos/zbiry.f, Bi(z) for id = 0 and Bi'(z) for id = 1, returning (bi, ierr), where bi is zero
+// on the errors for which the Fortran leaves it undefined
+use super::zbinu::zbinu;
+use super::{azabs, azsqrt, d1mach, float, i1mach, zdiv, Limits};
+use num::complex::Complex;
+#[cfg(not(feature = "std"))]
+use num::Float;
+
+const TTH: f64 = 6.66666666666666667e-01;
+const C1: f64 = 6.14926627446000736e-01;
+const C2: f64 = 4.48288357353826359e-01;
+
+pub(crate) fn zbiry(zr: f64, zi: f64, id: i32, kode: i32) -> (Complex<f64>, i32) {
+    const COEF: f64 = 5.77350269189625765e-01;
+    const PI: f64 = 3.14159265358979324;
+
+    let zero = Complex::new(0.0, 0.0);
+
+    if !(0..=1).contains(&id) || !(1..=2).contains(&kode) {
+        return (zero, 1);
+    }
+
+    let az = azabs(zr, zi);
+    let tol = d1mach(4).max(1.0e-18);
+    let fid = float(id);
+
+    if az <= 1.0 {
+        return (zbiry_series(zr, zi, az, id, kode, tol), 0);
+    }
+
+    // The relation to I with fnu = 1/3 or 2/3 for |z| > 1, see amos/zbiry.f lines 230-345
+    let mut fnu = (1.0 + fid) / 3.0;
+    let Limits {
+        elim,
+        alim,
+        rl,
+        fnul,
+        ..
+    } = Limits::new();
+    let mut aa = 0.5 / tol;
+    let bb = float(i1mach(9)) * 0.5;
+    aa = aa.min(bb);
+    aa = aa.powf(TTH);
+
+    if az > aa {
+        return (zero, 4);
+    }
+
+    aa = aa.sqrt();
+    let ierr = match az > aa {
+        true => 3,
+        false => 0,
+    };
+
+    let (csqr, csqi) = azsqrt(zr, zi);
+    let mut ztar = TTH * (zr * csqr - zi * csqi);
+    let mut ztai = TTH * (zr * csqi + zi * csqr);
+
+    // Re(zeta) <= 0 when Re(z) < 0, which is set explicitly to avoid round off errors
+    let mut sfac = 1.0;
+    let ak = ztai;
+
+    if zr < 0.0 {
+        let bk = ztar;
+        let ck = -bk.abs();
+        ztar = ck;
+        ztai = ak;
+    }
+
+    if zi == 0.0 && zr <= 0.0 {
+        ztar = 0.0;
+        ztai = ak;
+    }
+
+    let aa = ztar;
+
+    // Overflow test
+    if kode != 2 {
+        let mut bb = aa.abs();
+
+        if bb >= alim {
+            bb += 0.25 * az.ln();
+            sfac = tol;
+
+            if bb > elim {
+                return (zero, 2);
+            }
+        }
+    }
+
+    let mut fmr = 0.0;
+
+    if !(aa >= 0.0 && zr > 0.0) {
+        fmr = PI;
+
+        if zi < 0.0 {
+            fmr = -PI;
+        }
+
+        ztar = -ztar;
+        ztai = -ztai;
+    }
+
+    // aa is the factor for the analytic continuation of I(fnu, zeta), where kode = 2 returns
+    // exp(-|Re(zeta)|) I(fnu, zeta) from zbesi
+    let mut cy = [zero; 2];
+    let nz = zbinu(
+        ztar,
+        ztai,
+        fnu,
+        kode,
+        &mut cy[..1],
+        rl,
+        fnul,
+        tol,
+        elim,
+        alim,
+    );
+
+    if nz < 0 {
+        return match nz == -1 {
+            true => (zero, 2),
+            false => (zero, 5),
+        };
+    }
+
+    let aa = fmr * fnu;
+    let z3r = sfac;
+    let str = aa.cos();
+    let sti = aa.sin();
+    let mut s1r = (str * cy[0].re - sti * cy[0].im) * z3r;
+    let mut s1i = (str * cy[0].im + sti * cy[0].re) * z3r;
+    fnu = (2.0 - fid) / 3.0;
+    zbinu(ztar, ztai, fnu, kode, &mut cy, rl, fnul, tol, elim, alim);
+    cy[0] = Complex::new(cy[0].re * z3r, cy[0].im * z3r);
+    cy[1] = Complex::new(cy[1].re * z3r, cy[1].im * z3r);
+
+    // Backward recur one step for orders -1/3 or -2/3
+    let (str, sti) = zdiv(cy[0].re, cy[0].im, ztar, ztai);
+    let s2r = (fnu + fnu) * str + cy[1].re;
+    let s2i = (fnu + fnu) * sti + cy[1].im;
+    let aa = fmr * (fnu - 1.0);
+    let str = aa.cos();
+    let sti = aa.sin();
+    s1r = COEF * (s1r + s2r * str - s2i * sti);
+    s1i = COEF * (s1i + s2r * sti + s2i * str);
+
+    let str = match id == 1 {
+        true => {
+            let str = zr * s1r - zi * s1i;
+            s1i = zr * s1i + zi * s1r;
+            str
+        }
+        false => {
+            let str = csqr * s1r - csqi * s1i;
+            s1i = csqr * s1i + csqi * s1r;
+            str
+        }
+    };
+
+    s1r = str;
+
+    (Complex::new(s1r / sfac, s1i / sfac), ierr)
+}
+
+// The power series for |z| <= 1, see amos/zbiry.f lines 145-226
+fn zbiry_series(zr: f64, zi: f64, az: f64, id: i32, kode: i32, tol: f64) -> Complex<f64> {
+    let fid = float(id);
+    let mut s1r = 1.0;
+    let mut s1i = 0.0;
+    let mut s2r = 1.0;
+    let mut s2i = 0.0;
+
+    if az < tol {
+        let aa = C1 * (1.0 - fid) + fid * C2;
+
+        return Complex::new(aa, 0.0);
+    }
+
+    let aa = az * az;
+
+    if aa >= tol / az {
+        let mut trm1r = 1.0;
+        let mut trm1i = 0.0;
+        let mut trm2r = 1.0;
+        let mut trm2i = 0.0;
+        let mut atrm = 1.0;
+        let mut str = zr * zr - zi * zi;
+        let sti = zr * zi + zi * zr;
+        let z3r = str * zr - sti * zi;
+        let z3i = str * zi + sti * zr;
+        let az3 = az * aa;
+        let mut ak = 2.0 + fid;
+        let mut bk = 3.0 - fid - fid;
+        let ck = 4.0 - fid;
+        let dk = 3.0 + fid + fid;
+        let mut d1 = ak * dk;
+        let mut d2 = bk * ck;
+        let mut ad = d1.min(d2);
+        ak = 24.0 + 9.0 * fid;
+        bk = 30.0 - 9.0 * fid;
+
+        for _ in 1..=25 {
+            str = (trm1r * z3r - trm1i * z3i) / d1;
+            trm1i = (trm1r * z3i + trm1i * z3r) / d1;
+            trm1r = str;
+            s1r += trm1r;
+            s1i += trm1i;
+            str = (trm2r * z3r - trm2i * z3i) / d2;
+            trm2i = (trm2r * z3i + trm2i * z3r) / d2;
+            trm2r = str;
+            s2r += trm2r;
+            s2i += trm2i;
+            atrm = atrm * az3 / ad;
+            d1 += ak;
+            d2 += bk;
+            ad = d1.min(d2);
+
+            if atrm < tol * ad {
+                break;
+            }
+
+            ak += 18.0;
+            bk += 18.0;
+        }
+    }
+
+    let (mut bir, mut bii) = match id == 1 {
+        true => {
+            let mut bir = s2r * C2;
+            let mut bii = s2i * C2;
+
+            if az > tol {
+                let cc = C1 / (1.0 + fid);
+                let str = s1r * zr - s1i * zi;
+                let sti = s1r * zi + s1i * zr;
+                bir += cc * (str * zr - sti * zi);
+                bii += cc * (str * zi + sti * zr);
+            }
+
+            (bir, bii)
+        }
+        false => (
+            C1 * s1r + C2 * (zr * s2r - zi * s2i),
+            C1 * s1i + C2 * (zr * s2i + zi * s2r),
+        ),
+    };
+
+    if kode == 1 {
+        return Complex::new(bir, bii);
+    }
+
+    let (str, sti) = azsqrt(zr, zi);
+    let ztar = TTH * (zr * str - zi * sti);
+    let mut aa = ztar;
+    aa = -aa.abs();
+    let eaa = aa.exp();
+    bir *= eaa;
+    bii *= eaa;
+
+    Complex::new(bir, bii)
+}
@@ -0,0 +1,641 @@
+// amos/zbknu.f, K for Re(z) >= 0 by the series for |z| <= 2 and the Miller algorithm normalized by
+// the Wronskian otherwise, followed by forward recurrence, returning nz, or -2 if the Miller
+// algorithm did not converge
+use super::dgamln::dgamln;
+use super::zkscl::zkscl;
+use super::{
+    azabs, azexp, azlog, azsqrt, d1mach, float, i1mach, int_sngl, zdiv, zmlt, zshch, zuchk,
+};
+use num::complex::Complex;
+#[cfg(not(feature = "std"))]
+use num::Float;
+
+const CC: [f64; 8] = [
+    5.77215664901532861e-01,
+    -4.20026350340952355e-02,
+    -4.21977345555443367e-02,
+    7.21894324666309954e-03,
+    -2.15241674114950973e-04,
+    -2.01348547807882387e-05,
+    1.13302723198169588e-06,
+    6.11609510448141582e-09,
+];
+
+pub(super) fn zbknu(
+    zr: f64,
+    zi: f64,
+    fnu: f64,
+    kode: i32,
+    y: &mut [Complex<f64>],
+    tol: f64,
+    elim: f64,
+    alim: f64,
+) -> i32 {
+    const KMAX: i32 = 30;
+    const R1: f64 = 2.0;
+    const DPI: f64 = 3.14159265358979324;
+    const RTHPI: f64 = 1.25331413731550025;
+    const SPI: f64 = 1.90985931710274403;
+    const HPI: f64 = 1.57079632679489662;
+    const FPI: f64 = 1.89769999331517738;
+    const TTH: f64 = 6.66666666666666666e-01;
+
+    let n = y.len();
+    let caz = azabs(zr, zi);
+    let cscl = 1.0 / tol;
+    let crsc = tol;
+    let cssr = [cscl, 1.0, crsc];
+    let csrr = [crsc, 1.0, cscl];
+    let mut bry = [0.0; 3];
+    bry[0] = 1.0e+3 * d1mach(1) / tol;
+    bry[1] = 1.0 / bry[0];
+    bry[2] = d1mach(2);
+    let mut nz = 0;
+    let mut iflag = 0;
+    let rcaz = 1.0 / caz;
+    let str = zr * rcaz;
+    let sti = -zi * rcaz;
+    let rzr = (str + str) * rcaz;
+    let rzi = (sti + sti) * rcaz;
+    let mut inu = int_sngl(fnu + 0.5);
+    let dnu = fnu - float(inu);
+    let mut dnu2 = 0.0;
+
+    if dnu.abs() > tol {
+        dnu2 = dnu * dnu;
+    }
+
+    let mut s1r;
+    let mut s1i;
+    let mut s2r;
+    let mut s2i;
+    let mut kflag: usize;
+
+    // Whether K(fnu, z) for n = 1 is already in s1, such that the forward recurrence is skipped
+    let skip = match dnu.abs() != 0.5 && caz <= R1 {
+        true => {
+            // The series for |z| <= r1, where gam(1-z) gam(1+z) = pi z/sin(pi z), t1 = 1/gam(1-dnu)
+            // and t2 = 1/gam(1+dnu), see amos/zbknu.f lines 70-204
+            let mut fc = 1.0;
+            let (mut smur, mut smui) = azlog(rzr, rzi);
+            let fmur = smur * dnu;
+            let fmui = smui * dnu;
+            let ((cshr, cshi), (cchr, cchi)) = zshch(fmur, fmui);
+
+            if dnu != 0.0 {
+                fc = dnu * DPI;
+                fc /= fc.sin();
+                smur = cshr / dnu;
+                smui = cshi / dnu;
+            }
+
+            let a2 = 1.0 + dnu;
+            let t2 = (-dgamln(a2)).exp();
+            let t1 = 1.0 / (t2 * fc);
+
+            // The series for f0 to resolve the indeterminacy for small |dnu|
+            let g1 = match dnu.abs() > 0.1 {
+                true => (t1 - t2) / (dnu + dnu),
+                false => {
+                    let mut ak = 1.0;
+                    let mut s = CC[0];
+
+                    for cc in CC[1..].iter() {
+                        ak *= dnu2;
+                        let tm = cc * ak;
+                        s += tm;
+
+                        if tm.abs() < tol {
+                            break;
+                        }
+                    }
+
+                    -s
+                }
+            };
+
+            let g2 = (t1 + t2) * 0.5;
+            let mut fr = fc * (cchr * g1 + smur * g2);
+            let mut fi = fc * (cchi * g1 + smui * g2);
+            let (str, sti) = azexp(fmur, fmui);
+            let mut pr = 0.5 * str / t2;
+            let mut pi = 0.5 * sti / t2;
+            let (ptr, pti) = zdiv(0.5, 0.0, str, sti);
+            let mut qr = ptr / t1;
+            let mut qi = pti / t1;
+            s1r = fr;
+            s1i = fi;
+            s2r = pr;
+            s2i = pi;
+            let mut ak = 1.0;
+            let mut a1 = 1.0;
+            let mut ckr = 1.0;
+            let mut cki = 0.0;
+            let mut bk = 1.0 - dnu2;
+            let single = inu <= 0 && n <= 1;
+
+            // Generate K(fnu, z) for 0 <= fnu < 0.5 and n = 1 in s1, and K(dnu, z) and K(dnu+1, z)
+            // for the forward recurrence in s1 and s2 otherwise
+            if caz >= tol {
+                let (mut czr, mut czi) = zmlt(zr, zi, zr, zi);
+                czr *= 0.25;
+                czi *= 0.25;
+                let t1 = 0.25 * caz * caz;
+
+                loop {
+                    fr = (fr * ak + pr + qr) / bk;
+                    fi = (fi * ak + pi + qi) / bk;
+                    let mut str = 1.0 / (ak - dnu);
+                    pr *= str;
+                    pi *= str;
+                    str = 1.0 / (ak + dnu);
+                    qr *= str;
+                    qi *= str;
+                    str = ckr * czr - cki * czi;
+                    let rak = 1.0 / ak;
+                    cki = (ckr * czi + cki * czr) * rak;
+                    ckr = str * rak;
+                    s1r += ckr * fr - cki * fi;
+                    s1i += ckr * fi + cki * fr;
+
+                    if !single {
+                        let str = pr - fr * ak;
+                        let sti = pi - fi * ak;
+                        s2r += ckr * str - cki * sti;
+                        s2i += ckr * sti + cki * str;
+                    }
+
+                    a1 = a1 * t1 * rak;
+                    bk = bk + ak + ak + 1.0;
+                    ak += 1.0;
+
+                    if a1 <= tol {
+                        break;
+                    }
+                }
+            }
+
+            if single {
+                y[0] = Complex::new(s1r, s1i);
+
+                if kode != 1 {
+                    let (str, sti) = azexp(zr, zi);
+                    let (yr, yi) = zmlt(s1r, s1i, str, sti);
+                    y[0] = Complex::new(yr, yi);
+                }
+
+                return nz;
+            }
+
+            kflag = 2;
+            let a1 = fnu + 1.0;
+            let ak = a1 * smur.abs();
+
+            if ak > alim {
+                kflag = 3;
+            }
+
+            let str = cssr[kflag - 1];
+            let p2r = s2r * str;
+            let p2i = s2i * str;
+            (s2r, s2i) = zmlt(p2r, p2i, rzr, rzi);
+            s1r *= str;
+            s1i *= str;
+
+            if kode != 1 {
+                let (fr, fi) = azexp(zr, zi);
+                (s1r, s1i) = zmlt(s1r, s1i, fr, fi);
+                (s2r, s2i) = zmlt(s2r, s2i, fr, fi);
+            }
+
+            false
+        }
+        false => {
+            // iflag = 1 means that an underflow occurred, such that the computation proceeds with
+            // the scaling by exp(z) and a test for on scale values during the forward recurrence
+            let (str, sti) = azsqrt(zr, zi);
+            let (mut coefr, mut coefi) = zdiv(RTHPI, 0.0, str, sti);
+            kflag = 2;
+
+            if kode == 1 {
+                match zr > alim {
+                    true => iflag = 1,
+                    false => {
+                        let mut str = (-zr).exp() * cssr[kflag - 1];
+                        let sti = -str * zi.sin();
+                        str *= zi.cos();
+                        (coefr, coefi) = zmlt(coefr, coefi, str, sti);
+                    }
+                }
+            }
+
+            'miller: {
+                // The closed form coef of K for fnu half an odd integer, where dnu = -0.5
+                let mut ak = (DPI * dnu).cos();
+                ak = ak.abs();
+                let mut fhs = (0.25 - dnu2).abs();
+
+                if dnu.abs() == 0.5 || ak == 0.0 || fhs == 0.0 {
+                    s1r = coefr;
+                    s1i = coefi;
+                    s2r = coefr;
+                    s2i = coefi;
+                    break 'miller false;
+                }
+
+                // The Miller algorithm for |z| > r1, where r2 = f(e) is a straight line on
+                // 12 <= e <= 60, for e computed from 2^(-e) = b^(1-i1mach(14)) = tol, see
+                // amos/zbknu.f lines 238-288
+                let mut t1 = float(i1mach(14) - 1);
+                t1 = t1 * d1mach(5) * 3.321928094;
+                t1 = t1.max(12.0);
+                t1 = t1.min(60.0);
+                let t2 = TTH * t1 - 6.0;
+
+                t1 = match zr != 0.0 {
+                    true => (zi / zr).atan().abs(),
+                    false => HPI,
+                };
+
+                let mut fk;
+
+                match t2 > caz {
+                    // The backward index k for |z| < r2
+                    true => {
+                        let a2 = caz.sqrt();
+                        ak = FPI * ak / (tol * a2.sqrt());
+                        let aa = 3.0 * t1 / (1.0 + caz);
+                        let bb = 14.7 * t1 / (28.0 + caz);
+                        ak = (ak.ln() + caz * aa.cos() / (1.0 + 0.008 * caz)) / bb.cos();
+                        fk = 0.12125 * ak * ak / caz + 1.5;
+                    }
+                    // Forward recurrence for the backward index k when |z| >= r2
+                    false => {
+                        let etest = ak / (DPI * caz * tol);
+                        fk = 1.0;
+
+                        if etest >= 1.0 {
+                            let mut fks = 2.0;
+                            let mut ckr = caz + caz + 2.0;
+                            let mut p1r = 0.0;
+                            let mut p2r = 1.0;
+
+                            let converged = 'forward: {
+                                for _ in 1..=KMAX {
+                                    ak = fhs / fks;
+                                    let cbr = ckr / (fk + 1.0);
+                                    let ptr = p2r;
+                                    p2r = cbr * p2r - p1r * ak;
+                                    p1r = ptr;
+                                    ckr += 2.0;
+                                    fks = fks + fk + fk + 2.0;
+                                    fhs = fhs + fk + fk;
+                                    fk += 1.0;
+                                    let str = p2r.abs() * fk;
+
+                                    if etest < str {
+                                        break 'forward true;
+                                    }
+                                }
+
+                                false
+                            };
+
+                            if !converged {
+                                return -2;
+                            }
+
+                            fk += SPI * t1 * (t2 / caz).sqrt();
+                            fhs = (0.25 - dnu2).abs();
+                        }
+                    }
+                }
+
+                // The backward recurrence of the Miller algorithm
+                let k = int_sngl(fk);
+                fk = float(k);
+                let mut fks = fk * fk;
+                let mut p1r = 0.0;
+                let mut p1i = 0.0;
+                let mut p2r = tol;
+                let mut p2i = 0.0;
+                let mut csr = p2r;
+                let mut csi = p2i;
+
+                for _ in 1..=k {
+                    let a1 = fks - fk;
+                    let ak = (fks + fk) / (a1 + fhs);
+                    let rak = 2.0 / (fk + 1.0);
+                    let cbr = (fk + zr) * rak;
+                    let cbi = zi * rak;
+                    let ptr = p2r;
+                    let pti = p2i;
+                    p2r = (ptr * cbr - pti * cbi - p1r) * ak;
+                    p2i = (pti * cbr + ptr * cbi - p1i) * ak;
+                    p1r = ptr;
+                    p1i = pti;
+                    csr += p2r;
+                    csi += p2i;
+                    fks = a1 - fk + 1.0;
+                    fk -= 1.0;
+                }
+
+                // (p2/cs) = (p2/|cs|) (conj(cs)/|cs|) for better scaling
+                let tm = azabs(csr, csi);
+                let ptr = 1.0 / tm;
+                s1r = p2r * ptr;
+                s1i = p2i * ptr;
+                csr *= ptr;
+                csi = -csi * ptr;
+                let (str, sti) = zmlt(coefr, coefi, s1r, s1i);
+                (s1r, s1i) = zmlt(str, sti, csr, csi);
+
+                if inu <= 0 && n <= 1 {
+                    s2r = s1r;
+                    s2i = s1i;
+                    break 'miller true;
+                }
+
+                // p1/p2 = (p1/|p2|) (conj(p2)/|p2|) for scaling
+                let tm = azabs(p2r, p2i);
+                let ptr = 1.0 / tm;
+                p1r *= ptr;
+                p1i *= ptr;
+                p2r *= ptr;
+                p2i = -p2i * ptr;
+                let (ptr, pti) = zmlt(p1r, p1i, p2r, p2i);
+                let str = dnu + 0.5 - ptr;
+                let sti = -pti;
+                let (mut str, sti) = zdiv(str, sti, zr, zi);
+                str += 1.0;
+                (s2r, s2i) = zmlt(str, sti, s1r, s1i);
+
+                false
+            }
+        }
+    };
+
+    let mut zdr = zr;
+    let zdi = zi;
+    let mut ckr = 0.0;
+    let mut cki = 0.0;
+
+    // Forward recurrence on the three term recurrence relation with scaling near the exponent
+    // extremes for kflag = 1 and kflag = 3, where underflow selects the recurrence on the scaled
+    // values of zkscl, see amos/zbknu.f lines 356-407
+    let underflow = match skip {
+        true => iflag == 1,
+        false => 'forward: {
+            let str = dnu + 1.0;
+            ckr = str * rzr;
+            cki = str * rzi;
+
+            if n == 1 {
+                inu -= 1;
+            }
+
+            if inu <= 0 {
+                if n == 1 {
+                    s1r = s2r;
+                    s1i = s2i;
+                }
+
+                break 'forward iflag == 1;
+            }
+
+            let mut inub = 1;
+
+            // Recur on the values scaled by exp(-z) until two members in a row are on scale, see
+            // amos/zbknu.f lines 457-517
+            if iflag == 1 {
+                let helim = 0.5 * elim;
+                let elm = (-elim).exp();
+                let celmr = elm;
+                let ascle = bry[0];
+                let mut cy = [Complex::new(0.0, 0.0); 2];
+                let mut ic = -1;
+                let mut j = 2;
+
+                let onscale = 'scaled: {
+                    for i in 1..=inu {
+                        let mut str = s2r;
+                        let mut sti = s2i;
+                        s2r = str * ckr - sti * cki + s1r;
+                        s2i = sti * ckr + str * cki + s1i;
+                        s1r = str;
+                        s1i = sti;
+                        ckr += rzr;
+                        cki += rzi;
+                        let as_ = azabs(s2r, s2i);
+                        let alas = as_.ln();
+                        let mut p2r = -zdr + alas;
+
+                        if p2r >= -elim {
+                            (str, sti) = azlog(s2r, s2i);
+                            p2r = -zdr + str;
+                            let p2i = -zdi + sti;
+                            let p2m = p2r.exp() / tol;
+                            let p1r = p2m * p2i.cos();
+                            let p1i = p2m * p2i.sin();
+
+                            if zuchk(p1r, p1i, ascle, tol) == 0 {
+                                j = 3 - j;
+                                cy[j - 1] = Complex::new(p1r, p1i);
+
+                                if ic == i - 1 {
+                                    break 'scaled Some(i);
+                                }
+
+                                ic = i;
+                                continue;
+                            }
+                        }
+
+                        if alas < helim {
+                            continue;
+                        }
+
+                        zdr -= elim;
+                        s1r *= celmr;
+                        s1i *= celmr;
+                        s2r *= celmr;
+                        s2i *= celmr;
+                    }
+
+                    None
+                };
+
+                match onscale {
+                    Some(i) => {
+                        kflag = 1;
+                        inub = i + 1;
+                        s2r = cy[j - 1].re;
+                        s2i = cy[j - 1].im;
+                        j = 3 - j;
+                        s1r = cy[j - 1].re;
+                        s1i = cy[j - 1].im;
+                    }
+                    None => {
+                        if n == 1 {
+                            s1r = s2r;
+                            s1i = s2i;
+                        }
+
+                        break 'forward true;
+                    }
+                }
+            }
+
+            let mut p1r = csrr[kflag - 1];
+            let mut ascle = bry[kflag - 1];
+
+            for _ in inub..=inu {
+                let str = s2r;
+                let sti = s2i;
+                s2r = ckr * str - cki * sti + s1r;
+                s2i = ckr * sti + cki * str + s1i;
+                s1r = str;
+                s1i = sti;
+                ckr += rzr;
+                cki += rzi;
+
+                if kflag >= 3 {
+                    continue;
+                }
+
+                let p2r = s2r * p1r;
+                let p2i = s2i * p1r;
+                let str = p2r.abs();
+                let sti = p2i.abs();
+                let p2m = str.max(sti);
+
+                if p2m <= ascle {
+                    continue;
+                }
+
+                kflag += 1;
+                ascle = bry[kflag - 1];
+                s1r *= p1r;
+                s1i *= p1r;
+                s2r = p2r;
+                s2i = p2i;
+                let str = cssr[kflag - 1];
+                s1r *= str;
+                s1i *= str;
+                s2r *= str;
+                s2i *= str;
+                p1r = csrr[kflag - 1];
+            }
+
+            if n == 1 {
+                s1r = s2r;
+                s1i = s2i;
+            }
+
+            false
+        }
+    };
+
+    let kk = match underflow {
+        false => {
+            let str = csrr[kflag - 1];
+            y[0] = Complex::new(s1r * str, s1i * str);
+
+            if n == 1 {
+                return nz;
+            }
+
+            y[1] = Complex::new(s2r * str, s2i * str);
+
+            if n == 2 {
+                return nz;
+            }
+
+            3
+        }
+        true => {
+            y[0] = Complex::new(s1r, s1i);
+
+            if n != 1 {
+                y[1] = Complex::new(s2r, s2i);
+            }
+
+            nz = zkscl(zdr, zdi, fnu, y, rzr, rzi, bry[0], tol, elim);
+            let inu = n as i32 - nz;
+
+            if inu <= 0 {
+                return nz;
+            }
+
+            let mut kk = nz as usize + 1;
+            s1r = y[kk - 1].re;
+            s1i = y[kk - 1].im;
+            y[kk - 1] = Complex::new(s1r * csrr[0], s1i * csrr[0]);
+
+            if inu == 1 {
+                return nz;
+            }
+
+            kk = nz as usize + 2;
+            s2r = y[kk - 1].re;
+            s2i = y[kk - 1].im;
+            y[kk - 1] = Complex::new(s2r * csrr[0], s2i * csrr[0]);
+
+            if inu == 2 {
+                return nz;
+            }
+
+            let t2 = fnu + float(kk as i32 - 1);
+            ckr = t2 * rzr;
+            cki = t2 * rzi;
+            kflag = 1;
+
+            kk + 1
+        }
+    };
+
+    // Forward recurrence on the remaining members, see amos/zbknu.f lines 417-452
+    let mut p1r = csrr[kflag - 1];
+    let mut ascle = bry[kflag - 1];
+
+    for y in y[kk - 1..].iter_mut() {
+        let mut p2r = s2r;
+        let mut p2i = s2i;
+        s2r = ckr * p2r - cki * p2i + s1r;
+        s2i = cki * p2r + ckr * p2i + s1i;
+        s1r = p2r;
+        s1i = p2i;
+        ckr += rzr;
+        cki += rzi;
+        p2r = s2r * p1r;
+        p2i = s2i * p1r;
+        *y = Complex::new(p2r, p2i);
+
+        if kflag >= 3 {
+            continue;
+        }
+
+        let str = p2r.abs();
+        let sti = p2i.abs();
+        let p2m = str.max(sti);
+
+        if p2m <= ascle {
+            continue;
+        }
+
+        kflag += 1;
+        ascle = bry[kflag - 1];
+        s1r *= p1r;
+        s1i *= p1r;
+        s2r = p2r;
+        s2i = p2i;
+        let str = cssr[kflag - 1];
+        s1r *= str;
+        s1i *= str;
+        s2r *= str;
+        s2i *= str;
+        p1r = csrr[kflag - 1];
+    }
+
+    nz
+}
@@ -0,0 +1,183 @@
+// amos/zbuni.f, I for large orders by the uniform asymptotic expansions, returning (nz, nlast),
+// where nlast != 0 is the number of members left to compute by other means, since their order is
+// below fnul
+use super::zuni1::zuni1;
+use super::zuni2::zuni2;
+use super::{azabs, d1mach, float, nz_error};
+use num::complex::Complex;
+
+pub(super) fn zbuni(
+    zr: f64,
+    zi: f64,
+    fnu: f64,
+    kode: i32,
+    y: &mut [Complex<f64>],
+    nui: i32,
+    fnul: f64,
+    tol: f64,
+    elim: f64,
+    alim: f64,
+) -> (i32, i32) {
+    let n = y.len();
+    let ax = zr.abs() * 1.7321;
+    let ay = zi.abs();
+    let iform = match ay > ax {
+        true => 2,
+        false => 1,
+    };
+
+    if nui == 0 {
+        let (nw, nlast) = match iform == 2 {
+            true => zuni2(zr, zi, fnu, kode, y, fnul, tol, elim, alim),
+            false => zuni1(zr, zi, fnu, kode, y, fnul, tol, elim, alim),
+        };
+
+        return match nw < 0 {
+            true => (nz_error(nw), nlast),
+            false => (nw, nlast),
+        };
+    }
+
+    // Compute the orders fnu+n-1+nui and fnu+n-2+nui and recur backward, see amos/zbuni.f lines
+    // 81-174
+    let mut fnui = float(nui);
+    let dfnu = fnu + float(n as i32 - 1);
+    let gnu = dfnu + fnui;
+    let mut cy = [Complex::new(0.0, 0.0); 2];
+
+    let (nw, nlast) = match iform == 2 {
+        true => zuni2(zr, zi, gnu, kode, &mut cy, fnul, tol, elim, alim),
+        false => zuni1(zr, zi, gnu, kode, &mut cy, fnul, tol, elim, alim),
+    };
+
+    if nw < 0 {
+        return (nz_error(nw), nlast);
+    }
+
+    if nw != 0 {
+        return (0, n as i32);
+    }
+
+    let mut str = azabs(cy[0].re, cy[0].im);
+    let mut bry = [0.0; 3];
+    bry[0] = 1.0e+3 * d1mach(1) / tol;
+    bry[1] = 1.0 / bry[0];
+    bry[2] = bry[1];
+    let mut iflag = 2;
+    let mut ascle = bry[1];
+    let mut csclr = 1.0;
+
+    match str > bry[0] {
+        true => {
+            if str >= bry[1] {
+                iflag = 3;
+                ascle = bry[2];
+                csclr = tol;
+            }
+        }
+        false => {
+            iflag = 1;
+            ascle = bry[0];
+            csclr = 1.0 / tol;
+        }
+    }
+
+    let mut cscrr = 1.0 / csclr;
+    let mut s1r = cy[1].re * csclr;
+    let mut s1i = cy[1].im * csclr;
+    let mut s2r = cy[0].re * csclr;
+    let mut s2i = cy[0].im * csclr;
+    let raz = 1.0 / azabs(zr, zi);
+    str = zr * raz;
+    let mut sti = -zi * raz;
+    let rzr = (str + str) * raz;
+    let rzi = (sti + sti) * raz;
+
+    for _ in 1..=nui {
+        str = s2r;
+        sti = s2i;
+        s2r = (dfnu + fnui) * (rzr * str - rzi * sti) + s1r;
+        s2i = (dfnu + fnui) * (rzr * sti + rzi * str) + s1i;
+        s1r = str;
+        s1i = sti;
+        fnui -= 1.0;
+
+        if iflag >= 3 {
+            continue;
+        }
+
+        str = s2r * cscrr;
+        sti = s2i * cscrr;
+        let c1r = str.abs();
+        let c1i = sti.abs();
+        let c1m = c1r.max(c1i);
+
+        if c1m <= ascle {
+            continue;
+        }
+
+        iflag += 1;
+        ascle = bry[iflag - 1];
+        s1r *= cscrr;
+        s1i *= cscrr;
+        s2r = str;
+        s2i = sti;
+        csclr *= tol;
+        cscrr = 1.0 / csclr;
+        s1r *= csclr;
+        s1i *= csclr;
+        s2r *= csclr;
+        s2i *= csclr;
+    }
+
+    y[n - 1] = Complex::new(s2r * cscrr, s2i * cscrr);
+
+    if n == 1 {
+        return (0, nlast);
+    }
+
+    let nl = n - 1;
+    fnui = float(nl as i32);
+    let mut k = nl;
+
+    for _ in 1..=nl {
+        str = s2r;
+        sti = s2i;
+        s2r = (fnu + fnui) * (rzr * str - rzi * sti) + s1r;
+        s2i = (fnu + fnui) * (rzr * sti + rzi * str) + s1i;
+        s1r = str;
+        s1i = sti;
+        str = s2r * cscrr;
+        sti = s2i * cscrr;
+        y[k - 1] = Complex::new(str, sti);
+        fnui -= 1.0;
+        k -= 1;
+
+        if iflag >= 3 {
+            continue;
+        }
+
+        let c1r = str.abs();
+        let c1i = sti.abs();
+        let c1m = c1r.max(c1i);
+
+        if c1m <= ascle {
+            continue;
+        }
+
+        iflag += 1;
+        ascle = bry[iflag - 1];
+        s1r *= cscrr;
+        s1i *= cscrr;
+        s2r = str;
+        s2i = sti;
+        csclr *= tol;
+        cscrr = 1.0 / csclr;
+        s1r *= csclr;
+        s1i *= csclr;
+        s2r *= csclr;
+        s2i *= csclr;
+    }
+
+    (0, nlast)
+}
@@ -0,0 +1,25 @@
+// amos/zbunk.f, K for large orders by the uniform asymptotic expansion of zunk1 for
+// |arg(z)| <= pi/3 and of zunk2 in the Airy functions otherwise, returning nz
+use super::zunk1::zunk1;
+use super::zunk2::zunk2;
+use num::complex::Complex;
+
+pub(super) fn zbunk(
+    zr: f64,
+    zi: f64,
+    fnu: f64,
+    kode: i32,
+    mr: i32,
+    y: &mut [Complex<f64>],
+    tol: f64,
+    elim: f64,
+    alim: f64,
+) -> i32 {
+    let ax = zr.abs() * 1.7321;
+    let ay = zi.abs();
+
+    match ay > ax {
+        true => zunk2(zr, zi, fnu, kode, mr, y, tol, elim, alim),
+        false => zunk1(zr, zi, fnu, kode, mr, y, tol, elim, alim),
+    }
+}
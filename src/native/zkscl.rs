@@ -0,0 +1,145 @@
+// amos/zkscl.f, sets the K functions of y which are below the underflow limit on scaling by
+// exp(-z) to zero, and recurs forward on the rest, returning nz
+use super::{azabs, azlog, zuchk};
+use num::complex::Complex;
+#[cfg(not(feature = "std"))]
+use num::Float;
+
+pub(super) fn zkscl(
+    zrr: f64,
+    zri: f64,
+    fnu: f64,
+    y: &mut [Complex<f64>],
+    rzr: f64,
+    rzi: f64,
+    ascle: f64,
+    tol: f64,
+    elim: f64,
+) -> i32 {
+    let n = y.len();
+    let mut nz = 0;
+    let mut ic = 0;
+    let nn = n.min(2);
+    let mut cy = [Complex::new(0.0, 0.0); 2];
+
+    for i in 1..=nn {
+        let s1r = y[i - 1].re;
+        let s1i = y[i - 1].im;
+        cy[i - 1] = Complex::new(s1r, s1i);
+        let as_ = azabs(s1r, s1i);
+        let acs = -zrr + as_.ln();
+        nz += 1;
+        y[i - 1] = Complex::new(0.0, 0.0);
+
+        if acs < -elim {
+            continue;
+        }
+
+        let (mut csr, mut csi) = azlog(s1r, s1i);
+        csr -= zrr;
+        csi -= zri;
+        let str = csr.exp() / tol;
+        csr = str * csi.cos();
+        csi = str * csi.sin();
+
+        if zuchk(csr, csi, ascle, tol) != 0 {
+            continue;
+        }
+
+        y[i - 1] = Complex::new(csr, csi);
+        ic = i;
+        nz -= 1;
+    }
+
+    if n == 1 {
+        return nz;
+    }
+
+    if ic <= 1 {
+        y[0] = Complex::new(0.0, 0.0);
+        nz = 2;
+    }
+
+    if n == 2 || nz == 0 {
+        return nz;
+    }
+
+    // Recur forward while the members underflow, and stop at the second member in a row which does
+    // not, see amos/zkscl.f lines 55-109
+    let fn_ = fnu + 1.0;
+    let mut ckr = fn_ * rzr;
+    let mut cki = fn_ * rzi;
+    let mut s1r = cy[0].re;
+    let mut s1i = cy[0].im;
+    let mut s2r = cy[1].re;
+    let mut s2i = cy[1].im;
+    let helim = 0.5 * elim;
+    let elm = (-elim).exp();
+    let celmr = elm;
+    let mut zdr = zrr;
+    let zdi = zri;
+
+    let kk = 'recur: {
+        for i in 3..=n {
+            let kk = i;
+            let mut csr = s2r;
+            let mut csi = s2i;
+            s2r = ckr * csr - cki * csi + s1r;
+            s2i = cki * csr + ckr * csi + s1i;
+            s1r = csr;
+            s1i = csi;
+            ckr += rzr;
+            cki += rzi;
+            let as_ = azabs(s2r, s2i);
+            let alas = as_.ln();
+            let acs = -zdr + alas;
+            y[i - 1] = Complex::new(0.0, 0.0);
+
+            if acs >= -elim {
+                (csr, csi) = azlog(s2r, s2i);
+                csr -= zdr;
+                csi -= zdi;
+                let str = csr.exp() / tol;
+                csr = str * csi.cos();
+                csi = str * csi.sin();
+
+                if zuchk(csr, csi, ascle, tol) == 0 {
+                    y[i - 1] = Complex::new(csr, csi);
+
+                    if ic == kk - 1 {
+                        break 'recur Some(kk);
+                    }
+
+                    ic = kk;
+                    continue;
+                }
+            }
+
+            if alas < helim {
+                continue;
+            }
+
+            zdr -= elim;
+            s1r *= celmr;
+            s1i *= celmr;
+            s2r *= celmr;
+            s2i *= celmr;
+        }
+
+        None
+    };
+
+    nz = match kk {
+        Some(kk) => kk as i32 - 2,
+        None => match ic == n {
+            true => n as i32 - 1,
+            false => n as i32,
+        },
+    };
+
+    for y in y[..nz as usize].iter_mut() {
+        *y = Complex::new(0.0, 0.0);
+    }
+
+    nz
+}
@@ -0,0 +1,216 @@
+// amos/zmlri.f, I by the Miller algorithm normalized by a Neumann series, returning nz = -2 if the
+// starting index for the backward recurrence could not be found
+use super::dgamln::dgamln;
+use super::{azabs, azexp, azlog, d1mach, float, int_sngl, zmlt};
+use num::complex::Complex;
+#[cfg(not(feature = "std"))]
+use num::Float;
+
+pub(super) fn zmlri(
+    zr: f64,
+    zi: f64,
+    fnu: f64,
+    kode: i32,
+    y: &mut [Complex<f64>],
+    tol: f64,
+) -> i32 {
+    let n = y.len();
+    let scle = d1mach(1) / tol;
+    let az = azabs(zr, zi);
+    let iaz = int_sngl(az);
+    let ifnu = int_sngl(fnu);
+    let inu = ifnu + n as i32 - 1;
+    let mut at = float(iaz) + 1.0;
+    let raz = 1.0 / az;
+    let mut str = zr * raz;
+    let mut sti = -zi * raz;
+    let mut ckr = str * at * raz;
+    let mut cki = sti * at * raz;
+    let rzr = (str + str) * raz;
+    let rzi = (sti + sti) * raz;
+    let mut p1r = 0.0;
+    let mut p1i = 0.0;
+    let mut p2r = 1.0;
+    let mut p2i = 0.0;
+    let mut ack = (at + 1.0) * raz;
+    let mut rho = ack + (ack * ack - 1.0).sqrt();
+    let rho2 = rho * rho;
+    let mut tst = (rho2 + rho2) / ((rho2 - 1.0) * (rho - 1.0));
+    tst /= tol;
+
+    // Compute the relative truncation error index for orders up to |z|, see amos/zmlri.f lines
+    // 46-60
+    let mut ak = at;
+    let mut i = 0;
+
+    for j in 1..=80 {
+        let ptr = p2r;
+        let pti = p2i;
+        p2r = p1r - (ckr * ptr - cki * pti);
+        p2i = p1i - (cki * ptr + ckr * pti);
+        p1r = ptr;
+        p1i = pti;
+        ckr += rzr;
+        cki += rzi;
+        let ap = azabs(p2r, p2i);
+
+        if ap > tst * ak * ak {
+            i = j;
+            break;
+        }
+
+        ak += 1.0;
+    }
+
+    if i == 0 {
+        return -2;
+    }
+
+    i += 1;
+    let mut k = 0;
+
+    // Compute the relative truncation error for the ratios, see amos/zmlri.f lines 63-98
+    if inu >= iaz {
+        p1r = 0.0;
+        p1i = 0.0;
+        p2r = 1.0;
+        p2i = 0.0;
+        at = float(inu) + 1.0;
+        str = zr * raz;
+        sti = -zi * raz;
+        ckr = str * at * raz;
+        cki = sti * at * raz;
+        ack = at * raz;
+        tst = (ack / tol).sqrt();
+        let mut itime = 1;
+
+        for j in 1..=80 {
+            let ptr = p2r;
+            let pti = p2i;
+            p2r = p1r - (ckr * ptr - cki * pti);
+            p2i = p1i - (ckr * pti + cki * ptr);
+            p1r = ptr;
+            p1i = pti;
+            ckr += rzr;
+            cki += rzi;
+            let ap = azabs(p2r, p2i);
+
+            if ap < tst {
+                continue;
+            }
+
+            if itime == 2 {
+                k = j;
+                break;
+            }
+
+            ack = azabs(ckr, cki);
+            let flam = ack + (ack * ack - 1.0).sqrt();
+            let fkap = ap / azabs(p1r, p1i);
+            rho = flam.min(fkap);
+            tst *= (rho / (rho * rho - 1.0)).sqrt();
+            itime = 2;
+        }
+
+        if k == 0 {
+            return -2;
+        }
+    }
+
+    // Backward recurrence and sum of the normalizing relation, see amos/zmlri.f lines 99-203
+    k += 1;
+    let kk = (i + iaz).max(k + inu);
+    let mut fkk = float(kk);
+    p1r = 0.0;
+    p1i = 0.0;
+    p2r = scle;
+    p2i = 0.0;
+    let fnf = fnu - float(ifnu);
+    let tfnf = fnf + fnf;
+    let mut bk = dgamln(fkk + tfnf + 1.0) - dgamln(fkk + 1.0) - dgamln(tfnf + 1.0);
+    bk = bk.exp();
+    let mut sumr = 0.0;
+    let mut sumi = 0.0;
+    let km = kk - inu;
+
+    for _ in 1..=km {
+        let ptr = p2r;
+        let pti = p2i;
+        p2r = p1r + (fkk + fnf) * (rzr * ptr - rzi * pti);
+        p2i = p1i + (fkk + fnf) * (rzi * ptr + rzr * pti);
+        p1r = ptr;
+        p1i = pti;
+        ak = 1.0 - tfnf / (fkk + tfnf);
+        ack = bk * ak;
+        sumr += (ack + bk) * p1r;
+        sumi += (ack + bk) * p1i;
+        bk = ack;
+        fkk -= 1.0;
+    }
+
+    y[n - 1] = Complex::new(p2r, p2i);
+
+    for i in 2..=n {
+        let ptr = p2r;
+        let pti = p2i;
+        p2r = p1r + (fkk + fnf) * (rzr * ptr - rzi * pti);
+        p2i = p1i + (fkk + fnf) * (rzi * ptr + rzr * pti);
+        p1r = ptr;
+        p1i = pti;
+        ak = 1.0 - tfnf / (fkk + tfnf);
+        ack = bk * ak;
+        sumr += (ack + bk) * p1r;
+        sumi += (ack + bk) * p1i;
+        bk = ack;
+        fkk -= 1.0;
+        let m = n - i + 1;
+        y[m - 1] = Complex::new(p2r, p2i);
+    }
+
+    for _ in 1..=ifnu {
+        let ptr = p2r;
+        let pti = p2i;
+        p2r = p1r + (fkk + fnf) * (rzr * ptr - rzi * pti);
+        p2i = p1i + (fkk + fnf) * (rzr * pti + rzi * ptr);
+        p1r = ptr;
+        p1i = pti;
+        ak = 1.0 - tfnf / (fkk + tfnf);
+        ack = bk * ak;
+        sumr += (ack + bk) * p1r;
+        sumi += (ack + bk) * p1i;
+        bk = ack;
+        fkk -= 1.0;
+    }
+
+    let mut ptr = zr;
+    let mut pti = zi;
+
+    if kode == 2 {
+        ptr = 0.0;
+    }
+
+    (str, sti) = azlog(rzr, rzi);
+    p1r = -fnf * str + ptr;
+    p1i = -fnf * sti + pti;
+    let ap = dgamln(1.0 + fnf);
+    ptr = p1r - ap;
+    pti = p1i;
+    p2r += sumr;
+    p2i += sumi;
+    let ap = azabs(p2r, p2i);
+    p1r = 1.0 / ap;
+    (str, sti) = azexp(ptr, pti);
+    ckr = str * p1r;
+    cki = sti * p1r;
+    ptr = p2r * p1r;
+    pti = -p2i * p1r;
+    let (cnormr, cnormi) = zmlt(ckr, cki, ptr, pti);
+
+    for y in y.iter_mut() {
+        let str = y.re * cnormr - y.im * cnormi;
+        let sti = y.re * cnormi + y.im * cnormr;
+        *y = Complex::new(str, sti);
+    }
+
+    0
+}
@@ -0,0 +1,138 @@
+// amos/zrati.f, the ratios I(fnu+k, z)/I(fnu+k-1, z), k = 1, ..., n, stored in cy by backward
+// recurrence
+use super::{azabs, float, int_sngl, zdiv};
+use num::complex::Complex;
+#[cfg(not(feature = "std"))]
+use num::Float;
+
+pub(super) fn zrati(zr: f64, zi: f64, fnu: f64, cy: &mut [Complex<f64>], tol: f64) {
+    const RT2: f64 = 1.41421356237309505;
+
+    let n = cy.len();
+    let az = azabs(zr, zi);
+    let inu = int_sngl(fnu);
+    let idnu = inu + n as i32 - 1;
+    let magz = int_sngl(az);
+    let amagz = float(magz + 1);
+    let fdnu = float(idnu);
+    let fnup = amagz.max(fdnu);
+    let mut id = idnu - magz - 1;
+    let mut itime = 1;
+    let mut k = 1;
+    let mut ptr = 1.0 / az;
+    let rzr = ptr * (zr + zr) * ptr;
+    let rzi = -ptr * (zi + zi) * ptr;
+    let mut t1r = rzr * fnup;
+    let mut t1i = rzi * fnup;
+    let mut p2r = -t1r;
+    let mut p2i = -t1i;
+    let mut p1r = 1.0;
+    let mut p1i = 0.0;
+    t1r += rzr;
+    t1i += rzi;
+
+    if id > 0 {
+        id = 0;
+    }
+
+    let mut ap2 = azabs(p2r, p2i);
+    let mut ap1 = azabs(p1r, p1i);
+
+    // The overflow test of the forward recurrence for the starting index of the backward
+    // recurrence, see amos/zrati.f lines 51-85
+    let arg = (ap2 + ap2) / (ap1 * tol);
+    let test1 = arg.sqrt();
+    let mut test = test1;
+    let rap1 = 1.0 / ap1;
+    p1r *= rap1;
+    p1i *= rap1;
+    p2r *= rap1;
+    p2i *= rap1;
+    ap2 *= rap1;
+
+    loop {
+        k += 1;
+        ap1 = ap2;
+        ptr = p2r;
+        let pti = p2i;
+        p2r = p1r - (t1r * ptr - t1i * pti);
+        p2i = p1i - (t1r * pti + t1i * ptr);
+        p1r = ptr;
+        p1i = pti;
+        t1r += rzr;
+        t1i += rzi;
+        ap2 = azabs(p2r, p2i);
+
+        if ap1 <= test {
+            continue;
+        }
+
+        if itime == 2 {
+            break;
+        }
+
+        let ak = azabs(t1r, t1i) * 0.5;
+        let flam = ak + (ak * ak - 1.0).sqrt();
+        let rho = (ap2 / ap1).min(flam);
+        test = test1 * (rho / (rho * rho - 1.0)).sqrt();
+        itime = 2;
+    }
+
+    let kk = k + 1 - id;
+    let ak = float(kk);
+    t1r = ak;
+    let t1i = 0.0;
+    let dfnu = fnu + float(n as i32 - 1);
+    p1r = 1.0 / ap2;
+    p1i = 0.0;
+    p2r = 0.0;
+    p2i = 0.0;
+
+    for _ in 1..=kk {
+        ptr = p1r;
+        let pti = p1i;
+        let rap1 = dfnu + t1r;
+        let ttr = rzr * rap1;
+        let tti = rzi * rap1;
+        p1r = (ptr * ttr - pti * tti) + p2r;
+        p1i = (ptr * tti + pti * ttr) + p2i;
+        p2r = ptr;
+        p2i = pti;
+        t1r -= 1.0;
+    }
+
+    if p1r == 0.0 && p1i == 0.0 {
+        p1r = tol;
+        p1i = tol;
+    }
+
+    let (cyr, cyi) = zdiv(p2r, p2i, p1r, p1i);
+    cy[n - 1] = Complex::new(cyr, cyi);
+
+    if n == 1 {
+        return;
+    }
+
+    let mut k = n - 1;
+    let ak = float(k as i32);
+    t1r = ak;
+    let cdfnur = fnu * rzr;
+    let cdfnui = fnu * rzi;
+
+    for _ in 2..=n {
+        let mut ptr = cdfnur + (t1r * rzr - t1i * rzi) + cy[k].re;
+        let mut pti = cdfnui + (t1r * rzi + t1i * rzr) + cy[k].im;
+        let mut ak = azabs(ptr, pti);
+
+        if ak == 0.0 {
+            ptr = tol;
+            pti = tol;
+            ak = tol * RT2;
+        }
+
+        let rak = 1.0 / ak;
+        cy[k - 1] = Complex::new(rak * ptr * rak, -rak * pti * rak);
+        t1r -= 1.0;
+        k -= 1;
+    }
+}
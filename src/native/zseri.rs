@@ -0,0 +1,226 @@
+// amos/zseri.f, the power series of I for small |z|, returning nz where nz < 0 signals that some
+// members were set to zero by underflow, while larger orders could still be computed by recurrence
+use super::dgamln::dgamln;
+use super::{azabs, azlog, d1mach, float, zdiv, zmlt, zuchk};
+use num::complex::Complex;
+#[cfg(not(feature = "std"))]
+use num::Float;
+
+pub(super) fn zseri(
+    zr: f64,
+    zi: f64,
+    fnu: f64,
+    kode: i32,
+    y: &mut [Complex<f64>],
+    tol: f64,
+    elim: f64,
+    alim: f64,
+) -> i32 {
+    let n = y.len();
+    let mut nz = 0;
+    let az = azabs(zr, zi);
+    let arm = 1.0e+3 * d1mach(1);
+    let rtr1 = arm.sqrt();
+    let mut crscr = 1.0;
+    let mut iflag = 0;
+
+    // Return I(0, 0) = 1 and I(fnu, 0) = 0 for fnu > 0, where |z| below arm counts as underflow,
+    // see amos/zseri.f lines 167-187
+    if az < arm {
+        if az != 0.0 {
+            nz = n as i32;
+
+            if fnu == 0.0 {
+                nz -= 1;
+            }
+        }
+
+        for y in y.iter_mut() {
+            *y = Complex::new(0.0, 0.0);
+        }
+
+        if fnu == 0.0 {
+            y[0] = Complex::new(1.0, 0.0);
+        }
+
+        return nz;
+    }
+
+    let hzr = 0.5 * zr;
+    let hzi = 0.5 * zi;
+    let mut czr = 0.0;
+    let mut czi = 0.0;
+
+    if az > rtr1 {
+        (czr, czi) = zmlt(hzr, hzi, hzr, hzi);
+    }
+
+    let acz = azabs(czr, czi);
+    let mut nn = n;
+    let (ckr, cki) = azlog(hzr, hzi);
+    let mut w = [Complex::new(0.0, 0.0); 2];
+    let mut ss = 0.0;
+    let mut ascle = 0.0;
+
+    // Drop the last member until the series does not underflow, see amos/zseri.f lines 44-56
+    loop {
+        let mut dfnu = fnu + float(nn as i32 - 1);
+        let mut fnup = dfnu + 1.0;
+        let mut ak1r = ckr * dfnu;
+        let ak1i = cki * dfnu;
+        let ak = dgamln(fnup);
+        ak1r -= ak;
+
+        if kode == 2 {
+            ak1r -= zr;
+        }
+
+        let underflow = 'series: {
+            if ak1r <= -elim {
+                break 'series true;
+            }
+
+            if ak1r <= -alim {
+                iflag = 1;
+                ss = 1.0 / tol;
+                crscr = tol;
+                ascle = arm * ss;
+            }
+
+            let mut aa = ak1r.exp();
+
+            if iflag == 1 {
+                aa *= ss;
+            }
+
+            let mut coefr = aa * ak1i.cos();
+            let mut coefi = aa * ak1i.sin();
+            let atol = tol * acz / fnup;
+            let il = nn.min(2);
+
+            for i in 1..=il {
+                dfnu = fnu + float((nn - i) as i32);
+                fnup = dfnu + 1.0;
+                let mut s1r = 1.0;
+                let mut s1i = 0.0;
+
+                if acz >= tol * fnup {
+                    let mut ak1r = 1.0;
+                    let mut ak1i = 0.0;
+                    let mut ak = fnup + 2.0;
+                    let mut s = fnup;
+                    let mut aa = 2.0;
+
+                    loop {
+                        let rs = 1.0 / s;
+                        let str = ak1r * czr - ak1i * czi;
+                        let sti = ak1r * czi + ak1i * czr;
+                        ak1r = str * rs;
+                        ak1i = sti * rs;
+                        s1r += ak1r;
+                        s1i += ak1i;
+                        s += ak;
+                        ak += 2.0;
+                        aa = aa * acz * rs;
+
+                        if aa <= atol {
+                            break;
+                        }
+                    }
+                }
+
+                let s2r = s1r * coefr - s1i * coefi;
+                let s2i = s1r * coefi + s1i * coefr;
+                w[i - 1] = Complex::new(s2r, s2i);
+
+                if iflag != 0 && zuchk(s2r, s2i, ascle, tol) != 0 {
+                    break 'series true;
+                }
+
+                let m = nn - i + 1;
+                y[m - 1] = Complex::new(s2r * crscr, s2i * crscr);
+
+                if i != il {
+                    let (str, sti) = zdiv(coefr, coefi, hzr, hzi);
+                    coefr = str * dfnu;
+                    coefi = sti * dfnu;
+                }
+            }
+
+            false
+        };
+
+        if !underflow {
+            break;
+        }
+
+        nz += 1;
+        y[nn - 1] = Complex::new(0.0, 0.0);
+
+        if acz > dfnu {
+            return -nz;
+        }
+
+        nn -= 1;
+
+        if nn == 0 {
+            return nz;
+        }
+    }
+
+    if nn <= 2 {
+        return nz;
+    }
+
+    let mut k = nn - 2;
+    let mut ak = float(k as i32);
+    let raz = 1.0 / az;
+    let str = zr * raz;
+    let sti = -zi * raz;
+    let rzr = (str + str) * raz;
+    let rzi = (sti + sti) * raz;
+    let mut ib = 3;
+
+    // Recur backward with scaling while the members are small, see amos/zseri.f lines 138-166
+    if iflag == 1 {
+        let mut s1r = w[0].re;
+        let mut s1i = w[0].im;
+        let mut s2r = w[1].re;
+        let mut s2i = w[1].im;
+        let mut scaled = true;
+
+        for l in 3..=nn {
+            let mut ckr = s2r;
+            let mut cki = s2i;
+            s2r = s1r + (ak + fnu) * (rzr * ckr - rzi * cki);
+            s2i = s1i + (ak + fnu) * (rzr * cki + rzi * ckr);
+            s1r = ckr;
+            s1i = cki;
+            ckr = s2r * crscr;
+            cki = s2i * crscr;
+            y[k - 1] = Complex::new(ckr, cki);
+            ak -= 1.0;
+            k -= 1;
+
+            if azabs(ckr, cki) > ascle {
+                ib = l + 1;
+                scaled = false;
+                break;
+            }
+        }
+
+        if scaled || ib > nn {
+            return nz;
+        }
+    }
+
+    for _ in ib..=nn {
+        let yr = (ak + fnu) * (rzr * y[k].re - rzi * y[k].im) + y[k + 1].re;
+        let yi = (ak + fnu) * (rzr * y[k].im + rzi * y[k].re) + y[k + 1].im;
+        y[k - 1] = Complex::new(yr, yi);
+        ak -= 1.0;
+        k -= 1;
+    }
+
+    nz
+}
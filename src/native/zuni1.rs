@@ -0,0 +1,235 @@
+// amos/zuni1.f, I by the uniform asymptotic expansion of zunik for -pi/3 <= arg(z) <= pi/3,
+// returning (nz, nlast) as zbuni
+use super::zunik::{zunik, Zunik};
+use super::zuoik::zuoik;
+use super::{azabs, d1mach, float, zuchk};
+use num::complex::Complex;
+#[cfg(not(feature = "std"))]
+use num::Float;
+
+pub(super) fn zuni1(
+    zr: f64,
+    zi: f64,
+    fnu: f64,
+    kode: i32,
+    y: &mut [Complex<f64>],
+    fnul: f64,
+    tol: f64,
+    elim: f64,
+    alim: f64,
+) -> (i32, i32) {
+    let n = y.len();
+    let mut nz = 0;
+    let mut nd = n;
+    let cscl = 1.0 / tol;
+    let crsc = tol;
+    let cssr = [cscl, 1.0, crsc];
+    let csrr = [crsc, 1.0, cscl];
+    let mut bry = [0.0; 3];
+    bry[0] = 1.0e+3 * d1mach(1) / tol;
+
+    // Check for underflow and overflow on the first member, see amos/zuni1.f lines 53-67
+    let mut fn_ = fnu.max(1.0);
+    let mut state = Zunik::new();
+    let (_, zeta1, zeta2, _) = zunik(zr, zi, fn_, 1, 1, tol, &mut state);
+    let mut rs1 = match kode == 1 {
+        true => -zeta1.re + zeta2.re,
+        false => {
+            let str = zr + zeta2.re;
+            let sti = zi + zeta2.im;
+            let rast = fn_ / azabs(str, sti);
+            -zeta1.re + str * rast * rast
+        }
+    };
+
+    if rs1.abs() > elim {
+        if rs1 > 0.0 {
+            return (-1, 0);
+        }
+
+        for y in y.iter_mut() {
+            *y = Complex::new(0.0, 0.0);
+        }
+
+        return (n as i32, 0);
+    }
+
+    let mut cy = [Complex::new(0.0, 0.0); 2];
+    let mut iflag = 2;
+
+    loop {
+        let nn = nd.min(2);
+
+        let underflow = 'members: {
+            for i in 1..=nn {
+                fn_ = fnu + float((nd - i) as i32);
+                let mut state = Zunik::new();
+                let (phi, zeta1, zeta2, sum) = zunik(zr, zi, fn_, 1, 0, tol, &mut state);
+                let mut s1r;
+                let mut s1i;
+
+                match kode == 1 {
+                    true => {
+                        s1r = -zeta1.re + zeta2.re;
+                        s1i = -zeta1.im + zeta2.im;
+                    }
+                    false => {
+                        let mut str = zr + zeta2.re;
+                        let mut sti = zi + zeta2.im;
+                        let rast = fn_ / azabs(str, sti);
+                        str = str * rast * rast;
+                        sti = -sti * rast * rast;
+                        s1r = -zeta1.re + str;
+                        s1i = -zeta1.im + sti + zi;
+                    }
+                }
+
+                // Test for underflow and overflow, see amos/zuni1.f lines 91-103
+                rs1 = s1r;
+
+                if rs1.abs() > elim {
+                    break 'members true;
+                }
+
+                if i == 1 {
+                    iflag = 2;
+                }
+
+                if rs1.abs() >= alim {
+                    let aphi = azabs(phi.re, phi.im);
+                    rs1 += aphi.ln();
+
+                    if rs1.abs() > elim {
+                        break 'members true;
+                    }
+
+                    if i == 1 {
+                        iflag = 1;
+                    }
+
+                    if rs1 >= 0.0 && i == 1 {
+                        iflag = 3;
+                    }
+                }
+
+                // Scale s1 if |s1| < ascle
+                let mut s2r = phi.re * sum.re - phi.im * sum.im;
+                let mut s2i = phi.re * sum.im + phi.im * sum.re;
+                let str = s1r.exp() * cssr[iflag - 1];
+                s1r = str * s1i.cos();
+                s1i = str * s1i.sin();
+                let str = s2r * s1r - s2i * s1i;
+                s2i = s2r * s1i + s2i * s1r;
+                s2r = str;
+
+                if iflag == 1 && zuchk(s2r, s2i, bry[0], tol) != 0 {
+                    break 'members true;
+                }
+
+                cy[i - 1] = Complex::new(s2r, s2i);
+                let m = nd - i + 1;
+                y[m - 1] = Complex::new(s2r * csrr[iflag - 1], s2i * csrr[iflag - 1]);
+            }
+
+            false
+        };
+
+        if !underflow {
+            break;
+        }
+
+        // Set the member to zero on underflow and retry with the remaining ones, see
+        // amos/zuni1.f lines 177-194
+        if rs1 > 0.0 {
+            return (-1, 0);
+        }
+
+        y[nd - 1] = Complex::new(0.0, 0.0);
+        nz += 1;
+        nd -= 1;
+
+        if nd == 0 {
+            return (nz, 0);
+        }
+
+        let nuf = zuoik(zr, zi, fnu, kode, 1, &mut y[..nd], tol, elim, alim);
+
+        if nuf < 0 {
+            return (-1, 0);
+        }
+
+        nd -= nuf as usize;
+        nz += nuf;
+
+        if nd == 0 {
+            return (nz, 0);
+        }
+
+        fn_ = fnu + float(nd as i32 - 1);
+
+        if fn_ < fnul {
+            return (nz, nd as i32);
+        }
+    }
+
+    if nd <= 2 {
+        return (nz, 0);
+    }
+
+    // Recur backward with rescaling, see amos/zuni1.f lines 126-171
+    let rast = 1.0 / azabs(zr, zi);
+    let str = zr * rast;
+    let sti = -zi * rast;
+    let rzr = (str + str) * rast;
+    let rzi = (sti + sti) * rast;
+    bry[1] = 1.0 / bry[0];
+    bry[2] = d1mach(2);
+    let mut s1r = cy[0].re;
+    let mut s1i = cy[0].im;
+    let mut s2r = cy[1].re;
+    let mut s2i = cy[1].im;
+    let mut c1r = csrr[iflag - 1];
+    let mut ascle = bry[iflag - 1];
+    let mut k = nd - 2;
+    fn_ = float(k as i32);
+
+    for _ in 3..=nd {
+        let mut c2r = s2r;
+        let mut c2i = s2i;
+        s2r = s1r + (fnu + fn_) * (rzr * c2r - rzi * c2i);
+        s2i = s1i + (fnu + fn_) * (rzr * c2i + rzi * c2r);
+        s1r = c2r;
+        s1i = c2i;
+        c2r = s2r * c1r;
+        c2i = s2i * c1r;
+        y[k - 1] = Complex::new(c2r, c2i);
+        k -= 1;
+        fn_ -= 1.0;
+
+        if iflag >= 3 {
+            continue;
+        }
+
+        let str = c2r.abs();
+        let sti = c2i.abs();
+        let c2m = str.max(sti);
+
+        if c2m <= ascle {
+            continue;
+        }
+
+        iflag += 1;
+        ascle = bry[iflag - 1];
+        s1r *= c1r;
+        s1i *= c1r;
+        s2r = c2r;
+        s2i = c2i;
+        s1r *= cssr[iflag - 1];
+        s1i *= cssr[iflag - 1];
+        s2r *= cssr[iflag - 1];
+        s2i *= cssr[iflag - 1];
+        c1r = csrr[iflag - 1];
+    }
+
+    (nz, 0)
+}
@@ -0,0 +1,293 @@
+// amos/zuni2.f, I by the uniform asymptotic expansion of zunhj in the Airy functions for
+// pi/3 < |arg(z)| <= pi/2, returning (nz, nlast) as zbuni
+use super::zairy::zairy;
+use super::zunhj::zunhj;
+use super::zuoik::zuoik;
+use super::{azabs, d1mach, float, int_sngl, zuchk};
+use num::complex::Complex;
+#[cfg(not(feature = "std"))]
+use num::Float;
+
+// i^k, k = 0, ..., 3
+const CIPR: [f64; 4] = [1.0, 0.0, -1.0, 0.0];
+const CIPI: [f64; 4] = [0.0, 1.0, 0.0, -1.0];
+
+pub(super) fn zuni2(
+    zr: f64,
+    zi: f64,
+    fnu: f64,
+    kode: i32,
+    y: &mut [Complex<f64>],
+    fnul: f64,
+    tol: f64,
+    elim: f64,
+    alim: f64,
+) -> (i32, i32) {
+    const HPI: f64 = 1.57079632679489662e+00;
+    const AIC: f64 = 1.265512123484645396e+00;
+
+    let n = y.len();
+    let mut nz = 0;
+    let mut nd = n;
+    let cscl = 1.0 / tol;
+    let crsc = tol;
+    let cssr = [cscl, 1.0, crsc];
+    let csrr = [crsc, 1.0, cscl];
+    let mut bry = [0.0; 3];
+    bry[0] = 1.0e+3 * d1mach(1) / tol;
+
+    // zn is in the right half plane after rotating by -i or i, and c2 = exp(+-i pi fnu/2) such that
+    // I(fnu, z) = c2 J(fnu, zn), see amos/zuni2.f lines 72-93
+    let mut znr = zi;
+    let zni = -zr;
+    let zbr = zr;
+    let mut zbi = zi;
+    let mut cidi = -1.0;
+    let inu = int_sngl(fnu);
+    let ang = HPI * (fnu - float(inu));
+    let mut c2r = ang.cos();
+    let mut c2i = ang.sin();
+    let car = c2r;
+    let sar = c2i;
+    let mut in_ = inu + n as i32 - 1;
+    in_ = in_ % 4 + 1;
+    let str = c2r * CIPR[in_ as usize - 1] - c2i * CIPI[in_ as usize - 1];
+    c2i = c2r * CIPI[in_ as usize - 1] + c2i * CIPR[in_ as usize - 1];
+    c2r = str;
+
+    if zi <= 0.0 {
+        znr = -znr;
+        zbi = -zbi;
+        cidi = -cidi;
+        c2i = -c2i;
+    }
+
+    // Check for underflow and overflow on the first member
+    let mut fn_ = fnu.max(1.0);
+    let (_, _, zeta1, zeta2, _, _) = zunhj(znr, zni, fn_, 1, tol);
+    let mut rs1 = match kode == 1 {
+        true => -zeta1.re + zeta2.re,
+        false => {
+            let str = zbr + zeta2.re;
+            let sti = zbi + zeta2.im;
+            let rast = fn_ / azabs(str, sti);
+            -zeta1.re + str * rast * rast
+        }
+    };
+
+    if rs1.abs() > elim {
+        if rs1 > 0.0 {
+            return (-1, 0);
+        }
+
+        for y in y.iter_mut() {
+            *y = Complex::new(0.0, 0.0);
+        }
+
+        return (n as i32, 0);
+    }
+
+    let mut cy = [Complex::new(0.0, 0.0); 2];
+    let mut iflag = 2;
+
+    loop {
+        let nn = nd.min(2);
+
+        let underflow = 'members: {
+            for i in 1..=nn {
+                fn_ = fnu + float((nd - i) as i32);
+                let (phi, arg, zeta1, zeta2, asum, bsum) = zunhj(znr, zni, fn_, 0, tol);
+                let mut s1r;
+                let mut s1i;
+
+                match kode == 1 {
+                    true => {
+                        s1r = -zeta1.re + zeta2.re;
+                        s1i = -zeta1.im + zeta2.im;
+                    }
+                    false => {
+                        let mut str = zbr + zeta2.re;
+                        let mut sti = zbi + zeta2.im;
+                        let rast = fn_ / azabs(str, sti);
+                        str = str * rast * rast;
+                        sti = -sti * rast * rast;
+                        s1r = -zeta1.re + str;
+                        s1i = -zeta1.im + sti + zi.abs();
+                    }
+                }
+
+                // Test for underflow and overflow, see amos/zuni2.f lines 129-142
+                rs1 = s1r;
+
+                if rs1.abs() > elim {
+                    break 'members true;
+                }
+
+                if i == 1 {
+                    iflag = 2;
+                }
+
+                if rs1.abs() >= alim {
+                    let aphi = azabs(phi.re, phi.im);
+                    let aarg = azabs(arg.re, arg.im);
+                    rs1 = rs1 + aphi.ln() - 0.25 * aarg.ln() - AIC;
+
+                    if rs1.abs() > elim {
+                        break 'members true;
+                    }
+
+                    if i == 1 {
+                        iflag = 1;
+                    }
+
+                    if rs1 >= 0.0 && i == 1 {
+                        iflag = 3;
+                    }
+                }
+
+                // Scale s1 to keep the intermediate arithmetic on scale near exponent extremes
+                let (ai, _, _) = zairy(arg.re, arg.im, 0, 2);
+                let (dai, _, _) = zairy(arg.re, arg.im, 1, 2);
+                let mut str = dai.re * bsum.re - dai.im * bsum.im;
+                let mut sti = dai.re * bsum.im + dai.im * bsum.re;
+                str += ai.re * asum.re - ai.im * asum.im;
+                sti += ai.re * asum.im + ai.im * asum.re;
+                let mut s2r = phi.re * str - phi.im * sti;
+                let mut s2i = phi.re * sti + phi.im * str;
+                str = s1r.exp() * cssr[iflag - 1];
+                s1r = str * s1i.cos();
+                s1i = str * s1i.sin();
+                str = s2r * s1r - s2i * s1i;
+                s2i = s2r * s1i + s2i * s1r;
+                s2r = str;
+
+                if iflag == 1 && zuchk(s2r, s2i, bry[0], tol) != 0 {
+                    break 'members true;
+                }
+
+                if zi <= 0.0 {
+                    s2i = -s2i;
+                }
+
+                str = s2r * c2r - s2i * c2i;
+                s2i = s2r * c2i + s2i * c2r;
+                s2r = str;
+                cy[i - 1] = Complex::new(s2r, s2i);
+                let j = nd - i + 1;
+                y[j - 1] = Complex::new(s2r * csrr[iflag - 1], s2i * csrr[iflag - 1]);
+                str = -c2i * cidi;
+                c2i = c2r * cidi;
+                c2r = str;
+            }
+
+            false
+        };
+
+        if !underflow {
+            break;
+        }
+
+        // Set the member to zero on underflow and retry with the remaining ones, see
+        // amos/zuni2.f lines 223-248
+        if rs1 > 0.0 {
+            return (-1, 0);
+        }
+
+        y[nd - 1] = Complex::new(0.0, 0.0);
+        nz += 1;
+        nd -= 1;
+
+        if nd == 0 {
+            return (nz, 0);
+        }
+
+        let nuf = zuoik(zr, zi, fnu, kode, 1, &mut y[..nd], tol, elim, alim);
+
+        if nuf < 0 {
+            return (-1, 0);
+        }
+
+        nd -= nuf as usize;
+        nz += nuf;
+
+        if nd == 0 {
+            return (nz, 0);
+        }
+
+        fn_ = fnu + float(nd as i32 - 1);
+
+        if fn_ < fnul {
+            return (nz, nd as i32);
+        }
+
+        in_ = inu + nd as i32 - 1;
+        in_ = in_ % 4 + 1;
+        c2r = car * CIPR[in_ as usize - 1] - sar * CIPI[in_ as usize - 1];
+        c2i = car * CIPI[in_ as usize - 1] + sar * CIPR[in_ as usize - 1];
+
+        if zi <= 0.0 {
+            c2i = -c2i;
+        }
+    }
+
+    if nd <= 2 {
+        return (nz, 0);
+    }
+
+    // Recur backward with rescaling, see amos/zuni2.f lines 175-220
+    let raz = 1.0 / azabs(zr, zi);
+    let str = zr * raz;
+    let sti = -zi * raz;
+    let rzr = (str + str) * raz;
+    let rzi = (sti + sti) * raz;
+    bry[1] = 1.0 / bry[0];
+    bry[2] = d1mach(2);
+    let mut s1r = cy[0].re;
+    let mut s1i = cy[0].im;
+    let mut s2r = cy[1].re;
+    let mut s2i = cy[1].im;
+    let mut c1r = csrr[iflag - 1];
+    let mut ascle = bry[iflag - 1];
+    let mut k = nd - 2;
+    fn_ = float(k as i32);
+
+    for _ in 3..=nd {
+        let mut c2r = s2r;
+        let mut c2i = s2i;
+        s2r = s1r + (fnu + fn_) * (rzr * c2r - rzi * c2i);
+        s2i = s1i + (fnu + fn_) * (rzr * c2i + rzi * c2r);
+        s1r = c2r;
+        s1i = c2i;
+        c2r = s2r * c1r;
+        c2i = s2i * c1r;
+        y[k - 1] = Complex::new(c2r, c2i);
+        k -= 1;
+        fn_ -= 1.0;
+
+        if iflag >= 3 {
+            continue;
+        }
+
+        let str = c2r.abs();
+        let sti = c2i.abs();
+        let c2m = str.max(sti);
+
+        if c2m <= ascle {
+            continue;
+        }
+
+        iflag += 1;
+        ascle = bry[iflag - 1];
+        s1r *= c1r;
+        s1i *= c1r;
+        s2r = c2r;
+        s2i = c2i;
+        s1r *= cssr[iflag - 1];
+        s1i *= cssr[iflag - 1];
+        s2r *= cssr[iflag - 1];
+        s2i *= cssr[iflag - 1];
+        c1r = csrr[iflag - 1];
+    }
+
+    (nz, 0)
+}
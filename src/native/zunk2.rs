@@ -0,0 +1,549 @@
+// amos/zunk2.f, K by the uniform asymptotic expansion of zunhj in the Airy functions for
+// pi/3 < |arg(z)| <= pi/2 with Re(z) >= 0, and its analytic continuation to Re(z) < 0 for mr != 0,
+// returning nz, or -1 on overflow
+use super::zairy::zairy;
+use super::zunhj::{zunhj, Expansion};
+use super::{azabs, d1mach, float, int_sngl, zs1s2, zuchk};
+use num::complex::Complex;
+#[cfg(not(feature = "std"))]
+use num::Float;
+
+// i^(-k), k = 0, ..., 3
+const CIPR: [f64; 4] = [1.0, 0.0, -1.0, 0.0];
+const CIPI: [f64; 4] = [0.0, -1.0, 0.0, 1.0];
+
+// The (phi, arg, zeta1, zeta2, asum, bsum) of zunhj
+pub(super) fn zunk2(
+    zr: f64,
+    zi: f64,
+    fnu: f64,
+    kode: i32,
+    mr: i32,
+    y: &mut [Complex<f64>],
+    tol: f64,
+    elim: f64,
+    alim: f64,
+) -> i32 {
+    const CR1R: f64 = 1.0;
+    const CR1I: f64 = 1.73205080756887729;
+    const CR2R: f64 = -0.5;
+    const CR2I: f64 = -8.66025403784438647e-01;
+    const HPI: f64 = 1.57079632679489662e+00;
+    const PI: f64 = 3.14159265358979324e+00;
+    const AIC: f64 = 1.26551212348464539e+00;
+
+    let n = y.len();
+    let mut kdflg = 1;
+    let mut nz = 0;
+    let cscl = 1.0 / tol;
+    let crsc = tol;
+    let cssr = [cscl, 1.0, crsc];
+    let csrr = [crsc, 1.0, cscl];
+    let mut bry = [0.0; 3];
+    bry[0] = 1.0e+3 * d1mach(1) / tol;
+    bry[1] = 1.0 / bry[0];
+    bry[2] = d1mach(2);
+    let (zrr, zri) = match zr >= 0.0 {
+        true => (zr, zi),
+        false => (-zr, -zi),
+    };
+
+    // K(fnu, z) = cs H(2, fnu, zn) with zn = -i z in the upper half plane or zn = i z in the lower
+    // half plane, where cs = -pi/2 exp(-i pi fnu/2) i for H in terms of the Airy functions, see
+    // amos/zunk2.f lines 69-89
+    let yy = zri;
+    let mut znr = zri;
+    let zni = -zrr;
+    let zbr = zrr;
+    let mut zbi = zri;
+    let inu = int_sngl(fnu);
+    let fnf = fnu - float(inu);
+    let ang = -HPI * fnf;
+    let car = ang.cos();
+    let sar = ang.sin();
+    let c2r = HPI * sar;
+    let c2i = -HPI * car;
+    let kk = (inu % 4 + 1) as usize;
+    let str = c2r * CIPR[kk - 1] - c2i * CIPI[kk - 1];
+    let sti = c2r * CIPI[kk - 1] + c2i * CIPR[kk - 1];
+    let mut csr = CR1R * str - CR1I * sti;
+    let mut csi = CR1R * sti + CR1I * str;
+
+    if yy <= 0.0 {
+        znr = -znr;
+        zbi = -zbi;
+    }
+
+    // The expansions of the first two members on scale are kept in expansions for the analytic
+    // continuation, see amos/zunk2.f lines 95-194
+    let zero = Complex::new(0.0, 0.0);
+    let mut expansions: [Expansion; 2] = [(zero, zero, zero, zero, zero, zero); 2];
+    let mut cy = [Complex::new(0.0, 0.0); 2];
+    let mut kflag = 2;
+    let mut j = 2;
+    let mut fn_ = fnu;
+
+    let last = 'members: {
+        for i in 1..=n {
+            j = 3 - j;
+            fn_ = fnu + float(i as i32 - 1);
+            expansions[j - 1] = zunhj(znr, zni, fn_, 0, tol);
+            let (phi, arg, zeta1, zeta2, asum, bsum) = expansions[j - 1];
+            let (mut s1r, mut s1i) = match kode == 1 {
+                true => (zeta1.re - zeta2.re, zeta1.im - zeta2.im),
+                false => {
+                    let mut str = zbr + zeta2.re;
+                    let mut sti = zbi + zeta2.im;
+                    let rast = fn_ / azabs(str, sti);
+                    str = str * rast * rast;
+                    sti = -sti * rast * rast;
+                    (zeta1.re - str, zeta1.im - sti)
+                }
+            };
+
+            // Test for underflow and overflow
+            let mut rs1 = s1r;
+
+            let underflow = 'test: {
+                if rs1.abs() > elim {
+                    break 'test true;
+                }
+
+                if kdflg == 1 {
+                    kflag = 2;
+                }
+
+                if rs1.abs() >= alim {
+                    let aphi = azabs(phi.re, phi.im);
+                    let aarg = azabs(arg.re, arg.im);
+                    rs1 = rs1 + aphi.ln() - 0.25 * aarg.ln() - AIC;
+
+                    if rs1.abs() > elim {
+                        break 'test true;
+                    }
+
+                    if kdflg == 1 {
+                        kflag = 1;
+                    }
+
+                    if rs1 >= 0.0 && kdflg == 1 {
+                        kflag = 3;
+                    }
+                }
+
+                // Scale s1 to keep the intermediate arithmetic on scale near exponent extremes
+                let c2r = arg.re * CR2R - arg.im * CR2I;
+                let c2i = arg.re * CR2I + arg.im * CR2R;
+                let (ai, _, _) = zairy(c2r, c2i, 0, 2);
+                let (dai, _, _) = zairy(c2r, c2i, 1, 2);
+                let mut str = dai.re * bsum.re - dai.im * bsum.im;
+                let mut sti = dai.re * bsum.im + dai.im * bsum.re;
+                let mut ptr = str * CR2R - sti * CR2I;
+                let mut pti = str * CR2I + sti * CR2R;
+                str = ptr + (ai.re * asum.re - ai.im * asum.im);
+                sti = pti + (ai.re * asum.im + ai.im * asum.re);
+                ptr = str * phi.re - sti * phi.im;
+                pti = str * phi.im + sti * phi.re;
+                let mut s2r = ptr * csr - pti * csi;
+                let mut s2i = ptr * csi + pti * csr;
+                str = s1r.exp() * cssr[kflag - 1];
+                s1r = str * s1i.cos();
+                s1i = str * s1i.sin();
+                str = s2r * s1r - s2i * s1i;
+                s2i = s1r * s2i + s2r * s1i;
+                s2r = str;
+
+                if kflag == 1 && zuchk(s2r, s2i, bry[0], tol) != 0 {
+                    break 'test true;
+                }
+
+                if yy <= 0.0 {
+                    s2i = -s2i;
+                }
+
+                cy[kdflg - 1] = Complex::new(s2r, s2i);
+                y[i - 1] = Complex::new(s2r * csrr[kflag - 1], s2i * csrr[kflag - 1]);
+
+                false
+            };
+
+            let str = csi;
+            csi = -csr;
+            csr = str;
+
+            if !underflow {
+                if kdflg == 2 {
+                    break 'members i;
+                }
+
+                kdflg = 2;
+                continue;
+            }
+
+            if rs1 > 0.0 || zr < 0.0 {
+                return -1;
+            }
+
+            kdflg = 1;
+            y[i - 1] = Complex::new(0.0, 0.0);
+            nz += 1;
+
+            if i == 1 || (y[i - 2].re == 0.0 && y[i - 2].im == 0.0) {
+                continue;
+            }
+
+            y[i - 2] = Complex::new(0.0, 0.0);
+            nz += 1;
+        }
+
+        n
+    };
+
+    let razr = 1.0 / azabs(zrr, zri);
+    let str = zrr * razr;
+    let sti = -zri * razr;
+    let rzr = (str + str) * razr;
+    let rzi = (sti + sti) * razr;
+    let mut ckr = fn_ * rzr;
+    let mut cki = fn_ * rzi;
+    let mut ib = last + 1;
+    let mut expansiond: Expansion = (zero, zero, zero, zero, zero, zero);
+
+    if n >= ib {
+        // Test the last member for underflow and overflow, and recur forward, see amos/zunk2.f
+        // lines 209-285
+        fn_ = fnu + float(n as i32 - 1);
+        let ipard = match mr != 0 {
+            true => 0,
+            false => 1,
+        };
+        expansiond = zunhj(znr, zni, fn_, ipard, tol);
+        let (phid, _, zet1d, zet2d, _, _) = expansiond;
+        let s1r = match kode == 1 {
+            true => zet1d.re - zet2d.re,
+            false => {
+                let str = zbr + zet2d.re;
+                let sti = zbi + zet2d.im;
+                let rast = fn_ / azabs(str, sti);
+                zet1d.re - str * rast * rast
+            }
+        };
+
+        let mut rs1 = s1r;
+        let mut on_scale = rs1.abs() <= elim;
+
+        if on_scale && rs1.abs() >= alim {
+            let aphi = azabs(phid.re, phid.im);
+            rs1 += aphi.ln();
+            on_scale = rs1.abs() < elim;
+        }
+
+        if !on_scale {
+            if rs1 > 0.0 || zr < 0.0 {
+                return -1;
+            }
+
+            for y in y.iter_mut() {
+                *y = Complex::new(0.0, 0.0);
+            }
+
+            return n as i32;
+        }
+
+        let mut s1r = cy[0].re;
+        let mut s1i = cy[0].im;
+        let mut s2r = cy[1].re;
+        let mut s2i = cy[1].im;
+        let mut c1r = csrr[kflag - 1];
+        let mut ascle = bry[kflag - 1];
+
+        for y in y[ib - 1..].iter_mut() {
+            let mut c2r = s2r;
+            let mut c2i = s2i;
+            s2r = ckr * c2r - cki * c2i + s1r;
+            s2i = ckr * c2i + cki * c2r + s1i;
+            s1r = c2r;
+            s1i = c2i;
+            ckr += rzr;
+            cki += rzi;
+            c2r = s2r * c1r;
+            c2i = s2i * c1r;
+            *y = Complex::new(c2r, c2i);
+
+            if kflag >= 3 {
+                continue;
+            }
+
+            let str = c2r.abs();
+            let sti = c2i.abs();
+            let c2m = str.max(sti);
+
+            if c2m <= ascle {
+                continue;
+            }
+
+            kflag += 1;
+            ascle = bry[kflag - 1];
+            s1r *= c1r;
+            s1i *= c1r;
+            s2r = c2r;
+            s2i = c2i;
+            s1r *= cssr[kflag - 1];
+            s1i *= cssr[kflag - 1];
+            s2r *= cssr[kflag - 1];
+            s2i *= cssr[kflag - 1];
+            c1r = csrr[kflag - 1];
+        }
+    }
+
+    if mr == 0 {
+        return nz;
+    }
+
+    // The analytic continuation to the left half plane, K(fnu, zn e^(i mp)) = K(fnu, zn)
+    // e^(-mp fnu) - mp I(fnu, zn) with mp = pi mr i, on the members from the highest order down,
+    // see amos/zunk2.f lines 286-445
+    nz = 0;
+    let fmr = float(mr);
+    let sgn = -PI.copysign(fmr);
+    let mut csgni = sgn;
+
+    if yy <= 0.0 {
+        csgni = -csgni;
+    }
+
+    let ifn = inu + n as i32 - 1;
+    let ang = fnf * sgn;
+    let mut cspnr = ang.cos();
+    let mut cspni = ang.sin();
+
+    if ifn % 2 != 0 {
+        cspnr = -cspnr;
+        cspni = -cspni;
+    }
+
+    // The factor cs of I in terms of the Airy functions, see amos/zunk2.f lines 305-319
+    csr = sar * csgni;
+    csi = car * csgni;
+    let in_ = (ifn % 4 + 1) as usize;
+    let c2r = CIPR[in_ - 1];
+    let c2i = CIPI[in_ - 1];
+    let str = csr * c2r + csi * c2i;
+    csi = -csr * c2i + csi * c2r;
+    csr = str;
+    let asc = bry[0];
+    let mut iuf = 0;
+    let mut kk = n;
+    kdflg = 1;
+    ib -= 1;
+    let ic = ib - 1;
+    let mut iflag = 2;
+
+    let k = 'members: {
+        for k in 1..=n {
+            fn_ = fnu + float(kk as i32 - 1);
+
+            // Reuse the expansions of the first loop for the orders where they were computed,
+            // which for n <= 2 are all of them
+            match n > 2 && kk != ib && kk != ic {
+                true => {
+                    if !(kk == n && ib < n) {
+                        expansiond = zunhj(znr, zni, fn_, 0, tol);
+                    }
+                }
+                false => {
+                    expansiond = expansions[j - 1];
+                    j = 3 - j;
+                }
+            }
+
+            let (phid, argd, zet1d, zet2d, asumd, bsumd) = expansiond;
+            let (mut s1r, mut s1i) = match kode == 1 {
+                true => (-zet1d.re + zet2d.re, -zet1d.im + zet2d.im),
+                false => {
+                    let mut str = zbr + zet2d.re;
+                    let mut sti = zbi + zet2d.im;
+                    let rast = fn_ / azabs(str, sti);
+                    str = str * rast * rast;
+                    sti = -sti * rast * rast;
+                    (-zet1d.re + str, -zet1d.im + sti)
+                }
+            };
+
+            // Test for underflow and overflow
+            let mut rs1 = s1r;
+
+            let s2 = 'test: {
+                if rs1.abs() > elim {
+                    break 'test None;
+                }
+
+                if kdflg == 1 {
+                    iflag = 2;
+                }
+
+                if rs1.abs() >= alim {
+                    let aphi = azabs(phid.re, phid.im);
+                    let aarg = azabs(argd.re, argd.im);
+                    rs1 = rs1 + aphi.ln() - 0.25 * aarg.ln() - AIC;
+
+                    if rs1.abs() > elim {
+                        break 'test None;
+                    }
+
+                    if kdflg == 1 {
+                        iflag = 1;
+                    }
+
+                    if rs1 >= 0.0 && kdflg == 1 {
+                        iflag = 3;
+                    }
+                }
+
+                let (ai, _, _) = zairy(argd.re, argd.im, 0, 2);
+                let (dai, _, _) = zairy(argd.re, argd.im, 1, 2);
+                let mut str = dai.re * bsumd.re - dai.im * bsumd.im;
+                let mut sti = dai.re * bsumd.im + dai.im * bsumd.re;
+                str += ai.re * asumd.re - ai.im * asumd.im;
+                sti += ai.re * asumd.im + ai.im * asumd.re;
+                let ptr = str * phid.re - sti * phid.im;
+                let pti = str * phid.im + sti * phid.re;
+                let mut s2r = ptr * csr - pti * csi;
+                let mut s2i = ptr * csi + pti * csr;
+                str = s1r.exp() * cssr[iflag - 1];
+                s1r = str * s1i.cos();
+                s1i = str * s1i.sin();
+                str = s2r * s1r - s2i * s1i;
+                s2i = s2r * s1i + s2i * s1r;
+                s2r = str;
+
+                if iflag == 1 && zuchk(s2r, s2i, bry[0], tol) != 0 {
+                    s2r = 0.0;
+                    s2i = 0.0;
+                }
+
+                Some((s2r, s2i))
+            };
+
+            let (s2r, mut s2i) = match s2 {
+                Some(s2) => s2,
+                None => {
+                    if rs1 > 0.0 {
+                        return -1;
+                    }
+
+                    (0.0, 0.0)
+                }
+            };
+
+            if yy <= 0.0 {
+                s2i = -s2i;
+            }
+
+            cy[kdflg - 1] = Complex::new(s2r, s2i);
+            let c2r = s2r;
+            let c2i = s2i;
+            let mut s2 = Complex::new(s2r * csrr[iflag - 1], s2i * csrr[iflag - 1]);
+            let mut s1 = y[kk - 1];
+
+            if kode != 1 {
+                let nw = zs1s2(zrr, zri, &mut s1, &mut s2, asc, alim, &mut iuf);
+                nz += nw;
+            }
+
+            y[kk - 1] = Complex::new(
+                s1.re * cspnr - s1.im * cspni + s2.re,
+                s1.re * cspni + s1.im * cspnr + s2.im,
+            );
+            kk -= 1;
+            cspnr = -cspnr;
+            cspni = -cspni;
+            let str = csi;
+            csi = -csr;
+            csr = str;
+
+            if c2r == 0.0 && c2i == 0.0 {
+                kdflg = 1;
+                continue;
+            }
+
+            if kdflg == 2 {
+                break 'members k;
+            }
+
+            kdflg = 2;
+        }
+
+        n
+    };
+
+    let il = n - k;
+
+    if il == 0 {
+        return nz;
+    }
+
+    // Recur backward for the remaining members, see amos/zunk2.f lines 446-500
+    let mut s1r = cy[0].re;
+    let mut s1i = cy[0].im;
+    let mut s2r = cy[1].re;
+    let mut s2i = cy[1].im;
+    let mut csr = csrr[iflag - 1];
+    let mut ascle = bry[iflag - 1];
+    let mut fn_ = float(inu + il as i32);
+
+    for _ in 1..=il {
+        let mut c2r = s2r;
+        let mut c2i = s2i;
+        s2r = s1r + (fn_ + fnf) * (rzr * c2r - rzi * c2i);
+        s2i = s1i + (fn_ + fnf) * (rzr * c2i + rzi * c2r);
+        s1r = c2r;
+        s1i = c2i;
+        fn_ -= 1.0;
+        c2r = s2r * csr;
+        c2i = s2i * csr;
+        let ckr = c2r;
+        let cki = c2i;
+        let mut c1 = y[kk - 1];
+        let mut c2 = Complex::new(c2r, c2i);
+
+        if kode != 1 {
+            let nw = zs1s2(zrr, zri, &mut c1, &mut c2, asc, alim, &mut iuf);
+            nz += nw;
+        }
+
+        y[kk - 1] = Complex::new(
+            c1.re * cspnr - c1.im * cspni + c2.re,
+            c1.re * cspni + c1.im * cspnr + c2.im,
+        );
+        kk -= 1;
+        cspnr = -cspnr;
+        cspni = -cspni;
+
+        if iflag >= 3 {
+            continue;
+        }
+
+        let c2r = ckr.abs();
+        let c2i = cki.abs();
+        let c2m = c2r.max(c2i);
+
+        if c2m <= ascle {
+            continue;
+        }
+
+        iflag += 1;
+        ascle = bry[iflag - 1];
+        s1r *= csr;
+        s1i *= csr;
+        s2r = ckr;
+        s2i = cki;
+        s1r *= cssr[iflag - 1];
+        s1i *= cssr[iflag - 1];
+        s2r *= cssr[iflag - 1];
+        s2i *= cssr[iflag - 1];
+        csr = csrr[iflag - 1];
+    }
+
+    nz
+}
@@ -0,0 +1,192 @@
+// amos/zuoik.f, the overflow and underflow test on I (ikflg = 1) and K (ikflg = 2) by the leading
+// terms of the uniform asymptotic expansions, returning nuf, the number of members of y set to zero
+// on underflow, or -1 on overflow
+use super::zunhj::zunhj;
+use super::zunik::{zunik, Zunik};
+use super::{azabs, azlog, d1mach, float, zuchk};
+use num::complex::Complex;
+#[cfg(not(feature = "std"))]
+use num::Float;
+
+pub(super) fn zuoik(
+    zr: f64,
+    zi: f64,
+    fnu: f64,
+    kode: i32,
+    ikflg: i32,
+    y: &mut [Complex<f64>],
+    tol: f64,
+    elim: f64,
+    alim: f64,
+) -> i32 {
+    const AIC: f64 = 1.265512123484645396e+00;
+
+    let n = y.len();
+    let mut nuf = 0;
+    let mut nn = n;
+    let (zrr, zri) = match zr >= 0.0 {
+        true => (zr, zi),
+        false => (-zr, -zi),
+    };
+    let zbr = zrr;
+    let zbi = zri;
+    let ax = zr.abs() * 1.7321;
+    let ay = zi.abs();
+    let iform = match ay > ax {
+        true => 2,
+        false => 1,
+    };
+    let mut znr = zri;
+    let zni = -zrr;
+
+    if zi <= 0.0 {
+        znr = -znr;
+    }
+
+    // The leading terms -zeta1 + zeta2 of the expansion in cz with phi, arg and |arg|, where arg is
+    // only used for iform = 2, see amos/zuoik.f lines 64-81
+    let leading = |gnu: f64, ikflg: i32| match iform == 2 {
+        true => {
+            let (phi, arg, zeta1, zeta2, _, _) = zunhj(znr, zni, gnu, 1, tol);
+            let czr = -zeta1.re + zeta2.re;
+            let czi = -zeta1.im + zeta2.im;
+
+            (czr, czi, phi, arg, azabs(arg.re, arg.im))
+        }
+        false => {
+            let mut state = Zunik::new();
+            let (phi, zeta1, zeta2, _) = zunik(zrr, zri, gnu, ikflg, 1, tol, &mut state);
+            let czr = -zeta1.re + zeta2.re;
+            let czi = -zeta1.im + zeta2.im;
+
+            (czr, czi, phi, Complex::new(0.0, 0.0), 0.0)
+        }
+    };
+
+    // Adds log |phi| and, for iform = 2, the log of the Airy function's asymptotic factor to Re(cz)
+    let adjust = |rcz: f64, phi: Complex<f64>, aarg: f64| {
+        let aphi = azabs(phi.re, phi.im);
+        let mut rcz = rcz + aphi.ln();
+
+        if iform == 2 {
+            rcz = rcz - 0.25 * aarg.ln() - AIC;
+        }
+
+        rcz
+    };
+
+    // The underflow test on exp(cz) phi (arg)^(-1/4) near the underflow limit, returning true if the
+    // member underflows, see amos/zuoik.f lines 118-134
+    let underflows = |mut czi: f64, rcz: f64, phi: Complex<f64>, arg: Complex<f64>| {
+        let ascle = 1.0e+3 * d1mach(1) / tol;
+        let (_, sti) = azlog(phi.re, phi.im);
+        czi += sti;
+
+        if iform != 1 {
+            let (_, sti) = azlog(arg.re, arg.im);
+            czi -= 0.25 * sti;
+        }
+
+        let ax = rcz.exp() / tol;
+        let ay = czi;
+        let czr = ax * ay.cos();
+        let czi = ax * ay.sin();
+
+        zuchk(czr, czi, ascle, tol) != 0
+    };
+
+    let mut gnu = fnu.max(1.0);
+
+    if ikflg != 1 {
+        let fnn = float(nn as i32);
+        let gnn = fnu + fnn - 1.0;
+        gnu = gnn.max(fnn);
+    }
+
+    let (mut czr, mut czi, phi, arg, aarg) = leading(gnu, ikflg);
+
+    if kode != 1 {
+        czr -= zbr;
+        czi -= zbi;
+    }
+
+    if ikflg != 1 {
+        czr = -czr;
+        czi = -czi;
+    }
+
+    let mut rcz = czr;
+
+    if rcz > elim {
+        return -1;
+    }
+
+    let underflow = match rcz >= alim {
+        true => {
+            rcz = adjust(rcz, phi, aarg);
+
+            if rcz > elim {
+                return -1;
+            }
+
+            false
+        }
+        false => match rcz < -elim {
+            true => true,
+            false => match rcz > -alim {
+                true => false,
+                false => {
+                    rcz = adjust(rcz, phi, aarg);
+
+                    rcz <= -elim || underflows(czi, rcz, phi, arg)
+                }
+            },
+        },
+    };
+
+    if underflow {
+        for y in y.iter_mut() {
+            *y = Complex::new(0.0, 0.0);
+        }
+
+        return nn as i32;
+    }
+
+    if ikflg == 2 || n == 1 {
+        return nuf;
+    }
+
+    // Set the members of the highest orders to zero while they underflow, see amos/zuoik.f lines
+    // 140-190
+    loop {
+        gnu = fnu + float(nn as i32 - 1);
+        let (mut czr, mut czi, phi, arg, aarg) = leading(gnu, ikflg);
+
+        if kode != 1 {
+            czr -= zbr;
+            czi -= zbi;
+        }
+
+        let mut rcz = czr;
+
+        if rcz >= -elim {
+            if rcz > -alim {
+                return nuf;
+            }
+
+            rcz = adjust(rcz, phi, aarg);
+
+            if rcz > -elim && !underflows(czi, rcz, phi, arg) {
+                return nuf;
+            }
+        }
+
+        y[nn - 1] = Complex::new(0.0, 0.0);
+        nn -= 1;
+        nuf += 1;
+
+        if nn == 0 {
+            return nuf;
+        }
+    }
+}
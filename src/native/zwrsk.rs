@@ -0,0 +1,88 @@
+// amos/zwrsk.f, I for Re(z) >= 0 from the ratios of zrati normalized by the Wronskian with K from
+// zbknu
+use super::zbknu::zbknu;
+use super::zrati::zrati;
+use super::{azabs, d1mach, nz_error};
+use num::complex::Complex;
+#[cfg(not(feature = "std"))]
+use num::Float;
+
+pub(super) fn zwrsk(
+    zrr: f64,
+    zri: f64,
+    fnu: f64,
+    kode: i32,
+    y: &mut [Complex<f64>],
+    tol: f64,
+    elim: f64,
+    alim: f64,
+) -> i32 {
+    let n = y.len();
+    let mut cw = [Complex::new(0.0, 0.0); 2];
+
+    let nw = zbknu(zrr, zri, fnu, kode, &mut cw, tol, elim, alim);
+
+    if nw != 0 {
+        return nz_error(nw);
+    }
+
+    zrati(zrr, zri, fnu, y, tol);
+
+    // Recur forward on I(fnu+1, z) = r(fnu, z) I(fnu, z), see amos/zwrsk.f lines 54-94
+    let mut cinur = 1.0;
+    let mut cinui = 0.0;
+
+    if kode != 1 {
+        cinur = zri.cos();
+        cinui = zri.sin();
+    }
+
+    // Scale K to avoid underflow or overflow in the Wronskian
+    let acw = azabs(cw[1].re, cw[1].im);
+    let mut ascle = 1.0e+3 * d1mach(1) / tol;
+    let mut csclr = 1.0;
+
+    match acw > ascle {
+        true => {
+            ascle = 1.0 / ascle;
+
+            if acw >= ascle {
+                csclr = tol;
+            }
+        }
+        false => csclr = 1.0 / tol,
+    }
+
+    let c1r = cw[0].re * csclr;
+    let c1i = cw[0].im * csclr;
+    let c2r = cw[1].re * csclr;
+    let c2i = cw[1].im * csclr;
+    let mut str = y[0].re;
+    let mut sti = y[0].im;
+    let mut ptr = str * c1r - sti * c1i;
+    let mut pti = str * c1i + sti * c1r;
+    ptr += c2r;
+    pti += c2i;
+    let mut ctr = zrr * ptr - zri * pti;
+    let mut cti = zrr * pti + zri * ptr;
+    let act = azabs(ctr, cti);
+    let ract = 1.0 / act;
+    ctr *= ract;
+    cti = -cti * ract;
+    ptr = cinur * ract;
+    pti = cinui * ract;
+    cinur = ptr * ctr - pti * cti;
+    cinui = ptr * cti + pti * ctr;
+    y[0] = Complex::new(cinur * csclr, cinui * csclr);
+
+    for y in y[1..n].iter_mut() {
+        ptr = str * cinur - sti * cinui;
+        cinui = str * cinui + sti * cinur;
+        cinur = ptr;
+        str = y.re;
+        sti = y.im;
+        *y = Complex::new(cinur * csclr, cinui * csclr);
+    }
+
+    0
+}
@@ -0,0 +1,131 @@
+// JavaScript bindings with the wasm-bindgen feature. Complex arguments are passed as their real and
+// imaginary parts, and complex results are returned as a Complex64, or as a Float64Array with the
+// real and imaginary parts interleaved for the sequences of hankel1 and friends.
+use crate::{zbesh, zbesi, zbesj, zbesk, zbesy};
+use num::complex::Complex;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Complex64 {
+    pub re: f64,
+    pub im: f64,
+}
+
+#[wasm_bindgen]
+impl Complex64 {
+    #[wasm_bindgen(constructor)]
+    pub fn new(re: f64, im: f64) -> Self {
+        Complex64 { re, im }
+    }
+}
+
+impl From<Complex<f64>> for Complex64 {
+    fn from(z: Complex<f64>) -> Self {
+        Complex64::new(z.re, z.im)
+    }
+}
+
+fn interleave(cy: Vec<Complex<f64>>) -> Vec<f64> {
+    cy.iter().flat_map(|c| [c.re, c.im]).collect()
+}
+
+#[wasm_bindgen]
+pub fn iv(nu: f64, re: f64, im: f64) -> Complex64 {
+    zbesi::iv(nu, Complex::new(re, im)).into()
+}
+
+#[wasm_bindgen]
+pub fn ive(nu: f64, re: f64, im: f64) -> Complex64 {
+    zbesi::ive(nu, Complex::new(re, im)).into()
+}
+
+#[wasm_bindgen]
+pub fn iv_real(nu: f64, x: f64) -> f64 {
+    zbesi::iv_real(nu, x)
+}
+
+#[wasm_bindgen]
+pub fn ive_real(nu: f64, x: f64) -> f64 {
+    zbesi::ive_real(nu, x)
+}
+
+#[wasm_bindgen]
+pub fn jv(nu: f64, re: f64, im: f64) -> Complex64 {
+    zbesj::jv(nu, Complex::new(re, im)).into()
+}
+
+#[wasm_bindgen]
+pub fn jve(nu: f64, re: f64, im: f64) -> Complex64 {
+    zbesj::jve(nu, Complex::new(re, im)).into()
+}
+
+#[wasm_bindgen]
+pub fn jv_real(nu: f64, x: f64) -> f64 {
+    zbesj::jv_real(nu, x)
+}
+
+#[wasm_bindgen]
+pub fn jve_real(nu: f64, x: f64) -> f64 {
+    zbesj::jve_real(nu, x)
+}
+
+#[wasm_bindgen]
+pub fn kv(nu: f64, re: f64, im: f64) -> Complex64 {
+    zbesk::kv(nu, Complex::new(re, im)).into()
+}
+
+#[wasm_bindgen]
+pub fn kve(nu: f64, re: f64, im: f64) -> Complex64 {
+    zbesk::kve(nu, Complex::new(re, im)).into()
+}
+
+#[wasm_bindgen]
+pub fn kv_real(nu: f64, x: f64) -> f64 {
+    zbesk::kv_real(nu, x)
+}
+
+#[wasm_bindgen]
+pub fn kve_real(nu: f64, x: f64) -> f64 {
+    zbesk::kve_real(nu, x)
+}
+
+#[wasm_bindgen]
+pub fn yv(nu: f64, re: f64, im: f64) -> Complex64 {
+    zbesy::yv(nu, Complex::new(re, im)).into()
+}
+
+#[wasm_bindgen]
+pub fn yve(nu: f64, re: f64, im: f64) -> Complex64 {
+    zbesy::yve(nu, Complex::new(re, im)).into()
+}
+
+#[wasm_bindgen]
+pub fn yv_real(nu: f64, x: f64) -> f64 {
+    zbesy::yv_real(nu, x)
+}
+
+#[wasm_bindgen]
+pub fn yve_real(nu: f64, x: f64) -> f64 {
+    zbesy::yve_real(nu, x)
+}
+
+#[wasm_bindgen]
+pub fn hankel1(nu: f64, re: f64, im: f64, n: i32) -> Vec<f64> {
+    interleave(zbesh::hankel1(nu, Complex::new(re, im), n))
+}
+
+#[wasm_bindgen]
+pub fn hankel1e(nu: f64, re: f64, im: f64, n: i32) -> Vec<f64> {
+    interleave(zbesh::hankel1e(nu, Complex::new(re, im), n))
+}
+
+#[wasm_bindgen]
+pub fn hankel2(nu: f64, re: f64, im: f64, n: i32) -> Vec<f64> {
+    interleave(zbesh::hankel2(nu, Complex::new(re, im), n))
+}
+
+#[wasm_bindgen]
+pub fn hankel2e(nu: f64, re: f64, im: f64, n: i32) -> Vec<f64> {
+    interleave(zbesh::hankel2e(nu, Complex::new(re, im), n))
+}
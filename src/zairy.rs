@@ -0,0 +1,167 @@
+use crate::amos_errors::{input_ierr, no_computation_done};
+use crate::bindings::{zairy_, zbiry_};
+use core::ffi::{c_double, c_int};
+use num::complex::Complex;
+
+// Ai(z) for id = 0 and Ai'(z) for id = 1, scaled by exp(2 / 3 z^(3 / 2)) for kode = 2, see
+// amos/zairy.f lines 12-15
+pub fn zairy(z: Complex<f64>, id: i32, kode: i32) -> Complex<f64> {
+    zairy_ierr(z, id, kode).0
+}
+
+// As zairy, returning the NZ and IERR of AMOS as well
+pub fn zairy_ierr(z: Complex<f64>, id: i32, kode: i32) -> (Complex<f64>, i32, i32) {
+    if !(0..=1).contains(&id) {
+        panic!("id must be 0 (Ai) or 1 (Ai')");
+    }
+
+    if !(1..=2).contains(&kode) {
+        panic!("kode must be 1 (ai) or 2 (aie)");
+    }
+
+    match input_ierr(0.0, z) {
+        0 => {}
+        ierr => return (Complex::new(f64::NAN, f64::NAN), 0, ierr),
+    }
+
+    let mut air = 0.0;
+    let mut aii = 0.0;
+    let mut nz: c_int = 0;
+    let mut ierr: c_int = 0;
+
+    unsafe {
+        zairy_(
+            &z.re,
+            &z.im,
+            &id as *const c_int,
+            &kode as *const c_int,
+            &mut air as *mut c_double,
+            &mut aii as *mut c_double,
+            &mut nz as *mut c_int,
+            &mut ierr as *mut c_int,
+        );
+    }
+
+    match no_computation_done(ierr) {
+        true => (Complex::new(f64::NAN, f64::NAN), nz, ierr),
+        false => (Complex::new(air, aii), nz, ierr),
+    }
+}
+
+// Bi(z) for id = 0 and Bi'(z) for id = 1, scaled by exp(-|Re(2 / 3 z^(3 / 2))|) for kode = 2, see
+// amos/zbiry.f lines 12-15
+pub fn zbiry(z: Complex<f64>, id: i32, kode: i32) -> Complex<f64> {
+    zbiry_ierr(z, id, kode).0
+}
+
+// As zbiry, returning the IERR of AMOS as well. ZBIRY has no NZ, as Bi does not underflow.
+pub fn zbiry_ierr(z: Complex<f64>, id: i32, kode: i32) -> (Complex<f64>, i32) {
+    if !(0..=1).contains(&id) {
+        panic!("id must be 0 (Bi) or 1 (Bi')");
+    }
+
+    if !(1..=2).contains(&kode) {
+        panic!("kode must be 1 (bi) or 2 (bie)");
+    }
+
+    match input_ierr(0.0, z) {
+        0 => {}
+        ierr => return (Complex::new(f64::NAN, f64::NAN), ierr),
+    }
+
+    let mut bir = 0.0;
+    let mut bii = 0.0;
+    let mut ierr: c_int = 0;
+
+    unsafe {
+        zbiry_(
+            &z.re,
+            &z.im,
+            &id as *const c_int,
+            &kode as *const c_int,
+            &mut bir as *mut c_double,
+            &mut bii as *mut c_double,
+            &mut ierr as *mut c_int,
+        );
+    }
+
+    match no_computation_done(ierr) {
+        true => (Complex::new(f64::NAN, f64::NAN), ierr),
+        false => (Complex::new(bir, bii), ierr),
+    }
+}
+
+pub fn ai(z: Complex<f64>) -> Complex<f64> {
+    zairy(z, 0, 1)
+}
+
+pub fn aie(z: Complex<f64>) -> Complex<f64> {
+    zairy(z, 0, 2)
+}
+
+pub fn aip(z: Complex<f64>) -> Complex<f64> {
+    zairy(z, 1, 1)
+}
+
+pub fn aipe(z: Complex<f64>) -> Complex<f64> {
+    zairy(z, 1, 2)
+}
+
+pub fn bi(z: Complex<f64>) -> Complex<f64> {
+    zbiry(z, 0, 1)
+}
+
+pub fn bie(z: Complex<f64>) -> Complex<f64> {
+    zbiry(z, 0, 2)
+}
+
+pub fn bip(z: Complex<f64>) -> Complex<f64> {
+    zbiry(z, 1, 1)
+}
+
+pub fn bipe(z: Complex<f64>) -> Complex<f64> {
+    zbiry(z, 1, 2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_zairy() {
+        // Ai(0) = 3^(-2 / 3) / Gamma(2 / 3) and Ai'(0) = -3^(-1 / 3) / Gamma(1 / 3)
+        let z = Complex::new(0.0, 0.0);
+
+        assert_relative_eq!(ai(z).re, 0.355_028_053_887_817_2, max_relative = 1e-14);
+        assert_relative_eq!(aip(z).re, -0.258_819_403_792_806_8, max_relative = 1e-14);
+        assert_eq!(ai(z).im, 0.0);
+
+        // Ai underflows far into the right half plane, and is NaN for an infinite argument
+        let (cy, nz, ierr) = zairy_ierr(Complex::new(200.0, 0.0), 0, 1);
+        assert_eq!((cy, nz, ierr), (Complex::new(0.0, 0.0), 1, 0));
+
+        let (cy, nz, ierr) = zairy_ierr(Complex::new(f64::INFINITY, 0.0), 0, 1);
+        assert!(cy.is_nan());
+        assert_eq!((nz, ierr), (0, 4));
+    }
+
+    #[test]
+    fn test_zbiry() {
+        // Bi(0) = sqrt(3) Ai(0) and Bi'(0) = -sqrt(3) Ai'(0)
+        let z = Complex::new(0.0, 0.0);
+
+        assert_relative_eq!(bi(z).re, 0.614_926_627_446_000_7, max_relative = 1e-14);
+        assert_relative_eq!(bip(z).re, 0.448_288_357_353_826_4, max_relative = 1e-14);
+
+        // The scaling of Bi is exp(-|Re(2 / 3 z^(3 / 2))|)
+        let z = Complex::new(2.0, 0.0);
+        let zeta = 2.0 / 3.0 * z.powf(1.5);
+
+        assert_relative_eq!(
+            bie(z).re,
+            bi(z).re * (-zeta.re.abs()).exp(),
+            max_relative = 1e-14
+        );
+    }
+}
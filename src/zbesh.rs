@@ -1,7 +1,12 @@
+use crate::amos_errors::{input_ierr, no_computation_done};
 use crate::bindings::zbesh_;
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+use core::f64::consts::PI;
+use core::ffi::{c_double, c_int};
 use num::complex::Complex;
-use std::os::raw::c_int;
 
+#[cfg(feature = "alloc")]
 pub fn zbesh(
     nu: f64,
     z: num::complex::Complex<f64>,
@@ -9,21 +14,35 @@ pub fn zbesh(
     m: i32,
     n: i32,
 ) -> Vec<Complex<f64>> {
+    zbesh_ierr(nu, z, kode, m, n).0
+}
+
+// As zbesh, returning the NZ and IERR of AMOS as well
+#[cfg(feature = "alloc")]
+pub fn zbesh_ierr(
+    nu: f64,
+    z: Complex<f64>,
+    kode: i32,
+    m: i32,
+    n: i32,
+) -> (Vec<Complex<f64>>, i32, i32) {
     if m < 1 || m > 2 {
         panic!("m must 1 (hankel1) or 2 (hankel2)");
     }
 
     if kode < 1 || kode > 2 {
-        panic!(
-            "{}",
-            format!("kode must be 1 (hankel{}) or 2 (hankel{}e)", m, m)
-        );
+        panic!("kode must be 1 (hankel{}) or 2 (hankel{}e)", m, m);
     }
 
     if n < 1 {
         panic!("n must be at least 1");
     }
 
+    match input_ierr(nu, z) {
+        0 => {}
+        ierr => return (vec![Complex::new(f64::NAN, f64::NAN); n as usize], 0, ierr),
+    }
+
     let sign = nu.signum();
     let nu = nu * sign;
 
@@ -50,7 +69,10 @@ pub fn zbesh(
         );
     }
 
-    // Handle ierr and nz
+    if no_computation_done(ierr) {
+        cyr.fill(f64::NAN);
+        cyi.fill(f64::NAN);
+    }
 
     let mut cy: Vec<Complex<f64>> = cyr
         .iter()
@@ -58,23 +80,80 @@ pub fn zbesh(
         .map(|(&re, &im)| Complex::new(re, im))
         .collect();
 
-    // See amos/zbesh.f lines 89-95 on how to handle negative orders of nu
     if sign < 0.0 {
-        let e_sign = match m {
-            1 => 1.0,
-            2 => -1.0,
-            _ => panic!("Whoops! This case should not occur."),
-        };
-
         for i in 0..(n as usize) {
-            cy[i] =
-                cy[i] * Complex::new(0.0, e_sign * std::f64::consts::PI * (nu + (i as f64))).exp();
+            cy[i] = cy[i] * rotation(nu + (i as f64), m);
         }
     }
 
-    cy
+    (cy, nz, ierr)
+}
+
+// As zbesh for n = 1 without allocating, returning H(m, nu, z) and the NZ and IERR of AMOS.
+pub fn zbesh_scalar(nu: f64, z: Complex<f64>, kode: i32, m: i32) -> (Complex<f64>, i32, i32) {
+    if !(1..=2).contains(&m) {
+        panic!("m must 1 (hankel1) or 2 (hankel2)");
+    }
+
+    if !(1..=2).contains(&kode) {
+        panic!("kode must be 1 (hankel{}) or 2 (hankel{}e)", m, m);
+    }
+
+    match input_ierr(nu, z) {
+        0 => {}
+        ierr => return (Complex::new(f64::NAN, f64::NAN), 0, ierr),
+    }
+
+    let n: i32 = 1;
+    let sign = nu.signum();
+    let nu = nu * sign;
+
+    let mut cyr = 0.0;
+    let mut cyi = 0.0;
+    let mut nz: c_int = 0;
+    let mut ierr: c_int = 0;
+
+    unsafe {
+        zbesh_(
+            &z.re,
+            &z.im,
+            &nu,
+            &kode as *const c_int,
+            &m as *const c_int,
+            &n as *const c_int,
+            &mut cyr as *mut c_double,
+            &mut cyi as *mut c_double,
+            &mut nz as *mut c_int,
+            &mut ierr as *mut c_int,
+        );
+    }
+
+    if no_computation_done(ierr) {
+        cyr = f64::NAN;
+        cyi = f64::NAN;
+    }
+
+    let cy = Complex::new(cyr, cyi);
+
+    match sign < 0.0 {
+        true => (cy * rotation(nu, m), nz, ierr),
+        false => (cy, nz, ierr),
+    }
+}
+
+// See amos/zbesh.f lines 89-95 on how to handle negative orders of nu, i.e.,
+// hankel1(-nu, z) = exp(i pi nu) hankel1(nu, z) and hankel2(-nu, z) = exp(-i pi nu) hankel2(nu, z)
+fn rotation(nu: f64, m: i32) -> Complex<f64> {
+    let e_sign = match m {
+        1 => 1.0,
+        2 => -1.0,
+        _ => panic!("Whoops! This case should not occur."),
+    };
+
+    Complex::new(0.0, e_sign * PI * nu).exp()
 }
 
+#[cfg(feature = "alloc")]
 pub fn hankel1(nu: f64, z: Complex<f64>, n: i32) -> Vec<Complex<f64>> {
     let kode: i32 = 1;
     let m: i32 = 1;
@@ -82,6 +161,7 @@ pub fn hankel1(nu: f64, z: Complex<f64>, n: i32) -> Vec<Complex<f64>> {
     zbesh(nu, z, kode, m, n)
 }
 
+#[cfg(feature = "alloc")]
 pub fn hankel1e(nu: f64, z: Complex<f64>, n: i32) -> Vec<Complex<f64>> {
     let kode: i32 = 2;
     let m: i32 = 1;
@@ -89,6 +169,7 @@ pub fn hankel1e(nu: f64, z: Complex<f64>, n: i32) -> Vec<Complex<f64>> {
     zbesh(nu, z, kode, m, n)
 }
 
+#[cfg(feature = "alloc")]
 pub fn hankel2(nu: f64, z: Complex<f64>, n: i32) -> Vec<Complex<f64>> {
     let kode: i32 = 1;
     let m: i32 = 2;
@@ -96,6 +177,7 @@ pub fn hankel2(nu: f64, z: Complex<f64>, n: i32) -> Vec<Complex<f64>> {
     zbesh(nu, z, kode, m, n)
 }
 
+#[cfg(feature = "alloc")]
 pub fn hankel2e(nu: f64, z: Complex<f64>, n: i32) -> Vec<Complex<f64>> {
     let kode: i32 = 2;
     let m: i32 = 2;
@@ -1,7 +1,13 @@
+use crate::amos_errors::{combine_ierr, input_ierr, no_computation_done};
 use crate::bindings::{zbesi_, zbesk_};
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+use core::f64::consts::PI;
+use core::ffi::{c_double, c_int};
+use core::fmt;
 use num::complex::Complex;
-use std::fmt;
-use std::os::raw::{c_double, c_int};
+#[cfg(not(feature = "std"))]
+use num::Float;
 
 enum ZbesiErrors<T> {
     // Definitions of the errors are found in amos/zbesi.f lines 42-60
@@ -37,10 +43,21 @@ impl<T> fmt::Display for ZbesiErrors<T> {
 }
 
 pub fn zbesi(nu: f64, z: Complex<f64>, kode: i32) -> Complex<f64> {
+    zbesi_ierr(nu, z, kode).0
+}
+
+// As zbesi, returning the NZ and IERR of AMOS as well. For negative orders NZ is that of ZBESI,
+// while IERR combines those of ZBESI and ZBESK.
+pub fn zbesi_ierr(nu: f64, z: Complex<f64>, kode: i32) -> (Complex<f64>, i32, i32) {
     if kode < 1 || kode > 2 {
         panic!("kode must be 1 (iv) or 2 (ive)");
     }
 
+    match input_ierr(nu, z) {
+        0 => {}
+        ierr => return (Complex::new(f64::NAN, f64::NAN), 0, ierr),
+    }
+
     let n = 1;
     let sign = nu.signum();
     let nu = nu * sign;
@@ -67,7 +84,10 @@ pub fn zbesi(nu: f64, z: Complex<f64>, kode: i32) -> Complex<f64> {
         );
     }
 
-    // Handle ierr and nz
+    if no_computation_done(ierr) {
+        cyr = f64::NAN;
+        cyi = f64::NAN;
+    }
 
     let mut cy = Complex::new(cyr, cyi);
 
@@ -77,8 +97,8 @@ pub fn zbesi(nu: f64, z: Complex<f64>, kode: i32) -> Complex<f64> {
     if sign < 0.0 && nu.floor() != nu {
         let mut cy_kr = 0.0;
         let mut cy_ki = 0.0;
-        let mut nz: c_int = 0;
-        let mut ierr: c_int = 0;
+        let mut nz_k: c_int = 0;
+        let mut ierr_k: c_int = 0;
 
         unsafe {
             zbesk_(
@@ -89,12 +109,17 @@ pub fn zbesi(nu: f64, z: Complex<f64>, kode: i32) -> Complex<f64> {
                 &n as *const c_int,
                 &mut cy_kr as *mut c_double,
                 &mut cy_ki as *mut c_double,
-                &mut nz as *mut c_int,
-                &mut ierr as *mut c_int,
+                &mut nz_k as *mut c_int,
+                &mut ierr_k as *mut c_int,
             );
         }
 
-        // Handle nz and ierr
+        if no_computation_done(ierr_k) {
+            cy_kr = f64::NAN;
+            cy_ki = f64::NAN;
+        }
+
+        ierr = combine_ierr(ierr, ierr_k);
 
         let cy_k = Complex::new(cy_kr, cy_ki);
 
@@ -128,11 +153,11 @@ pub fn zbesi(nu: f64, z: Complex<f64>, kode: i32) -> Complex<f64> {
         }
 
         // Handle lines 72-78 in zbesi.f
-        let sin_nupi = (std::f64::consts::PI * nu).sin();
-        cy = cy + (2.0 / std::f64::consts::PI) * sin_nupi * k_scaling * cy_k;
+        let sin_nupi = (PI * nu).sin();
+        cy = cy + (2.0 / PI) * sin_nupi * k_scaling * cy_k;
     }
 
-    cy
+    (cy, nz, ierr)
 }
 
 pub fn iv(nu: f64, z: Complex<f64>) -> Complex<f64> {
@@ -147,19 +172,46 @@ pub fn ive(nu: f64, z: Complex<f64>) -> Complex<f64> {
     zbesi(nu, z, kode)
 }
 
+// On the negative real axis iv(nu, z) is only real-valued when nu is an integer. Following
+// scipy.special.iv and scipy.special.ive we return NaN for the real-argument functions when the
+// result would be complex, instead of silently dropping the imaginary part.
+fn is_complex_valued(nu: f64, z: f64) -> bool {
+    z < 0.0 && nu.floor() != nu
+}
+
 pub fn iv_real(nu: f64, z: f64) -> f64 {
     let kode: i32 = 1;
 
+    if is_complex_valued(nu, z) {
+        return f64::NAN;
+    }
+
     zbesi(nu, Complex::new(z, 0.0), kode).re
 }
 
 pub fn ive_real(nu: f64, z: f64) -> f64 {
     let kode: i32 = 2;
 
+    if is_complex_valued(nu, z) {
+        return f64::NAN;
+    }
+
     zbesi(nu, Complex::new(z, 0.0), kode).re
 }
 
+#[cfg(feature = "alloc")]
 pub fn zbesi_vec(nu: f64, z: Complex<f64>, kode: i32, n: i32) -> Vec<Complex<f64>> {
+    zbesi_vec_ierr(nu, z, kode, n).0
+}
+
+// As zbesi_vec, returning the NZ and IERR of AMOS as well, see zbesi_ierr
+#[cfg(feature = "alloc")]
+pub fn zbesi_vec_ierr(
+    nu: f64,
+    z: Complex<f64>,
+    kode: i32,
+    n: i32,
+) -> (Vec<Complex<f64>>, i32, i32) {
     if kode < 1 || kode > 2 {
         panic!("kode must be 1 (iv) or 2 (ive)");
     }
@@ -168,6 +220,11 @@ pub fn zbesi_vec(nu: f64, z: Complex<f64>, kode: i32, n: i32) -> Vec<Complex<f64
         panic!("n must be at least 1");
     }
 
+    match input_ierr(nu, z) {
+        0 => {}
+        ierr => return (vec![Complex::new(f64::NAN, f64::NAN); n as usize], 0, ierr),
+    }
+
     let sign = nu.signum();
     let nu = nu * sign;
 
@@ -193,7 +250,10 @@ pub fn zbesi_vec(nu: f64, z: Complex<f64>, kode: i32, n: i32) -> Vec<Complex<f64
         );
     }
 
-    // Handle ierr and nz
+    if no_computation_done(ierr) {
+        cyr.fill(f64::NAN);
+        cyi.fill(f64::NAN);
+    }
 
     let mut cy: Vec<Complex<f64>> = cyr
         .iter()
@@ -207,8 +267,8 @@ pub fn zbesi_vec(nu: f64, z: Complex<f64>, kode: i32, n: i32) -> Vec<Complex<f64
     if sign < 0.0 && nu.floor() != nu {
         let mut cy_kr = vec![0.0; n as usize];
         let mut cy_ki = vec![0.0; n as usize];
-        let mut nz: c_int = 0;
-        let mut ierr: c_int = 0;
+        let mut nz_k: c_int = 0;
+        let mut ierr_k: c_int = 0;
 
         unsafe {
             zbesk_(
@@ -219,12 +279,17 @@ pub fn zbesi_vec(nu: f64, z: Complex<f64>, kode: i32, n: i32) -> Vec<Complex<f64
                 &n as *const c_int,
                 cy_kr.as_mut_ptr(),
                 cy_ki.as_mut_ptr(),
-                &mut nz as *mut c_int,
-                &mut ierr as *mut c_int,
+                &mut nz_k as *mut c_int,
+                &mut ierr_k as *mut c_int,
             );
         }
 
-        // Handle nz and ierr
+        if no_computation_done(ierr_k) {
+            cy_kr.fill(f64::NAN);
+            cy_ki.fill(f64::NAN);
+        }
+
+        ierr = combine_ierr(ierr, ierr_k);
 
         let cy_k: Vec<Complex<f64>> = cy_kr
             .iter()
@@ -263,38 +328,51 @@ pub fn zbesi_vec(nu: f64, z: Complex<f64>, kode: i32, n: i32) -> Vec<Complex<f64
 
         // Handle lines 72-78 in zbesi.f
         for i in 0..(n as usize) {
-            let sin_nupi = (std::f64::consts::PI * (nu + (i as f64))).sin();
-            cy[i] = cy[i] + (2.0 / std::f64::consts::PI) * sin_nupi * k_scaling * cy_k[i];
+            let sin_nupi = (PI * (nu + (i as f64))).sin();
+            cy[i] = cy[i] + (2.0 / PI) * sin_nupi * k_scaling * cy_k[i];
         }
     }
 
-    cy
+    (cy, nz, ierr)
 }
 
+#[cfg(feature = "alloc")]
 pub fn iv_vec(nu: f64, z: Complex<f64>, n: i32) -> Vec<Complex<f64>> {
     let kode: i32 = 1;
 
     zbesi_vec(nu, z, kode, n)
 }
 
+#[cfg(feature = "alloc")]
 pub fn ive_vec(nu: f64, z: Complex<f64>, n: i32) -> Vec<Complex<f64>> {
     let kode: i32 = 2;
 
     zbesi_vec(nu, z, kode, n)
 }
 
+#[cfg(feature = "alloc")]
 pub fn iv_real_vec(nu: f64, z: f64, n: i32) -> Vec<f64> {
     let kode: i32 = 1;
 
+    // All orders nu + k in the sequence share the fractional part of nu
+    if n >= 1 && is_complex_valued(nu, z) {
+        return vec![f64::NAN; n as usize];
+    }
+
     zbesi_vec(nu, Complex::new(z, 0.0), kode, n)
         .iter()
         .map(|&cy| cy.re)
         .collect()
 }
 
+#[cfg(feature = "alloc")]
 pub fn ive_real_vec(nu: f64, z: f64, n: i32) -> Vec<f64> {
     let kode: i32 = 2;
 
+    if n >= 1 && is_complex_valued(nu, z) {
+        return vec![f64::NAN; n as usize];
+    }
+
     zbesi_vec(nu, Complex::new(z, 0.0), kode, n)
         .iter()
         .map(|&cy| cy.re)
@@ -357,4 +435,42 @@ mod tests {
         assert_abs_diff_eq!(foo_vec[0].re, foo.re);
         assert_abs_diff_eq!(foo_vec[0].im, foo.im);
     }
+
+    #[test]
+    fn test_iv_real_negative_axis() {
+        // Non-integer orders give complex results on the negative real axis
+        assert!(iv_real(0.5, -1.0).is_nan());
+        assert!(iv_real(-1.3, -2.0).is_nan());
+        assert!(ive_real(0.5, -1.0).is_nan());
+        assert!(ive_real(-1.3, -2.0).is_nan());
+
+        assert!(iv_real_vec(0.5, -1.0, 3).iter().all(|cy| cy.is_nan()));
+        assert!(ive_real_vec(-1.3, -2.0, 3).iter().all(|cy| cy.is_nan()));
+
+        // The complex-argument functions are unaffected
+        let cy = iv(0.5, Complex::new(-1.0, 0.0));
+        assert!(cy.re.is_finite());
+        assert!(cy.im.abs() > 0.0);
+
+        // Integer orders satisfy iv(n, -x) = (-1)^n iv(n, x)
+        for n in -3..=3 {
+            let sign = if n % 2 == 0 { 1.0 } else { -1.0 };
+            let nu = n as f64;
+
+            assert_abs_diff_eq!(iv_real(nu, -1.5), sign * iv_real(nu, 1.5), epsilon = 1e-12);
+            assert_abs_diff_eq!(
+                ive_real(nu, -1.5),
+                sign * ive_real(nu, 1.5),
+                epsilon = 1e-12
+            );
+        }
+
+        let cy_m = iv_real_vec(1.0, -1.5, 3);
+        let cy_p = iv_real_vec(1.0, 1.5, 3);
+
+        assert_eq!(cy_m.len(), 3);
+        assert_abs_diff_eq!(cy_m[0], -cy_p[0], epsilon = 1e-12);
+        assert_abs_diff_eq!(cy_m[1], cy_p[1], epsilon = 1e-12);
+        assert_abs_diff_eq!(cy_m[2], -cy_p[2], epsilon = 1e-12);
+    }
 }
@@ -0,0 +1,359 @@
+use crate::amos_errors::{combine_ierr, input_ierr, no_computation_done};
+use crate::bindings::zbesj_;
+#[cfg(feature = "alloc")]
+use crate::zbesy::zbesy_nonneg;
+use crate::zbesy::zbesy_nonneg_scalar;
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+use core::f64::consts::PI;
+use core::ffi::{c_double, c_int};
+use num::complex::Complex;
+#[cfg(not(feature = "std"))]
+use num::Float;
+
+// Calls zbesj_ for nu >= 0, returning the sequence J(nu + i, z) for i = 0, ..., n - 1
+// and the NZ and IERR of AMOS.
+#[cfg(feature = "alloc")]
+pub(crate) fn zbesj_nonneg(
+    nu: f64,
+    z: Complex<f64>,
+    kode: i32,
+    n: i32,
+) -> (Vec<Complex<f64>>, i32, i32) {
+    // Sanity check that should be removed
+    assert!(nu >= 0.0);
+
+    let mut cyr = vec![0.0; n as usize];
+    let mut cyi = vec![0.0; n as usize];
+    let mut nz: c_int = 0;
+    let mut ierr: c_int = 0;
+
+    unsafe {
+        zbesj_(
+            &z.re,
+            &z.im,
+            &nu,
+            &kode as *const c_int,
+            &n as *const c_int,
+            cyr.as_mut_ptr(),
+            cyi.as_mut_ptr(),
+            &mut nz as *mut c_int,
+            &mut ierr as *mut c_int,
+        );
+    }
+
+    if no_computation_done(ierr) {
+        cyr.fill(f64::NAN);
+        cyi.fill(f64::NAN);
+    }
+
+    let cy = cyr
+        .iter()
+        .zip(cyi.iter())
+        .map(|(&re, &im)| Complex::new(re, im))
+        .collect();
+
+    (cy, nz, ierr)
+}
+
+// As zbesj_nonneg, for n = 1 without allocating.
+pub(crate) fn zbesj_nonneg_scalar(nu: f64, z: Complex<f64>, kode: i32) -> (Complex<f64>, i32, i32) {
+    // Sanity check that should be removed
+    assert!(nu >= 0.0);
+
+    let n: i32 = 1;
+    let mut cyr = 0.0;
+    let mut cyi = 0.0;
+    let mut nz: c_int = 0;
+    let mut ierr: c_int = 0;
+
+    unsafe {
+        zbesj_(
+            &z.re,
+            &z.im,
+            &nu,
+            &kode as *const c_int,
+            &n as *const c_int,
+            &mut cyr as *mut c_double,
+            &mut cyi as *mut c_double,
+            &mut nz as *mut c_int,
+            &mut ierr as *mut c_int,
+        );
+    }
+
+    if no_computation_done(ierr) {
+        cyr = f64::NAN;
+        cyi = f64::NAN;
+    }
+
+    (Complex::new(cyr, cyi), nz, ierr)
+}
+
+// See amos/zbesj.f lines 72-84 on how to handle negative orders of nu, i.e.,
+//
+//      jv(-nu, z) = jv(nu, z) * cos(pi * nu) - yv(nu, z) * sin(pi * nu).
+//
+// As both jve and yve are scaled by exp(-abs(z.im)), the same formula holds for kode == 2.
+// Since sin(pi * nu) = 0 when nu is an integer, we test nu.floor() == nu to avoid computing yv
+// unnecessarily, in which case cy_y is None. This also avoids polluting jv(-n, z) = (-1)^n jv(n, z)
+// by a large yv(n, z) multiplied by the rounding error in sin(pi * n).
+fn reflect(nu: f64, cy: Complex<f64>, cy_y: Option<Complex<f64>>) -> Complex<f64> {
+    match cy_y {
+        Some(cy_y) => {
+            let (sin_nupi, cos_nupi) = (PI * nu).sin_cos();
+
+            cy * cos_nupi - cy_y * sin_nupi
+        }
+        // jv(-n, z) = (-1)^n jv(n, z) for integer orders
+        None if nu % 2.0 == 0.0 => cy,
+        None => -cy,
+    }
+}
+
+#[cfg(feature = "alloc")]
+pub fn zbesj_vec(nu: f64, z: Complex<f64>, kode: i32, n: i32) -> Vec<Complex<f64>> {
+    zbesj_vec_ierr(nu, z, kode, n).0
+}
+
+// As zbesj_vec, returning the NZ and IERR of AMOS as well, see zbesj_ierr
+#[cfg(feature = "alloc")]
+pub fn zbesj_vec_ierr(
+    nu: f64,
+    z: Complex<f64>,
+    kode: i32,
+    n: i32,
+) -> (Vec<Complex<f64>>, i32, i32) {
+    if !(1..=2).contains(&kode) {
+        panic!("kode must be 1 (jv) or 2 (jve)");
+    }
+
+    if n < 1 {
+        panic!("n must be at least 1");
+    }
+
+    match input_ierr(nu, z) {
+        0 => {}
+        ierr => return (vec![Complex::new(f64::NAN, f64::NAN); n as usize], 0, ierr),
+    }
+
+    let sign = nu.signum();
+    let nu = nu * sign;
+
+    let (mut cy, nz, mut ierr) = zbesj_nonneg(nu, z, kode, n);
+
+    if sign < 0.0 {
+        let cy_y = match nu.floor() != nu {
+            true => {
+                let (cy_y, _, ierr_y) = zbesy_nonneg(nu, z, kode, n);
+                ierr = combine_ierr(ierr, ierr_y);
+
+                Some(cy_y)
+            }
+            false => None,
+        };
+
+        for i in 0..(n as usize) {
+            let nu_i = nu + (i as f64);
+
+            cy[i] = reflect(nu_i, cy[i], cy_y.as_ref().map(|cy_y| cy_y[i]));
+        }
+    }
+
+    (cy, nz, ierr)
+}
+
+pub fn zbesj(nu: f64, z: Complex<f64>, kode: i32) -> Complex<f64> {
+    zbesj_ierr(nu, z, kode).0
+}
+
+// As zbesj, returning the NZ and IERR of AMOS as well. For negative orders IERR combines those
+// of ZBESJ and ZBESY, and NZ is that of ZBESJ.
+pub fn zbesj_ierr(nu: f64, z: Complex<f64>, kode: i32) -> (Complex<f64>, i32, i32) {
+    if !(1..=2).contains(&kode) {
+        panic!("kode must be 1 (jv) or 2 (jve)");
+    }
+
+    match input_ierr(nu, z) {
+        0 => {}
+        ierr => return (Complex::new(f64::NAN, f64::NAN), 0, ierr),
+    }
+
+    let sign = nu.signum();
+    let nu = nu * sign;
+
+    let (cy, nz, mut ierr) = zbesj_nonneg_scalar(nu, z, kode);
+
+    if sign < 0.0 {
+        let cy_y = match nu.floor() != nu {
+            true => {
+                let (cy_y, _, ierr_y) = zbesy_nonneg_scalar(nu, z, kode);
+                ierr = combine_ierr(ierr, ierr_y);
+
+                Some(cy_y)
+            }
+            false => None,
+        };
+
+        return (reflect(nu, cy, cy_y), nz, ierr);
+    }
+
+    (cy, nz, ierr)
+}
+
+pub fn jv(nu: f64, z: Complex<f64>) -> Complex<f64> {
+    let kode: i32 = 1;
+
+    zbesj(nu, z, kode)
+}
+
+pub fn jve(nu: f64, z: Complex<f64>) -> Complex<f64> {
+    let kode: i32 = 2;
+
+    zbesj(nu, z, kode)
+}
+
+// As for iv, jv(nu, x) is complex on the negative real axis unless nu is an integer. We then
+// return NaN following scipy.special.jv.
+fn is_complex_valued(nu: f64, z: f64) -> bool {
+    z < 0.0 && nu.floor() != nu
+}
+
+pub fn jv_real(nu: f64, z: f64) -> f64 {
+    let kode: i32 = 1;
+
+    if is_complex_valued(nu, z) {
+        return f64::NAN;
+    }
+
+    zbesj(nu, Complex::new(z, 0.0), kode).re
+}
+
+pub fn jve_real(nu: f64, z: f64) -> f64 {
+    let kode: i32 = 2;
+
+    if is_complex_valued(nu, z) {
+        return f64::NAN;
+    }
+
+    zbesj(nu, Complex::new(z, 0.0), kode).re
+}
+
+#[cfg(feature = "alloc")]
+pub fn jv_vec(nu: f64, z: Complex<f64>, n: i32) -> Vec<Complex<f64>> {
+    let kode: i32 = 1;
+
+    zbesj_vec(nu, z, kode, n)
+}
+
+#[cfg(feature = "alloc")]
+pub fn jve_vec(nu: f64, z: Complex<f64>, n: i32) -> Vec<Complex<f64>> {
+    let kode: i32 = 2;
+
+    zbesj_vec(nu, z, kode, n)
+}
+
+#[cfg(feature = "alloc")]
+pub fn jv_real_vec(nu: f64, z: f64, n: i32) -> Vec<f64> {
+    let kode: i32 = 1;
+
+    if n >= 1 && is_complex_valued(nu, z) {
+        return vec![f64::NAN; n as usize];
+    }
+
+    zbesj_vec(nu, Complex::new(z, 0.0), kode, n)
+        .iter()
+        .map(|&cy| cy.re)
+        .collect()
+}
+
+#[cfg(feature = "alloc")]
+pub fn jve_real_vec(nu: f64, z: f64, n: i32) -> Vec<f64> {
+    let kode: i32 = 2;
+
+    if n >= 1 && is_complex_valued(nu, z) {
+        return vec![f64::NAN; n as usize];
+    }
+
+    zbesj_vec(nu, Complex::new(z, 0.0), kode, n)
+        .iter()
+        .map(|&cy| cy.re)
+        .collect()
+}
+
+// The tests compare against H^(1) = J + iY
+#[cfg(all(test, feature = "hankel"))]
+mod tests {
+    use super::*;
+    use crate::zbesh::hankel1;
+    use crate::zbesy::yv_vec;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_zbesj() {
+        let kode: i32 = 1;
+        let n: i32 = 3;
+
+        let foo_vec = zbesj_vec(0.3, Complex::new(1.0, 1.0), kode, n);
+        let foo = zbesj(0.3, Complex::new(1.0, 1.0), kode);
+
+        // AMOS computes the sequence by backward recurrence from the highest order, so the first
+        // member agrees to within rounding only
+        assert_eq!(foo_vec.len(), n as usize);
+        assert_abs_diff_eq!(foo_vec[0].re, foo.re, epsilon = 1e-14);
+        assert_abs_diff_eq!(foo_vec[0].im, foo.im, epsilon = 1e-14);
+    }
+
+    #[test]
+    fn test_jv_sym() {
+        let n: i32 = 3;
+
+        let foo_vec = jv_vec(-1.0, Complex::new(1.0, 1.0), n);
+        let foo_2_vec = jv_vec(1.0, Complex::new(1.0, 1.0), n);
+
+        assert_eq!(foo_vec.len(), n as usize);
+
+        // jv(-n, z) = (-1)^n jv(n, z)
+        for i in 0..(n as usize) {
+            let sign = if i % 2 == 0 { -1.0 } else { 1.0 };
+
+            assert_abs_diff_eq!(foo_vec[i].re, sign * foo_2_vec[i].re);
+            assert_abs_diff_eq!(foo_vec[i].im, sign * foo_2_vec[i].im);
+        }
+
+        let foo = jv(-1.0, Complex::new(1.0, 1.0));
+
+        assert_abs_diff_eq!(foo_vec[0].re, foo.re);
+        assert_abs_diff_eq!(foo_vec[0].im, foo.im);
+    }
+
+    #[test]
+    fn test_jv_negative_order() {
+        // hankel1(nu, z) = jv(nu, z) + i yv(nu, z) also holds for negative orders, where hankel1
+        // uses a different reflection formula than jv and yv.
+        let n: i32 = 3;
+        let z = Complex::new(1.3, -0.4);
+
+        for nu in [-0.3, -0.5, -1.7] {
+            let cy_j = jv_vec(nu, z, n);
+            let cy_y = yv_vec(nu, z, n);
+            let cy_h = hankel1(nu, z, n);
+
+            for i in 0..(n as usize) {
+                let cy = cy_j[i] + Complex::<f64>::i() * cy_y[i];
+
+                assert_abs_diff_eq!(cy.re, cy_h[i].re, epsilon = 1e-12);
+                assert_abs_diff_eq!(cy.im, cy_h[i].im, epsilon = 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_jv_real_negative_axis() {
+        assert!(jv_real(0.5, -1.0).is_nan());
+        assert!(jve_real_vec(-1.3, -2.0, 3).iter().all(|cy| cy.is_nan()));
+
+        // jv(n, -x) = (-1)^n jv(n, x)
+        assert_abs_diff_eq!(jv_real(3.0, -1.5), -jv_real(3.0, 1.5), epsilon = 1e-12);
+        assert_abs_diff_eq!(jv_real(-2.0, -1.5), jv_real(2.0, 1.5), epsilon = 1e-12);
+    }
+}
@@ -0,0 +1,277 @@
+use crate::amos_errors::{input_ierr, no_computation_done};
+use crate::bindings::zbesk_;
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+use core::ffi::{c_double, c_int};
+use num::complex::Complex;
+
+#[cfg(feature = "alloc")]
+pub fn zbesk_vec(nu: f64, z: Complex<f64>, kode: i32, n: i32) -> Vec<Complex<f64>> {
+    zbesk_vec_ierr(nu, z, kode, n).0
+}
+
+// As zbesk_vec, returning the NZ and IERR of AMOS as well
+#[cfg(feature = "alloc")]
+pub fn zbesk_vec_ierr(
+    nu: f64,
+    z: Complex<f64>,
+    kode: i32,
+    n: i32,
+) -> (Vec<Complex<f64>>, i32, i32) {
+    if !(1..=2).contains(&kode) {
+        panic!("kode must be 1 (kv) or 2 (kve)");
+    }
+
+    if n < 1 {
+        panic!("n must be at least 1");
+    }
+
+    match input_ierr(nu, z) {
+        0 => {}
+        ierr => return (vec![Complex::new(f64::NAN, f64::NAN); n as usize], 0, ierr),
+    }
+
+    // kv(-nu, z) = kv(nu, z), see amos/zbesk.f
+    let sign = nu.signum();
+    let nu = nu * sign;
+
+    // zbesk.f treats z = 0 as an input error (IERR=1). We follow scipy.special.kv and return inf.
+    if z.re == 0.0 && z.im == 0.0 {
+        return (vec![Complex::new(f64::INFINITY, 0.0); n as usize], 0, 0);
+    }
+
+    let mut cyr = vec![0.0; n as usize];
+    let mut cyi = vec![0.0; n as usize];
+    let mut nz: c_int = 0;
+    let mut ierr: c_int = 0;
+
+    unsafe {
+        zbesk_(
+            &z.re,
+            &z.im,
+            &nu,
+            &kode as *const c_int,
+            &n as *const c_int,
+            cyr.as_mut_ptr(),
+            cyi.as_mut_ptr(),
+            &mut nz as *mut c_int,
+            &mut ierr as *mut c_int,
+        );
+    }
+
+    if no_computation_done(ierr) {
+        cyr.fill(f64::NAN);
+        cyi.fill(f64::NAN);
+    }
+
+    let cy = cyr
+        .iter()
+        .zip(cyi.iter())
+        .map(|(&re, &im)| Complex::new(re, im))
+        .collect();
+
+    (cy, nz, ierr)
+}
+
+pub fn zbesk(nu: f64, z: Complex<f64>, kode: i32) -> Complex<f64> {
+    zbesk_ierr(nu, z, kode).0
+}
+
+// As zbesk, returning the NZ and IERR of AMOS as well
+pub fn zbesk_ierr(nu: f64, z: Complex<f64>, kode: i32) -> (Complex<f64>, i32, i32) {
+    if !(1..=2).contains(&kode) {
+        panic!("kode must be 1 (kv) or 2 (kve)");
+    }
+
+    match input_ierr(nu, z) {
+        0 => {}
+        ierr => return (Complex::new(f64::NAN, f64::NAN), 0, ierr),
+    }
+
+    let n: i32 = 1;
+    let nu = nu.abs();
+
+    if z.re == 0.0 && z.im == 0.0 {
+        return (Complex::new(f64::INFINITY, 0.0), 0, 0);
+    }
+
+    let mut cyr = 0.0;
+    let mut cyi = 0.0;
+    let mut nz: c_int = 0;
+    let mut ierr: c_int = 0;
+
+    unsafe {
+        zbesk_(
+            &z.re,
+            &z.im,
+            &nu,
+            &kode as *const c_int,
+            &n as *const c_int,
+            &mut cyr as *mut c_double,
+            &mut cyi as *mut c_double,
+            &mut nz as *mut c_int,
+            &mut ierr as *mut c_int,
+        );
+    }
+
+    if no_computation_done(ierr) {
+        cyr = f64::NAN;
+        cyi = f64::NAN;
+    }
+
+    (Complex::new(cyr, cyi), nz, ierr)
+}
+
+pub fn kv(nu: f64, z: Complex<f64>) -> Complex<f64> {
+    let kode: i32 = 1;
+
+    zbesk(nu, z, kode)
+}
+
+pub fn kve(nu: f64, z: Complex<f64>) -> Complex<f64> {
+    let kode: i32 = 2;
+
+    zbesk(nu, z, kode)
+}
+
+// kv(nu, x) is complex for all orders on the negative real axis, and scipy.special.kv returns NaN
+pub fn kv_real(nu: f64, z: f64) -> f64 {
+    let kode: i32 = 1;
+
+    if z < 0.0 {
+        return f64::NAN;
+    }
+
+    zbesk(nu, Complex::new(z, 0.0), kode).re
+}
+
+pub fn kve_real(nu: f64, z: f64) -> f64 {
+    let kode: i32 = 2;
+
+    if z < 0.0 {
+        return f64::NAN;
+    }
+
+    zbesk(nu, Complex::new(z, 0.0), kode).re
+}
+
+#[cfg(feature = "alloc")]
+pub fn kv_vec(nu: f64, z: Complex<f64>, n: i32) -> Vec<Complex<f64>> {
+    let kode: i32 = 1;
+
+    zbesk_vec(nu, z, kode, n)
+}
+
+#[cfg(feature = "alloc")]
+pub fn kve_vec(nu: f64, z: Complex<f64>, n: i32) -> Vec<Complex<f64>> {
+    let kode: i32 = 2;
+
+    zbesk_vec(nu, z, kode, n)
+}
+
+#[cfg(feature = "alloc")]
+pub fn kv_real_vec(nu: f64, z: f64, n: i32) -> Vec<f64> {
+    let kode: i32 = 1;
+
+    if n >= 1 && z < 0.0 {
+        return vec![f64::NAN; n as usize];
+    }
+
+    zbesk_vec(nu, Complex::new(z, 0.0), kode, n)
+        .iter()
+        .map(|&cy| cy.re)
+        .collect()
+}
+
+#[cfg(feature = "alloc")]
+pub fn kve_real_vec(nu: f64, z: f64, n: i32) -> Vec<f64> {
+    let kode: i32 = 2;
+
+    if n >= 1 && z < 0.0 {
+        return vec![f64::NAN; n as usize];
+    }
+
+    zbesk_vec(nu, Complex::new(z, 0.0), kode, n)
+        .iter()
+        .map(|&cy| cy.re)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_zbesk() {
+        let kode: i32 = 1;
+        let n: i32 = 3;
+
+        let foo_vec = zbesk_vec(0.3, Complex::new(1.0, 1.0), kode, n);
+        let foo = zbesk(0.3, Complex::new(1.0, 1.0), kode);
+
+        assert_eq!(foo_vec.len(), n as usize);
+        assert_abs_diff_eq!(foo_vec[0].re, foo.re);
+        assert_abs_diff_eq!(foo_vec[0].im, foo.im);
+    }
+
+    #[test]
+    fn test_kv_sym() {
+        let foo = kv(-1.3, Complex::new(1.0, 1.0));
+        let foo_2 = kv(1.3, Complex::new(1.0, 1.0));
+
+        assert_abs_diff_eq!(foo.re, foo_2.re);
+        assert_abs_diff_eq!(foo.im, foo_2.im);
+    }
+
+    #[test]
+    fn test_kv_half_integer() {
+        // kv(1/2, x) = sqrt(pi / (2 x)) exp(-x)
+        let x: f64 = 1.7;
+
+        assert_abs_diff_eq!(
+            kv_real(0.5, x),
+            (std::f64::consts::PI / (2.0 * x)).sqrt() * (-x).exp(),
+            epsilon = 1e-14
+        );
+        assert_abs_diff_eq!(
+            kve_real(0.5, x),
+            (std::f64::consts::PI / (2.0 * x)).sqrt(),
+            epsilon = 1e-14
+        );
+    }
+
+    #[test]
+    fn test_kv_real_domain() {
+        assert!(kv_real(1.0, -1.0).is_nan());
+        assert_eq!(kv_real(0.0, 0.0), f64::INFINITY);
+    }
+
+    // NaN input used to crash AMOS, and kv(2, 1e-320) overflows with no computation done
+    #[test]
+    fn test_kv_errors() {
+        let (cy, _, ierr) = zbesk_ierr(f64::NAN, Complex::new(f64::NAN, 1.0), 1);
+        assert!(cy.is_nan());
+        assert_eq!(ierr, 1);
+
+        assert!(kv_vec(1.0, Complex::new(1.0, f64::NAN), 3)
+            .iter()
+            .all(|cy| cy.is_nan()));
+
+        let (cy, _, ierr) = zbesk_ierr(2.0, Complex::new(1e-320, 0.0), 1);
+        assert!(cy.is_nan());
+        assert_eq!(ierr, 2);
+    }
+
+    // kv(0, 800) underflows, which AMOS reports in NZ rather than IERR
+    #[test]
+    fn test_kv_underflow() {
+        let (cy, nz, ierr) = zbesk_ierr(-0.0, Complex::new(800.0, 0.0), 1);
+        assert_eq!(cy, Complex::new(0.0, 0.0));
+        assert_eq!((nz, ierr), (1, 0));
+
+        let (cy, nz, ierr) = zbesk_vec_ierr(0.5, Complex::new(800.0, 0.0), 1, 2);
+        assert!(cy.iter().all(|cy| cy.norm() == 0.0));
+        assert_eq!((nz, ierr), (2, 0));
+    }
+}
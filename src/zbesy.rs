@@ -0,0 +1,364 @@
+use crate::amos_errors::{combine_ierr, input_ierr, no_computation_done};
+use crate::bindings::zbesy_;
+#[cfg(feature = "alloc")]
+use crate::zbesj::zbesj_nonneg;
+use crate::zbesj::zbesj_nonneg_scalar;
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+use core::f64::consts::PI;
+use core::ffi::{c_double, c_int};
+use num::complex::Complex;
+#[cfg(not(feature = "std"))]
+use num::Float;
+
+// Calls zbesy_ for nu >= 0, returning the sequence Y(nu + i, z) for i = 0, ..., n - 1
+// and the NZ and IERR of AMOS.
+#[cfg(feature = "alloc")]
+pub(crate) fn zbesy_nonneg(
+    nu: f64,
+    z: Complex<f64>,
+    kode: i32,
+    n: i32,
+) -> (Vec<Complex<f64>>, i32, i32) {
+    // Sanity check that should be removed
+    assert!(nu >= 0.0);
+
+    // zbesy.f treats z = 0 as an input error (IERR=1). We follow scipy.special.yv and return -inf.
+    if z.re == 0.0 && z.im == 0.0 {
+        return (vec![Complex::new(f64::NEG_INFINITY, 0.0); n as usize], 0, 0);
+    }
+
+    let mut cyr = vec![0.0; n as usize];
+    let mut cyi = vec![0.0; n as usize];
+    let mut cwrkr = vec![0.0; n as usize];
+    let mut cwrki = vec![0.0; n as usize];
+    let mut nz: c_int = 0;
+    let mut ierr: c_int = 0;
+
+    unsafe {
+        zbesy_(
+            &z.re,
+            &z.im,
+            &nu,
+            &kode as *const c_int,
+            &n as *const c_int,
+            cyr.as_mut_ptr(),
+            cyi.as_mut_ptr(),
+            &mut nz as *mut c_int,
+            cwrkr.as_mut_ptr(),
+            cwrki.as_mut_ptr(),
+            &mut ierr as *mut c_int,
+        );
+    }
+
+    if no_computation_done(ierr) {
+        cyr.fill(f64::NAN);
+        cyi.fill(f64::NAN);
+    }
+
+    let cy = cyr
+        .iter()
+        .zip(cyi.iter())
+        .map(|(&re, &im)| Complex::new(re, im))
+        .collect();
+
+    (cy, nz, ierr)
+}
+
+// As zbesy_nonneg, for n = 1 without allocating.
+pub(crate) fn zbesy_nonneg_scalar(nu: f64, z: Complex<f64>, kode: i32) -> (Complex<f64>, i32, i32) {
+    // Sanity check that should be removed
+    assert!(nu >= 0.0);
+
+    if z.re == 0.0 && z.im == 0.0 {
+        return (Complex::new(f64::NEG_INFINITY, 0.0), 0, 0);
+    }
+
+    let n: i32 = 1;
+    let mut cyr = 0.0;
+    let mut cyi = 0.0;
+    let mut cwrkr = 0.0;
+    let mut cwrki = 0.0;
+    let mut nz: c_int = 0;
+    let mut ierr: c_int = 0;
+
+    unsafe {
+        zbesy_(
+            &z.re,
+            &z.im,
+            &nu,
+            &kode as *const c_int,
+            &n as *const c_int,
+            &mut cyr as *mut c_double,
+            &mut cyi as *mut c_double,
+            &mut nz as *mut c_int,
+            &mut cwrkr as *mut c_double,
+            &mut cwrki as *mut c_double,
+            &mut ierr as *mut c_int,
+        );
+    }
+
+    if no_computation_done(ierr) {
+        cyr = f64::NAN;
+        cyi = f64::NAN;
+    }
+
+    (Complex::new(cyr, cyi), nz, ierr)
+}
+
+fn is_half_integer(nu: f64) -> bool {
+    (nu - 0.5).floor() == nu - 0.5
+}
+
+// See amos/zbesy.f lines 75-86 on how to handle negative orders of nu, i.e.,
+//
+//      yv(-nu, z) = yv(nu, z) * cos(pi * nu) + jv(nu, z) * sin(pi * nu).
+//
+// As both jve and yve are scaled by exp(-abs(z.im)), the same formula holds for kode == 2.
+// For integer orders sin(pi * nu) = 0, and for half-integer orders cos(pi * nu) = 0. In these
+// cases we skip the vanishing term to avoid multiplying a possibly large function value by the
+// rounding error in sin(pi * nu) or cos(pi * nu). For integer orders jv is not computed, and cy_j
+// is None.
+fn reflect(nu: f64, cy: Complex<f64>, cy_j: Option<Complex<f64>>) -> Complex<f64> {
+    let (sin_nupi, cos_nupi) = (PI * nu).sin_cos();
+
+    match cy_j {
+        // yv(-n, z) = (-1)^n yv(n, z) for integer orders
+        None if nu % 2.0 == 0.0 => cy,
+        None => -cy,
+        // yv(-nu, z) = (-1)^(nu - 1/2) jv(nu, z) for half-integer orders
+        Some(cy_j) if is_half_integer(nu) => cy_j * sin_nupi.round(),
+        Some(cy_j) => cy * cos_nupi + cy_j * sin_nupi,
+    }
+}
+
+#[cfg(feature = "alloc")]
+pub fn zbesy_vec(nu: f64, z: Complex<f64>, kode: i32, n: i32) -> Vec<Complex<f64>> {
+    zbesy_vec_ierr(nu, z, kode, n).0
+}
+
+// As zbesy_vec, returning the NZ and IERR of AMOS as well, see zbesy_ierr
+#[cfg(feature = "alloc")]
+pub fn zbesy_vec_ierr(
+    nu: f64,
+    z: Complex<f64>,
+    kode: i32,
+    n: i32,
+) -> (Vec<Complex<f64>>, i32, i32) {
+    if !(1..=2).contains(&kode) {
+        panic!("kode must be 1 (yv) or 2 (yve)");
+    }
+
+    if n < 1 {
+        panic!("n must be at least 1");
+    }
+
+    match input_ierr(nu, z) {
+        0 => {}
+        ierr => return (vec![Complex::new(f64::NAN, f64::NAN); n as usize], 0, ierr),
+    }
+
+    let sign = nu.signum();
+    let nu = nu * sign;
+
+    let (mut cy, mut nz, mut ierr) = zbesy_nonneg(nu, z, kode, n);
+
+    if sign < 0.0 {
+        let cy_j = match nu.floor() == nu {
+            false => {
+                let (cy_j, nz_j, ierr_j) = zbesj_nonneg(nu, z, kode, n);
+
+                // For half-integer orders yv(-nu, z) is given by jv(nu, z) alone, see reflect
+                (nz, ierr) = match is_half_integer(nu) {
+                    true => (nz_j, ierr_j),
+                    false => (nz, combine_ierr(ierr, ierr_j)),
+                };
+
+                Some(cy_j)
+            }
+            true => None,
+        };
+
+        for i in 0..(n as usize) {
+            let nu_i = nu + (i as f64);
+
+            cy[i] = reflect(nu_i, cy[i], cy_j.as_ref().map(|cy_j| cy_j[i]));
+        }
+    }
+
+    (cy, nz, ierr)
+}
+
+pub fn zbesy(nu: f64, z: Complex<f64>, kode: i32) -> Complex<f64> {
+    zbesy_ierr(nu, z, kode).0
+}
+
+// As zbesy, returning the NZ and IERR of AMOS as well. For negative orders IERR combines those
+// of ZBESY and ZBESJ, and NZ is that of ZBESY, except for half-integer orders, where both are
+// those of ZBESJ.
+pub fn zbesy_ierr(nu: f64, z: Complex<f64>, kode: i32) -> (Complex<f64>, i32, i32) {
+    if !(1..=2).contains(&kode) {
+        panic!("kode must be 1 (yv) or 2 (yve)");
+    }
+
+    match input_ierr(nu, z) {
+        0 => {}
+        ierr => return (Complex::new(f64::NAN, f64::NAN), 0, ierr),
+    }
+
+    let sign = nu.signum();
+    let nu = nu * sign;
+
+    let (cy, mut nz, mut ierr) = zbesy_nonneg_scalar(nu, z, kode);
+
+    if sign < 0.0 {
+        let cy_j = match nu.floor() == nu {
+            false => {
+                let (cy_j, nz_j, ierr_j) = zbesj_nonneg_scalar(nu, z, kode);
+
+                // For half-integer orders yv(-nu, z) is given by jv(nu, z) alone, see reflect
+                (nz, ierr) = match is_half_integer(nu) {
+                    true => (nz_j, ierr_j),
+                    false => (nz, combine_ierr(ierr, ierr_j)),
+                };
+
+                Some(cy_j)
+            }
+            true => None,
+        };
+
+        return (reflect(nu, cy, cy_j), nz, ierr);
+    }
+
+    (cy, nz, ierr)
+}
+
+pub fn yv(nu: f64, z: Complex<f64>) -> Complex<f64> {
+    let kode: i32 = 1;
+
+    zbesy(nu, z, kode)
+}
+
+pub fn yve(nu: f64, z: Complex<f64>) -> Complex<f64> {
+    let kode: i32 = 2;
+
+    zbesy(nu, z, kode)
+}
+
+// yv(nu, x) is complex for all orders on the negative real axis, and scipy.special.yv returns NaN
+pub fn yv_real(nu: f64, z: f64) -> f64 {
+    let kode: i32 = 1;
+
+    if z < 0.0 {
+        return f64::NAN;
+    }
+
+    zbesy(nu, Complex::new(z, 0.0), kode).re
+}
+
+pub fn yve_real(nu: f64, z: f64) -> f64 {
+    let kode: i32 = 2;
+
+    if z < 0.0 {
+        return f64::NAN;
+    }
+
+    zbesy(nu, Complex::new(z, 0.0), kode).re
+}
+
+#[cfg(feature = "alloc")]
+pub fn yv_vec(nu: f64, z: Complex<f64>, n: i32) -> Vec<Complex<f64>> {
+    let kode: i32 = 1;
+
+    zbesy_vec(nu, z, kode, n)
+}
+
+#[cfg(feature = "alloc")]
+pub fn yve_vec(nu: f64, z: Complex<f64>, n: i32) -> Vec<Complex<f64>> {
+    let kode: i32 = 2;
+
+    zbesy_vec(nu, z, kode, n)
+}
+
+#[cfg(feature = "alloc")]
+pub fn yv_real_vec(nu: f64, z: f64, n: i32) -> Vec<f64> {
+    let kode: i32 = 1;
+
+    if n >= 1 && z < 0.0 {
+        return vec![f64::NAN; n as usize];
+    }
+
+    zbesy_vec(nu, Complex::new(z, 0.0), kode, n)
+        .iter()
+        .map(|&cy| cy.re)
+        .collect()
+}
+
+#[cfg(feature = "alloc")]
+pub fn yve_real_vec(nu: f64, z: f64, n: i32) -> Vec<f64> {
+    let kode: i32 = 2;
+
+    if n >= 1 && z < 0.0 {
+        return vec![f64::NAN; n as usize];
+    }
+
+    zbesy_vec(nu, Complex::new(z, 0.0), kode, n)
+        .iter()
+        .map(|&cy| cy.re)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_zbesy() {
+        let kode: i32 = 2;
+        let n: i32 = 3;
+
+        let foo_vec = zbesy_vec(0.3, Complex::new(1.0, 1.0), kode, n);
+        let foo = zbesy(0.3, Complex::new(1.0, 1.0), kode);
+
+        assert_eq!(foo_vec.len(), n as usize);
+        assert_abs_diff_eq!(foo_vec[0].re, foo.re);
+        assert_abs_diff_eq!(foo_vec[0].im, foo.im);
+    }
+
+    #[test]
+    fn test_yv_sym() {
+        let n: i32 = 3;
+
+        let foo_vec = yv_vec(-2.0, Complex::new(1.0, 1.0), n);
+        let foo_2_vec = yv_vec(2.0, Complex::new(1.0, 1.0), n);
+
+        // yv(-n, z) = (-1)^n yv(n, z)
+        for i in 0..(n as usize) {
+            let sign = if i % 2 == 0 { 1.0 } else { -1.0 };
+
+            assert_abs_diff_eq!(foo_vec[i].re, sign * foo_2_vec[i].re);
+            assert_abs_diff_eq!(foo_vec[i].im, sign * foo_2_vec[i].im);
+        }
+    }
+
+    #[test]
+    fn test_yv_half_integer() {
+        // yv(-1/2, x) = jv(1/2, x) = sqrt(2 / (pi x)) sin(x)
+        let x: f64 = 2.3;
+        let cy = yv_real(-0.5, x);
+
+        assert_abs_diff_eq!(
+            cy,
+            (2.0 / (std::f64::consts::PI * x)).sqrt() * x.sin(),
+            epsilon = 1e-14
+        );
+    }
+
+    #[test]
+    fn test_yv_real_domain() {
+        assert!(yv_real(1.0, -1.0).is_nan());
+        assert!(yve_real_vec(0.0, -1.0, 2).iter().all(|cy| cy.is_nan()));
+        assert_eq!(yv_real(0.0, 0.0), f64::NEG_INFINITY);
+    }
+}
@@ -0,0 +1,118 @@
+// The test data in tests/dat, where each CSV file has a header naming the columns
+//
+//     function,nu,n_index,z_re,z_im,expected_re,expected_im,tolerance,source
+//
+// and a row per reference value. The expected value is the member n_index of the sequence of the
+// function starting at order nu, i.e., the order is nu + n_index, or nu - n_index for negative nu,
+// as returned by the _vec functions. Functions of real arguments have z_im and expected_im zero, and
+// the Airy functions have nu zero. The tolerance bounds the relative error, and source names where
+// the expected value comes from, e.g., mpmath. The columns may come in any order, see also
+// xtask/src/main.rs on generating the data.
+#![allow(dead_code)]
+
+use num::complex::Complex;
+
+#[derive(Clone, Debug)]
+pub struct Case {
+    pub function: String,
+    pub nu: f64,
+    pub n_index: usize,
+    pub z: Complex<f64>,
+    pub expected: Complex<f64>,
+    pub tolerance: f64,
+    pub source: String,
+}
+
+pub const COLUMNS: [&str; 9] = [
+    "function",
+    "nu",
+    "n_index",
+    "z_re",
+    "z_im",
+    "expected_re",
+    "expected_im",
+    "tolerance",
+    "source",
+];
+
+// Parses the contents of a data file, where name is only used in the error messages
+pub fn parse(name: &str, contents: &str) -> Vec<Case> {
+    let mut lines = contents.trim().lines();
+
+    let header: Vec<&str> = lines
+        .next()
+        .unwrap_or_else(|| panic!("{name}: missing header"))
+        .split(',')
+        .map(str::trim)
+        .collect();
+
+    let index: Vec<usize> = COLUMNS
+        .iter()
+        .map(|column| {
+            header
+                .iter()
+                .position(|h| h == column)
+                .unwrap_or_else(|| panic!("{name}: missing column {column}"))
+        })
+        .collect();
+
+    lines
+        .enumerate()
+        .map(|(i, line)| {
+            // The header is line 1
+            let row: Vec<&str> = line.split(',').map(str::trim).collect();
+            assert_eq!(
+                row.len(),
+                header.len(),
+                "{name}:{}: wrong number of columns",
+                i + 2
+            );
+
+            let field = |column: usize| row[index[column]];
+            let float = |column: usize| -> f64 {
+                field(column).parse().unwrap_or_else(|_| {
+                    panic!(
+                        "{name}:{}: invalid {} {}",
+                        i + 2,
+                        COLUMNS[column],
+                        field(column)
+                    )
+                })
+            };
+
+            Case {
+                function: field(0).to_string(),
+                nu: float(1),
+                n_index: field(2)
+                    .parse()
+                    .unwrap_or_else(|_| panic!("{name}:{}: invalid n_index {}", i + 2, field(2))),
+                z: Complex::new(float(3), float(4)),
+                expected: Complex::new(float(5), float(6)),
+                tolerance: float(7),
+                source: field(8).to_string(),
+            }
+        })
+        .collect()
+}
+
+pub fn read(path: &str) -> Vec<Case> {
+    let contents = std::fs::read_to_string(path).unwrap_or_else(|err| panic!("{path}: {err}"));
+
+    parse(path, &contents)
+}
+
+// The cases of a function in a data file
+pub fn read_function(path: &str, function: &str) -> Vec<Case> {
+    read(path)
+        .into_iter()
+        .filter(|case| case.function == function)
+        .collect()
+}
+
+// The relative error of cy, or the absolute error where the expected value is zero
+pub fn relative_error(cy: Complex<f64>, expected: Complex<f64>) -> f64 {
+    match expected.norm() {
+        0.0 => cy.norm(),
+        norm => (cy - expected).norm() / norm,
+    }
+}
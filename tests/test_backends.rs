@@ -0,0 +1,185 @@
+// Runs the comparisons against tests/dat for both the linked AMOS backend (the Fortran library, or
+// the C translation with the c-backend feature) and the C translation in amos-c/, which build.rs
+// compiles with its symbols prefixed by amos_c_ for the backend-comparison feature, see Cargo.toml.
+// Negative orders are handled in the Rust wrappers rather than by AMOS, and are covered for the
+// linked backend by tests/test_data.rs.
+mod common;
+
+use amos_rs::zbesh::{hankel1, hankel1e, hankel2, hankel2e};
+use amos_rs::zbesi::{iv_real_vec, iv_vec, ive_real_vec, ive_vec};
+use common::relative_error;
+use num::complex::Complex;
+use std::os::raw::{c_double, c_int};
+
+extern "C" {
+    fn amos_c_zbesh_(
+        zr: *const c_double,
+        zi: *const c_double,
+        fnu: *const c_double,
+        kode: *const c_int,
+        m: *const c_int,
+        n: *const c_int,
+        cyr: *mut c_double,
+        cyi: *mut c_double,
+        nz: *mut c_int,
+        ierr: *mut c_int,
+    );
+
+    fn amos_c_zbesi_(
+        zr: *const c_double,
+        zi: *const c_double,
+        fnu: *const c_double,
+        kode: *const c_int,
+        n: *const c_int,
+        cyr: *mut c_double,
+        cyi: *mut c_double,
+        nz: *mut c_int,
+        ierr: *mut c_int,
+    );
+}
+
+const ZBESH: &str = "./tests/dat/zbesh.csv";
+const ZBESI: &str = "./tests/dat/zbesi.csv";
+
+fn c_zbesh(nu: f64, z: Complex<f64>, kode: i32, m: i32, n: i32) -> Vec<Complex<f64>> {
+    let mut cyr = vec![0.0; n as usize];
+    let mut cyi = vec![0.0; n as usize];
+    let mut nz: c_int = 0;
+    let mut ierr: c_int = 0;
+
+    unsafe {
+        amos_c_zbesh_(
+            &z.re,
+            &z.im,
+            &nu,
+            &kode,
+            &m,
+            &n,
+            cyr.as_mut_ptr(),
+            cyi.as_mut_ptr(),
+            &mut nz,
+            &mut ierr,
+        );
+    }
+
+    assert_eq!(ierr, 0);
+
+    cyr.iter()
+        .zip(cyi.iter())
+        .map(|(&re, &im)| Complex::new(re, im))
+        .collect()
+}
+
+fn c_zbesi(nu: f64, z: Complex<f64>, kode: i32, n: i32) -> Vec<Complex<f64>> {
+    let mut cyr = vec![0.0; n as usize];
+    let mut cyi = vec![0.0; n as usize];
+    let mut nz: c_int = 0;
+    let mut ierr: c_int = 0;
+
+    unsafe {
+        amos_c_zbesi_(
+            &z.re,
+            &z.im,
+            &nu,
+            &kode,
+            &n,
+            cyr.as_mut_ptr(),
+            cyi.as_mut_ptr(),
+            &mut nz,
+            &mut ierr,
+        );
+    }
+
+    assert_eq!(ierr, 0);
+
+    cyr.iter()
+        .zip(cyi.iter())
+        .map(|(&re, &im)| Complex::new(re, im))
+        .collect()
+}
+
+// Compares the cases of a function in tests/dat, where cy is the member n_index of the sequence
+// starting at order nu. Cases with negative orders are skipped, see above.
+fn test_complex<F, G>(path: &str, function: &str, linked: F, translated: G)
+where
+    F: Fn(f64, Complex<f64>, i32) -> Vec<Complex<f64>>,
+    G: Fn(f64, Complex<f64>, i32) -> Vec<Complex<f64>>,
+{
+    let cases = common::read_function(path, function);
+    assert!(!cases.is_empty());
+
+    for case in cases.iter().filter(|case| case.nu >= 0.0) {
+        let (nu, z, j) = (case.nu, case.z, case.n_index);
+
+        let cy_linked = linked(nu, z, j as i32 + 1)[j];
+        let cy_translated = translated(nu, z, j as i32 + 1)[j];
+
+        for cy in [cy_linked, cy_translated] {
+            assert!(
+                relative_error(cy, case.expected) <= case.tolerance,
+                "{case:?}: {cy}"
+            );
+        }
+
+        assert!(
+            relative_error(cy_linked, cy_translated) <= 1e-14,
+            "{case:?}: {cy_linked} and {cy_translated} differ"
+        );
+    }
+}
+
+// As test_complex for the functions of real arguments
+fn test_real<F, G>(path: &str, function: &str, linked: F, translated: G)
+where
+    F: Fn(f64, f64, i32) -> Vec<f64>,
+    G: Fn(f64, f64, i32) -> Vec<f64>,
+{
+    test_complex(
+        path,
+        function,
+        |nu, z, n| linked(nu, z.re, n).into_iter().map(Complex::from).collect(),
+        |nu, z, n| {
+            translated(nu, z.re, n)
+                .into_iter()
+                .map(Complex::from)
+                .collect()
+        },
+    );
+}
+
+#[test]
+fn test_backends_hankel() {
+    test_complex(ZBESH, "hankel1", hankel1, |nu, z, n| {
+        c_zbesh(nu, z, 1, 1, n)
+    });
+    test_complex(ZBESH, "hankel1e", hankel1e, |nu, z, n| {
+        c_zbesh(nu, z, 2, 1, n)
+    });
+    test_complex(ZBESH, "hankel2", hankel2, |nu, z, n| {
+        c_zbesh(nu, z, 1, 2, n)
+    });
+    test_complex(ZBESH, "hankel2e", hankel2e, |nu, z, n| {
+        c_zbesh(nu, z, 2, 2, n)
+    });
+}
+
+#[test]
+fn test_backends_iv() {
+    test_complex(ZBESI, "iv", iv_vec, |nu, z, n| c_zbesi(nu, z, 1, n));
+    test_complex(ZBESI, "ive", ive_vec, |nu, z, n| c_zbesi(nu, z, 2, n));
+}
+
+#[test]
+fn test_backends_iv_real() {
+    let translated = |kode: i32| {
+        move |nu: f64, x: f64, n: i32| -> Vec<f64> {
+            c_zbesi(nu, Complex::new(x, 0.0), kode, n)
+                .iter()
+                .map(|cy| cy.re)
+                .collect()
+        }
+    };
+
+    test_real(ZBESI, "iv_real", iv_real_vec, translated(1));
+    test_real(ZBESI, "ive_real", ive_real_vec, translated(2));
+}
@@ -0,0 +1,169 @@
+use amos_rs::cephes;
+use amos_rs::zbesh::{hankel1, hankel1e};
+use amos_rs::zbesi::{iv_real, ive_real};
+use amos_rs::zbesj::jv_real;
+use amos_rs::zbesy::yv_real;
+use num::complex::Complex;
+
+// Dense grid covering both the small and large argument intervals of every approximation
+fn get_grid() -> Vec<f64> {
+    let mut x: Vec<f64> = (1..=4000).map(|i| (i as f64) * 0.01).collect();
+    x.extend((1..=200).map(|i| 40.0 + (i as f64) * 1.5));
+
+    x
+}
+
+// K_nu(x) = (pi / 2) i^(nu + 1) H^(1)_nu(ix) and similarly for the scaled functions as
+// hankel1e(nu, ix) = exp(x) H^(1)_nu(ix).
+fn kv_amos(nu: i32, x: f64, scaled: bool) -> f64 {
+    let z = Complex::new(0.0, x);
+    let h = match scaled {
+        false => hankel1(nu as f64, z, 1)[0],
+        true => hankel1e(nu as f64, z, 1)[0],
+    };
+
+    (std::f64::consts::FRAC_PI_2 * Complex::i().powi(nu + 1) * h).re
+}
+
+fn assert_close(name: &str, x: f64, cy: f64, cy_t: f64) {
+    assert!(
+        approx::relative_eq!(cy, cy_t, epsilon = 1e-15, max_relative = 1e-13),
+        "{}({}) = {}, expected {}",
+        name,
+        x,
+        cy,
+        cy_t
+    );
+}
+
+#[test]
+fn test_i0_i1() {
+    for x in get_grid() {
+        for x in [x, -x] {
+            if x.abs() < 600.0 {
+                assert_close("i0", x, cephes::i0(x), iv_real(0.0, x));
+                assert_close("i1", x, cephes::i1(x), iv_real(1.0, x));
+            }
+
+            assert_close("i0e", x, cephes::i0e(x), ive_real(0.0, x));
+            assert_close("i1e", x, cephes::i1e(x), ive_real(1.0, x));
+        }
+    }
+}
+
+#[test]
+fn test_k0_k1() {
+    for x in get_grid() {
+        if x < 600.0 {
+            assert_close("k0", x, cephes::k0(x), kv_amos(0, x, false));
+            assert_close("k1", x, cephes::k1(x), kv_amos(1, x, false));
+        }
+
+        assert_close("k0e", x, cephes::k0e(x), kv_amos(0, x, true));
+        assert_close("k1e", x, cephes::k1e(x), kv_amos(1, x, true));
+    }
+
+    assert_eq!(cephes::k0(0.0), f64::INFINITY);
+    assert_eq!(cephes::k1e(0.0), f64::INFINITY);
+    assert!(cephes::k0(-1.0).is_nan());
+    assert!(cephes::k1e(-1.0).is_nan());
+}
+
+#[test]
+fn test_j0_j1_y0_y1() {
+    for x in get_grid() {
+        // H^(1)_n(x) = J_n(x) + i Y_n(x) for real x. As J and Y oscillate, the errors are measured
+        // relative to the envelope sqrt(2 / (pi x)) rather than the function values.
+        let h0 = hankel1(0.0, Complex::new(x, 0.0), 2);
+        let scale = (2.0 / (std::f64::consts::PI * x)).sqrt().min(1.0);
+
+        for (name, cy, cy_t) in [
+            ("j0", cephes::j0(x), h0[0].re),
+            ("j1", cephes::j1(x), h0[1].re),
+            ("y0", cephes::y0(x), h0[0].im),
+            ("y1", cephes::y1(x), h0[1].im),
+        ] {
+            assert_close(name, x, cy / scale, cy_t / scale);
+        }
+
+        assert_eq!(cephes::j0(-x), cephes::j0(x));
+        assert_eq!(cephes::j1(-x), -cephes::j1(x));
+    }
+
+    assert_eq!(cephes::y0(0.0), f64::NEG_INFINITY);
+    assert_eq!(cephes::y1(0.0), f64::NEG_INFINITY);
+    assert!(cephes::y0(-1.0).is_nan());
+    assert!(cephes::y1(-1.0).is_nan());
+
+    // The functions decay to 0 at infinity, as in scipy.special
+    for f in [cephes::j0, cephes::j1, cephes::y0, cephes::y1] {
+        assert_eq!(f(f64::INFINITY), 0.0);
+    }
+
+    assert_eq!(cephes::j0(f64::NEG_INFINITY), 0.0);
+    assert_eq!(cephes::j1(f64::NEG_INFINITY), 0.0);
+}
+
+#[test]
+fn test_jn_yn() {
+    let x: Vec<f64> = (1..=600).map(|i| (i as f64) * 0.1).collect();
+
+    for n in (-40..=40i32).chain([75, 150, 400]) {
+        for &x in &x {
+            // J and Y are compared relative to the envelope in the oscillatory region x > n, where
+            // AMOS itself loses a few digits close to the zeros
+            let scale = match x > (n.abs() as f64) {
+                true => (2.0 / (std::f64::consts::PI * x)).sqrt().min(1.0),
+                false => 1.0,
+            };
+
+            let cy_t = jv_real(n as f64, x);
+            let cy = cephes::jn(n, x);
+
+            if cy_t.abs() > 1e-290 {
+                assert!(
+                    approx::relative_eq!(cy, cy_t, epsilon = 5e-14 * scale, max_relative = 1e-12),
+                    "jn({}, {}) = {}, expected {}",
+                    n,
+                    x,
+                    cy,
+                    cy_t
+                );
+            }
+
+            let cy_t = yv_real(n as f64, x);
+            let cy = cephes::yn(n, x);
+
+            if cy_t != 0.0 && cy_t.abs() < 1e290 {
+                assert!(
+                    approx::relative_eq!(cy, cy_t, epsilon = 5e-14 * scale, max_relative = 1e-12),
+                    "yn({}, {}) = {}, expected {}",
+                    n,
+                    x,
+                    cy,
+                    cy_t
+                );
+            }
+        }
+
+        // jn(n, -x) = (-1)^n jn(n, x)
+        let sign = if n % 2 == 0 { 1.0 } else { -1.0 };
+        assert_eq!(cephes::jn(n, -2.5), sign * cephes::jn(n, 2.5));
+    }
+
+    assert_eq!(cephes::yn(2, 0.0), f64::NEG_INFINITY);
+    assert_eq!(cephes::yn(300, 1.0), f64::NEG_INFINITY);
+    assert!(cephes::yn(2, -1.0).is_nan());
+    assert_eq!(cephes::jn(300, 1.0), 0.0);
+
+    // The forward recurrences from orders 0 and 1 give 0 at infinity
+    assert_eq!(cephes::jn(7, f64::INFINITY), 0.0);
+    assert_eq!(cephes::yn(-7, f64::INFINITY), 0.0);
+    assert_eq!(cephes::jn(2000, f64::INFINITY), 0.0);
+    assert_eq!(cephes::yn(2000, f64::INFINITY), 0.0);
+
+    // Above the recurrence, yn overflows to -inf for x well below n, and is finite close to n
+    assert_eq!(cephes::yn(2000, 1.0), f64::NEG_INFINITY);
+    assert_eq!(cephes::yn(-2001, 1.0), f64::INFINITY);
+    assert!(cephes::yn(100_000_000, 99_999_999.0).is_finite());
+}
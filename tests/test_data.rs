@@ -0,0 +1,169 @@
+// Drives every function family from the CSV files in tests/dat, see tests/common/mod.rs for the
+// format. A new data file is picked up without changes here, as long as its functions are listed
+// in function below. The maximum relative error is reported per function and source, run with
+// `cargo test --test test_data -- --nocapture` to see them all.
+mod common;
+
+use amos_rs::{bessel_ratio, log_bessel, zairy, zbesh, zbesi, zbesj, zbesk, zbesy};
+use common::{relative_error, Case};
+use num::complex::Complex;
+use std::collections::BTreeMap;
+
+type Named = fn(f64, Complex<f64>) -> Complex<f64>;
+type NamedVec = fn(f64, Complex<f64>, i32) -> Vec<Complex<f64>>;
+type Real = fn(f64, f64) -> f64;
+type RealVec = fn(f64, f64, i32) -> Vec<f64>;
+
+enum Function {
+    // The sequence functions, along with the function for the first member where there is one
+    Sequence(NamedVec, Option<Named>),
+    RealSequence(RealVec, Real),
+    Scalar(Named),
+    RealScalar(Real),
+    Airy(fn(Complex<f64>) -> Complex<f64>),
+}
+
+fn function(name: &str) -> Function {
+    use Function::*;
+
+    match name {
+        "iv" => Sequence(zbesi::iv_vec, Some(zbesi::iv)),
+        "ive" => Sequence(zbesi::ive_vec, Some(zbesi::ive)),
+        "jv" => Sequence(zbesj::jv_vec, Some(zbesj::jv)),
+        "jve" => Sequence(zbesj::jve_vec, Some(zbesj::jve)),
+        "kv" => Sequence(zbesk::kv_vec, Some(zbesk::kv)),
+        "kve" => Sequence(zbesk::kve_vec, Some(zbesk::kve)),
+        "yv" => Sequence(zbesy::yv_vec, Some(zbesy::yv)),
+        "yve" => Sequence(zbesy::yve_vec, Some(zbesy::yve)),
+        "hankel1" => Sequence(zbesh::hankel1, None),
+        "hankel1e" => Sequence(zbesh::hankel1e, None),
+        "hankel2" => Sequence(zbesh::hankel2, None),
+        "hankel2e" => Sequence(zbesh::hankel2e, None),
+        "iv_real" => RealSequence(zbesi::iv_real_vec, zbesi::iv_real),
+        "ive_real" => RealSequence(zbesi::ive_real_vec, zbesi::ive_real),
+        "jv_real" => RealSequence(zbesj::jv_real_vec, zbesj::jv_real),
+        "jve_real" => RealSequence(zbesj::jve_real_vec, zbesj::jve_real),
+        "kv_real" => RealSequence(zbesk::kv_real_vec, zbesk::kv_real),
+        "kve_real" => RealSequence(zbesk::kve_real_vec, zbesk::kve_real),
+        "yv_real" => RealSequence(zbesy::yv_real_vec, zbesy::yv_real),
+        "yve_real" => RealSequence(zbesy::yve_real_vec, zbesy::yve_real),
+        "log_hankel1" => Scalar(log_bessel::log_hankel1),
+        "log_hankel2" => Scalar(log_bessel::log_hankel2),
+        "log_iv" => RealScalar(log_bessel::log_iv),
+        "log_kv" => RealScalar(log_bessel::log_kv),
+        "iv_ratio" => RealScalar(bessel_ratio::iv_ratio),
+        "kv_ratio" => RealScalar(bessel_ratio::kv_ratio),
+        "ai" => Airy(zairy::ai),
+        "aie" => Airy(zairy::aie),
+        "aip" => Airy(zairy::aip),
+        "aipe" => Airy(zairy::aipe),
+        "bi" => Airy(zairy::bi),
+        "bie" => Airy(zairy::bie),
+        "bip" => Airy(zairy::bip),
+        "bipe" => Airy(zairy::bipe),
+        _ => panic!("Unknown function {name}"),
+    }
+}
+
+// Evaluates the function of a case, where the member n_index of a sequence is taken from the
+// sequence of n_index + 1 members, whose first member is checked against the scalar function
+fn evaluate(case: &Case) -> Complex<f64> {
+    let (nu, z, n) = (case.nu, case.z, case.n_index as i32 + 1);
+    let real = |cy: f64| Complex::new(cy, 0.0);
+
+    let function = function(&case.function);
+
+    if !matches!(
+        function,
+        Function::Sequence(..) | Function::RealSequence(..)
+    ) {
+        assert_eq!(case.n_index, 0, "{case:?}: not a sequence");
+    }
+
+    let (cy, first) = match function {
+        Function::Sequence(vec, scalar) => {
+            let cy = vec(nu, z, n);
+            assert_eq!(cy.len(), n as usize, "{case:?}");
+
+            (cy[n as usize - 1], scalar.map(|f| (cy[0], f(nu, z))))
+        }
+        Function::RealSequence(vec, scalar) => {
+            let cy = vec(nu, z.re, n);
+            assert_eq!(cy.len(), n as usize, "{case:?}");
+
+            (
+                real(cy[n as usize - 1]),
+                Some((real(cy[0]), real(scalar(nu, z.re)))),
+            )
+        }
+        Function::Scalar(f) => (f(nu, z), None),
+        Function::RealScalar(f) => (real(f(nu, z.re)), None),
+        Function::Airy(f) => (f(z), None),
+    };
+
+    // AMOS computes the members of longer sequences by recurrence, so the first member may differ
+    // from the function in the last digits
+    if let Some((cy_vec, cy_scalar)) = first {
+        assert!(
+            relative_error(cy_scalar, cy_vec) <= case.tolerance,
+            "{case:?}: the first member {cy_vec} differs from {cy_scalar}"
+        );
+    }
+
+    cy
+}
+
+#[test]
+fn test_data() {
+    let mut paths: Vec<_> = std::fs::read_dir("./tests/dat")
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "csv"))
+        .collect();
+    paths.sort();
+
+    assert!(!paths.is_empty());
+
+    // Maximum relative error per function and source, along with the case where it occurs
+    let mut errors: BTreeMap<(String, String), (f64, Case)> = BTreeMap::new();
+    let mut failures = Vec::new();
+
+    for path in paths {
+        for case in common::read(path.to_str().unwrap()) {
+            let cy = evaluate(&case);
+            let err = relative_error(cy, case.expected);
+
+            if err.is_nan() || err > case.tolerance {
+                failures.push(format!(
+                    "{}: {} at nu = {}, n_index = {}, z = {} is {cy:e}, expected {:e} from {}, \
+                     relative error {err:e} exceeds {:e}",
+                    path.display(),
+                    case.function,
+                    case.nu,
+                    case.n_index,
+                    case.z,
+                    case.expected,
+                    case.source,
+                    case.tolerance
+                ));
+            }
+
+            let key = (case.function.clone(), case.source.clone());
+            let entry = errors.entry(key).or_insert((0.0, case.clone()));
+
+            // NaN is kept as the worst error
+            if err.is_nan() || err > entry.0 {
+                *entry = (err, case);
+            }
+        }
+    }
+
+    for ((function, source), (err, case)) in &errors {
+        println!(
+            "{function:<10} {source:<8} {err:.3e} at nu = {}, z = {}",
+            case.nu, case.z
+        );
+    }
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
@@ -0,0 +1,38 @@
+// Smoke test of the AMOS build, run for each installed Fortran compiler by
+// scripts/smoke-test-compilers.sh. The pure-Rust functions in amos_rs::cephes serve as an
+// independent reference, so this catches runtime libraries or calling conventions that do not
+// match the compiler.
+use amos_rs::cephes;
+use amos_rs::zbesh::hankel1;
+use amos_rs::zbesi::{iv_real, iv_real_vec};
+use amos_rs::zbesj::jv_real;
+use amos_rs::zbesk::kv_real;
+use amos_rs::zbesy::yv_real;
+use approx::assert_relative_eq;
+use num::complex::Complex;
+
+#[test]
+fn test_fortran_compiler() {
+    println!(
+        "AMOS built with Fortran compiler: {}",
+        option_env!("AMOS_FORTRAN_COMPILER_ID").unwrap_or("unknown")
+    );
+
+    for x in [0.3, 1.0, 4.7, 25.0] {
+        assert_relative_eq!(iv_real(0.0, x), cephes::i0(x), max_relative = 1e-13);
+        assert_relative_eq!(kv_real(1.0, x), cephes::k1(x), max_relative = 1e-13);
+        assert_relative_eq!(jv_real(0.0, x), cephes::j0(x), epsilon = 1e-13);
+        assert_relative_eq!(yv_real(1.0, x), cephes::y1(x), epsilon = 1e-13);
+
+        // Sequences are returned through arrays
+        let cy = iv_real_vec(0.0, x, 2);
+
+        assert_relative_eq!(cy[0], cephes::i0(x), max_relative = 1e-13);
+        assert_relative_eq!(cy[1], cephes::i1(x), max_relative = 1e-13);
+
+        let cy = hankel1(1.0, Complex::new(x, 0.0), 1)[0];
+
+        assert_relative_eq!(cy.re, cephes::j1(x), epsilon = 1e-13);
+        assert_relative_eq!(cy.im, cephes::y1(x), epsilon = 1e-13);
+    }
+}
@@ -0,0 +1,218 @@
+// Property-based tests of identities between the functions over random orders and arguments. An
+// identity sum_i t_i = 0 evaluated with relative errors eps in each term has a residual of up to
+// eps sum_i |t_i|, where sum_i |t_i| / |t_0| is the condition number of the sum. The functions
+// themselves are computed to a relative accuracy of about eps max(1, |z|, |nu|), as AMOS loses
+// digits in the argument reduction, see amos/zbesi.f lines 92-98. For negative orders the
+// reflection formulas multiply by sin(nu pi), and for J and Y by cos(nu pi) as well, whose
+// condition numbers |nu pi cot(nu pi)| and |nu pi tan(nu pi)| are large close to the integers and
+// half-integers. The tolerance is therefore TOL (1 + |z| + |nu| + kappa) sum_i |t_i|, where kappa
+// is the largest condition number of the factors used for the orders involved.
+use amos_rs::zbesh::{hankel1, hankel2};
+use amos_rs::zbesi::iv;
+use amos_rs::zbesj::jv;
+use amos_rs::zbesk::kv;
+use amos_rs::zbesy::yv;
+use num::complex::Complex;
+use proptest::prelude::*;
+use std::f64::consts::PI;
+
+const TOL: f64 = 1e-13;
+
+fn h1(nu: f64, z: Complex<f64>) -> Complex<f64> {
+    hankel1(nu, z, 1)[0]
+}
+
+fn h2(nu: f64, z: Complex<f64>) -> Complex<f64> {
+    hankel2(nu, z, 1)[0]
+}
+
+// The factors of the reflection formulas, see test_reflection, where K is reflected without any and
+// H^(1) and H^(2) by a rotation, whose error is covered by the |nu| of the tolerance
+#[derive(Clone, Copy)]
+enum Reflection {
+    None,
+    Sin,
+    SinCos,
+}
+
+fn is_half_integer_multiple(nu: f64) -> bool {
+    (2.0 * nu).fract() == 0.0
+}
+
+// sin(nu pi) and cos(nu pi), which are exact for integers and half-integers, as in the wrappers
+fn sin_cos_pi(nu: f64) -> (f64, f64) {
+    let (sin, cos) = (PI * nu).sin_cos();
+
+    match is_half_integer_multiple(nu) {
+        true => (sin.round(), cos.round()),
+        false => (sin, cos),
+    }
+}
+
+// Condition number of the factors of the reflection for the negative orders among nu - 1 to nu + 1,
+// which is zero for integers and half-integers, as the wrappers use the exact factors for these
+fn reflection_condition(nu: f64, reflection: Reflection) -> f64 {
+    (-1..=1)
+        .map(|k| nu + k as f64)
+        .filter(|&nu| nu < 0.0 && !is_half_integer_multiple(nu))
+        .map(|nu| {
+            let (sin, cos) = (PI * nu).sin_cos();
+            let (cot, tan) = ((nu * PI * cos / sin).abs(), (nu * PI * sin / cos).abs());
+
+            match reflection {
+                Reflection::None => 0.0,
+                Reflection::Sin => cot,
+                Reflection::SinCos => cot.max(tan),
+            }
+        })
+        .fold(0.0, f64::max)
+}
+
+// Checks that the terms sum to zero, skipping cases where any of them overflows
+fn check_identity(
+    name: &str,
+    nu: f64,
+    z: Complex<f64>,
+    reflection: Reflection,
+    terms: &[Complex<f64>],
+) -> Result<(), TestCaseError> {
+    prop_assume!(terms.iter().all(|t| t.is_finite()));
+
+    let residual = terms.iter().sum::<Complex<f64>>().norm();
+    let scale: f64 = terms.iter().map(|t| t.norm()).sum();
+    let tol = TOL * (1.0 + z.norm() + nu.abs() + reflection_condition(nu, reflection)) * scale;
+
+    prop_assert!(
+        residual <= tol,
+        "{name}: residual {residual:e} exceeds {tol:e} at nu = {nu}, z = {z}"
+    );
+
+    Ok(())
+}
+
+// Orders in [-25, 25], with integers, half-integers and zero drawn as well, since the reflection
+// formulas treat them separately
+fn order() -> impl Strategy<Value = f64> {
+    prop_oneof![
+        -25.0..25.0,
+        (-25..=25).prop_map(|n| n as f64),
+        (-25..=25).prop_map(|n| n as f64 + 0.5),
+    ]
+}
+
+// Arguments with |z| in [0.01, 50], away from the cut along the negative real axis, where the
+// functions of conj(z) are on the other side of the cut
+fn argument() -> impl Strategy<Value = Complex<f64>> {
+    (-2.0..1.7f64, -PI + 1e-3..PI - 1e-3)
+        .prop_map(|(log10_r, theta)| Complex::from_polar(10.0f64.powf(log10_r), theta))
+}
+
+proptest! {
+    // I_nu K'_nu - I'_nu K_nu = -1 / z, with I'_nu = I_{nu + 1} + (nu / z) I_nu and
+    // K'_nu = -K_{nu + 1} + (nu / z) K_nu, see DLMF 10.28 and 10.29
+    #[test]
+    fn test_wronskian_ik(nu in order(), z in argument()) {
+        let (i, i_1) = (iv(nu, z), iv(nu + 1.0, z));
+        let (k, k_1) = (kv(nu, z), kv(nu + 1.0, z));
+
+        let di = i_1 + nu / z * i;
+        let dk = -k_1 + nu / z * k;
+
+        check_identity("I K' - I' K", nu, z, Reflection::Sin, &[i * dk, -di * k, 1.0 / z])?;
+    }
+
+    // J_nu Y'_nu - J'_nu Y_nu = 2 / (pi z), with C'_nu = C_{nu - 1} - (nu / z) C_nu, see DLMF
+    // 10.5 and 10.6
+    #[test]
+    fn test_wronskian_jy(nu in order(), z in argument()) {
+        let (j, j_1) = (jv(nu, z), jv(nu - 1.0, z));
+        let (y, y_1) = (yv(nu, z), yv(nu - 1.0, z));
+
+        let dj = j_1 - nu / z * j;
+        let dy = y_1 - nu / z * y;
+
+        let terms = [j * dy, -dj * y, -2.0 / (PI * z)];
+        check_identity("J Y' - J' Y", nu, z, Reflection::SinCos, &terms)?;
+    }
+
+    // C_{nu - 1} + C_{nu + 1} = (2 nu / z) C_nu for C = J, Y, H^(1) and H^(2), see DLMF 10.6
+    #[test]
+    fn test_recurrence_jyh(nu in order(), z in argument()) {
+        for (name, f, reflection) in [
+            ("J", jv as fn(f64, Complex<f64>) -> Complex<f64>, Reflection::SinCos),
+            ("Y", yv, Reflection::SinCos),
+            ("H1", h1, Reflection::None),
+            ("H2", h2, Reflection::None),
+        ] {
+            let terms = [f(nu - 1.0, z), f(nu + 1.0, z), -2.0 * nu / z * f(nu, z)];
+
+            check_identity(name, nu, z, reflection, &terms)?;
+        }
+    }
+
+    // I_{nu - 1} - I_{nu + 1} = (2 nu / z) I_nu and K_{nu - 1} - K_{nu + 1} = -(2 nu / z) K_nu,
+    // see DLMF 10.29
+    #[test]
+    fn test_recurrence_ik(nu in order(), z in argument()) {
+        let terms = [iv(nu - 1.0, z), -iv(nu + 1.0, z), -2.0 * nu / z * iv(nu, z)];
+        check_identity("I", nu, z, Reflection::Sin, &terms)?;
+
+        let terms = [kv(nu - 1.0, z), -kv(nu + 1.0, z), 2.0 * nu / z * kv(nu, z)];
+        check_identity("K", nu, z, Reflection::None, &terms)?;
+    }
+
+    // J_{-nu} = cos(nu pi) J_nu - sin(nu pi) Y_nu, Y_{-nu} = sin(nu pi) J_nu + cos(nu pi) Y_nu,
+    // I_{-nu} = I_nu + (2 / pi) sin(nu pi) K_nu, K_{-nu} = K_nu,
+    // H^(1)_{-nu} = exp(i nu pi) H^(1)_nu and H^(2)_{-nu} = exp(-i nu pi) H^(2)_nu, see DLMF 10.4
+    // and 10.27
+    #[test]
+    fn test_reflection(nu in order(), z in argument()) {
+        // The orders are reflected from nu >= 0 to -nu, for which the condition applies
+        let nu = nu.abs();
+        let (sin, cos) = sin_cos_pi(nu);
+        let (j, y) = (jv(nu, z), yv(nu, z));
+
+        check_identity("J", -nu, z, Reflection::SinCos, &[jv(-nu, z), -cos * j, sin * y])?;
+        check_identity("Y", -nu, z, Reflection::SinCos, &[yv(-nu, z), -sin * j, -cos * y])?;
+
+        let terms = [iv(-nu, z), -iv(nu, z), -2.0 / PI * sin * kv(nu, z)];
+        check_identity("I", -nu, z, Reflection::Sin, &terms)?;
+        check_identity("K", -nu, z, Reflection::None, &[kv(-nu, z), -kv(nu, z)])?;
+
+        let rotation = Complex::new(cos, sin);
+        let terms = [h1(-nu, z), -rotation * h1(nu, z)];
+        check_identity("H1", -nu, z, Reflection::None, &terms)?;
+
+        let terms = [h2(-nu, z), -rotation.conj() * h2(nu, z)];
+        check_identity("H2", -nu, z, Reflection::None, &terms)?;
+    }
+
+    // f(conj(z)) = conj(f(z)) for f = I, J, K and Y of real order, and
+    // H^(1)_nu(conj(z)) = conj(H^(2)_nu(z))
+    #[test]
+    fn test_conjugate_symmetry(nu in order(), z in argument()) {
+        for (name, f, reflection) in [
+            ("I", iv as fn(f64, Complex<f64>) -> Complex<f64>, Reflection::Sin),
+            ("J", jv, Reflection::SinCos),
+            ("K", kv, Reflection::None),
+            ("Y", yv, Reflection::SinCos),
+        ] {
+            let terms = [f(nu, z.conj()), -f(nu, z).conj()];
+
+            check_identity(name, nu, z, reflection, &terms)?;
+        }
+
+        let terms = [h1(nu, z.conj()), -h2(nu, z).conj()];
+        check_identity("H1", nu, z, Reflection::None, &terms)?;
+    }
+
+    // H^(1)_nu = J_nu + i Y_nu and H^(2)_nu = J_nu - i Y_nu, see DLMF 10.4
+    #[test]
+    fn test_hankel(nu in order(), z in argument()) {
+        let (j, y) = (jv(nu, z), yv(nu, z));
+        let i = Complex::new(0.0, 1.0);
+
+        check_identity("H1", nu, z, Reflection::SinCos, &[h1(nu, z), -j, -i * y])?;
+        check_identity("H2", nu, z, Reflection::SinCos, &[h2(nu, z), -j, i * y])?;
+    }
+}
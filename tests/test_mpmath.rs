@@ -0,0 +1,194 @@
+// Compares against the reference values in tests/dat/mpmath.csv, computed with mpmath to at least
+// 40 digits by create-test-data/create_mpmath_test_data.py, independently of AMOS and hence of
+// SciPy.
+// The maximum relative error is reported per region of orders and arguments, run with
+// `cargo test --test test_mpmath -- --nocapture` to see them all.
+mod common;
+
+use amos_rs::zairy::{ai, aip, bi, bip};
+use amos_rs::zbesh::{hankel1, hankel2};
+use amos_rs::zbesi::iv;
+use amos_rs::zbesj::jv;
+use amos_rs::zbesk::kv;
+use amos_rs::zbesy::yv;
+use common::relative_error;
+use num::complex::Complex;
+use std::collections::BTreeMap;
+
+const DATA: &str = "./tests/dat/mpmath.csv";
+
+fn bessel_region(nu: f64, z: Complex<f64>) -> &'static str {
+    let r = z.norm();
+
+    if r <= 1.0 {
+        "|z| <= 1"
+    } else if r < nu.abs() {
+        "1 < |z| < |nu|"
+    } else if r <= 20.0 {
+        "|nu| <= |z| <= 20"
+    } else {
+        "|z| > 20"
+    }
+}
+
+fn airy_region(z: Complex<f64>) -> &'static str {
+    let r = z.norm();
+
+    if r <= 1.0 {
+        "|z| <= 1"
+    } else if r <= 5.0 {
+        "1 < |z| <= 5"
+    } else {
+        "|z| > 5"
+    }
+}
+
+// Maximum relative error per region, along with the order and argument where it occurs
+#[derive(Default)]
+struct RegionErrors {
+    regions: BTreeMap<&'static str, (f64, f64, Complex<f64>)>,
+}
+
+impl RegionErrors {
+    fn add(
+        &mut self,
+        region: &'static str,
+        nu: f64,
+        z: Complex<f64>,
+        cy: Complex<f64>,
+        cy_t: Complex<f64>,
+    ) {
+        let err = relative_error(cy, cy_t);
+
+        let entry = self.regions.entry(region).or_insert((0.0, nu, z));
+
+        // NaN is kept as the worst error
+        if err.is_nan() || err > entry.0 {
+            *entry = (err, nu, z);
+        }
+    }
+
+    fn check(&self, name: &str, tolerances: &[(&str, f64)]) {
+        for (region, &(err, nu, z)) in &self.regions {
+            println!("{name:<8} {region:<18} {err:.3e} at nu = {nu}, z = {z}");
+        }
+
+        for &(region, tol) in tolerances {
+            let (err, nu, z) = self.regions[region];
+
+            assert!(
+                err <= tol,
+                "{name}: relative error {err:e} at nu = {nu}, z = {z} exceeds {tol:e} for {region}"
+            );
+        }
+    }
+}
+
+// The tolerance column of the data file, which tests/test_data.rs checks against, is written from
+// the same tables by the generator
+fn check_tolerance(case: &common::Case, region: &str, tolerances: &[(&str, f64)]) {
+    let &(_, tol) = tolerances.iter().find(|&&(r, _)| r == region).unwrap();
+
+    assert_eq!(
+        case.tolerance, tol,
+        "tolerance of {} at nu = {}, z = {} in {DATA} differs from {region}",
+        case.function, case.nu, case.z
+    );
+}
+
+fn check_bessel(name: &str, f: fn(f64, Complex<f64>) -> Complex<f64>, tolerances: &[(&str, f64)]) {
+    let mut errors = RegionErrors::default();
+
+    for case in common::read_function(DATA, name) {
+        let (nu, z) = (case.nu, case.z);
+        let region = bessel_region(nu, z);
+
+        check_tolerance(&case, region, tolerances);
+        errors.add(region, nu, z, f(nu, z), case.expected);
+    }
+
+    errors.check(name, tolerances);
+}
+
+fn check_airy(name: &str, f: fn(Complex<f64>) -> Complex<f64>, tolerances: &[(&str, f64)]) {
+    let mut errors = RegionErrors::default();
+
+    for case in common::read_function(DATA, name) {
+        let z = case.z;
+        let region = airy_region(z);
+
+        check_tolerance(&case, region, tolerances);
+        errors.add(region, 0.0, z, f(z), case.expected);
+    }
+
+    errors.check(name, tolerances);
+}
+
+// Each tolerance is about twice the largest error measured in its region, over all functions and
+// both backends, and is written to the data file by create-test-data/create_mpmath_test_data.py.
+// AMOS loses accuracy for large arguments and orders, see amos/zbesi.f lines 89-98, which shows up
+// in the region between |z| = 1 and |nu|
+const BESSEL_TOLERANCES: [(&str, f64); 4] = [
+    ("|z| <= 1", 6e-14),
+    ("1 < |z| < |nu|", 1.2e-13),
+    ("|nu| <= |z| <= 20", 8e-14),
+    ("|z| > 20", 7e-14),
+];
+
+// The power series used for |z| <= 1 is accurate to a few ulps. For larger |z| the error grows
+// with log10(|z|), see amos/zairy.f lines 92-97, and peaks on the negative real axis
+const AIRY_TOLERANCES: [(&str, f64); 3] = [
+    ("|z| <= 1", 2e-15),
+    ("1 < |z| <= 5", 4e-13),
+    ("|z| > 5", 5e-13),
+];
+
+#[test]
+fn test_mpmath_iv() {
+    check_bessel("iv", iv, &BESSEL_TOLERANCES);
+}
+
+#[test]
+fn test_mpmath_kv() {
+    check_bessel("kv", kv, &BESSEL_TOLERANCES);
+}
+
+#[test]
+fn test_mpmath_jv() {
+    check_bessel("jv", jv, &BESSEL_TOLERANCES);
+}
+
+#[test]
+fn test_mpmath_yv() {
+    check_bessel("yv", yv, &BESSEL_TOLERANCES);
+}
+
+#[test]
+fn test_mpmath_hankel1() {
+    check_bessel("hankel1", |nu, z| hankel1(nu, z, 1)[0], &BESSEL_TOLERANCES);
+}
+
+#[test]
+fn test_mpmath_hankel2() {
+    check_bessel("hankel2", |nu, z| hankel2(nu, z, 1)[0], &BESSEL_TOLERANCES);
+}
+
+#[test]
+fn test_mpmath_ai() {
+    check_airy("ai", ai, &AIRY_TOLERANCES);
+}
+
+#[test]
+fn test_mpmath_aip() {
+    check_airy("aip", aip, &AIRY_TOLERANCES);
+}
+
+#[test]
+fn test_mpmath_bi() {
+    check_airy("bi", bi, &AIRY_TOLERANCES);
+}
+
+#[test]
+fn test_mpmath_bip() {
+    check_airy("bip", bip, &AIRY_TOLERANCES);
+}
@@ -0,0 +1,234 @@
+// Compares the Rust port of AMOS behind the pure-rust feature against the linked AMOS backend,
+// which is still built on the host. The grid covers the power series, the asymptotic expansion for
+// large |z|, the Miller algorithm, the uniform asymptotic expansions for large orders, which are
+// in terms of the Airy functions, and the analytic continuation into the left half plane.
+#![cfg(feature = "pure-rust")]
+
+use amos_rs::zbesh::zbesh;
+use amos_rs::zbesi::zbesi_vec;
+use amos_rs::zbesj::zbesj_vec;
+use amos_rs::zbesk::zbesk_vec;
+use amos_rs::zbesy::zbesy_vec;
+use num::complex::Complex;
+use std::f64::consts::{FRAC_PI_2, PI};
+use std::os::raw::{c_double, c_int};
+
+extern "C" {
+    fn zbesh_(
+        zr: *const c_double,
+        zi: *const c_double,
+        fnu: *const c_double,
+        kode: *const c_int,
+        m: *const c_int,
+        n: *const c_int,
+        cyr: *mut c_double,
+        cyi: *mut c_double,
+        nz: *mut c_int,
+        ierr: *mut c_int,
+    );
+
+    fn zbesi_(
+        zr: *const c_double,
+        zi: *const c_double,
+        fnu: *const c_double,
+        kode: *const c_int,
+        n: *const c_int,
+        cyr: *mut c_double,
+        cyi: *mut c_double,
+        nz: *mut c_int,
+        ierr: *mut c_int,
+    );
+
+    fn zbesj_(
+        zr: *const c_double,
+        zi: *const c_double,
+        fnu: *const c_double,
+        kode: *const c_int,
+        n: *const c_int,
+        cyr: *mut c_double,
+        cyi: *mut c_double,
+        nz: *mut c_int,
+        ierr: *mut c_int,
+    );
+
+    fn zbesk_(
+        zr: *const c_double,
+        zi: *const c_double,
+        fnu: *const c_double,
+        kode: *const c_int,
+        n: *const c_int,
+        cyr: *mut c_double,
+        cyi: *mut c_double,
+        nz: *mut c_int,
+        ierr: *mut c_int,
+    );
+
+    fn zbesy_(
+        zr: *const c_double,
+        zi: *const c_double,
+        fnu: *const c_double,
+        kode: *const c_int,
+        n: *const c_int,
+        cyr: *mut c_double,
+        cyi: *mut c_double,
+        nz: *mut c_int,
+        cwrkr: *mut c_double,
+        cwrki: *mut c_double,
+        ierr: *mut c_int,
+    );
+}
+
+const ORDERS: [f64; 9] = [0.0, 0.3, 0.5, 1.0, 2.7, 10.0, 35.5, 90.0, 250.0];
+const MODULI: [f64; 8] = [1.0e-3, 0.2, 1.0, 2.5, 8.0, 20.0, 60.0, 300.0];
+const ANGLES: [f64; 7] = [0.0, 0.4, 1.2, FRAC_PI_2, 2.2, PI, -2.6];
+
+// The maximum number of ULPs between a member of the port and the library, relative to the largest
+// member in modulus
+const ULPS: f64 = 8.0;
+
+// Calls a library routine with the arguments cyr, cyi, nz and ierr for a sequence of n members,
+// returning the members and ierr
+fn library(
+    n: i32,
+    f: impl FnOnce(*mut f64, *mut f64, *mut c_int, *mut c_int),
+) -> (Vec<Complex<f64>>, i32) {
+    let mut cyr = vec![0.0; n as usize];
+    let mut cyi = vec![0.0; n as usize];
+    let mut nz: c_int = 0;
+    let mut ierr: c_int = 0;
+
+    f(cyr.as_mut_ptr(), cyi.as_mut_ptr(), &mut nz, &mut ierr);
+
+    let cy = cyr
+        .iter()
+        .zip(cyi.iter())
+        .map(|(&re, &im)| Complex::new(re, im))
+        .collect();
+
+    (cy, ierr)
+}
+
+fn assert_ulps(expected: &[Complex<f64>], actual: &[Complex<f64>], what: &str) {
+    let scale = expected.iter().map(|c| c.norm()).fold(0.0, f64::max);
+    let tol = ULPS * f64::EPSILON * scale;
+
+    for (e, a) in expected.iter().zip(actual.iter()) {
+        assert!(
+            (e.re - a.re).abs() <= tol && (e.im - a.im).abs() <= tol,
+            "{}: expected {}, got {}",
+            what,
+            e,
+            a
+        );
+    }
+}
+
+// The library routine and the Rust port of a sequence function of (nu, z, kode, n)
+type Sequence<'a> = &'a dyn Fn(f64, Complex<f64>, i32, i32) -> (Vec<Complex<f64>>, i32);
+type Wrapper<'a> = &'a dyn Fn(f64, Complex<f64>, i32, i32) -> Vec<Complex<f64>>;
+
+fn compare(lib: Sequence, rust: Wrapper, name: &str) {
+    let mut count = 0;
+
+    for &nu in ORDERS.iter() {
+        for &r in MODULI.iter() {
+            for &theta in ANGLES.iter() {
+                let z = Complex::from_polar(r, theta);
+
+                for kode in 1..=2 {
+                    for n in 1..=5 {
+                        let (expected, ierr) = lib(nu, z, kode, n);
+
+                        // The members are not computed on the errors other than a loss of significance
+                        if ierr != 0 && ierr != 3 {
+                            continue;
+                        }
+
+                        let actual = rust(nu, z, kode, n);
+                        let what = format!(
+                            "{}(nu = {}, z = {}, kode = {}, n = {})",
+                            name, nu, z, kode, n
+                        );
+                        assert_ulps(&expected, &actual, &what);
+                        count += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    assert!(count > 0);
+}
+
+#[test]
+fn test_zbesh_against_library() {
+    for m in 1..=2 {
+        let lib = |nu: f64, z: Complex<f64>, kode: i32, n: i32| {
+            library(n, |cyr, cyi, nz, ierr| unsafe {
+                zbesh_(&z.re, &z.im, &nu, &kode, &m, &n, cyr, cyi, nz, ierr)
+            })
+        };
+        let rust = |nu, z, kode, n| zbesh(nu, z, kode, m, n);
+
+        compare(&lib, &rust, &format!("zbesh(m = {})", m));
+    }
+}
+
+#[test]
+fn test_zbesi_against_library() {
+    let lib = |nu: f64, z: Complex<f64>, kode: i32, n: i32| {
+        library(n, |cyr, cyi, nz, ierr| unsafe {
+            zbesi_(&z.re, &z.im, &nu, &kode, &n, cyr, cyi, nz, ierr)
+        })
+    };
+
+    compare(&lib, &zbesi_vec, "zbesi");
+}
+
+#[test]
+fn test_zbesj_against_library() {
+    let lib = |nu: f64, z: Complex<f64>, kode: i32, n: i32| {
+        library(n, |cyr, cyi, nz, ierr| unsafe {
+            zbesj_(&z.re, &z.im, &nu, &kode, &n, cyr, cyi, nz, ierr)
+        })
+    };
+
+    compare(&lib, &zbesj_vec, "zbesj");
+}
+
+#[test]
+fn test_zbesk_against_library() {
+    let lib = |nu: f64, z: Complex<f64>, kode: i32, n: i32| {
+        library(n, |cyr, cyi, nz, ierr| unsafe {
+            zbesk_(&z.re, &z.im, &nu, &kode, &n, cyr, cyi, nz, ierr)
+        })
+    };
+
+    compare(&lib, &zbesk_vec, "zbesk");
+}
+
+#[test]
+fn test_zbesy_against_library() {
+    let lib = |nu: f64, z: Complex<f64>, kode: i32, n: i32| {
+        let mut cwrkr = vec![0.0; n as usize];
+        let mut cwrki = vec![0.0; n as usize];
+
+        library(n, |cyr, cyi, nz, ierr| unsafe {
+            zbesy_(
+                &z.re,
+                &z.im,
+                &nu,
+                &kode,
+                &n,
+                cyr,
+                cyi,
+                nz,
+                cwrkr.as_mut_ptr(),
+                cwrki.as_mut_ptr(),
+                ierr,
+            )
+        })
+    };
+
+    compare(&lib, &zbesy_vec, "zbesy");
+}
@@ -0,0 +1,27 @@
+// With the static-fortran-runtime feature the test binary itself should not depend on the shared
+// Fortran runtime libraries. On Linux the names of the shared libraries a binary depends on are
+// stored as strings in its dynamic section, e.g., "libgfortran.so.5".
+#![cfg(all(feature = "static-fortran-runtime", target_os = "linux"))]
+
+use amos_rs::zbesi::iv_real;
+use std::fs;
+
+#[test]
+fn test_no_dynamic_fortran_runtime() {
+    // Call into AMOS so that the Fortran code is linked into the test binary
+    assert!(iv_real(0.0, 1.0) > 1.0);
+
+    let exe = std::env::current_exe().unwrap();
+    let bytes = fs::read(&exe).unwrap();
+
+    for lib in ["libgfortran.so", "libquadmath.so"] {
+        assert!(
+            !bytes
+                .windows(lib.len())
+                .any(|window| window == lib.as_bytes()),
+            "{} depends on {}",
+            exe.display(),
+            lib
+        );
+    }
+}
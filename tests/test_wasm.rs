@@ -0,0 +1,116 @@
+// Runs the comparisons against tests/dat for the functions exported to JavaScript, built for
+// wasm32 with the wasm-bindgen feature. The data is included at compile time as there is no file
+// system, and the tests are run under node by wasm-bindgen-test-runner with
+// `cargo test --target wasm32-unknown-unknown --features wasm-bindgen --test test_wasm`.
+#![cfg(all(target_arch = "wasm32", feature = "wasm-bindgen"))]
+
+mod common;
+
+use amos_rs::wasm;
+use common::{relative_error, Case};
+use num::complex::Complex;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+// The names and contents of the data files
+type Data = (&'static str, &'static str);
+
+const ZBESH: Data = ("tests/dat/zbesh.csv", include_str!("dat/zbesh.csv"));
+const ZBESI: Data = ("tests/dat/zbesi.csv", include_str!("dat/zbesi.csv"));
+
+fn cases((name, contents): Data, function: &str) -> Vec<Case> {
+    let cases: Vec<Case> = common::parse(name, contents)
+        .into_iter()
+        .filter(|case| case.function == function)
+        .collect();
+    assert!(!cases.is_empty());
+
+    cases
+}
+
+// Compares the members n_index of the sequences against the last member of the sequence of
+// n_index + 1 members returned as interleaved real and imaginary parts
+fn test_sequence(f: fn(f64, f64, f64, i32) -> Vec<f64>, data: Data, function: &str) {
+    for case in cases(data, function) {
+        let n = case.n_index as i32 + 1;
+        let cy = f(case.nu, case.z.re, case.z.im, n);
+
+        assert_eq!(cy.len(), 2 * n as usize);
+
+        let cy = Complex::new(cy[cy.len() - 2], cy[cy.len() - 1]);
+        assert!(
+            relative_error(cy, case.expected) <= case.tolerance,
+            "{case:?}: {cy}"
+        );
+    }
+}
+
+// Compares the first members of the sequences
+fn test_complex(f: fn(f64, f64, f64) -> wasm::Complex64, data: Data, function: &str) {
+    for case in cases(data, function)
+        .iter()
+        .filter(|case| case.n_index == 0)
+    {
+        let cy = f(case.nu, case.z.re, case.z.im);
+        let cy = Complex::new(cy.re, cy.im);
+
+        assert!(
+            relative_error(cy, case.expected) <= case.tolerance,
+            "{case:?}: {cy}"
+        );
+    }
+}
+
+// Compares the first members of the sequences of real arguments
+fn test_real(f: fn(f64, f64) -> f64, data: Data, function: &str) {
+    for case in cases(data, function)
+        .iter()
+        .filter(|case| case.n_index == 0)
+    {
+        let cy = Complex::new(f(case.nu, case.z.re), 0.0);
+
+        assert!(
+            relative_error(cy, case.expected) <= case.tolerance,
+            "{case:?}: {cy}"
+        );
+    }
+}
+
+#[wasm_bindgen_test]
+fn test_hankel1() {
+    test_sequence(wasm::hankel1, ZBESH, "hankel1");
+}
+
+#[wasm_bindgen_test]
+fn test_hankel1e() {
+    test_sequence(wasm::hankel1e, ZBESH, "hankel1e");
+}
+
+#[wasm_bindgen_test]
+fn test_hankel2() {
+    test_sequence(wasm::hankel2, ZBESH, "hankel2");
+}
+
+#[wasm_bindgen_test]
+fn test_hankel2e() {
+    test_sequence(wasm::hankel2e, ZBESH, "hankel2e");
+}
+
+#[wasm_bindgen_test]
+fn test_iv() {
+    test_complex(wasm::iv, ZBESI, "iv");
+}
+
+#[wasm_bindgen_test]
+fn test_ive() {
+    test_complex(wasm::ive, ZBESI, "ive");
+}
+
+#[wasm_bindgen_test]
+fn test_iv_real() {
+    test_real(wasm::iv_real, ZBESI, "iv_real");
+}
+
+#[wasm_bindgen_test]
+fn test_ive_real() {
+    test_real(wasm::ive_real, ZBESI, "ive_real");
+}
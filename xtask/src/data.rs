@@ -0,0 +1,200 @@
+// Reads and writes the points and the test data, in the format of tests/common/mod.rs. The floats
+// are written with the shortest representation that parses back to the same double, such that
+// reference values can be matched to the points exactly.
+use crate::sampling::Point;
+use num::complex::Complex;
+use std::collections::HashMap;
+use std::io::{self, Write};
+
+pub const POINT_COLUMNS: &str = "function,nu,n_index,z_re,z_im";
+pub const COLUMNS: &str = "function,nu,n_index,z_re,z_im,expected_re,expected_im,tolerance,source";
+
+pub fn write_points(out: &mut impl Write, points: &[Point]) -> io::Result<()> {
+    writeln!(out, "{POINT_COLUMNS}")?;
+
+    for point in points {
+        writeln!(
+            out,
+            "{},{:e},{},{:e},{:e}",
+            point.function, point.nu, point.n_index, point.z.re, point.z.im
+        )?;
+    }
+
+    Ok(())
+}
+
+pub struct Row<'a> {
+    pub point: &'a Point,
+    pub expected: Complex<f64>,
+    pub source: &'a str,
+}
+
+pub fn write_test_data(out: &mut impl Write, rows: &[Row], tolerance: f64) -> io::Result<()> {
+    writeln!(out, "{COLUMNS}")?;
+
+    for row in rows {
+        let point = row.point;
+
+        writeln!(
+            out,
+            "{},{:e},{},{:e},{:e},{:e},{:e},{tolerance:e},{}",
+            point.function,
+            point.nu,
+            point.n_index,
+            point.z.re,
+            point.z.im,
+            row.expected.re,
+            row.expected.im,
+            row.source
+        )?;
+    }
+
+    Ok(())
+}
+
+// The exact order, index and argument of a point
+type Key = (String, u64, usize, u64, u64);
+
+fn key(function: &str, nu: f64, n_index: usize, z: Complex<f64>) -> Key {
+    let zero = |x: f64| x + 0.0;
+
+    // -0 and 0 are the same point
+    (
+        function.to_string(),
+        zero(nu).to_bits(),
+        n_index,
+        zero(z.re).to_bits(),
+        zero(z.im).to_bits(),
+    )
+}
+
+// Reference values by point, read from a CSV file with at least the columns of the points and
+// expected_re and expected_im, e.g., test data or the output of
+// create-test-data/create_mpmath_test_data.py. The source column is optional, and defaults to
+// default_source.
+pub struct Reference {
+    values: HashMap<Key, (Complex<f64>, String)>,
+}
+
+impl Reference {
+    pub fn parse(contents: &str, default_source: &str) -> Result<Self, String> {
+        let mut lines = contents.trim().lines();
+
+        let header: Vec<&str> = lines
+            .next()
+            .ok_or("missing header")?
+            .split(',')
+            .map(str::trim)
+            .collect();
+
+        let column = |name: &str| header.iter().position(|&h| h == name);
+        let required = |name: &str| column(name).ok_or(format!("missing column {name}"));
+
+        let index = [
+            required("function")?,
+            required("nu")?,
+            required("n_index")?,
+            required("z_re")?,
+            required("z_im")?,
+            required("expected_re")?,
+            required("expected_im")?,
+        ];
+        let source = column("source");
+
+        let mut values = HashMap::new();
+
+        for (i, line) in lines.enumerate() {
+            // The header is line 1
+            let line_number = i + 2;
+            let row: Vec<&str> = line.split(',').map(str::trim).collect();
+
+            if row.len() != header.len() {
+                return Err(format!("line {line_number}: wrong number of columns"));
+            }
+
+            let float = |j: usize| -> Result<f64, String> {
+                let field = row[index[j]];
+
+                field
+                    .parse()
+                    .map_err(|_| format!("line {line_number}: invalid number {field}"))
+            };
+
+            let n_index = row[index[2]]
+                .parse()
+                .map_err(|_| format!("line {line_number}: invalid n_index {}", row[index[2]]))?;
+
+            let key = key(
+                row[index[0]],
+                float(1)?,
+                n_index,
+                Complex::new(float(3)?, float(4)?),
+            );
+            let source = source.map_or(default_source, |j| row[j]).to_string();
+
+            values.insert(key, (Complex::new(float(5)?, float(6)?), source));
+        }
+
+        Ok(Self { values })
+    }
+
+    pub fn get(&self, point: &Point) -> Option<(Complex<f64>, &str)> {
+        self.values
+            .get(&key(point.function, point.nu, point.n_index, point.z))
+            .map(|(expected, source)| (*expected, source.as_str()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::functions::ALL;
+    use crate::sampling::Sampling;
+
+    #[test]
+    fn test_reference() {
+        let sampling = Sampling {
+            functions: ALL.to_vec(),
+            ..Sampling::default()
+        };
+        let points = sampling.points();
+
+        let rows: Vec<Row> = points
+            .iter()
+            .enumerate()
+            .map(|(i, point)| Row {
+                point,
+                expected: Complex::new(i as f64, -1.0 / (i + 1) as f64),
+                source: "test",
+            })
+            .collect();
+
+        let mut out = Vec::new();
+        write_test_data(&mut out, &rows, 1e-13).unwrap();
+
+        // The points are read back exactly
+        let reference = Reference::parse(&String::from_utf8(out).unwrap(), "default").unwrap();
+
+        for row in &rows {
+            assert_eq!(reference.get(row.point), Some((row.expected, "test")));
+        }
+
+        let point = Point {
+            function: "iv",
+            nu: 0.5,
+            n_index: 0,
+            z: Complex::new(1.0, -0.0),
+        };
+        let reference = Reference::parse(
+            "nu,function,z_re,z_im,n_index,expected_re,expected_im\n0.5,iv,1,0,0,2,3",
+            "mpmath",
+        )
+        .unwrap();
+
+        assert_eq!(
+            reference.get(&point),
+            Some((Complex::new(2.0, 3.0), "mpmath"))
+        );
+        assert!(Reference::parse("function,nu\niv,0", "mpmath").is_err());
+    }
+}
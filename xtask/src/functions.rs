@@ -0,0 +1,110 @@
+// The functions that test data can be generated for, by the names in the function column of
+// tests/dat, see tests/common/mod.rs
+use amos_rs::{zairy, zbesh, zbesi, zbesj, zbesk, zbesy};
+use num::complex::Complex;
+
+type NamedVec = fn(f64, Complex<f64>, i32) -> Vec<Complex<f64>>;
+type RealVec = fn(f64, f64, i32) -> Vec<f64>;
+
+#[derive(Clone, Copy)]
+pub enum Kind {
+    Sequence(NamedVec),
+    // The functions of real arguments, which are real on the negative real axis only for integer
+    // orders if negative_axis is set, and never otherwise
+    RealSequence(RealVec, bool),
+    Airy(fn(Complex<f64>) -> Complex<f64>),
+}
+
+#[derive(Clone, Copy)]
+pub struct Function {
+    pub name: &'static str,
+    pub kind: Kind,
+}
+
+pub const ALL: [Function; 28] = [
+    Function::sequence("iv", zbesi::iv_vec),
+    Function::sequence("ive", zbesi::ive_vec),
+    Function::sequence("jv", zbesj::jv_vec),
+    Function::sequence("jve", zbesj::jve_vec),
+    Function::sequence("kv", zbesk::kv_vec),
+    Function::sequence("kve", zbesk::kve_vec),
+    Function::sequence("yv", zbesy::yv_vec),
+    Function::sequence("yve", zbesy::yve_vec),
+    Function::sequence("hankel1", zbesh::hankel1),
+    Function::sequence("hankel1e", zbesh::hankel1e),
+    Function::sequence("hankel2", zbesh::hankel2),
+    Function::sequence("hankel2e", zbesh::hankel2e),
+    Function::real("iv_real", zbesi::iv_real_vec, true),
+    Function::real("ive_real", zbesi::ive_real_vec, true),
+    Function::real("jv_real", zbesj::jv_real_vec, true),
+    Function::real("jve_real", zbesj::jve_real_vec, true),
+    Function::real("kv_real", zbesk::kv_real_vec, false),
+    Function::real("kve_real", zbesk::kve_real_vec, false),
+    Function::real("yv_real", zbesy::yv_real_vec, false),
+    Function::real("yve_real", zbesy::yve_real_vec, false),
+    Function::airy("ai", zairy::ai),
+    Function::airy("aie", zairy::aie),
+    Function::airy("aip", zairy::aip),
+    Function::airy("aipe", zairy::aipe),
+    Function::airy("bi", zairy::bi),
+    Function::airy("bie", zairy::bie),
+    Function::airy("bip", zairy::bip),
+    Function::airy("bipe", zairy::bipe),
+];
+
+impl Function {
+    const fn sequence(name: &'static str, f: NamedVec) -> Self {
+        Self {
+            name,
+            kind: Kind::Sequence(f),
+        }
+    }
+
+    const fn real(name: &'static str, f: RealVec, negative_axis: bool) -> Self {
+        Self {
+            name,
+            kind: Kind::RealSequence(f, negative_axis),
+        }
+    }
+
+    const fn airy(name: &'static str, f: fn(Complex<f64>) -> Complex<f64>) -> Self {
+        Self {
+            name,
+            kind: Kind::Airy(f),
+        }
+    }
+
+    pub fn find(name: &str) -> Option<Self> {
+        ALL.iter().copied().find(|function| function.name == name)
+    }
+
+    // The member n_index of the sequence starting at order nu, see tests/common/mod.rs
+    pub fn evaluate(&self, nu: f64, n_index: usize, z: Complex<f64>) -> Complex<f64> {
+        let n = n_index as i32 + 1;
+
+        match self.kind {
+            Kind::Sequence(f) => f(nu, z, n)[n_index],
+            Kind::RealSequence(f, _) => Complex::new(f(nu, z.re, n)[n_index], 0.0),
+            Kind::Airy(f) => f(z),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_evaluate() {
+        let z = Complex::new(0.5, -1.5);
+        let iv = Function::find("iv").unwrap();
+
+        assert_eq!(iv.evaluate(1.5, 2, z), zbesi::iv(3.5, z));
+        assert_eq!(iv.evaluate(-1.5, 2, z), zbesi::iv(-3.5, z));
+        assert_eq!(
+            Function::find("ai").unwrap().evaluate(0.0, 0, z),
+            zairy::ai(z)
+        );
+        assert!(Function::find("zbesi").is_none());
+    }
+}
@@ -0,0 +1,236 @@
+// Generates the test data in tests/dat without Python. The sampling points are drawn
+// deterministically from the seed, see sampling.rs, with configurable fractions of negative and
+// integer orders, of arguments near the real axis and of large |z|. They are written with
+//
+//     cargo xtask points --functions iv,ive --out points.csv
+//
+// for reference values to be computed elsewhere, e.g., with mpmath by
+// create-test-data/create_mpmath_test_data.py points.csv reference.csv, and then combined into test
+// data with the same options:
+//
+//     cargo xtask test-data --functions iv,ive --reference reference.csv --out tests/dat/zbesi.csv
+//
+// Without a reference file, the expected values are computed by the crate itself, which only
+// guards against regressions.
+mod data;
+mod functions;
+mod sampling;
+
+use data::{Reference, Row};
+use functions::Function;
+use sampling::Sampling;
+use std::env;
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::PathBuf;
+use std::process;
+
+const USAGE: &str = "Usage: xtask points|test-data [--out FILE] [--functions NAME,...] [--seed N] \
+[--count N] [--n N] [--max-nu NU] [--negative FRACTION] [--integer FRACTION] \
+[--near-axis FRACTION] [--large FRACTION] [--abs-z MIN,MAX] [--large-abs-z MIN,MAX] \
+[--reference FILE] [--tolerance TOL]";
+
+#[derive(Debug, PartialEq)]
+enum Command {
+    Points,
+    TestData,
+}
+
+struct Options {
+    command: Command,
+    sampling: Sampling,
+    // Standard output if not given
+    out: Option<PathBuf>,
+    reference: Option<PathBuf>,
+    tolerance: f64,
+}
+
+fn parse_list(value: &str) -> Option<Vec<f64>> {
+    value.split(',').map(|v| v.trim().parse().ok()).collect()
+}
+
+fn parse_range(value: &str) -> Option<(f64, f64)> {
+    match parse_list(value)?.as_slice() {
+        &[min, max] if 0.0 < min && min < max => Some((min, max)),
+        _ => None,
+    }
+}
+
+fn parse_fraction(value: &str) -> Option<f64> {
+    value.parse().ok().filter(|f| (0.0..=1.0).contains(f))
+}
+
+fn parse_args(args: &[String]) -> Option<Options> {
+    let mut args = args.iter();
+
+    let command = match args.next()?.as_str() {
+        "points" => Command::Points,
+        "test-data" => Command::TestData,
+        _ => return None,
+    };
+
+    let mut options = Options {
+        command,
+        sampling: Sampling::default(),
+        out: None,
+        reference: None,
+        tolerance: 1e-12,
+    };
+    let sampling = &mut options.sampling;
+
+    while let Some(arg) = args.next() {
+        let value = args.next()?;
+
+        match arg.as_str() {
+            "--out" => options.out = Some(PathBuf::from(value)),
+            "--functions" => {
+                sampling.functions = value
+                    .split(',')
+                    .map(|name| Function::find(name.trim()))
+                    .collect::<Option<_>>()?
+            }
+            "--seed" => sampling.seed = value.parse().ok()?,
+            "--count" => sampling.count = value.parse().ok()?,
+            "--n" => sampling.n = value.parse().ok().filter(|&n| n > 0)?,
+            "--max-nu" => sampling.max_nu = value.parse().ok().filter(|&nu| nu > 0.0)?,
+            "--negative" => sampling.negative = parse_fraction(value)?,
+            "--integer" => sampling.integer = parse_fraction(value)?,
+            "--near-axis" => sampling.near_axis = parse_fraction(value)?,
+            "--large" => sampling.large = parse_fraction(value)?,
+            "--abs-z" => sampling.abs_z = parse_range(value)?,
+            "--large-abs-z" => sampling.large_abs_z = parse_range(value)?,
+            "--reference" => options.reference = Some(PathBuf::from(value)),
+            "--tolerance" => options.tolerance = value.parse().ok().filter(|&tol| tol > 0.0)?,
+            _ => return None,
+        }
+    }
+
+    Some(options)
+}
+
+fn fail(message: String) -> ! {
+    eprintln!("{message}");
+    process::exit(1);
+}
+
+fn write_test_data(options: &Options, out: &mut impl Write) -> io::Result<()> {
+    let points = options.sampling.points();
+
+    let reference = options.reference.as_ref().map(|path| {
+        let contents = fs::read_to_string(path)
+            .unwrap_or_else(|err| fail(format!("Failed to read {}: {err}", path.display())));
+        let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+
+        Reference::parse(&contents, &stem)
+            .unwrap_or_else(|err| fail(format!("{}: {err}", path.display())))
+    });
+
+    let mut rows = Vec::with_capacity(points.len());
+    let mut missing = 0;
+    let mut not_finite = 0;
+
+    for point in &points {
+        let (expected, source) = match &reference {
+            Some(reference) => match reference.get(point) {
+                Some(value) => value,
+                None => {
+                    missing += 1;
+                    continue;
+                }
+            },
+            None => (
+                Function::find(point.function)
+                    .unwrap()
+                    .evaluate(point.nu, point.n_index, point.z),
+                "amos-rs",
+            ),
+        };
+
+        // The test data only holds finite values
+        if !expected.is_finite() {
+            not_finite += 1;
+            continue;
+        }
+
+        rows.push(Row {
+            point,
+            expected,
+            source,
+        });
+    }
+
+    if missing > 0 {
+        fail(format!(
+            "{missing} of {} points are missing from the reference, which must be computed for \
+             the points of the same options",
+            points.len()
+        ));
+    }
+
+    if not_finite > 0 {
+        eprintln!("Skipped {not_finite} points where the expected value is not finite");
+    }
+
+    data::write_test_data(out, &rows, options.tolerance)
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let options = parse_args(&args).unwrap_or_else(|| {
+        eprintln!("{USAGE}");
+        process::exit(2);
+    });
+
+    let mut out: BufWriter<Box<dyn Write>> = BufWriter::new(match &options.out {
+        Some(path) => Box::new(
+            File::create(path)
+                .unwrap_or_else(|err| fail(format!("Failed to create {}: {err}", path.display()))),
+        ),
+        None => Box::new(io::stdout()),
+    });
+
+    let result = match options.command {
+        Command::Points => data::write_points(&mut out, &options.sampling.points()),
+        Command::TestData => write_test_data(&options, &mut out),
+    };
+
+    result
+        .and_then(|_| out.flush())
+        .unwrap_or_else(|err| fail(format!("Failed to write: {err}")));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_args() {
+        let options = parse_args(&args(&[
+            "points",
+            "--functions",
+            "iv,hankel1e",
+            "--negative",
+            "0.5",
+            "--abs-z",
+            "0.1,2",
+        ]))
+        .unwrap();
+
+        assert_eq!(options.command, Command::Points);
+        assert_eq!(options.sampling.functions.len(), 2);
+        assert_eq!(options.sampling.functions[1].name, "hankel1e");
+        assert_eq!(options.sampling.negative, 0.5);
+        assert_eq!(options.sampling.abs_z, (0.1, 2.0));
+
+        assert!(parse_args(&args(&["test-data", "--functions", "iv,zbesi"])).is_none());
+        assert!(parse_args(&args(&["test-data", "--negative", "2"])).is_none());
+        assert!(parse_args(&args(&["test-data", "--abs-z", "0,1"])).is_none());
+        assert!(parse_args(&args(&["points", "--out"])).is_none());
+        assert!(parse_args(&args(&["--out", "points.csv"])).is_none());
+    }
+}
@@ -0,0 +1,275 @@
+// Deterministic sampling of orders and arguments. The generator is SplitMix64, implemented here
+// rather than taken from a crate, such that the points only depend on the seed and the options, and
+// not on the version of a dependency. Each function is sampled from its own stream, seeded by the
+// seed and the name of the function, so the points of a function do not change when others are
+// added to or removed from the list.
+use crate::functions::{Function, Kind};
+use num::complex::Complex;
+use std::f64::consts::PI;
+
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    // The stream of a function, where the name is hashed with FNV-1a
+    pub fn for_function(seed: u64, name: &str) -> Self {
+        let hash = name.bytes().fold(0xcbf29ce484222325_u64, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+        });
+
+        Self::new(seed ^ hash)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+
+        z ^ (z >> 31)
+    }
+
+    // Uniform in [0, 1) with 53 random bits
+    pub fn uniform(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1_u64 << 53) as f64
+    }
+
+    pub fn range(&mut self, min: f64, max: f64) -> f64 {
+        min + (max - min) * self.uniform()
+    }
+
+    pub fn log_range(&mut self, min: f64, max: f64) -> f64 {
+        10.0_f64.powf(self.range(min.log10(), max.log10()))
+    }
+
+    pub fn chance(&mut self, probability: f64) -> bool {
+        self.uniform() < probability
+    }
+}
+
+pub struct Sampling {
+    pub functions: Vec<Function>,
+    pub seed: u64,
+    // The number of sequences per function, and the number of members of each
+    pub count: usize,
+    pub n: usize,
+    // The magnitude of the starting orders is uniform in [0, max_nu)
+    pub max_nu: f64,
+    // The fractions of negative and of integer starting orders
+    pub negative: f64,
+    pub integer: f64,
+    // The fraction of arguments within arg z = 1e-8 to 1e-2 of the positive or negative real axis,
+    // and the fraction with |z| log-uniform in large_abs_z, whereas the others have |z| log-uniform
+    // in abs_z
+    pub near_axis: f64,
+    pub large: f64,
+    pub abs_z: (f64, f64),
+    pub large_abs_z: (f64, f64),
+}
+
+impl Default for Sampling {
+    fn default() -> Self {
+        Self {
+            functions: ["iv", "jv", "kv", "yv", "hankel1", "hankel2"]
+                .iter()
+                .map(|name| Function::find(name).unwrap())
+                .collect(),
+            seed: 2022,
+            count: 8,
+            n: 3,
+            max_nu: 10.0,
+            negative: 0.25,
+            integer: 0.25,
+            near_axis: 0.2,
+            large: 0.2,
+            abs_z: (0.01, 20.0),
+            // The losses of significance by argument reduction grow with |z|, see amos/zbesi.f
+            // lines 92-98
+            large_abs_z: (20.0, 1000.0),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Point {
+    pub function: &'static str,
+    pub nu: f64,
+    pub n_index: usize,
+    pub z: Complex<f64>,
+}
+
+impl Sampling {
+    pub fn points(&self) -> Vec<Point> {
+        self.functions
+            .iter()
+            .flat_map(|function| self.function_points(function))
+            .collect()
+    }
+
+    fn function_points(&self, function: &Function) -> Vec<Point> {
+        let mut rng = Rng::for_function(self.seed, function.name);
+        let mut points = Vec::new();
+
+        for _ in 0..self.count {
+            let (nu, n) = match function.kind {
+                Kind::Airy(_) => (0.0, 1),
+                _ => (self.order(&mut rng), self.n),
+            };
+
+            let mut z = self.argument(&mut rng);
+
+            // Only the real axis, where the negative part gives complex values unless the order is
+            // an integer, and for K and Y always
+            if let Kind::RealSequence(_, negative_axis) = function.kind {
+                let negative = negative_axis && nu.fract() == 0.0 && rng.chance(0.5);
+
+                z = Complex::new(
+                    match negative {
+                        true => -z.norm(),
+                        false => z.norm(),
+                    },
+                    0.0,
+                );
+            }
+
+            points.extend((0..n).map(|n_index| Point {
+                function: function.name,
+                nu,
+                n_index,
+                z,
+            }));
+        }
+
+        points
+    }
+
+    fn order(&self, rng: &mut Rng) -> f64 {
+        let mut nu = rng.range(0.0, self.max_nu);
+
+        if rng.chance(self.integer) {
+            nu = nu.floor();
+        }
+
+        match rng.chance(self.negative) && nu != 0.0 {
+            true => -nu,
+            false => nu,
+        }
+    }
+
+    fn argument(&self, rng: &mut Rng) -> Complex<f64> {
+        let near_axis = rng.chance(self.near_axis);
+
+        let r = match rng.chance(self.large) {
+            true => rng.log_range(self.large_abs_z.0, self.large_abs_z.1),
+            false => rng.log_range(self.abs_z.0, self.abs_z.1),
+        };
+
+        let theta = match near_axis {
+            true => {
+                let theta = rng.log_range(1e-8, 1e-2);
+                let theta = match rng.chance(0.5) {
+                    true => PI - theta,
+                    false => theta,
+                };
+
+                match rng.chance(0.5) {
+                    true => -theta,
+                    false => theta,
+                }
+            }
+            false => rng.range(-PI, PI),
+        };
+
+        Complex::from_polar(r, theta)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rng() {
+        // The first outputs of SplitMix64 for seed 0
+        let mut rng = Rng::new(0);
+
+        assert_eq!(rng.next_u64(), 0xe220a8397b1dcdaf);
+        assert_eq!(rng.next_u64(), 0x6e789e6aa1b965f4);
+
+        for _ in 0..1000 {
+            let u = rng.uniform();
+            assert!((0.0..1.0).contains(&u));
+        }
+    }
+
+    #[test]
+    fn test_points() {
+        let sampling = Sampling {
+            count: 1000,
+            ..Sampling::default()
+        };
+        let points = sampling.points();
+
+        assert_eq!(points, sampling.points());
+        assert_eq!(points.len(), 6 * 1000 * 3);
+
+        // The sequences share the order and argument
+        for sequence in points.chunks(3) {
+            assert!(sequence.iter().all(|point| point.nu == sequence[0].nu));
+            assert!(sequence.iter().all(|point| point.z == sequence[0].z));
+            assert_eq!(sequence[2].n_index, 2);
+        }
+
+        let fraction = |f: &dyn Fn(&Point) -> bool| {
+            points.iter().filter(|point| f(point)).count() as f64 / points.len() as f64
+        };
+
+        assert!((fraction(&|point| point.nu < 0.0) - 0.25).abs() < 0.05);
+        assert!((fraction(&|point| point.z.norm() > 20.0) - 0.2).abs() < 0.05);
+        assert!((fraction(&|point| point.z.im.abs() < 1e-2 * point.z.norm()) - 0.2).abs() < 0.05);
+    }
+
+    #[test]
+    fn test_points_per_function() {
+        let iv = Function::find("iv").unwrap();
+        let kv = Function::find("kv").unwrap();
+
+        let both = Sampling {
+            functions: vec![iv, kv],
+            ..Sampling::default()
+        };
+        let only = Sampling {
+            functions: vec![kv],
+            ..Sampling::default()
+        };
+
+        assert_eq!(both.points()[8 * 3..], only.points());
+    }
+
+    #[test]
+    fn test_real_points() {
+        let sampling = Sampling {
+            functions: vec![
+                Function::find("iv_real").unwrap(),
+                Function::find("kv_real").unwrap(),
+            ],
+            count: 200,
+            ..Sampling::default()
+        };
+
+        for point in sampling.points() {
+            assert_eq!(point.z.im, 0.0);
+
+            if point.z.re < 0.0 {
+                assert_eq!(point.function, "iv_real");
+                assert_eq!(point.nu.fract(), 0.0);
+            }
+        }
+    }
+}